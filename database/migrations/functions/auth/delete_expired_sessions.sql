@@ -0,0 +1,10 @@
+-- Deletes all expired sessions and returns the number of sessions deleted.
+create or replace function delete_expired_sessions()
+returns bigint as $$
+    with deleted as (
+        delete from session
+        where expires_at < current_timestamp
+        returning 1
+    )
+    select count(*) from deleted;
+$$ language sql;
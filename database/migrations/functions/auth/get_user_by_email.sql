@@ -3,24 +3,31 @@ create or replace function get_user_by_email(p_email text)
 returns table(
     user_id uuid,
     auth_hash bytea,
+    admin boolean,
     email text,
     email_verified boolean,
     has_password boolean,
     has_profile boolean,
     moderator boolean,
     name text,
+    suspended boolean,
     username text,
     password text
 ) as $$
     select
         u.user_id,
         u.auth_hash,
+        u.admin,
         u.email,
         u.email_verified,
         u.password is not null as has_password,
         p.job_seeker_profile_id is not null as has_profile,
         u.moderator,
         u.name,
+        (
+            u.suspended_at is not null
+            and (u.suspended_until is null or u.suspended_until > current_timestamp)
+        ) as suspended,
         u.username,
         null::text as password
     from "user" u
@@ -14,6 +14,12 @@ begin
     and created_by = p_user_id;
     if found then return true; end if;
 
+    -- Profile photo or employer logo: user uploaded the same image
+    perform from image_upload
+    where image_id = p_image_id
+    and user_id = p_user_id;
+    if found then return true; end if;
+
     -- Profile photo: applied to a employer's job
     perform from job_seeker_profile p
     join application a on p.job_seeker_profile_id = a.job_seeker_profile_id
@@ -0,0 +1,21 @@
+-- Marks the email of the user provided as verified, without requiring a code.
+create or replace function verify_user_email(p_email text)
+returns uuid as $$
+declare
+    v_user_id uuid;
+begin
+    update "user"
+    set email_verified = true
+    where email = p_email
+    returning user_id into v_user_id;
+
+    if v_user_id is null then
+        raise exception 'no user found with that email';
+    end if;
+
+    -- Remove any pending verification code, as it is no longer needed
+    delete from email_verification_code where user_id = v_user_id;
+
+    return v_user_id;
+end
+$$ language plpgsql;
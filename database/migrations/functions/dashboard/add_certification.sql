@@ -0,0 +1,36 @@
+-- Adds a new certification to the catalogue.
+create or replace function add_certification(p_certification jsonb)
+returns uuid as $$
+declare
+    v_certification_id uuid;
+begin
+    -- Names and short names must be unique across the catalogue
+    if exists (
+        select 1
+        from certification
+        where name = p_certification->>'name'
+        or short_name = p_certification->>'short_name'
+    ) then
+        raise exception 'certification name or short name already in use';
+    end if;
+
+    insert into certification (
+        description,
+        logo_url,
+        name,
+        provider,
+        short_name,
+        url
+    ) values (
+        p_certification->>'description',
+        p_certification->>'logo_url',
+        p_certification->>'name',
+        p_certification->>'provider',
+        p_certification->>'short_name',
+        p_certification->>'url'
+    )
+    returning certification_id into v_certification_id;
+
+    return v_certification_id;
+end
+$$ language plpgsql;
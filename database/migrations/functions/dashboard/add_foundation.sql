@@ -0,0 +1,18 @@
+-- Adds a new foundation.
+create or replace function add_foundation(p_foundation jsonb)
+returns uuid as $$
+declare
+    v_foundation_id uuid;
+begin
+    -- Foundation names are referenced by projects and members
+    if exists (select 1 from foundation where name = p_foundation->>'name') then
+        raise exception 'foundation already exists';
+    end if;
+
+    insert into foundation (landscape_url, name)
+    values (p_foundation->>'landscape_url', p_foundation->>'name')
+    returning foundation_id into v_foundation_id;
+
+    return v_foundation_id;
+end
+$$ language plpgsql;
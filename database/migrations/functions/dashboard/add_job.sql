@@ -31,7 +31,8 @@ begin
         skills,
         tz_end,
         tz_start,
-        upstream_commitment
+        upstream_commitment,
+        review_requested_at
     ) values (
         p_employer_id,
         p_job->>'kind',
@@ -70,7 +71,8 @@ begin
         ),
         p_job->>'tz_end',
         p_job->>'tz_start',
-        (p_job->>'upstream_commitment')::int
+        (p_job->>'upstream_commitment')::int,
+        case when p_job->>'status' = 'pending-approval' then current_timestamp end
     )
     returning job_id into v_job_id;
 
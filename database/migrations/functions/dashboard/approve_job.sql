@@ -2,17 +2,27 @@
 create or replace function approve_job(p_job_id uuid, p_reviewer uuid)
 returns timestamptz as $$
     with old as (
-        select first_published_at
+        select first_published_at, review_requested_at, status
         from job
         where job_id = p_job_id
+    ),
+    approved_job as (
+        update job
+        set
+            status = 'published',
+            first_published_at = coalesce(first_published_at, current_timestamp),
+            published_at = current_timestamp,
+            reviewed_at = current_timestamp,
+            reviewed_by = p_reviewer
+        where job_id = p_job_id
+        returning job_id, (select first_published_at from old) as previous_first_published_at
+    ),
+    moderation_action_entry as (
+        insert into moderation_action (kind, job_id, moderator_id, review_requested_at)
+        select 'approve-job', job_id, p_reviewer, (
+            select review_requested_at from old where status = 'pending-approval'
+        )
+        from approved_job
     )
-    update job
-    set
-        status = 'published',
-        first_published_at = coalesce(first_published_at, current_timestamp),
-        published_at = current_timestamp,
-        reviewed_at = current_timestamp,
-        reviewed_by = p_reviewer
-    where job_id = p_job_id
-    returning (select first_published_at from old);
+    select previous_first_published_at from approved_job;
 $$ language sql;
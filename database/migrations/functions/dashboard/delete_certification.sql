@@ -0,0 +1,17 @@
+-- Deletes a certification from the catalogue when no jobs reference it.
+create or replace function delete_certification(p_certification_id uuid)
+returns void as $$
+begin
+    if exists (
+        select 1 from job_certification where certification_id = p_certification_id
+    ) then
+        raise exception 'certification is required by some jobs and cannot be deleted';
+    end if;
+
+    delete from certification where certification_id = p_certification_id;
+
+    if not found then
+        raise exception 'certification not found';
+    end if;
+end
+$$ language plpgsql;
@@ -0,0 +1,90 @@
+-- Returns the analytics of the employer's jobs over the last days provided in
+-- json format: the funnel totals, the funnel of each job and the daily series.
+create or replace function get_employer_analytics(p_employer_id uuid, p_days int)
+returns json as $$
+    with
+    employer_jobs as (
+        select job_id, title, status
+        from job
+        where employer_id = p_employer_id
+        and status <> 'deleted'
+    ),
+    counters as (
+        select job_id, day, total as search_appearances, 0 as views, 0 as apply_clicks, 0 as applications
+        from search_appearances
+        where day > current_date - p_days
+        union all
+        select job_id, day, 0, total, 0, 0
+        from job_views
+        where day > current_date - p_days
+        union all
+        select job_id, day, 0, 0, total, 0
+        from apply_clicks
+        where day > current_date - p_days
+        union all
+        select job_id, created_at::date, 0, 0, 0, 1
+        from application
+        where created_at::date > current_date - p_days
+    ),
+    daily as (
+        select
+            ej.job_id,
+            ej.title,
+            c.day,
+            sum(c.search_appearances)::bigint as search_appearances,
+            sum(c.views)::bigint as views,
+            sum(c.apply_clicks)::bigint as apply_clicks,
+            sum(c.applications)::bigint as applications
+        from counters c
+        join employer_jobs ej using (job_id)
+        group by ej.job_id, ej.title, c.day
+    ),
+    jobs as (
+        select
+            ej.job_id,
+            ej.title,
+            ej.status,
+            coalesce(sum(d.search_appearances), 0)::bigint as search_appearances,
+            coalesce(sum(d.views), 0)::bigint as views,
+            coalesce(sum(d.apply_clicks), 0)::bigint as apply_clicks,
+            coalesce(sum(d.applications), 0)::bigint as applications
+        from employer_jobs ej
+        left join daily d using (job_id)
+        group by ej.job_id, ej.title, ej.status
+    )
+    select json_build_object(
+        'daily', (
+            select coalesce(json_agg(json_build_object(
+                'applications', applications,
+                'apply_clicks', apply_clicks,
+                'day', day,
+                'job_id', job_id,
+                'search_appearances', search_appearances,
+                'title', title,
+                'views', views
+            ) order by day asc, title asc, job_id asc), '[]'::json)
+            from daily
+        ),
+        'jobs', (
+            select coalesce(json_agg(json_build_object(
+                'applications', applications,
+                'apply_clicks', apply_clicks,
+                'job_id', job_id,
+                'search_appearances', search_appearances,
+                'status', status,
+                'title', title,
+                'views', views
+            ) order by views desc, title asc, job_id asc), '[]'::json)
+            from jobs
+        ),
+        'totals', (
+            select json_build_object(
+                'applications', coalesce(sum(applications), 0),
+                'apply_clicks', coalesce(sum(apply_clicks), 0),
+                'search_appearances', coalesce(sum(search_appearances), 0),
+                'views', coalesce(sum(views), 0)
+            )
+            from jobs
+        )
+    );
+$$ language sql;
@@ -24,7 +24,12 @@ returns json as $$
                 'foundation', p.foundation,
                 'logo_url', p.logo_url,
                 'maturity', p.maturity,
-                'name', p.name
+                'name', p.name,
+                'category', p.category,
+                'description', p.description,
+                'homepage_url', p.homepage_url,
+                'repository_url', p.repository_url,
+                'subcategory', p.subcategory
             ))
             from project p
             join job_project jp on p.project_id = jp.project_id
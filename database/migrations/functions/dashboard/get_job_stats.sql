@@ -2,6 +2,25 @@
 create or replace function get_job_stats(p_job_id uuid)
 returns json as $$
     select json_strip_nulls(json_build_object(
+        'apply_clicks_daily', (
+            select coalesce(json_agg(json_build_array(
+                floor(extract(epoch from day) * 1000),
+                total
+            )), '[]'::json)
+            from (
+                select day, total
+                from apply_clicks
+                where job_id = p_job_id
+                and day >= current_date - '1 month'::interval
+                order by day asc
+            ) daily_apply_clicks
+        ),
+        'apply_clicks_total_last_month', (
+            select coalesce(sum(total), 0)
+            from apply_clicks
+            where job_id = p_job_id
+            and day >= current_date - '1 month'::interval
+        ),
         'search_appearances_daily', (
             select coalesce(json_agg(json_build_array(
                 floor(extract(epoch from day) * 1000),
@@ -21,6 +40,14 @@ returns json as $$
             where job_id = p_job_id
             and day >= current_date - '1 month'::interval
         ),
+        'search_appearances_total', (
+            select coalesce(sum(total), 0)
+            from (
+                select total from search_appearances where job_id = p_job_id
+                union all
+                select total from search_appearances_monthly where job_id = p_job_id
+            ) search_appearances_all
+        ),
         'views_daily', (
             select coalesce(json_agg(json_build_array(
                 floor(extract(epoch from day) * 1000),
@@ -34,6 +61,25 @@ returns json as $$
                 order by day asc
             ) daily_views
         ),
+        'views_sources_last_month', (
+            select coalesce(json_agg(json_build_array(source, total)), '[]'::json)
+            from (
+                select source, sum(total) as total
+                from job_views_sources
+                where job_id = p_job_id
+                and day >= current_date - '1 month'::interval
+                group by source
+                order by total desc, source asc
+            ) sources
+        ),
+        'views_total', (
+            select coalesce(sum(total), 0)
+            from (
+                select total from job_views where job_id = p_job_id
+                union all
+                select total from job_views_monthly where job_id = p_job_id
+            ) views_all
+        ),
         'views_total_last_month', (
             select coalesce(sum(total), 0)
             from job_views
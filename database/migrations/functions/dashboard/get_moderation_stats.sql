@@ -0,0 +1,143 @@
+-- Returns stats about the moderation queue and review throughput in json format.
+create or replace function get_moderation_stats(p_review_sla_hours int)
+returns json as $$
+    with pending as (
+        -- Jobs currently waiting for review
+        select
+            e.company,
+            j.job_id,
+            coalesce(j.review_requested_at, j.created_at) as review_requested_at,
+            j.title
+        from job j
+        join employer e on j.employer_id = e.employer_id
+        where j.status = 'pending-approval'
+    ),
+    decisions as (
+        -- Approvals and rejections recorded in the moderation audit trail
+        select
+            ma.created_at as decided_at,
+            ma.kind,
+            ma.moderator_id,
+            ma.review_requested_at
+        from moderation_action ma
+        where ma.kind in ('approve-job', 'reject-job')
+        and ma.created_at >= current_date - '3 month'::interval
+    ),
+    review_cycles as (
+        -- Periods of time each job spent waiting for review
+        select review_requested_at, decided_at
+        from decisions
+        where review_requested_at is not null
+        union all
+        select review_requested_at, null
+        from pending
+    )
+    select json_strip_nulls(json_build_object(
+        'reviews', json_build_object(
+            'decisions_per_moderator', (
+                select json_agg(json_build_array(moderator, approvals, rejections))
+                from (
+                    select
+                        coalesce(u.name, 'Deleted') as moderator,
+                        count(*) filter (where d.kind = 'approve-job') as approvals,
+                        count(*) filter (where d.kind = 'reject-job') as rejections
+                    from decisions d
+                    left join "user" u on d.moderator_id = u.user_id
+                    group by coalesce(u.name, 'Deleted')
+                    order by count(*) desc, moderator asc
+                ) moderator_decisions
+            ),
+            'decisions_weekly', (
+                select json_agg(json_build_array(
+                    floor(extract(epoch from week) * 1000),
+                    approvals,
+                    rejections
+                ))
+                from (
+                    select
+                        date_trunc('week', decided_at) as week,
+                        count(*) filter (where kind = 'approve-job') as approvals,
+                        count(*) filter (where kind = 'reject-job') as rejections
+                    from decisions
+                    group by week
+                    order by week asc
+                ) wt
+            ),
+            'median_review_time', (
+                select round(percentile_cont(0.5) within group (
+                    order by extract(epoch from decided_at - review_requested_at)
+                ))
+                from decisions
+                where review_requested_at is not null
+            ),
+            'median_review_time_weekly', (
+                select json_agg(json_build_array(
+                    floor(extract(epoch from week) * 1000),
+                    median
+                ))
+                from (
+                    select
+                        date_trunc('week', decided_at) as week,
+                        round(percentile_cont(0.5) within group (
+                            order by extract(epoch from decided_at - review_requested_at)
+                        )) as median
+                    from decisions
+                    where review_requested_at is not null
+                    group by week
+                    order by week asc
+                ) wt
+            ),
+            'oldest_pending', (
+                select json_agg(json_build_object(
+                    'company', company,
+                    'job_id', job_id,
+                    'over_sla', over_sla,
+                    'review_requested_at', review_requested_at,
+                    'title', title
+                ))
+                from (
+                    select
+                        company,
+                        job_id,
+                        review_requested_at < current_timestamp - make_interval(hours => p_review_sla_hours) as over_sla,
+                        review_requested_at,
+                        title
+                    from pending
+                    order by review_requested_at asc
+                    limit 10
+                ) op
+            ),
+            'pending', (select count(*) from pending),
+            'pending_daily', (
+                select json_agg(json_build_array(
+                    floor(extract(epoch from day) * 1000),
+                    total
+                ))
+                from (
+                    select
+                        day,
+                        (
+                            select count(*)
+                            from review_cycles rc
+                            where rc.review_requested_at < day + '1 day'::interval
+                            and (rc.decided_at is null or rc.decided_at >= day + '1 day'::interval)
+                        ) as total
+                    from generate_series(
+                        current_date - '3 month'::interval,
+                        current_date::timestamp,
+                        '1 day'::interval
+                    ) as day
+                    order by day asc
+                ) dt
+            ),
+            'pending_over_sla', (
+                select count(*)
+                from pending
+                where review_requested_at < current_timestamp - make_interval(hours => p_review_sla_hours)
+            )
+        ),
+        'review_sla_hours', p_review_sla_hours,
+        'ts_now', floor(extract(epoch from current_timestamp) * 1000),
+        'ts_three_months_ago', floor(extract(epoch from current_timestamp - '3 month'::interval) * 1000)
+    ));
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Grants moderator rights to the verified user with the email provided.
+create or replace function grant_moderator(p_email text)
+returns uuid as $$
+declare
+    v_user_id uuid;
+begin
+    update "user"
+    set moderator = true
+    where email = p_email
+    and email_verified = true
+    returning user_id into v_user_id;
+
+    if v_user_id is null then
+        raise exception 'no verified user found with that email';
+    end if;
+
+    return v_user_id;
+end
+$$ language plpgsql;
@@ -0,0 +1,19 @@
+-- Returns all foundations with the number of projects and members synced, as
+-- well as the time of their last successful synchronization.
+create or replace function list_foundations_for_admin()
+returns json as $$
+    select coalesce(json_agg(json_build_object(
+        'foundation_id', f.foundation_id,
+        'landscape_url', f.landscape_url,
+        'last_synced_at', (
+            select max(sr.finished_at)
+            from sync_run sr
+            where sr.foundation = f.name
+            and sr.error is null
+        ),
+        'members', (select count(*) from member m where m.foundation = f.name),
+        'name', f.name,
+        'projects', (select count(*) from project p where p.foundation = f.name)
+    ) order by f.name asc), '[]'::json)
+    from foundation f;
+$$ language sql;
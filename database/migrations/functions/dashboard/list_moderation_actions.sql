@@ -0,0 +1,25 @@
+-- Returns the most recent moderation actions recorded in the audit trail.
+create or replace function list_moderation_actions(p_limit int)
+returns json as $$
+    select coalesce(json_agg(json_strip_nulls(json_build_object(
+        'created_at', ma.created_at,
+        'kind', ma.kind,
+        'moderation_action_id', ma.moderation_action_id,
+        'employer_company', e.company,
+        'expires_at', ma.expires_at,
+        'job_title', j.title,
+        'moderator_name', m.name,
+        'reason', ma.reason,
+        'user_name', u.name
+    )) order by ma.created_at desc), '[]'::json)
+    from (
+        select *
+        from moderation_action
+        order by created_at desc
+        limit p_limit
+    ) ma
+    left join employer e on ma.employer_id = e.employer_id
+    left join job j on ma.job_id = j.job_id
+    left join "user" m on ma.moderator_id = m.user_id
+    left join "user" u on ma.user_id = u.user_id;
+$$ language sql;
@@ -0,0 +1,13 @@
+-- Returns the users with moderator rights.
+create or replace function list_moderators()
+returns json as $$
+    select coalesce(json_agg(json_build_object(
+        'admin', u.admin,
+        'email', u.email,
+        'name', u.name,
+        'user_id', u.user_id,
+        'username', u.username
+    ) order by u.name asc, u.username asc), '[]'::json)
+    from "user" u
+    where u.moderator = true;
+$$ language sql;
@@ -0,0 +1,31 @@
+-- Returns the published jobs recommended to the user provided, based on the
+-- skills of their job seeker profile. Jobs the user has already applied to
+-- are not returned.
+create or replace function list_recommended_jobs(p_user_id uuid, p_limit int)
+returns json as $$
+begin
+    return coalesce((
+        select recommend_jobs(
+            p.skills,
+            null,
+            null,
+            case when p.open_to_remote then 'remote' end,
+            (
+                select to_tsquery('simple', string_agg(quote_literal(lexeme), ' | '))
+                from unnest(tsvector_to_array(
+                    to_tsvector('simple', i_array_to_string(p.skills, ' '))
+                )) as lexeme
+            ),
+            array(
+                select a.job_id
+                from application a
+                where a.job_seeker_profile_id = p.job_seeker_profile_id
+            ),
+            p_limit
+        )
+        from job_seeker_profile p
+        where p.user_id = p_user_id
+        and cardinality(p.skills) > 0
+    ), '[]'::json);
+end
+$$ language plpgsql;
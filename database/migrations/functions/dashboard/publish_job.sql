@@ -9,6 +9,7 @@ returns void as $$
     update job
     set
         status = 'pending-approval',
+        review_requested_at = current_timestamp,
         updated_at = current_timestamp,
         archived_at = null,
         salary_usd_year = p_salary_usd_year,
@@ -5,12 +5,25 @@ create or replace function reject_job(
     p_review_notes text
 )
 returns void as $$
-    update job
-    set
-        status = 'rejected',
-        review_notes = p_review_notes,
-        reviewed_at = current_timestamp,
-        reviewed_by = p_reviewer
-    where job_id = p_job_id
-    and (status = 'pending-approval' or status = 'published');
+    with old as (
+        select review_requested_at, status
+        from job
+        where job_id = p_job_id
+    ),
+    rejected_job as (
+        update job
+        set
+            status = 'rejected',
+            review_notes = p_review_notes,
+            reviewed_at = current_timestamp,
+            reviewed_by = p_reviewer
+        where job_id = p_job_id
+        and (status = 'pending-approval' or status = 'published')
+        returning job_id
+    )
+    insert into moderation_action (kind, job_id, moderator_id, reason, review_requested_at)
+    select 'reject-job', job_id, p_reviewer, p_review_notes, (
+        select review_requested_at from old where status = 'pending-approval'
+    )
+    from rejected_job;
 $$ language sql;
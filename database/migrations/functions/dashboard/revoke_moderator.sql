@@ -0,0 +1,14 @@
+-- Revokes the moderator rights of the user provided.
+create or replace function revoke_moderator(p_user_id uuid)
+returns void as $$
+begin
+    update "user"
+    set moderator = false
+    where user_id = p_user_id
+    and moderator = true;
+
+    if not found then
+        raise exception 'moderator not found';
+    end if;
+end
+$$ language plpgsql;
@@ -61,7 +61,8 @@ begin
                 'job_workplace', job_workplace,
                 'photo_id', photo_id,
                 'name', name,
-                'last_position', last_position
+                'last_position', last_position,
+                'match', job_match(job_id, job_seeker_profile_id)
             )), '[]'::json)
             from (
                 select *
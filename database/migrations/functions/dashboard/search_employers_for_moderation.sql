@@ -0,0 +1,67 @@
+-- Returns the employers that match the filters provided, for moderation.
+create or replace function search_employers_for_moderation(p_filters jsonb)
+returns json as $$
+declare
+    v_limit int := coalesce((p_filters->>'limit')::int, 20);
+    v_offset int := coalesce((p_filters->>'offset')::int, 0);
+    v_ts_query text := (p_filters->>'ts_query');
+begin
+    return (
+    with filtered_employers as (
+        select
+            e.employer_id,
+            e.company,
+            e.created_at,
+            e.logo_id,
+            (
+                select count(*)
+                from job j
+                where j.employer_id = e.employer_id
+                and j.status = 'published'
+            ) as published_jobs,
+            (
+                e.suspended_at is not null
+                and (e.suspended_until is null or e.suspended_until > current_timestamp)
+            ) as suspended,
+            e.suspended_at,
+            e.suspended_until,
+            e.suspension_reason,
+            e.website_url
+        from employer e
+        where
+            case when v_ts_query is not null then
+                e.company ilike '%' || v_ts_query || '%'
+                or e.website_url ilike '%' || v_ts_query || '%'
+            else true end
+    )
+    select json_build_object(
+        'employers',
+        (
+            select coalesce(json_agg(json_strip_nulls(json_build_object(
+                'company', company,
+                'created_at', created_at,
+                'employer_id', employer_id,
+                'logo_id', logo_id,
+                'published_jobs', published_jobs,
+                'suspended', suspended,
+                'suspended_at', suspended_at,
+                'suspended_until', suspended_until,
+                'suspension_reason', suspension_reason,
+                'website_url', website_url
+            ))), '[]'::json)
+            from (
+                select *
+                from filtered_employers
+                order by suspended desc, created_at desc
+                limit v_limit
+                offset v_offset
+            ) filtered_employers_page
+        ),
+        'total',
+        (
+            select count(*) from filtered_employers
+        )
+    )
+    );
+end
+$$ language plpgsql;
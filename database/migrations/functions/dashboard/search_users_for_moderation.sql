@@ -0,0 +1,63 @@
+-- Returns the users that match the filters provided, for moderation.
+create or replace function search_users_for_moderation(p_filters jsonb)
+returns json as $$
+declare
+    v_limit int := coalesce((p_filters->>'limit')::int, 20);
+    v_offset int := coalesce((p_filters->>'offset')::int, 0);
+    v_ts_query text := (p_filters->>'ts_query');
+begin
+    return (
+    with filtered_users as (
+        select
+            u.user_id,
+            u.created_at,
+            u.email,
+            u.moderator,
+            u.name,
+            (
+                u.suspended_at is not null
+                and (u.suspended_until is null or u.suspended_until > current_timestamp)
+            ) as suspended,
+            u.suspended_at,
+            u.suspended_until,
+            u.suspension_reason,
+            u.username
+        from "user" u
+        where
+            case when v_ts_query is not null then
+                u.email ilike '%' || v_ts_query || '%'
+                or u.name ilike '%' || v_ts_query || '%'
+                or u.username ilike '%' || v_ts_query || '%'
+            else true end
+    )
+    select json_build_object(
+        'users',
+        (
+            select coalesce(json_agg(json_strip_nulls(json_build_object(
+                'created_at', created_at,
+                'email', email,
+                'moderator', moderator,
+                'name', name,
+                'suspended', suspended,
+                'suspended_at', suspended_at,
+                'suspended_until', suspended_until,
+                'suspension_reason', suspension_reason,
+                'user_id', user_id,
+                'username', username
+            ))), '[]'::json)
+            from (
+                select *
+                from filtered_users
+                order by suspended desc, created_at desc
+                limit v_limit
+                offset v_offset
+            ) filtered_users_page
+        ),
+        'total',
+        (
+            select count(*) from filtered_users
+        )
+    )
+    );
+end
+$$ language plpgsql;
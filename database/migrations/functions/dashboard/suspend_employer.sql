@@ -0,0 +1,33 @@
+-- Suspends an employer, hiding its jobs from the job board, and records the
+-- moderation action. The accounts of the employer team members are not affected,
+-- so their sessions are intentionally kept.
+create or replace function suspend_employer(
+    p_employer_id uuid,
+    p_moderator_id uuid,
+    p_reason text,
+    p_expires_at timestamptz
+)
+returns void as $$
+begin
+    -- Suspensions with an expiry date must end in the future
+    if p_expires_at is not null and p_expires_at <= current_timestamp then
+        raise exception 'suspension expiry must be in the future';
+    end if;
+
+    -- Mark the employer as suspended
+    update employer
+    set
+        suspended_at = current_timestamp,
+        suspended_until = p_expires_at,
+        suspension_reason = p_reason
+    where employer_id = p_employer_id;
+
+    if not found then
+        raise exception 'employer not found';
+    end if;
+
+    -- Record the moderation action in the audit trail
+    insert into moderation_action (kind, moderator_id, employer_id, reason, expires_at)
+    values ('suspend-employer', p_moderator_id, p_employer_id, p_reason, p_expires_at);
+end
+$$ language plpgsql;
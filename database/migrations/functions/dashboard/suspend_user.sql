@@ -0,0 +1,40 @@
+-- Suspends a user, revokes their sessions and records the moderation action.
+create or replace function suspend_user(
+    p_user_id uuid,
+    p_moderator_id uuid,
+    p_reason text,
+    p_expires_at timestamptz
+)
+returns void as $$
+begin
+    -- Moderators cannot suspend their own account
+    if p_user_id = p_moderator_id then
+        raise exception 'cannot suspend your own account';
+    end if;
+
+    -- Suspensions with an expiry date must end in the future
+    if p_expires_at is not null and p_expires_at <= current_timestamp then
+        raise exception 'suspension expiry must be in the future';
+    end if;
+
+    -- Mark the user as suspended
+    update "user"
+    set
+        suspended_at = current_timestamp,
+        suspended_until = p_expires_at,
+        suspension_reason = p_reason
+    where user_id = p_user_id;
+
+    if not found then
+        raise exception 'user not found';
+    end if;
+
+    -- Revoke all the sessions of the user
+    delete from session
+    where data->'axum-login.data'->>'user_id' = p_user_id::text;
+
+    -- Record the moderation action in the audit trail
+    insert into moderation_action (kind, moderator_id, user_id, reason, expires_at)
+    values ('suspend-user', p_moderator_id, p_user_id, p_reason, p_expires_at);
+end
+$$ language plpgsql;
@@ -0,0 +1,25 @@
+-- Lifts the suspension of an employer and records the moderation action.
+create or replace function unsuspend_employer(
+    p_employer_id uuid,
+    p_moderator_id uuid
+)
+returns void as $$
+begin
+    -- Clear the suspension details
+    update employer
+    set
+        suspended_at = null,
+        suspended_until = null,
+        suspension_reason = null
+    where employer_id = p_employer_id
+    and suspended_at is not null;
+
+    if not found then
+        raise exception 'suspended employer not found';
+    end if;
+
+    -- Record the moderation action in the audit trail
+    insert into moderation_action (kind, moderator_id, employer_id)
+    values ('unsuspend-employer', p_moderator_id, p_employer_id);
+end
+$$ language plpgsql;
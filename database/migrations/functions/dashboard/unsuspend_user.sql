@@ -0,0 +1,25 @@
+-- Lifts the suspension of a user and records the moderation action.
+create or replace function unsuspend_user(
+    p_user_id uuid,
+    p_moderator_id uuid
+)
+returns void as $$
+begin
+    -- Clear the suspension details
+    update "user"
+    set
+        suspended_at = null,
+        suspended_until = null,
+        suspension_reason = null
+    where user_id = p_user_id
+    and suspended_at is not null;
+
+    if not found then
+        raise exception 'suspended user not found';
+    end if;
+
+    -- Record the moderation action in the audit trail
+    insert into moderation_action (kind, moderator_id, user_id)
+    values ('unsuspend-user', p_moderator_id, p_user_id);
+end
+$$ language plpgsql;
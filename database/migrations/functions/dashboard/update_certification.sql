@@ -0,0 +1,34 @@
+-- Updates a certification in the catalogue.
+create or replace function update_certification(
+    p_certification_id uuid,
+    p_certification jsonb
+)
+returns void as $$
+begin
+    -- Names and short names must be unique across the catalogue
+    if exists (
+        select 1
+        from certification
+        where certification_id <> p_certification_id
+        and (
+            name = p_certification->>'name'
+            or short_name = p_certification->>'short_name'
+        )
+    ) then
+        raise exception 'certification name or short name already in use';
+    end if;
+
+    update certification set
+        description = p_certification->>'description',
+        logo_url = p_certification->>'logo_url',
+        name = p_certification->>'name',
+        provider = p_certification->>'provider',
+        short_name = p_certification->>'short_name',
+        url = p_certification->>'url'
+    where certification_id = p_certification_id;
+
+    if not found then
+        raise exception 'certification not found';
+    end if;
+end
+$$ language plpgsql;
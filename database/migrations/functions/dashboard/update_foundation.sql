@@ -0,0 +1,16 @@
+-- Updates the landscape url of a foundation.
+create or replace function update_foundation(
+    p_foundation_id uuid,
+    p_foundation jsonb
+)
+returns void as $$
+begin
+    update foundation
+    set landscape_url = p_foundation->>'landscape_url'
+    where foundation_id = p_foundation_id;
+
+    if not found then
+        raise exception 'foundation not found';
+    end if;
+end
+$$ language plpgsql;
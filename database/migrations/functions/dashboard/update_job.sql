@@ -42,6 +42,12 @@ begin
         tz_end = p_job->>'tz_end',
         tz_start = p_job->>'tz_start',
         upstream_commitment = (p_job->>'upstream_commitment')::int,
+        review_requested_at = (
+            case
+                when p_job->>'status' = 'pending-approval' and status <> 'pending-approval' then current_timestamp
+                else review_requested_at
+            end
+        ),
         updated_at = current_timestamp
     where job_id = p_job_id
     and status <> 'deleted';
@@ -0,0 +1,21 @@
+-- Adds an image uploaded by the user provided using the identifier given, which
+-- is expected to be registered once all its versions have been stored. When an
+-- image with the same hash already exists, the upload is registered for it and
+-- its identifier is returned instead.
+create or replace function add_image(p_image_id uuid, p_user_id uuid, p_hash text)
+returns uuid as $$
+declare
+    v_image_id uuid;
+begin
+    -- Add image (or reuse the existing one with the same content)
+    insert into image (image_id, created_by, hash)
+    values (p_image_id, p_user_id, p_hash)
+    on conflict (hash) do update set hash = excluded.hash
+    returning image_id into v_image_id;
+
+    -- Register upload
+    perform register_image_upload(p_user_id, p_hash);
+
+    return v_image_id;
+end
+$$ language plpgsql;
@@ -0,0 +1,42 @@
+-- Deletes the image provided if it is still not referenced by any employer or
+-- job seeker profile and was last uploaded before the timestamp provided.
+-- Returns the size of the versions that were stored in the database, or null
+-- when the image was not deleted.
+--
+-- The image row is locked first, so that references added concurrently are
+-- either visible to the checks below or wait until the image is gone.
+create or replace function delete_unreferenced_image(
+    p_image_id uuid,
+    p_created_before timestamptz
+)
+returns bigint as $$
+declare
+    v_size bigint;
+begin
+    -- Lock the image, waiting for any transaction referencing it to finish
+    perform from image
+    where image_id = p_image_id
+    for update;
+    if not found then return null; end if;
+
+    -- Get the size of the versions stored in the database
+    select coalesce(sum(octet_length(iv.data)), 0)::bigint into v_size
+    from image_version iv
+    where iv.image_id = p_image_id;
+
+    -- Delete the image only if it is still unreferenced
+    delete from image i
+    where i.image_id = p_image_id
+    and i.created_at < p_created_before
+    and not exists (select 1 from employer e where e.logo_id = i.image_id)
+    and not exists (select 1 from job_seeker_profile p where p.photo_id = i.image_id)
+    and not exists (
+        select 1 from image_upload u
+        where u.image_id = i.image_id
+        and u.created_at >= p_created_before
+    );
+    if not found then return null; end if;
+
+    return v_size;
+end
+$$ language plpgsql;
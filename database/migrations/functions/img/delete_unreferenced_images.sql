@@ -0,0 +1,26 @@
+-- Deletes images not referenced by any employer or job seeker profile that
+-- were last uploaded before the timestamp provided. Returns the identifiers of
+-- the images deleted, along with the size of the versions stored in the database.
+create or replace function delete_unreferenced_images(p_created_before timestamptz)
+returns table(image_id uuid, size bigint) as $$
+    with deleted as (
+        delete from image i
+        where i.created_at < p_created_before
+        and not exists (select 1 from employer e where e.logo_id = i.image_id)
+        and not exists (select 1 from job_seeker_profile p where p.photo_id = i.image_id)
+        and not exists (
+            select 1 from image_upload u
+            where u.image_id = i.image_id
+            and u.created_at >= p_created_before
+        )
+        returning i.image_id
+    )
+    select
+        d.image_id,
+        coalesce((
+            select sum(octet_length(iv.data))
+            from image_version iv
+            where iv.image_id = d.image_id
+        ), 0)::bigint as size
+    from deleted d;
+$$ language sql;
@@ -1,13 +1,43 @@
 -- Returns an image version. We'll try first to get the version of the size
--- requested. If it doesn't exist, we'll return the svg version (if available).
+-- requested. If it's a retina version that doesn't exist (images uploaded
+-- before they were generated), we'll try the next larger version, which has
+-- the same dimensions (e.g. small@2x -> medium). Otherwise, we'll return the
+-- svg version (if available). The format of the version is inferred from its
+-- name (e.g. small.webp).
 create or replace function get_image_version(p_image_id uuid, p_version text)
 returns table(data bytea, format text) as $$
 begin
-    -- PNG
-    return query select iv.data, 'png' as format from image_version iv
+    -- Version requested
+    return query select
+        iv.data,
+        case
+            when p_version like '%.avif' then 'avif'
+            when p_version like '%.webp' then 'webp'
+            else 'png'
+        end as format
+    from image_version iv
     where image_id = p_image_id and version = p_version;
     if found then return; end if;
 
+    -- Next larger version for retina versions
+    if p_version like '%@2x%' then
+        return query select
+            iv.data,
+            case
+                when p_version like '%.avif' then 'avif'
+                when p_version like '%.webp' then 'webp'
+                else 'png'
+            end as format
+        from image_version iv
+        where image_id = p_image_id
+        and version = case
+            when p_version like 'small@2x%' then replace(p_version, 'small@2x', 'medium')
+            when p_version like 'medium@2x%' then replace(p_version, 'medium@2x', 'large')
+            else replace(p_version, '@2x', '')
+        end;
+        if found then return; end if;
+    end if;
+
     -- SVG
     return query select iv.data, 'svg' as format from image_version iv
     where image_id = p_image_id and version = 'svg';
@@ -0,0 +1,16 @@
+-- Lists images not referenced by any employer or job seeker profile that were
+-- last uploaded before the timestamp provided.
+create or replace function list_unreferenced_images(p_created_before timestamptz)
+returns table(image_id uuid) as $$
+    select i.image_id
+    from image i
+    where i.created_at < p_created_before
+    and not exists (select 1 from employer e where e.logo_id = i.image_id)
+    and not exists (select 1 from job_seeker_profile p where p.photo_id = i.image_id)
+    and not exists (
+        select 1 from image_upload u
+        where u.image_id = i.image_id
+        and u.created_at >= p_created_before
+    )
+    order by i.image_id;
+$$ language sql;
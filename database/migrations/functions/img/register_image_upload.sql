@@ -0,0 +1,9 @@
+-- Registers a new upload of the image with the hash provided by the user given,
+-- returning the image identifier, or null when no image has that hash.
+create or replace function register_image_upload(p_user_id uuid, p_hash text)
+returns uuid as $$
+    insert into image_upload (image_id, user_id)
+    select image_id, p_user_id from image where hash = p_hash
+    on conflict (image_id, user_id) do update set created_at = current_timestamp
+    returning image_id;
+$$ language sql;
@@ -0,0 +1,10 @@
+-- Returns the external apply URL of a published job, if it has one.
+create or replace function get_job_apply_url(p_job_id uuid)
+returns text as $$
+    select j.apply_url
+    from job j
+    join employer e on j.employer_id = e.employer_id
+    where j.job_id = p_job_id
+    and j.status = 'published'
+    and (e.suspended_at is null or e.suspended_until <= current_timestamp);
+$$ language sql;
@@ -23,7 +23,12 @@ returns json as $$
                 'foundation', p.foundation,
                 'logo_url', p.logo_url,
                 'maturity', p.maturity,
-                'name', p.name
+                'name', p.name,
+                'category', p.category,
+                'description', p.description,
+                'homepage_url', p.homepage_url,
+                'repository_url', p.repository_url,
+                'subcategory', p.subcategory
             ))
             from project p
             join job_project jp on p.project_id = jp.project_id
@@ -83,5 +88,6 @@ returns json as $$
     ) members on true
     left join location l on j.location_id = l.location_id
     where j.job_id = p_job_id
-    and j.status = 'published';
+    and j.status = 'published'
+    and (e.suspended_at is null or e.suspended_until <= current_timestamp);
 $$ language sql;
@@ -2,10 +2,19 @@
 create or replace function get_jobs_filters_options()
 returns json as $$
     select json_build_object(
-        'foundations',
-        coalesce(json_agg(json_build_object(
-            'name', f.name
-        ) order by f.name asc), '[]'::json)
-    )
-    from foundation f;
+        'foundations', (
+            select coalesce(json_agg(json_build_object(
+                'name', f.name
+            ) order by f.name asc), '[]'::json)
+            from foundation f
+        ),
+        'project_categories', (
+            select coalesce(json_agg(c.category order by c.category asc), '[]'::json)
+            from (
+                select distinct category
+                from project
+                where category is not null
+            ) c
+        )
+    );
 $$ language sql;
@@ -0,0 +1,29 @@
+-- Returns the published jobs most similar to the job provided. Other jobs of
+-- the same employer with the same title are considered duplicates and are not
+-- returned.
+create or replace function get_similar_jobs(p_job_id uuid, p_limit int)
+returns json as $$
+begin
+    return coalesce((
+        select recommend_jobs(
+            j.skills,
+            array(select project_id from job_project where job_id = j.job_id),
+            j.seniority,
+            j.workplace,
+            (
+                select to_tsquery('simple', string_agg(quote_literal(lexeme), ' | '))
+                from unnest(tsvector_to_array(to_tsvector('simple', j.title))) as lexeme
+            ),
+            array(
+                select d.job_id
+                from job d
+                where d.employer_id = j.employer_id
+                and lower(d.title) = lower(j.title)
+            ),
+            p_limit
+        )
+        from job j
+        where j.job_id = p_job_id
+    ), '[]'::json);
+end
+$$ language plpgsql;
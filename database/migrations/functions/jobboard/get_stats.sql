@@ -32,6 +32,15 @@ returns json as $$
                         to_char(first_published_at, 'Mon')
                 ) year_month_count
             ),
+            'published_per_workplace', (
+                select json_agg(json_build_array(workplace, jobs) order by jobs desc, workplace asc)
+                from (
+                    select workplace, count(*) as jobs
+                    from job
+                    where first_published_at is not null
+                    group by workplace
+                ) workplace_jobs
+            ),
             'published_running_total', (
                 select json_agg(json_build_array(
                     floor(extract(epoch from jobs_day) * 1000),
@@ -51,6 +60,82 @@ returns json as $$
                     ) mt
                 ) rt
             ),
+            'salary_median_per_seniority', (
+                select json_agg(json_build_array(seniority, salary) order by position asc)
+                from (
+                    select
+                        seniority,
+                        array_position(array['entry', 'junior', 'mid', 'senior', 'lead'], seniority) as position,
+                        round(percentile_cont(0.5) within group (order by salary))::bigint as salary
+                    from (
+                        select
+                            seniority,
+                            coalesce(salary_usd_year, (salary_min_usd_year + salary_max_usd_year) / 2) as salary
+                        from job
+                        where first_published_at >= current_date - '2 year'::interval
+                        and seniority is not null
+                    ) job_salary
+                    where salary is not null
+                    group by seniority
+                ) seniority_salary
+            ),
+            'salary_median_per_workplace', (
+                select json_agg(json_build_array(workplace, salary) order by workplace asc)
+                from (
+                    select
+                        workplace,
+                        round(percentile_cont(0.5) within group (order by salary))::bigint as salary
+                    from (
+                        select
+                            workplace,
+                            coalesce(salary_usd_year, (salary_min_usd_year + salary_max_usd_year) / 2) as salary
+                        from job
+                        where first_published_at >= current_date - '2 year'::interval
+                    ) job_salary
+                    where salary is not null
+                    group by workplace
+                ) workplace_salary
+            ),
+            'skills_monthly', (
+                select json_agg(json_build_array(skill, months) order by total desc, skill asc)
+                from (
+                    select
+                        skill,
+                        sum(jobs) as total,
+                        json_agg(json_build_array(
+                            floor(extract(epoch from month) * 1000),
+                            jobs
+                        ) order by month asc) as months
+                    from (
+                        select s.skill, date_trunc('month', j.first_published_at)::date as month, count(*) as jobs
+                        from job j, unnest(j.skills) as s(skill)
+                        where j.first_published_at >= current_date - '2 year'::interval
+                        group by s.skill, month
+                    ) skill_month
+                    where skill in (
+                        select s.skill
+                        from job j, unnest(j.skills) as s(skill)
+                        where j.first_published_at >= current_date - '2 year'::interval
+                        group by s.skill
+                        order by count(*) desc, s.skill asc
+                        limit 10
+                    )
+                    group by skill
+                ) skill_months
+            ),
+            'top_projects', (
+                select json_agg(json_build_array(foundation, name, jobs) order by jobs desc, name asc)
+                from (
+                    select p.foundation, p.name, count(distinct j.job_id) as jobs
+                    from job j
+                    join job_project jp on j.job_id = jp.job_id
+                    join project p on jp.project_id = p.project_id
+                    where j.first_published_at is not null
+                    group by p.foundation, p.name
+                    order by jobs desc, p.name asc
+                    limit 10
+                ) project_jobs
+            ),
             'views_daily', (
                 select json_agg(json_build_array(
                     floor(extract(epoch from day) * 1000),
@@ -70,9 +155,14 @@ returns json as $$
                     total
                 ))
                 from (
-                    select date_trunc('month', day) as month, sum(total) as total
-                    from job_views
-                    where day >= current_date - '2 year'::interval
+                    select month, sum(total) as total
+                    from (
+                        select date_trunc('month', day)::date as month, total
+                        from job_views
+                        union all
+                        select month, total
+                        from job_views_monthly
+                    ) views
                     group by month
                     order by month asc
                 ) mt
@@ -0,0 +1,110 @@
+-- Returns the published jobs that best match the criteria provided, sorted by
+-- score. Jobs are scored by the skills and projects they share with the
+-- criteria, their seniority and workplace and the full text similarity with
+-- the query provided. Only the best scored job of a given title is returned
+-- for each employer.
+create or replace function recommend_jobs(
+    p_skills text[],
+    p_project_ids uuid[],
+    p_seniority text,
+    p_workplace text,
+    p_tsquery tsquery,
+    p_exclude_job_ids uuid[],
+    p_limit int
+)
+returns json as $$
+    with candidates as (
+        select
+            j.job_id,
+            j.employer_id,
+            j.kind,
+            j.published_at,
+            j.title,
+            j.workplace,
+            j.open_source,
+            j.salary,
+            j.salary_currency,
+            j.salary_min,
+            j.salary_max,
+            j.salary_period,
+            j.seniority,
+            j.skills,
+            j.updated_at,
+            j.upstream_commitment,
+            e.company,
+            e.logo_id,
+            j.location_id,
+            cardinality(array(
+                select lower(s) from unnest(j.skills) s
+                intersect
+                select lower(s) from unnest(p_skills) s
+            )) as shared_skills,
+            (
+                select count(*)
+                from job_project jp
+                where jp.job_id = j.job_id
+                and jp.project_id = any(p_project_ids)
+            ) as shared_projects,
+            coalesce(ts_rank(j.tsdoc, p_tsquery), 0) as text_rank
+        from job j
+        join employer e on j.employer_id = e.employer_id
+        where j.status = 'published'
+        and (e.suspended_at is null or e.suspended_until <= current_timestamp)
+        and not j.job_id = any(coalesce(p_exclude_job_ids, '{}'))
+    ),
+    scored as (
+        select
+            *,
+            shared_skills * 3
+            + shared_projects * 4
+            + coalesce(seniority = p_seniority, false)::int * 2
+            + coalesce(workplace = p_workplace, false)::int
+            + text_rank * 10 as score
+        from candidates
+        where shared_skills > 0 or shared_projects > 0 or text_rank > 0
+    ),
+    deduplicated as (
+        select distinct on (employer_id, lower(title)) *
+        from scored
+        order by employer_id, lower(title), score desc, published_at desc
+    ),
+    recommended as (
+        select *
+        from deduplicated
+        order by score desc, published_at desc
+        limit p_limit
+    )
+    select coalesce(json_agg(json_build_object(
+        'job_id', r.job_id,
+        'kind', r.kind,
+        'published_at', r.published_at,
+        'title', r.title,
+        'workplace', r.workplace,
+        'open_source', r.open_source,
+        'salary', r.salary,
+        'salary_currency', r.salary_currency,
+        'salary_min', r.salary_min,
+        'salary_max', r.salary_max,
+        'salary_period', r.salary_period,
+        'seniority', r.seniority,
+        'skills', r.skills,
+        'updated_at', r.updated_at,
+        'upstream_commitment', r.upstream_commitment,
+        'employer', json_strip_nulls(json_build_object(
+            'company', r.company,
+            'employer_id', r.employer_id,
+            'logo_id', r.logo_id
+        )),
+        'location', (
+            select nullif(jsonb_strip_nulls(jsonb_build_object(
+                'location_id', l.location_id,
+                'city', l.city,
+                'country', l.country,
+                'state', l.state
+            )), '{}'::jsonb)
+            from location l
+            where l.location_id = r.location_id
+        )
+    ) order by r.score desc, r.published_at desc), '[]'::json)
+    from recommended r;
+$$ language sql;
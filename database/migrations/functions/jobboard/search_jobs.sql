@@ -1,11 +1,17 @@
--- Returns the jobs that match the filters provided.
-create or replace function search_jobs(p_filters jsonb)
+-- Returns the jobs that match the filters provided. When a user is provided
+-- and has a job seeker profile, how well each job matches it is included.
+-- When requested, the number of jobs available for each filter option under
+-- the current filters is included as well. Options of filters that select a
+-- single value out of many are counted ignoring the filter's own selection.
+create or replace function search_jobs(p_filters jsonb, p_user_id uuid, p_include_facets boolean)
 returns json as $$
 declare
     v_benefits text[];
+    v_category text := (p_filters->>'category');
     v_date_from date;
     v_date_to date;
     v_foundation text := (p_filters->>'foundation');
+    v_job_seeker_profile_id uuid;
     v_kind text[];
     v_limit int := coalesce((p_filters->>'limit')::int, 20);
     v_location_id uuid := ((p_filters->'location')->>'location_id')::uuid;
@@ -18,6 +24,8 @@ declare
     v_skills text[];
     v_sort text := coalesce((p_filters->>'sort'), 'date');
     v_tsquery_with_prefix_matching tsquery;
+    v_tz_end text := nullif(p_filters->>'tz_end', '');
+    v_tz_start text := nullif(p_filters->>'tz_start', '');
     v_upstream_commitment int := (p_filters->>'upstream_commitment')::int;
     v_workplace text[];
 begin
@@ -51,6 +59,11 @@ begin
         select array_agg(e::text) into v_workplace
         from jsonb_array_elements_text(p_filters->'workplace') e;
     end if;
+    if p_user_id is not null then
+        select job_seeker_profile_id into v_job_seeker_profile_id
+        from job_seeker_profile
+        where user_id = p_user_id;
+    end if;
     if p_filters ? 'ts_query' then
         select ts_rewrite(
             websearch_to_tsquery(p_filters->>'ts_query'),
@@ -65,10 +78,13 @@ begin
     end if;
 
     return (
-    with filtered_jobs as (
+    with candidate_jobs as (
         select
             j.job_id,
+            j.benefits,
+            j.employer_id,
             j.kind,
+            j.location_id,
             j.published_at,
             j.title,
             j.workplace,
@@ -76,6 +92,7 @@ begin
             j.salary,
             j.salary_currency,
             j.salary_min,
+            j.salary_min_usd_year,
             j.salary_max,
             j.salary_max_usd_year,
             j.salary_period,
@@ -83,74 +100,49 @@ begin
             j.skills,
             j.updated_at,
             j.upstream_commitment,
-            (
-                select nullif(jsonb_strip_nulls(jsonb_build_object(
-                    'company', e.company,
-                    'employer_id', e.employer_id,
-                    'logo_id', e.logo_id,
-                    'members', members.members,
-                    'website_url', e.website_url
-                )), '{}'::jsonb)
-            ) as employer,
-            (
-                select nullif(jsonb_strip_nulls(jsonb_build_object(
-                    'location_id', l.location_id,
-                    'city', l.city,
-                    'country', l.country,
-                    'state', l.state
-                )), '{}'::jsonb)
-            ) as location,
-            (
-                select json_agg(json_build_object(
-                    'project_id', p.project_id,
-                    'foundation', p.foundation,
-                    'logo_url', p.logo_url,
-                    'maturity', p.maturity,
-                    'name', p.name
-                ))
-                from project p
-                left join job_project using (project_id)
-                left join job using (job_id)
-                where job_id = j.job_id
-            ) as projects
+            -- Filters left out when counting the jobs for their own options
+            case when v_foundation is not null then
+                j.job_id = any(
+                    select job_id from job_project
+                    where project_id = any(
+                        select project_id from project
+                        where foundation = v_foundation
+                    )
+                )
+            else true end as foundation_matched,
+            case when cardinality(v_kind) > 0 then
+                j.kind = any(v_kind)
+            else true end as kind_matched,
+            case when v_salary_min is not null then
+                j.salary_min_usd_year >= v_salary_min
+            else true end as salary_matched,
+            case when v_seniority is not null then
+                j.seniority = v_seniority
+            else true end as seniority_matched,
+            case when cardinality(v_workplace) > 0 then
+                j.workplace = any(v_workplace)
+            else true end as workplace_matched
         from job j
         join employer e on j.employer_id = e.employer_id
-        left join lateral (
-            select
-                jsonb_agg(jsonb_build_object(
-                    'member_id', m.member_id,
-                    'foundation', m.foundation,
-                    'level', m.level,
-                    'logo_url', m.logo_url,
-                    'name', m.name
-                ) order by m.foundation asc, m.name asc) as members
-            from employer_member em
-            join member m on em.member_id = m.member_id
-            where em.employer_id = e.employer_id
-        ) members on true
-        left join location l on j.location_id = l.location_id
         where j.status = 'published'
+        and (e.suspended_at is null or e.suspended_until <= current_timestamp)
         and
             case when cardinality(v_benefits) > 0 then
                 j.benefits @> v_benefits
             else true end
         and
-            case when v_date_from is not null and v_date_to is not null then
-                j.published_at::date >= v_date_from and j.published_at::date <= v_date_to
-            else true end
-        and
-            case when v_foundation is not null then
-                j.job_id = any(
-                    select job_id from job_project
-                    where project_id = any(
-                        select project_id from project
-                        where foundation = v_foundation
-                    )
+            case when v_category is not null then
+                exists (
+                    select 1
+                    from job_project jp
+                    join project p on jp.project_id = p.project_id
+                    where jp.job_id = j.job_id
+                    and p.category = v_category
                 )
             else true end
         and
-            case when cardinality(v_kind) > 0 then
-                j.kind = any(v_kind)
+            case when v_date_from is not null and v_date_to is not null then
+                j.published_at::date >= v_date_from and j.published_at::date <= v_date_to
             else true end
         and
             case when v_location_id is not null and v_max_distance is not null then
@@ -186,14 +178,6 @@ begin
                     )
                 )
             else true end
-        and
-            case when v_salary_min is not null then
-                j.salary_min_usd_year >= v_salary_min
-            else true end
-        and
-            case when v_seniority is not null then
-                j.seniority = v_seniority
-            else true end
         and
             case when cardinality(v_skills) > 0 then
                 j.skills @> v_skills
@@ -203,13 +187,42 @@ begin
                 v_tsquery_with_prefix_matching @@ j.tsdoc
             else true end
         and
-            case when v_upstream_commitment is not null then
-                j.upstream_commitment >= v_upstream_commitment
+            case when v_tz_start is not null then
+                case
+                    -- Hybrid jobs without a timezone window can still be
+                    -- matched by the location filter, when provided
+                    when j.workplace = 'hybrid' and j.tz_start is null then
+                        v_location_id is not null and v_max_distance is not null
+                    -- Remote jobs without a timezone window can be done from
+                    -- anywhere, so they match any timezone
+                    when j.workplace = 'remote' and j.tz_start is null then
+                        true
+                    else
+                        j.workplace in ('hybrid', 'remote')
+                        and tz_windows_overlap(j.tz_start, j.tz_end, v_tz_start, v_tz_end)
+                end
             else true end
         and
-            case when cardinality(v_workplace) > 0 then
-                j.workplace = any(v_workplace)
+            case when v_upstream_commitment is not null then
+                j.upstream_commitment >= v_upstream_commitment
             else true end
+    ),
+    filtered_jobs as (
+        select
+            *,
+            -- The match of every job is only needed to sort by it, otherwise
+            -- it is computed just for the page of jobs returned
+            (
+                case when v_sort = 'match' and v_job_seeker_profile_id is not null then
+                    job_match(job_id, v_job_seeker_profile_id)
+                end
+            ) as job_match
+        from candidate_jobs
+        where foundation_matched
+        and kind_matched
+        and salary_matched
+        and seniority_matched
+        and workplace_matched
     )
     select json_build_object(
         'jobs',
@@ -232,23 +245,179 @@ begin
                 'upstream_commitment', upstream_commitment,
                 'employer', employer,
                 'location', location,
-                'projects', projects
+                'projects', projects,
+                'match', (
+                    case
+                        when v_sort = 'match' then job_match
+                        when v_job_seeker_profile_id is not null then
+                            job_match(job_id, v_job_seeker_profile_id)
+                    end
+                )
             )), '[]'::json)
             from (
-                select *
-                from filtered_jobs
-                order by
-                    (case when v_sort = 'open-source' then open_source end) desc nulls last,
-                    (case when v_sort = 'salary' then salary_max_usd_year end) desc nulls last,
-                    (case when v_sort = 'upstream-commitment' then upstream_commitment end) desc nulls last,
-                    published_at desc
-                limit v_limit
-                offset v_offset
+                select
+                    fj.*,
+                    (
+                        select nullif(jsonb_strip_nulls(jsonb_build_object(
+                            'company', e.company,
+                            'employer_id', e.employer_id,
+                            'logo_id', e.logo_id,
+                            'members', (
+                                select
+                                    jsonb_agg(jsonb_build_object(
+                                        'member_id', m.member_id,
+                                        'foundation', m.foundation,
+                                        'level', m.level,
+                                        'logo_url', m.logo_url,
+                                        'name', m.name
+                                    ) order by m.foundation asc, m.name asc)
+                                from employer_member em
+                                join member m on em.member_id = m.member_id
+                                where em.employer_id = e.employer_id
+                            ),
+                            'website_url', e.website_url
+                        )), '{}'::jsonb)
+                        from employer e
+                        where e.employer_id = fj.employer_id
+                    ) as employer,
+                    (
+                        select nullif(jsonb_strip_nulls(jsonb_build_object(
+                            'location_id', l.location_id,
+                            'city', l.city,
+                            'country', l.country,
+                            'state', l.state
+                        )), '{}'::jsonb)
+                        from location l
+                        where l.location_id = fj.location_id
+                    ) as location,
+                    (
+                        select json_agg(json_build_object(
+                            'project_id', p.project_id,
+                            'foundation', p.foundation,
+                            'logo_url', p.logo_url,
+                            'maturity', p.maturity,
+                            'name', p.name,
+                            'category', p.category,
+                            'description', p.description,
+                            'homepage_url', p.homepage_url,
+                            'repository_url', p.repository_url,
+                            'subcategory', p.subcategory
+                        ))
+                        from project p
+                        left join job_project using (project_id)
+                        left join job using (job_id)
+                        where job_id = fj.job_id
+                    ) as projects
+                from (
+                    select *
+                    from filtered_jobs
+                    order by
+                        (case when v_sort = 'match' then (job_match->>'score')::int end) desc nulls last,
+                        (case when v_sort = 'open-source' then open_source end) desc nulls last,
+                        (case when v_sort = 'salary' then salary_max_usd_year end) desc nulls last,
+                        (case when v_sort = 'upstream-commitment' then upstream_commitment end) desc nulls last,
+                        published_at desc
+                    limit v_limit
+                    offset v_offset
+                ) fj
             ) filtered_jobs_page
         ),
         'total',
         (
             select count(*) from filtered_jobs
+        ),
+        'facets',
+        (
+            case when p_include_facets then
+                json_build_object(
+                    'benefits', (
+                        select coalesce(json_object_agg(benefit, total), '{}'::json)
+                        from (
+                            select benefit, count(*) as total
+                            from filtered_jobs, unnest(benefits) as benefit
+                            group by benefit
+                        ) benefits_counts
+                    ),
+                    'foundation', (
+                        select coalesce(json_object_agg(foundation, total), '{}'::json)
+                        from (
+                            select p.foundation, count(distinct cj.job_id) as total
+                            from candidate_jobs cj
+                            join job_project jp on cj.job_id = jp.job_id
+                            join project p on jp.project_id = p.project_id
+                            where cj.kind_matched
+                            and cj.salary_matched
+                            and cj.seniority_matched
+                            and cj.workplace_matched
+                            group by p.foundation
+                        ) foundation_counts
+                    ),
+                    'kind', (
+                        select coalesce(json_object_agg(kind, total), '{}'::json)
+                        from (
+                            select kind, count(*) as total
+                            from candidate_jobs
+                            where foundation_matched
+                            and salary_matched
+                            and seniority_matched
+                            and workplace_matched
+                            group by kind
+                        ) kind_counts
+                    ),
+                    'salary', (
+                        select json_object_agg(salary_threshold, total)
+                        from (
+                            select
+                                salary_threshold,
+                                (
+                                    select count(*)
+                                    from candidate_jobs
+                                    where foundation_matched
+                                    and kind_matched
+                                    and seniority_matched
+                                    and workplace_matched
+                                    and salary_min_usd_year >= salary_threshold
+                                ) as total
+                            from generate_series(50000, 250000, 50000) as salary_threshold
+                        ) salary_counts
+                    ),
+                    'seniority', (
+                        select coalesce(json_object_agg(seniority, total), '{}'::json)
+                        from (
+                            select seniority, count(*) as total
+                            from candidate_jobs
+                            where seniority is not null
+                            and foundation_matched
+                            and kind_matched
+                            and salary_matched
+                            and workplace_matched
+                            group by seniority
+                        ) seniority_counts
+                    ),
+                    'skills', (
+                        select coalesce(json_object_agg(skill, total), '{}'::json)
+                        from (
+                            select skill, count(*) as total
+                            from filtered_jobs, unnest(skills) as skill
+                            group by skill
+                            order by total desc, skill asc
+                            limit 10
+                        ) skills_counts
+                    ),
+                    'workplace', (
+                        select coalesce(json_object_agg(workplace, total), '{}'::json)
+                        from (
+                            select workplace, count(*) as total
+                            from candidate_jobs
+                            where foundation_matched
+                            and kind_matched
+                            and salary_matched
+                            and seniority_matched
+                            group by workplace
+                        ) workplace_counts
+                    )
+                )
+            end
         )
     )
     );
@@ -0,0 +1,21 @@
+-- update_apply_clicks updates the apply clicks of the jobs provided.
+create or replace function update_apply_clicks(p_lock_key bigint, p_data jsonb)
+returns void as $$
+    -- Make sure only one batch of updates is processed at a time
+    select pg_advisory_xact_lock(p_lock_key);
+
+    -- Insert or update the corresponding apply clicks counters as needed
+    insert into apply_clicks (job_id, day, total)
+    select clicks_batch.*
+    from (
+        select
+            (value->>0)::uuid as job_id,
+            (value->>1)::date as day,
+            (value->>2)::integer as total
+        from jsonb_array_elements(p_data)
+    ) as clicks_batch
+    join job on job.job_id = clicks_batch.job_id
+    where job.status = 'published'
+    on conflict (job_id, day) do
+    update set total = apply_clicks.total + excluded.total;
+$$ language sql;
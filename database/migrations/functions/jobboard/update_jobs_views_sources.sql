@@ -0,0 +1,64 @@
+-- update_jobs_views_sources updates the views per traffic source of the jobs provided.
+-- To keep the number of sources bounded, up to 25 detailed sources (e.g. the
+-- referrer domains) are tracked per job and day, and views from any additional
+-- ones are counted as other.
+create or replace function update_jobs_views_sources(p_lock_key bigint, p_data jsonb)
+returns void as $$
+    -- Make sure only one batch of updates is processed at a time
+    select pg_advisory_xact_lock(p_lock_key);
+
+    -- Insert or update the corresponding views sources counters as needed
+    insert into job_views_sources (job_id, day, source, total)
+    with sources_batch as (
+        select
+            (value->>0)::uuid as job_id,
+            (value->>1)::date as day,
+            value->>2 as source,
+            (value->>3)::integer as total
+        from jsonb_array_elements(p_data)
+    ),
+    new_detailed_sources as (
+        -- Detailed sources not tracked yet for the job and day, most viewed first
+        select
+            sb.job_id,
+            sb.day,
+            sb.source,
+            row_number() over (
+                partition by sb.job_id, sb.day
+                order by sb.total desc, sb.source
+            ) as rank
+        from sources_batch sb
+        where sb.source not in ('direct', 'embed', 'other', 'search')
+        and not exists (
+            select 1 from job_views_sources jvs
+            where jvs.job_id = sb.job_id
+            and jvs.day = sb.day
+            and jvs.source = sb.source
+        )
+    ),
+    tracked_detailed_sources as (
+        select jvs.job_id, jvs.day, count(*) as total
+        from job_views_sources jvs
+        where jvs.source not in ('direct', 'embed', 'other', 'search')
+        and (jvs.job_id, jvs.day) in (select job_id, day from sources_batch)
+        group by jvs.job_id, jvs.day
+    )
+    select
+        sb.job_id,
+        sb.day,
+        case
+            when coalesce(tds.total, 0) + nds.rank > 25 then 'other'
+            else sb.source
+        end as source,
+        sum(sb.total)::integer as total
+    from sources_batch sb
+    join job on job.job_id = sb.job_id
+    left join new_detailed_sources nds
+        on nds.job_id = sb.job_id and nds.day = sb.day and nds.source = sb.source
+    left join tracked_detailed_sources tds
+        on tds.job_id = sb.job_id and tds.day = sb.day
+    where job.status = 'published'
+    group by 1, 2, 3
+    on conflict (job_id, day, source) do
+    update set total = job_views_sources.total + excluded.total;
+$$ language sql;
@@ -0,0 +1,67 @@
+-- Returns how well the job seeker profile provided matches the job provided,
+-- or null when any of them does not exist. Up to 70 points come from the
+-- skills required by the job the profile has, and up to 30 from the location
+-- fit: remote jobs fit seekers open to remote work and other jobs fit seekers
+-- located near them (100km) or open to relocation.
+create or replace function job_match(p_job_id uuid, p_job_seeker_profile_id uuid)
+returns json as $$
+    with skills as (
+        select
+            array(
+                select s from unnest(js.job_skills) s
+                where s = any(js.profile_skills)
+                order by s
+            ) as matched_skills,
+            array(
+                select s from unnest(js.job_skills) s
+                where not s = any(js.profile_skills)
+                order by s
+            ) as missing_skills
+        from (
+            select
+                array(select distinct lower(s) from unnest(j.skills) s) as job_skills,
+                array(select lower(s) from unnest(p.skills) s) as profile_skills
+            from job j, job_seeker_profile p
+            where j.job_id = p_job_id
+            and p.job_seeker_profile_id = p_job_seeker_profile_id
+        ) js
+    ),
+    location_fit as (
+        select
+            case
+                when j.workplace = 'remote' then
+                    case when coalesce(p.open_to_remote, false) then 'remote' else 'mismatch' end
+                when j.location_id = p.location_id
+                    or coalesce(st_dwithin(jl.coordinates, pl.coordinates, 100000), false) then
+                    'nearby'
+                when coalesce(p.open_to_relocation, false) then 'relocation'
+                else 'mismatch'
+            end as location_fit
+        from job j
+        join job_seeker_profile p on p.job_seeker_profile_id = p_job_seeker_profile_id
+        left join location jl on j.location_id = jl.location_id
+        left join location pl on p.location_id = pl.location_id
+        where j.job_id = p_job_id
+    )
+    select json_build_object(
+        'location_fit', lf.location_fit,
+        'matched_skills', s.matched_skills,
+        'missing_skills', s.missing_skills,
+        'score', (
+            case
+                when cardinality(s.matched_skills) + cardinality(s.missing_skills) = 0 then 35
+                else round(
+                    70.0 * cardinality(s.matched_skills)
+                    / (cardinality(s.matched_skills) + cardinality(s.missing_skills))
+                )::int
+            end
+            + case lf.location_fit
+                when 'remote' then 30
+                when 'nearby' then 30
+                when 'relocation' then 20
+                else 0
+            end
+        )
+    )
+    from skills s, location_fit lf;
+$$ language sql;
@@ -2,19 +2,29 @@
 create or replace function search_projects(p_foundation text, p_project text)
 returns json as $$
     select coalesce(json_agg(json_build_object(
+        'category', p.category,
+        'description', p.description,
         'foundation', p.foundation,
+        'homepage_url', p.homepage_url,
         'logo_url', p.logo_url,
         'maturity', p.maturity,
         'name', p.name,
-        'project_id', p.project_id
+        'project_id', p.project_id,
+        'repository_url', p.repository_url,
+        'subcategory', p.subcategory
     ) order by p.name asc), '[]'::json)
     from (
         select
+            category,
+            description,
             foundation,
+            homepage_url,
             logo_url,
             maturity,
             name,
-            project_id
+            project_id,
+            repository_url,
+            subcategory
         from project
         where foundation = p_foundation
         and name ilike '%' || p_project || '%'
@@ -0,0 +1,17 @@
+-- Returns the offset in hours of the UTC timezone provided (e.g. UTC+2,
+-- UTC-03:30), or null when it cannot be parsed or is outside the range of
+-- offsets in use (UTC-12 to UTC+14). A space is accepted as the plus sign, as
+-- it may come from a decoded query string.
+create or replace function tz_offset(p_tz text)
+returns real as $$
+    select case when o between -12 and 14 then o end
+    from (
+        select
+            case when m is null then null
+            else (
+                (case when m[1] = '-' then -1 else 1 end)
+                * (coalesce(m[2], '0')::real + coalesce(m[3], '0')::real / 60)
+            ) end as o
+        from regexp_match(trim(p_tz), '^UTC(?:([+ -]?)(\d{1,2})(?::?(\d{2}))?)?$', 'i') as m
+    ) t;
+$$ language sql immutable;
@@ -0,0 +1,26 @@
+-- Returns true if the two timezone windows provided overlap. A window whose
+-- start is east of its end wraps around the date line (e.g. UTC+10 to UTC-8).
+-- When the end of a window is missing, the window covers only its start.
+create or replace function tz_windows_overlap(
+    p_start1 text,
+    p_end1 text,
+    p_start2 text,
+    p_end2 text
+)
+returns boolean as $$
+    select
+        case
+            when s1 is null or s2 is null then false
+            when s1 > e1 and s2 > e2 then true
+            when s1 > e1 then e2 >= s1 or s2 <= e1
+            when s2 > e2 then e1 >= s2 or s1 <= e2
+            else s1 <= e2 and s2 <= e1
+        end
+    from (
+        select
+            tz_offset(p_start1) as s1,
+            coalesce(tz_offset(p_end1), tz_offset(p_start1)) as e1,
+            tz_offset(p_start2) as s2,
+            coalesce(tz_offset(p_end2), tz_offset(p_start2)) as e2
+    ) w;
+$$ language sql immutable;
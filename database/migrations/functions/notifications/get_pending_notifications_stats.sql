@@ -0,0 +1,18 @@
+-- Returns the number of notifications waiting to be delivered and the creation
+-- timestamp of the oldest one. Only notifications that can be delivered are
+-- considered (see get_pending_notification).
+create or replace function get_pending_notifications_stats()
+returns table(
+    pending bigint,
+
+    oldest_created_at timestamptz
+) as $$
+    select
+        count(*) as pending,
+
+        min(n.created_at) as oldest_created_at
+    from notification n
+    join "user" u using (user_id)
+    where n.processed = false
+    and (u.email_verified = true or n.kind = 'email-verification');
+$$ language sql;
@@ -0,0 +1,19 @@
+-- Marks the notifications that failed to be delivered as pending again so
+-- they are picked up by the notifications workers. Only notifications created
+-- after the timestamp provided are considered, when present. Returns the
+-- number of notifications updated.
+create or replace function retry_failed_notifications(p_created_after timestamptz)
+returns bigint as $$
+    with updated as (
+        update notification
+        set
+            processed = false,
+            processed_at = null,
+            error = null
+        where processed = true
+        and error is not null
+        and (p_created_after is null or created_at > p_created_after)
+        returning 1
+    )
+    select count(*) from updated;
+$$ language sql;
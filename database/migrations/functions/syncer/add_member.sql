@@ -3,9 +3,11 @@ create or replace function add_member(
     p_foundation text,
     p_name text,
     p_level text,
-    p_logo_url text
+    p_logo_url text,
+    p_homepage_url text,
+    p_crunchbase_url text
 )
 returns void as $$
-    insert into member (foundation, name, level, logo_url)
-    values (p_foundation, p_name, p_level, p_logo_url);
+    insert into member (foundation, name, level, logo_url, homepage_url, crunchbase_url)
+    values (p_foundation, p_name, p_level, p_logo_url, p_homepage_url, p_crunchbase_url);
 $$ language sql;
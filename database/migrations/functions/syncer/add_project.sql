@@ -3,9 +3,33 @@ create or replace function add_project(
     p_foundation text,
     p_name text,
     p_maturity text,
-    p_logo_url text
+    p_logo_url text,
+    p_homepage_url text,
+    p_repository_url text,
+    p_category text,
+    p_subcategory text,
+    p_description text
 )
 returns void as $$
-    insert into project (foundation, name, maturity, logo_url)
-    values (p_foundation, p_name, p_maturity, p_logo_url);
+    insert into project (
+        foundation,
+        name,
+        maturity,
+        logo_url,
+        homepage_url,
+        repository_url,
+        category,
+        subcategory,
+        description
+    ) values (
+        p_foundation,
+        p_name,
+        p_maturity,
+        p_logo_url,
+        p_homepage_url,
+        p_repository_url,
+        p_category,
+        p_subcategory,
+        p_description
+    );
 $$ language sql;
@@ -0,0 +1,20 @@
+-- Registers the end of a synchronization, along with the number of members and
+-- projects changed and the error found (if any).
+create or replace function finish_sync_run(
+    p_sync_run_id uuid,
+    p_stats jsonb,
+    p_error text
+)
+returns void as $$
+    update sync_run
+    set
+        error = p_error,
+        finished_at = current_timestamp,
+        members_added = (p_stats->>'members_added')::int,
+        members_removed = (p_stats->>'members_removed')::int,
+        members_updated = (p_stats->>'members_updated')::int,
+        projects_added = (p_stats->>'projects_added')::int,
+        projects_removed = (p_stats->>'projects_removed')::int,
+        projects_updated = (p_stats->>'projects_updated')::int
+    where sync_run_id = p_sync_run_id;
+$$ language sql;
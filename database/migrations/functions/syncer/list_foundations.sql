@@ -1,9 +1,15 @@
--- Lists all foundations with a configured landscape URL.
+-- Lists all foundations with a configured landscape URL, along with the time
+-- their last synchronization started.
 -- Uses a syncer-specific name to avoid colliding with dashboard list_foundations().
 create or replace function syncer_list_foundations()
 returns json as $$
     select coalesce(json_agg(json_build_object(
         'landscape_url', f.landscape_url,
+        'last_sync_started_at', (
+            select max(sr.started_at)
+            from sync_run sr
+            where sr.foundation = f.name
+        ),
         'name', f.name
     ) order by f.name asc), '[]'::json)
     from foundation f
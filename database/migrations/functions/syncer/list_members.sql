@@ -2,7 +2,9 @@
 create or replace function list_members(p_foundation text)
 returns json as $$
     select coalesce(json_agg(json_build_object(
+        'crunchbase_url', m.crunchbase_url,
         'foundation', p_foundation,
+        'homepage_url', m.homepage_url,
         'level', m.level,
         'logo_url', m.logo_url,
         'name', m.name
@@ -2,10 +2,15 @@
 create or replace function list_projects(p_foundation text)
 returns json as $$
     select coalesce(json_agg(json_build_object(
+        'category', p.category,
+        'description', p.description,
         'foundation', p_foundation,
+        'homepage_url', p.homepage_url,
         'logo_url', p.logo_url,
         'maturity', p.maturity,
-        'name', p.name
+        'name', p.name,
+        'repository_url', p.repository_url,
+        'subcategory', p.subcategory
     ) order by p.name asc), '[]'::json)
     from project p
     where p.foundation = p_foundation;
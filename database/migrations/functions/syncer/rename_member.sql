@@ -0,0 +1,9 @@
+-- Renames a member of the provided foundation, keeping its identifier so that
+-- the employers linked to it are not affected.
+create or replace function rename_member(p_foundation text, p_name text, p_new_name text)
+returns void as $$
+    update member
+    set name = p_new_name
+    where foundation = p_foundation
+    and name = p_name;
+$$ language sql;
@@ -0,0 +1,9 @@
+-- Renames a project of the provided foundation, keeping its identifier so that
+-- the jobs linked to it are not affected.
+create or replace function rename_project(p_foundation text, p_name text, p_new_name text)
+returns void as $$
+    update project
+    set name = p_new_name
+    where foundation = p_foundation
+    and name = p_name;
+$$ language sql;
@@ -0,0 +1,7 @@
+-- Registers the start of a synchronization of the provided foundation.
+create or replace function start_sync_run(p_foundation text)
+returns uuid as $$
+    insert into sync_run (foundation)
+    values (p_foundation)
+    returning sync_run_id;
+$$ language sql;
@@ -3,11 +3,15 @@ create or replace function update_member(
     p_foundation text,
     p_name text,
     p_level text,
-    p_logo_url text
+    p_logo_url text,
+    p_homepage_url text,
+    p_crunchbase_url text
 )
 returns void as $$
     update member
     set
+        crunchbase_url = p_crunchbase_url,
+        homepage_url = p_homepage_url,
         level = p_level,
         logo_url = p_logo_url
     where foundation = p_foundation
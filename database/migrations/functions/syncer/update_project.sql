@@ -3,13 +3,23 @@ create or replace function update_project(
     p_foundation text,
     p_name text,
     p_maturity text,
-    p_logo_url text
+    p_logo_url text,
+    p_homepage_url text,
+    p_repository_url text,
+    p_category text,
+    p_subcategory text,
+    p_description text
 )
 returns void as $$
     update project
     set
+        category = p_category,
+        description = p_description,
+        homepage_url = p_homepage_url,
+        logo_url = p_logo_url,
         maturity = p_maturity,
-        logo_url = p_logo_url
+        repository_url = p_repository_url,
+        subcategory = p_subcategory
     where foundation = p_foundation
     and name = p_name;
 $$ language sql;
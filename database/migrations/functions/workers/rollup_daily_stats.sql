@@ -0,0 +1,38 @@
+-- Compacts the daily job views and search appearances older than the horizon
+-- provided into monthly aggregates. Only whole months are rolled up, so the
+-- daily rows of the month the horizon falls in are kept until it is complete.
+-- Returns the number of daily rows compacted from each table in json format.
+create or replace function rollup_daily_stats(p_horizon_days int)
+returns json as $$
+    with
+    job_views_compacted as (
+        delete from job_views
+        where day < date_trunc('month', current_date - p_horizon_days)
+        returning job_id, day, total
+    ),
+    job_views_rolled_up as (
+        insert into job_views_monthly (job_id, month, total)
+        select job_id, date_trunc('month', day)::date, sum(total)
+        from job_views_compacted
+        group by job_id, date_trunc('month', day)
+        on conflict (job_id, month) do update
+        set total = job_views_monthly.total + excluded.total
+    ),
+    search_appearances_compacted as (
+        delete from search_appearances
+        where day < date_trunc('month', current_date - p_horizon_days)
+        returning job_id, day, total
+    ),
+    search_appearances_rolled_up as (
+        insert into search_appearances_monthly (job_id, month, total)
+        select job_id, date_trunc('month', day)::date, sum(total)
+        from search_appearances_compacted
+        group by job_id, date_trunc('month', day)
+        on conflict (job_id, month) do update
+        set total = search_appearances_monthly.total + excluded.total
+    )
+    select json_build_object(
+        'job_views', (select count(*) from job_views_compacted),
+        'search_appearances', (select count(*) from search_appearances_compacted)
+    );
+$$ language sql;
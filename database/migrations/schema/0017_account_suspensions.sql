@@ -0,0 +1,60 @@
+-- Track account suspensions applied by moderators to users and employers
+alter table "user" add column suspended_at timestamptz;
+alter table "user" add column suspended_until timestamptz;
+alter table "user" add column suspension_reason text check (suspension_reason <> '');
+
+alter table employer add column suspended_at timestamptz;
+alter table employer add column suspended_until timestamptz;
+alter table employer add column suspension_reason text check (suspension_reason <> '');
+
+-- Lookup table with the kinds of actions moderators can take
+create table moderation_action_kind (
+    moderation_action_kind_id uuid primary key default gen_random_uuid(),
+    name text not null unique check (name <> '')
+);
+
+insert into moderation_action_kind (name) values ('approve-job');
+insert into moderation_action_kind (name) values ('reject-job');
+insert into moderation_action_kind (name) values ('suspend-employer');
+insert into moderation_action_kind (name) values ('suspend-user');
+insert into moderation_action_kind (name) values ('unsuspend-employer');
+insert into moderation_action_kind (name) values ('unsuspend-user');
+
+-- Audit trail of every action taken by moderators
+create table moderation_action (
+    moderation_action_id uuid primary key default gen_random_uuid(),
+    kind text not null references moderation_action_kind (name) on delete restrict,
+    moderator_id uuid references "user" (user_id) on delete set null,
+
+    created_at timestamptz not null default current_timestamp,
+
+    employer_id uuid references employer on delete set null,
+    expires_at timestamptz,
+    job_id uuid references job on delete set null,
+    reason text check (reason <> ''),
+    user_id uuid references "user" (user_id) on delete set null
+);
+
+create index moderation_action_created_at_idx on moderation_action (created_at);
+create index moderation_action_employer_id_idx on moderation_action (employer_id);
+create index moderation_action_job_id_idx on moderation_action (job_id);
+create index moderation_action_moderator_id_idx on moderation_action (moderator_id);
+create index moderation_action_user_id_idx on moderation_action (user_id);
+
+-- Remove auth functions whose return type changes before reloading them
+drop function if exists get_user_by_email(text);
+drop function if exists get_user_by_id_verified(uuid);
+drop function if exists get_user_by_username(text);
+
+---- create above / drop below ----
+
+drop table moderation_action;
+drop table moderation_action_kind;
+
+alter table employer drop column suspension_reason;
+alter table employer drop column suspended_until;
+alter table employer drop column suspended_at;
+
+alter table "user" drop column suspension_reason;
+alter table "user" drop column suspended_until;
+alter table "user" drop column suspended_at;
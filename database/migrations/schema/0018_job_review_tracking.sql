@@ -0,0 +1,17 @@
+-- Track when jobs are submitted for review to measure moderation turnaround
+alter table job add column review_requested_at timestamptz;
+alter table moderation_action add column review_requested_at timestamptz;
+
+-- Approximate the submission time of the jobs already waiting for review
+update job set review_requested_at = coalesce(updated_at, created_at)
+where status = 'pending-approval';
+
+create index job_review_requested_at_idx on job (review_requested_at)
+where status = 'pending-approval';
+
+---- create above / drop below ----
+
+drop index job_review_requested_at_idx;
+
+alter table moderation_action drop column review_requested_at;
+alter table job drop column review_requested_at;
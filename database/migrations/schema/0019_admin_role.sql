@@ -0,0 +1,11 @@
+-- Allow some users to manage moderators and the catalogues used across the site
+alter table "user" add column admin boolean not null default false;
+
+-- Drop auth functions whose return type changes so they can be recreated
+drop function if exists get_user_by_email(text);
+drop function if exists get_user_by_id_verified(uuid);
+drop function if exists get_user_by_username(text);
+
+---- create above / drop below ----
+
+alter table "user" drop column admin;
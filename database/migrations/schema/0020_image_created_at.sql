@@ -0,0 +1,6 @@
+-- Track when images are uploaded so unreferenced ones can be collected safely
+alter table image add column created_at timestamptz not null default current_timestamp;
+
+---- create above / drop below ----
+
+alter table image drop column created_at;
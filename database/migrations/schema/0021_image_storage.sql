@@ -0,0 +1,4 @@
+-- Drop functions whose return type changes so they can be recreated
+drop function if exists delete_unreferenced_images(timestamptz);
+
+---- create above / drop below ----
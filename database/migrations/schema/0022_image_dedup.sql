@@ -0,0 +1,21 @@
+-- Identify images by their content so that identical uploads can be reused
+alter table image add column hash text unique check (hash <> '');
+
+-- Track all the users who uploaded each image, as the same image can be uploaded
+-- by several users (e.g. members of the same employer team)
+create table image_upload (
+    image_id uuid not null references image on delete cascade,
+    user_id uuid not null references "user" on delete cascade,
+    created_at timestamptz not null default current_timestamp,
+    primary key (image_id, user_id)
+);
+
+create index image_upload_user_id_idx on image_upload (user_id);
+
+insert into image_upload (image_id, user_id, created_at)
+select image_id, created_by, created_at from image where created_by is not null;
+
+---- create above / drop below ----
+
+drop table image_upload;
+alter table image drop column hash;
@@ -0,0 +1,21 @@
+-- Keep track of the foundations synchronizations run by the syncer
+create table sync_run (
+    sync_run_id uuid primary key default gen_random_uuid(),
+    foundation text not null references foundation (name) on delete cascade,
+    started_at timestamptz not null default current_timestamp,
+
+    error text,
+    finished_at timestamptz,
+    members_added int not null default 0,
+    members_removed int not null default 0,
+    members_updated int not null default 0,
+    projects_added int not null default 0,
+    projects_removed int not null default 0,
+    projects_updated int not null default 0
+);
+
+create index sync_run_foundation_started_at_idx on sync_run (foundation, started_at desc);
+
+---- create above / drop below ----
+
+drop table sync_run;
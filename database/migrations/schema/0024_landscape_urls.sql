@@ -0,0 +1,16 @@
+-- Landscape URLs used to identify members and projects when they are renamed
+alter table member add column homepage_url text check (homepage_url <> '');
+alter table project add column homepage_url text check (homepage_url <> '');
+alter table project add column repository_url text check (repository_url <> '');
+
+-- Drop functions whose signature changes so they can be recreated
+drop function if exists add_member(text, text, text, text);
+drop function if exists add_project(text, text, text, text);
+drop function if exists update_member(text, text, text, text);
+drop function if exists update_project(text, text, text, text);
+
+---- create above / drop below ----
+
+alter table member drop column homepage_url;
+alter table project drop column homepage_url;
+alter table project drop column repository_url;
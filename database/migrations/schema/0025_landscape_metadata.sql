@@ -0,0 +1,22 @@
+-- Additional landscape metadata for members and projects
+alter table member add column crunchbase_url text check (crunchbase_url <> '');
+alter table project add column category text check (category <> '');
+alter table project add column description text check (description <> '');
+alter table project add column subcategory text check (subcategory <> '');
+
+create index project_category_idx on project (category);
+
+-- Drop functions whose signature changes so they can be recreated
+drop function if exists add_member(text, text, text, text, text);
+drop function if exists add_project(text, text, text, text, text, text);
+drop function if exists update_member(text, text, text, text, text);
+drop function if exists update_project(text, text, text, text, text, text);
+
+---- create above / drop below ----
+
+drop index if exists project_category_idx;
+
+alter table member drop column crunchbase_url;
+alter table project drop column category;
+alter table project drop column description;
+alter table project drop column subcategory;
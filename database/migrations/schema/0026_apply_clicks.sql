@@ -0,0 +1,13 @@
+create table if not exists apply_clicks (
+    job_id uuid references job on delete set null,
+    day date not null,
+    total integer not null,
+    unique (job_id, day)
+);
+
+create index apply_clicks_job_id_idx on apply_clicks (job_id);
+create index apply_clicks_day_idx on apply_clicks (day);
+
+---- create above / drop below ----
+
+drop table if exists apply_clicks;
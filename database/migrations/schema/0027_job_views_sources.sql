@@ -0,0 +1,14 @@
+create table if not exists job_views_sources (
+    job_id uuid references job on delete set null,
+    day date not null,
+    source text not null check (source <> '' and length(source) <= 100),
+    total integer not null,
+    unique (job_id, day, source)
+);
+
+create index job_views_sources_job_id_idx on job_views_sources (job_id);
+create index job_views_sources_day_idx on job_views_sources (day);
+
+---- create above / drop below ----
+
+drop table if exists job_views_sources;
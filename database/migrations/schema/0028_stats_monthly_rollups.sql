@@ -0,0 +1,24 @@
+create table if not exists job_views_monthly (
+    job_id uuid references job on delete set null,
+    month date not null check (month = date_trunc('month', month)),
+    total bigint not null,
+    unique (job_id, month)
+);
+
+create index job_views_monthly_job_id_idx on job_views_monthly (job_id);
+create index job_views_monthly_month_idx on job_views_monthly (month);
+
+create table if not exists search_appearances_monthly (
+    job_id uuid references job on delete set null,
+    month date not null check (month = date_trunc('month', month)),
+    total bigint not null,
+    unique (job_id, month)
+);
+
+create index search_appearances_monthly_job_id_idx on search_appearances_monthly (job_id);
+create index search_appearances_monthly_month_idx on search_appearances_monthly (month);
+
+---- create above / drop below ----
+
+drop table if exists search_appearances_monthly;
+drop table if exists job_views_monthly;
@@ -0,0 +1,6 @@
+-- Drop the search jobs function whose signature changes so it can be recreated
+drop function if exists search_jobs(jsonb);
+
+---- create above / drop below ----
+
+-- Nothing to do
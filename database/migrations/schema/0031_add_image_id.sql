@@ -0,0 +1,9 @@
+-- Drop the add image function whose signature changes so it can be recreated
+drop function if exists add_image(uuid, text);
+
+-- Drop the delete image function, replaced by delete_unreferenced_image
+drop function if exists delete_image(uuid);
+
+---- create above / drop below ----
+
+-- Nothing to do
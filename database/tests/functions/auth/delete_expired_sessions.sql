@@ -0,0 +1,39 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into session (data, expires_at, session_id) values
+    ('{}', current_timestamp - interval '1 day', 'expired'),
+    ('{}', current_timestamp + interval '1 day', 'valid');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the number of expired sessions deleted
+select is(
+    delete_expired_sessions(),
+    1::bigint,
+    'Should return the number of expired sessions deleted'
+);
+
+-- Should keep sessions that have not expired yet
+select is(
+    (select array_agg(session_id order by session_id) from session),
+    array['valid'],
+    'Should keep sessions that have not expired yet'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -31,6 +31,7 @@ insert into job_seeker_profile (email, name, public, summary, user_id) values
 select is(
     (
         select jsonb_build_object(
+            'admin', admin,
             'auth_hash', encode(auth_hash, 'hex'),
             'email', email,
             'email_verified', email_verified,
@@ -39,12 +40,14 @@ select is(
             'moderator', moderator,
             'name', name,
             'password', password,
+            'suspended', suspended,
             'user_id', user_id::text,
             'username', username
         )
         from get_user_by_email('verified@example.com')
     ),
     jsonb_build_object(
+        'admin', false,
         'auth_hash', '01',
         'email', 'verified@example.com',
         'email_verified', true,
@@ -53,6 +56,7 @@ select is(
         'moderator', false,
         'name', 'Verified User',
         'password', null,
+        'suspended', false,
         'user_id', :'verifiedUserID',
         'username', 'verified-user'
     ),
@@ -3,12 +3,14 @@
 -- ============================================================================
 
 begin;
-select plan(3);
+select plan(5);
 
 -- ============================================================================
 -- VARIABLES
 -- ============================================================================
 
+\set expiredSuspensionUserID '00000000-0000-0000-0000-000000000104'
+\set suspendedUserID '00000000-0000-0000-0000-000000000103'
 \set unknownUserID '00000000-0000-0000-0000-999999999999'
 \set unverifiedUserID '00000000-0000-0000-0000-000000000102'
 \set verifiedUserID '00000000-0000-0000-0000-000000000101'
@@ -21,6 +23,40 @@ insert into "user" (auth_hash, email, email_verified, name, user_id, username) v
     (decode('01', 'hex'), 'verified@example.com', true, 'Verified User', :'verifiedUserID', 'verified-user'),
     (decode('02', 'hex'), 'unverified@example.com', false, 'Unverified User', :'unverifiedUserID', 'unverified-user');
 
+insert into "user" (
+    auth_hash,
+    email,
+    email_verified,
+    name,
+    suspended_at,
+    suspended_until,
+    suspension_reason,
+    user_id,
+    username
+) values
+    (
+        decode('03', 'hex'),
+        'suspended@example.com',
+        true,
+        'Suspended User',
+        current_timestamp,
+        null,
+        'Spam',
+        :'suspendedUserID',
+        'suspended-user'
+    ),
+    (
+        decode('04', 'hex'),
+        'expired@example.com',
+        true,
+        'Expired Suspension User',
+        current_timestamp - interval '10 days',
+        current_timestamp - interval '1 day',
+        'Spam',
+        :'expiredSuspensionUserID',
+        'expired-suspension-user'
+    );
+
 insert into job_seeker_profile (email, name, public, summary, user_id) values
     ('verified@example.com', 'Verified User', true, 'Profile summary', :'verifiedUserID');
 
@@ -32,6 +68,7 @@ insert into job_seeker_profile (email, name, public, summary, user_id) values
 select is(
     (
         select jsonb_build_object(
+            'admin', admin,
             'auth_hash', encode(auth_hash, 'hex'),
             'email', email,
             'email_verified', email_verified,
@@ -40,12 +77,14 @@ select is(
             'moderator', moderator,
             'name', name,
             'password', password,
+            'suspended', suspended,
             'user_id', user_id::text,
             'username', username
         )
         from get_user_by_id_verified(:'verifiedUserID'::uuid)
     ),
     jsonb_build_object(
+        'admin', false,
         'auth_hash', '01',
         'email', 'verified@example.com',
         'email_verified', true,
@@ -54,6 +93,7 @@ select is(
         'moderator', false,
         'name', 'Verified User',
         'password', null,
+        'suspended', false,
         'user_id', :'verifiedUserID',
         'username', 'verified-user'
     ),
@@ -80,6 +120,26 @@ select is(
     'Should return no row for unknown users'
 );
 
+-- Should flag users with an active suspension as suspended
+select is(
+    (
+        select suspended
+        from get_user_by_id_verified(:'suspendedUserID'::uuid)
+    ),
+    true,
+    'Should flag users with an active suspension as suspended'
+);
+
+-- Should not flag users whose suspension has expired as suspended
+select is(
+    (
+        select suspended
+        from get_user_by_id_verified(:'expiredSuspensionUserID'::uuid)
+    ),
+    false,
+    'Should not flag users whose suspension has expired as suspended'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -33,6 +33,7 @@ insert into job_seeker_profile (email, name, public, summary, user_id) values
 select is(
     (
         select jsonb_build_object(
+            'admin', admin,
             'auth_hash', encode(auth_hash, 'hex'),
             'email', email,
             'email_verified', email_verified,
@@ -41,12 +42,14 @@ select is(
             'moderator', moderator,
             'name', name,
             'password', password,
+            'suspended', suspended,
             'user_id', user_id::text,
             'username', username
         )
         from get_user_by_username('with-password')
     ),
     jsonb_build_object(
+        'admin', false,
         'auth_hash', '01',
         'email', 'with-password@example.com',
         'email_verified', true,
@@ -55,6 +58,7 @@ select is(
         'moderator', false,
         'name', 'With Password',
         'password', 'hash1',
+        'suspended', false,
         'user_id', :'withPasswordID',
         'username', 'with-password'
     ),
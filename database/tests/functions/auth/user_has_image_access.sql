@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(6);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -20,6 +20,7 @@ select plan(6);
 \set ownedImageID '00000000-0000-0000-0000-000000000201'
 \set profilePhotoImageID '00000000-0000-0000-0000-000000000203'
 \set teamUserID '00000000-0000-0000-0000-000000000003'
+\set uploaderUserID '00000000-0000-0000-0000-000000000006'
 \set unapprovedTeamUserID '00000000-0000-0000-0000-000000000004'
 
 -- ============================================================================
@@ -39,7 +40,8 @@ insert into "user" (
     (:'moderatorUserID', decode('02', 'hex'), 'moderator@example.com', true, 'Moderator User', 'moderator'),
     (:'teamUserID', decode('03', 'hex'), 'team@example.com', false, 'Team User', 'team-user'),
     (:'unapprovedTeamUserID', decode('04', 'hex'), 'pending@example.com', false, 'Pending User', 'pending-user'),
-    (:'jobSeekerUserID', decode('05', 'hex'), 'seeker@example.com', false, 'Seeker User', 'seeker-user');
+    (:'jobSeekerUserID', decode('05', 'hex'), 'seeker@example.com', false, 'Seeker User', 'seeker-user'),
+    (:'uploaderUserID', decode('06', 'hex'), 'uploader@example.com', false, 'Uploader User', 'uploader');
 
 -- Images
 insert into image (image_id, created_by) values
@@ -48,6 +50,9 @@ insert into image (image_id, created_by) values
     (:'profilePhotoImageID', null),
     (:'nonAccessibleImageID', :'creatorUserID');
 
+insert into image_upload (image_id, user_id) values
+    (:'ownedImageID', :'uploaderUserID');
+
 -- Employer and team
 insert into employer (employer_id, company, description, logo_id)
 values (:'employerID', 'Test Employer', 'Employer used in access tests', :'employerLogoImageID');
@@ -113,6 +118,12 @@ select ok(
     'Should allow users to access images they created'
 );
 
+-- Should allow users to access images they uploaded after others
+select ok(
+    user_has_image_access(:'uploaderUserID'::uuid, :'ownedImageID'::uuid),
+    'Should allow users to access images they uploaded after others'
+);
+
 -- Should allow approved employer members to access candidate profile photos
 select ok(
     user_has_image_access(:'teamUserID'::uuid, :'profilePhotoImageID'::uuid),
@@ -0,0 +1,58 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, email_verified, name, user_id, username) values
+    (decode('01', 'hex'), 'user@example.com', false, 'User', :'userID', 'user');
+
+insert into email_verification_code (user_id) values (:'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the identifier of the user whose email was verified
+select is(
+    verify_user_email('user@example.com'),
+    :'userID'::uuid,
+    'Should return the identifier of the user whose email was verified'
+);
+
+-- Should mark the user email as verified
+select ok(
+    (select email_verified from "user" where user_id = :'userID'::uuid),
+    'Should mark the user email as verified'
+);
+
+-- Should remove pending verification codes for the user
+select ok(
+    not exists (select 1 from email_verification_code where user_id = :'userID'::uuid),
+    'Should remove pending verification codes for the user'
+);
+
+-- Should raise an error when no user has the email provided
+select throws_ok(
+    $$ select verify_user_email('missing@example.com') $$,
+    'no user found with that email',
+    'Should raise an error when no user has the email provided'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
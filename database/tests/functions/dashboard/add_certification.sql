@@ -0,0 +1,62 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the identifier of the new certification
+select ok(
+    (
+        add_certification('{
+            "description": "Test certification",
+            "logo_url": "https://example.com/logo.svg",
+            "name": "Test Certified Associate",
+            "provider": "Test",
+            "short_name": "TCA",
+            "url": "https://example.com/tca"
+        }'::jsonb) is not null
+    ),
+    'Should return the identifier of the new certification'
+);
+
+-- Should store the certification details
+select is(
+    (
+        select jsonb_build_object(
+            'description', description,
+            'logo_url', logo_url,
+            'provider', provider,
+            'short_name', short_name,
+            'url', url
+        )
+        from certification
+        where name = 'Test Certified Associate'
+    ),
+    jsonb_build_object(
+        'description', 'Test certification',
+        'logo_url', 'https://example.com/logo.svg',
+        'provider', 'Test',
+        'short_name', 'TCA',
+        'url', 'https://example.com/tca'
+    ),
+    'Should store the certification details'
+);
+
+-- Should raise an error when the short name is already in use
+select throws_ok(
+    $$ select add_certification('{"name": "Other", "provider": "Test", "short_name": "TCA"}'::jsonb) $$,
+    'certification name or short name already in use',
+    'Should raise an error when the short name is already in use'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,39 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the identifier of the new foundation
+select ok(
+    (
+        add_foundation('{"name": "lfai", "landscape_url": "https://landscape.lfai.foundation"}'::jsonb) is not null
+    ),
+    'Should return the identifier of the new foundation'
+);
+
+-- Should store the foundation details
+select is(
+    (select landscape_url from foundation where name = 'lfai'),
+    'https://landscape.lfai.foundation',
+    'Should store the foundation details'
+);
+
+-- Should raise an error when the foundation already exists
+select throws_ok(
+    $$ select add_foundation('{"name": "lfai"}'::jsonb) $$,
+    'foundation already exists',
+    'Should raise an error when the foundation already exists'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
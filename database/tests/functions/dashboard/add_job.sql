@@ -99,6 +99,7 @@ select is(
             'open_source', open_source,
             'qualifications', qualifications,
             'responsibilities', responsibilities,
+            'review_requested_at_is_null', review_requested_at is null,
             'salary', salary,
             'salary_currency', salary_currency,
             'salary_max', salary_max,
@@ -130,6 +131,7 @@ select is(
         'open_source', 75,
         'qualifications', 'Strong Rust experience',
         'responsibilities', 'Build and maintain APIs',
+        'review_requested_at_is_null', true,
         'salary', 120000,
         'salary_currency', 'USD',
         'salary_max', 150000,
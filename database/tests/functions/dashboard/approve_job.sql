@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(2);
+select plan(3);
 
 -- ============================================================================
 -- VARIABLES
@@ -23,8 +23,8 @@ insert into "user" (auth_hash, email, moderator, name, user_id, username) values
 insert into employer (company, description, employer_id) values
     ('Acme', 'Employer for approve_job tests', :'employerID');
 
-insert into job (description, employer_id, job_id, kind, status, title, workplace) values
-    ('Pending approval role', :'employerID', :'jobID', 'full-time', 'pending-approval', 'Platform Engineer', 'remote');
+insert into job (description, employer_id, job_id, kind, review_requested_at, status, title, workplace) values
+    ('Pending approval role', :'employerID', :'jobID', 'full-time', '2025-01-01 00:00:00+00', 'pending-approval', 'Platform Engineer', 'remote');
 
 -- ============================================================================
 -- TESTS
@@ -52,6 +52,26 @@ select ok(
     'Should mark job as published and store reviewer metadata'
 );
 
+-- Should record the approval in the moderation audit trail
+select is(
+    (
+        select jsonb_build_object(
+            'job_id', job_id,
+            'kind', kind,
+            'moderator_id', moderator_id,
+            'review_requested_at', review_requested_at
+        )
+        from moderation_action
+    ),
+    jsonb_build_object(
+        'job_id', :'jobID'::uuid,
+        'kind', 'approve-job',
+        'moderator_id', :'reviewerID'::uuid,
+        'review_requested_at', '2025-01-01 00:00:00+00'::timestamptz
+    ),
+    'Should record the approval in the moderation audit trail'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
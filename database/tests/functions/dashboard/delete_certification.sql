@@ -0,0 +1,65 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set certification1ID '00000000-0000-0000-0000-000000000401'
+\set certification2ID '00000000-0000-0000-0000-000000000402'
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set jobID '00000000-0000-0000-0000-000000000301'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into certification (certification_id, name, provider, short_name) values
+    (:'certification1ID', 'Test Certified Associate', 'Test', 'TCA'),
+    (:'certification2ID', 'Test Certified Professional', 'Test', 'TCP');
+
+insert into employer (company, description, employer_id) values
+    ('Acme', 'Employer for delete_certification tests', :'employerID');
+
+insert into job (description, employer_id, job_id, kind, status, title, workplace) values
+    ('Job', :'employerID', :'jobID', 'full-time', 'published', 'Engineer', 'remote');
+
+insert into job_certification (certification_id, job_id) values
+    (:'certification2ID', :'jobID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should delete certifications not required by any job
+select delete_certification(:'certification1ID'::uuid);
+
+select ok(
+    not exists (select 1 from certification where certification_id = :'certification1ID'::uuid),
+    'Should delete certifications not required by any job'
+);
+
+-- Should raise an error when the certification is required by some jobs
+select throws_ok(
+    $$ select delete_certification('00000000-0000-0000-0000-000000000402'::uuid) $$,
+    'certification is required by some jobs and cannot be deleted',
+    'Should raise an error when the certification is required by some jobs'
+);
+
+-- Should raise an error when the certification does not exist
+select throws_ok(
+    $$ select delete_certification('00000000-0000-0000-0000-000000000401'::uuid) $$,
+    'certification not found',
+    'Should raise an error when the certification does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,159 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set jobID '00000000-0000-0000-0000-000000000201'
+\set otherEmployerID '00000000-0000-0000-0000-000000000102'
+\set otherEmployerJobID '00000000-0000-0000-0000-000000000203'
+\set profileID '00000000-0000-0000-0000-000000000401'
+\set quietJobID '00000000-0000-0000-0000-000000000202'
+\set userID '00000000-0000-0000-0000-000000000301'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Users and profiles
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'seeker@example.com', 'Seeker', :'userID', 'seeker');
+
+insert into job_seeker_profile (email, job_seeker_profile_id, name, summary, user_id) values
+    ('seeker@example.com', :'profileID', 'Seeker', 'Profile summary', :'userID');
+
+-- Employers and jobs
+insert into employer (employer_id, company, description) values
+    (:'employerID', 'Analytics Employer', 'Employer for get_employer_analytics tests'),
+    (:'otherEmployerID', 'Other Employer', 'Other employer');
+
+insert into job (
+    job_id,
+    employer_id,
+    kind,
+    status,
+    title,
+    workplace,
+    description
+) values
+    (:'jobID', :'employerID', 'full-time', 'published', 'Primary Job', 'remote', 'Primary job'),
+    (:'quietJobID', :'employerID', 'full-time', 'draft', 'Quiet Job', 'remote', 'Quiet job'),
+    (:'otherEmployerJobID', :'otherEmployerID', 'full-time', 'published', 'Other Job', 'remote', 'Other job');
+
+-- Counters
+insert into search_appearances (day, job_id, total) values
+    ((current_date - interval '40 days')::date, :'jobID', 300),
+    ((current_date - interval '5 days')::date, :'jobID', 20),
+    (current_date, :'jobID', 10),
+    ((current_date - interval '5 days')::date, :'otherEmployerJobID', 99);
+
+insert into job_views (day, job_id, total) values
+    ((current_date - interval '40 days')::date, :'jobID', 100),
+    ((current_date - interval '5 days')::date, :'jobID', 8),
+    (current_date, :'jobID', 2),
+    ((current_date - interval '5 days')::date, :'otherEmployerJobID', 50);
+
+insert into apply_clicks (day, job_id, total) values
+    ((current_date - interval '5 days')::date, :'jobID', 3);
+
+insert into application (job_id, job_seeker_profile_id, created_at) values
+    (:'jobID', :'profileID', current_date - interval '5 days' + interval '10 hours');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the employer's funnel for the selected period
+select is(
+    get_employer_analytics(:'employerID'::uuid, 30)::jsonb,
+    jsonb_build_object(
+        'daily', jsonb_build_array(
+            jsonb_build_object(
+                'applications', 1,
+                'apply_clicks', 3,
+                'day', (current_date - interval '5 days')::date,
+                'job_id', :'jobID',
+                'search_appearances', 20,
+                'title', 'Primary Job',
+                'views', 8
+            ),
+            jsonb_build_object(
+                'applications', 0,
+                'apply_clicks', 0,
+                'day', current_date,
+                'job_id', :'jobID',
+                'search_appearances', 10,
+                'title', 'Primary Job',
+                'views', 2
+            )
+        ),
+        'jobs', jsonb_build_array(
+            jsonb_build_object(
+                'applications', 1,
+                'apply_clicks', 3,
+                'job_id', :'jobID',
+                'search_appearances', 30,
+                'status', 'published',
+                'title', 'Primary Job',
+                'views', 10
+            ),
+            jsonb_build_object(
+                'applications', 0,
+                'apply_clicks', 0,
+                'job_id', :'quietJobID',
+                'search_appearances', 0,
+                'status', 'draft',
+                'title', 'Quiet Job',
+                'views', 0
+            )
+        ),
+        'totals', jsonb_build_object(
+            'applications', 1,
+            'apply_clicks', 3,
+            'search_appearances', 30,
+            'views', 10
+        )
+    ),
+    'Should return the employer''s funnel for the selected period'
+);
+
+-- Should only include the days within the selected period
+select is(
+    get_employer_analytics(:'employerID'::uuid, 1)::jsonb->'totals',
+    jsonb_build_object(
+        'applications', 0,
+        'apply_clicks', 0,
+        'search_appearances', 10,
+        'views', 2
+    ),
+    'Should only include the days within the selected period'
+);
+
+-- Should return empty analytics for employers without jobs
+select is(
+    get_employer_analytics('00000000-0000-0000-0000-999999999999'::uuid, 30)::jsonb,
+    '{
+        "daily": [],
+        "jobs": [],
+        "totals": {
+            "applications": 0,
+            "apply_clicks": 0,
+            "search_appearances": 0,
+            "views": 0
+        }
+    }'::jsonb,
+    'Should return empty analytics for employers without jobs'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
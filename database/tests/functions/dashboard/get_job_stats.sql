@@ -34,7 +34,7 @@ insert into job (
     (:'jobID', :'employerID', 'full-time', 'published', 'Primary Job', 'remote', 'Primary job'),
     (:'otherJobID', :'employerID', 'full-time', 'published', 'Other Job', 'remote', 'Other job');
 
--- Views and search appearances
+-- Views, views sources, search appearances and apply clicks
 insert into job_views (day, job_id, total) values
     ((current_date - interval '40 days')::date, :'jobID', 100),
     ((current_date - interval '20 days')::date, :'jobID', 2),
@@ -47,15 +47,43 @@ insert into search_appearances (day, job_id, total) values
     ((current_date - interval '5 days')::date, :'jobID', 1),
     ((current_date - interval '3 days')::date, :'otherJobID', 60);
 
+insert into job_views_sources (day, job_id, source, total) values
+    ((current_date - interval '40 days')::date, :'jobID', 'direct', 100),
+    ((current_date - interval '20 days')::date, :'jobID', 'direct', 1),
+    ((current_date - interval '20 days')::date, :'jobID', 'search', 1),
+    ((current_date - interval '5 days')::date, :'jobID', 'embed:cncf.io', 1),
+    ((current_date - interval '5 days')::date, :'jobID', 'search', 2),
+    ((current_date - interval '3 days')::date, :'otherJobID', 'direct', 50);
+
+insert into apply_clicks (day, job_id, total) values
+    ((current_date - interval '40 days')::date, :'jobID', 9),
+    ((current_date - interval '5 days')::date, :'jobID', 4),
+    ((current_date - interval '3 days')::date, :'otherJobID', 6);
+
+-- Views and search appearances rolled up into monthly aggregates
+insert into job_views_monthly (job_id, month, total) values
+    (:'jobID', date_trunc('month', current_date - interval '2 years')::date, 1000),
+    (:'otherJobID', date_trunc('month', current_date - interval '2 years')::date, 500);
+
+insert into search_appearances_monthly (job_id, month, total) values
+    (:'jobID', date_trunc('month', current_date - interval '2 years')::date, 300);
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
 
--- Should return aggregated stats for the selected job and last month only
+-- Should return aggregated stats for the selected job, including rolled up totals
 select is(
     get_job_stats(:'jobID'::uuid)::jsonb,
     (
         select jsonb_build_object(
+            'apply_clicks_daily', jsonb_build_array(
+                jsonb_build_array(
+                    (extract(epoch from (current_date - interval '5 days')::date) * 1000)::bigint,
+                    4
+                )
+            ),
+            'apply_clicks_total_last_month', 4,
             'search_appearances_daily', jsonb_build_array(
                 jsonb_build_array(
                     (extract(epoch from (current_date - interval '20 days')::date) * 1000)::bigint,
@@ -66,6 +94,7 @@ select is(
                     1
                 )
             ),
+            'search_appearances_total', 338,
             'search_appearances_total_last_month', 8,
             'views_daily', jsonb_build_array(
                 jsonb_build_array(
@@ -77,19 +106,30 @@ select is(
                     3
                 )
             ),
+            'views_sources_last_month', jsonb_build_array(
+                jsonb_build_array('search', 3),
+                jsonb_build_array('direct', 1),
+                jsonb_build_array('embed:cncf.io', 1)
+            ),
+            'views_total', 1105,
             'views_total_last_month', 5
         )
     ),
-    'Should return aggregated stats for the selected job and last month only'
+    'Should return aggregated stats for the selected job, including rolled up totals'
 );
 
 -- Should return empty stats when the job has no counters
 select is(
     get_job_stats(:'unknownJobID'::uuid)::jsonb,
     '{
+        "apply_clicks_daily": [],
+        "apply_clicks_total_last_month": 0,
         "search_appearances_daily": [],
+        "search_appearances_total": 0,
         "search_appearances_total_last_month": 0,
         "views_daily": [],
+        "views_sources_last_month": [],
+        "views_total": 0,
         "views_total_last_month": 0
     }'::jsonb,
     'Should return empty stats when the job has no counters'
@@ -0,0 +1,132 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set job1ID '00000000-0000-0000-0000-000000000301'
+\set job2ID '00000000-0000-0000-0000-000000000302'
+\set job3ID '00000000-0000-0000-0000-000000000303'
+\set job4ID '00000000-0000-0000-0000-000000000304'
+\set moderator1ID '00000000-0000-0000-0000-000000000201'
+\set moderator2ID '00000000-0000-0000-0000-000000000202'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'alice@example.com', true, 'Alice', :'moderator1ID', 'alice'),
+    (decode('02', 'hex'), 'bob@example.com', true, 'Bob', :'moderator2ID', 'bob');
+
+insert into employer (company, description, employer_id) values
+    ('Acme', 'Employer for get_moderation_stats tests', :'employerID');
+
+insert into job (description, employer_id, job_id, kind, review_requested_at, status, title, workplace) values
+    ('Job one', :'employerID', :'job1ID', 'full-time', current_timestamp - interval '3 days', 'pending-approval', 'Oldest', 'remote'),
+    ('Job two', :'employerID', :'job2ID', 'full-time', current_timestamp - interval '1 hour', 'pending-approval', 'Newest', 'remote'),
+    ('Job three', :'employerID', :'job3ID', 'full-time', null, 'published', 'Approved', 'remote'),
+    ('Job four', :'employerID', :'job4ID', 'full-time', null, 'rejected', 'Rejected', 'remote');
+
+insert into moderation_action (created_at, job_id, kind, moderator_id, review_requested_at) values
+    (current_timestamp - interval '1 day', :'job3ID', 'approve-job', :'moderator1ID', current_timestamp - interval '3 days'),
+    (current_timestamp - interval '1 day', :'job4ID', 'reject-job', :'moderator1ID', current_timestamp - interval '2 days'),
+    (current_timestamp - interval '2 days', :'job4ID', 'approve-job', :'moderator2ID', null),
+    (current_timestamp - interval '1 year', :'job3ID', 'approve-job', :'moderator2ID', current_timestamp - interval '13 months');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should count pending jobs and those waiting longer than the SLA
+select is(
+    (
+        select jsonb_build_object(
+            'pending', stats->'reviews'->'pending',
+            'pending_over_sla', stats->'reviews'->'pending_over_sla',
+            'review_sla_hours', stats->'review_sla_hours'
+        )
+        from (
+            select get_moderation_stats(48)::jsonb as stats
+        ) t
+    ),
+    jsonb_build_object(
+        'pending', 2,
+        'pending_over_sla', 1,
+        'review_sla_hours', 48
+    ),
+    'Should count pending jobs and those waiting longer than the SLA'
+);
+
+-- Should list the oldest pending jobs first and flag those over the SLA
+select is(
+    (
+        select jsonb_build_object(
+            'over_sla', jsonb_path_query_array(stats->'reviews'->'oldest_pending', '$[*].over_sla'),
+            'titles', jsonb_path_query_array(stats->'reviews'->'oldest_pending', '$[*].title')
+        )
+        from (
+            select get_moderation_stats(48)::jsonb as stats
+        ) t
+    ),
+    jsonb_build_object(
+        'over_sla', '[true, false]'::jsonb,
+        'titles', '["Oldest", "Newest"]'::jsonb
+    ),
+    'Should list the oldest pending jobs first and flag those over the SLA'
+);
+
+-- Should aggregate recent approvals and rejections per moderator
+select is(
+    (
+        select stats->'reviews'->'decisions_per_moderator'
+        from (
+            select get_moderation_stats(48)::jsonb as stats
+        ) t
+    ),
+    '[["Alice", 1, 1], ["Bob", 1, 0]]'::jsonb,
+    'Should aggregate recent approvals and rejections per moderator'
+);
+
+-- Should compute the median review time from recent decisions
+select is(
+    (
+        select stats->'reviews'->'median_review_time'
+        from (
+            select get_moderation_stats(48)::jsonb as stats
+        ) t
+    ),
+    to_jsonb(129600),
+    'Should compute the median review time from recent decisions'
+);
+
+-- Should track the pending queue size at the end of each day
+select is(
+    (
+        select jsonb_build_array(
+            stats->'reviews'->'pending_daily'->-5,
+            stats->'reviews'->'pending_daily'->-1
+        )
+        from (
+            select get_moderation_stats(48)::jsonb as stats
+        ) t
+    ),
+    jsonb_build_array(
+        jsonb_build_array(floor(extract(epoch from current_date - interval '4 days') * 1000), 0),
+        jsonb_build_array(floor(extract(epoch from current_date::timestamp) * 1000), 2)
+    ),
+    'Should track the pending queue size at the end of each day'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,52 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set unverifiedUserID '00000000-0000-0000-0000-000000000102'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, email_verified, name, user_id, username) values
+    (decode('01', 'hex'), 'user@example.com', true, 'User', :'userID', 'user'),
+    (decode('02', 'hex'), 'unverified@example.com', false, 'Unverified', :'unverifiedUserID', 'unverified');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the identifier of the user granted moderator rights
+select is(
+    grant_moderator('user@example.com'),
+    :'userID'::uuid,
+    'Should return the identifier of the user granted moderator rights'
+);
+
+-- Should mark the user as moderator
+select ok(
+    (select moderator from "user" where user_id = :'userID'::uuid),
+    'Should mark the user as moderator'
+);
+
+-- Should raise an error when no verified user has the email provided
+select throws_ok(
+    $$ select grant_moderator('unverified@example.com') $$,
+    'no verified user found with that email',
+    'Should raise an error when no verified user has the email provided'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,60 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(1);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set foundationID '00000000-0000-0000-0000-000000000301'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+delete from foundation;
+
+insert into foundation (foundation_id, landscape_url, name) values
+    (:'foundationID', 'https://landscape.cncf.io', 'cncf');
+
+insert into project (foundation, logo_url, maturity, name) values
+    ('cncf', 'https://example.com/k8s.svg', 'graduated', 'kubernetes'),
+    ('cncf', 'https://example.com/prometheus.svg', 'graduated', 'prometheus');
+
+insert into member (foundation, level, logo_url, name) values
+    ('cncf', 'platinum', 'https://example.com/acme.svg', 'acme');
+
+insert into sync_run (error, finished_at, foundation, started_at) values
+    (null, '2026-01-01 10:05:00+00', 'cncf', '2026-01-01 10:00:00+00'),
+    ('error fetching landscape members', '2026-01-02 10:05:00+00', 'cncf', '2026-01-02 10:00:00+00');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return foundations with their projects and members counts and last
+-- successful synchronization
+select is(
+    list_foundations_for_admin()::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'foundation_id', :'foundationID',
+            'landscape_url', 'https://landscape.cncf.io',
+            'last_synced_at', '2026-01-01T10:05:00+00:00',
+            'members', 1,
+            'name', 'cncf',
+            'projects', 2
+        )
+    ),
+    'Should return foundations with their projects and members counts and last successful synchronization'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,84 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set jobID '00000000-0000-0000-0000-000000000301'
+\set moderatorID '00000000-0000-0000-0000-000000000201'
+\set userID '00000000-0000-0000-0000-000000000202'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'moderator@example.com', true, 'Moderator', :'moderatorID', 'moderator'),
+    (decode('02', 'hex'), 'user@example.com', false, 'User', :'userID', 'user');
+
+insert into employer (company, description, employer_id) values
+    ('Acme', 'Employer for list_moderation_actions tests', :'employerID');
+
+insert into job (description, employer_id, job_id, kind, status, title, workplace) values
+    ('Role', :'employerID', :'jobID', 'full-time', 'rejected', 'Engineer', 'remote');
+
+insert into moderation_action (created_at, employer_id, expires_at, job_id, kind, moderator_id, reason, user_id) values
+    ('2026-01-01 10:00:00+00', null, null, :'jobID', 'reject-job', :'moderatorID', 'Needs detail', null),
+    ('2026-01-02 10:00:00+00', null, '2099-01-01 00:00:00+00', null, 'suspend-user', :'moderatorID', 'Spam', :'userID'),
+    ('2026-01-03 10:00:00+00', :'employerID', null, null, 'suspend-employer', null, 'Fake jobs', null);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the most recent actions first with the related names
+select is(
+    (
+        select jsonb_agg(action - 'moderation_action_id')
+        from jsonb_array_elements(list_moderation_actions(10)::jsonb) as action
+    ),
+    jsonb_build_array(
+        jsonb_build_object(
+            'created_at', '2026-01-03 10:00:00+00'::timestamptz,
+            'employer_company', 'Acme',
+            'kind', 'suspend-employer',
+            'reason', 'Fake jobs'
+        ),
+        jsonb_build_object(
+            'created_at', '2026-01-02 10:00:00+00'::timestamptz,
+            'expires_at', '2099-01-01 00:00:00+00'::timestamptz,
+            'kind', 'suspend-user',
+            'moderator_name', 'Moderator',
+            'reason', 'Spam',
+            'user_name', 'User'
+        ),
+        jsonb_build_object(
+            'created_at', '2026-01-01 10:00:00+00'::timestamptz,
+            'job_title', 'Engineer',
+            'kind', 'reject-job',
+            'moderator_name', 'Moderator',
+            'reason', 'Needs detail'
+        )
+    ),
+    'Should return the most recent actions first with the related names'
+);
+
+-- Should limit the number of actions returned
+select is(
+    json_array_length(list_moderation_actions(1)),
+    1,
+    'Should limit the number of actions returned'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
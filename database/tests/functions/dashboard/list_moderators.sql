@@ -0,0 +1,56 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set moderatorID '00000000-0000-0000-0000-000000000201'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (admin, auth_hash, email, moderator, name, user_id, username) values
+    (true, decode('01', 'hex'), 'moderator@example.com', true, 'Moderator', :'moderatorID', 'moderator'),
+    (false, decode('02', 'hex'), 'user@example.com', false, 'User', :'userID', 'user');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return only users with moderator rights
+select is(
+    list_moderators()::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'admin', true,
+            'email', 'moderator@example.com',
+            'name', 'Moderator',
+            'user_id', :'moderatorID',
+            'username', 'moderator'
+        )
+    ),
+    'Should return only users with moderator rights'
+);
+
+-- Should return an empty array when there are no moderators
+update "user" set moderator = false;
+
+select is(
+    list_moderators()::jsonb,
+    '[]'::jsonb,
+    'Should return an empty array when there are no moderators'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,93 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set applicationID '00000000-0000-0000-0000-000000000601'
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set job1ID '00000000-0000-0000-0000-000000000301'
+\set job2ID '00000000-0000-0000-0000-000000000302'
+\set job3ID '00000000-0000-0000-0000-000000000303'
+\set job4ID '00000000-0000-0000-0000-000000000304'
+\set profileID '00000000-0000-0000-0000-000000000501'
+\set userID '00000000-0000-0000-0000-000000000201'
+\set userNoProfileID '00000000-0000-0000-0000-000000000202'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'alice@example.com', 'Alice', :'userID', 'alice'),
+    (decode('02', 'hex'), 'bob@example.com', 'Bob', :'userNoProfileID', 'bob');
+
+insert into job_seeker_profile (
+    email,
+    job_seeker_profile_id,
+    name,
+    open_to_remote,
+    skills,
+    summary,
+    user_id
+) values (
+    'alice@example.com',
+    :'profileID',
+    'Alice',
+    true,
+    array['Rust', 'Kubernetes'],
+    'Profile summary',
+    :'userID'
+);
+
+insert into employer (company, description, employer_id) values
+    ('Acme', 'Employer for list_recommended_jobs tests', :'employerID');
+
+insert into job (description, employer_id, job_id, kind, published_at, skills, status, title, workplace) values
+    ('Role one', :'employerID', :'job1ID', 'full-time', '2026-01-01 10:00:00+00', array['rust'], 'published', 'Role One', 'on-site'),
+    ('Role two', :'employerID', :'job2ID', 'full-time', '2026-01-02 10:00:00+00', array['rust'], 'published', 'Role Two', 'remote'),
+    ('Role three', :'employerID', :'job3ID', 'full-time', '2026-01-03 10:00:00+00', array['rust', 'kubernetes'], 'published', 'Role Three', 'on-site'),
+    ('Role four', :'employerID', :'job4ID', 'full-time', '2026-01-04 10:00:00+00', array['java'], 'published', 'Role Four', 'remote');
+
+insert into application (application_id, job_id, job_seeker_profile_id) values
+    (:'applicationID', :'job3ID', :'profileID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should recommend jobs matching the profile skills, preferring remote ones
+select is(
+    (
+        select array_agg(j->>'job_id')
+        from json_array_elements(list_recommended_jobs(:'userID'::uuid, 10)) j
+    ),
+    array[:'job2ID'::text, :'job1ID'::text],
+    'Should recommend jobs matching the profile skills, preferring remote ones'
+);
+
+-- Should return up to the limit provided
+select is(
+    json_array_length(list_recommended_jobs(:'userID'::uuid, 1)),
+    1,
+    'Should return up to the limit provided'
+);
+
+-- Should return an empty array when the user has no profile
+select is(
+    list_recommended_jobs(:'userNoProfileID'::uuid, 10)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty array when the user has no profile'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -74,6 +74,7 @@ select ok(
         and salary_usd_year = 120000
         and salary_min_usd_year = 100000
         and salary_max_usd_year = 150000
+        and review_requested_at is not null
         and updated_at is not null
     ),
     'Should publish draft jobs and update normalized salary fields'
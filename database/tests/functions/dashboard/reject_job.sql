@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(3);
+select plan(4);
 
 -- ============================================================================
 -- VARIABLES
@@ -25,12 +25,13 @@ insert into "user" (auth_hash, email, moderator, name, user_id, username) values
 insert into employer (company, description, employer_id) values
     ('Acme', 'Employer for reject_job tests', :'employerID');
 
-insert into job (description, employer_id, job_id, kind, status, title, workplace) values
+insert into job (description, employer_id, job_id, kind, review_requested_at, status, title, workplace) values
     (
         'Pending approval role',
         :'employerID',
         :'pendingApprovalJobID',
         'full-time',
+        '2025-01-01 00:00:00+00',
         'pending-approval',
         'Platform Engineer',
         'remote'
@@ -40,6 +41,7 @@ insert into job (description, employer_id, job_id, kind, status, title, workplac
         :'employerID',
         :'publishedJobID',
         'full-time',
+        '2024-12-01 00:00:00+00',
         'published',
         'Senior Engineer',
         'remote'
@@ -49,6 +51,7 @@ insert into job (description, employer_id, job_id, kind, status, title, workplac
         :'employerID',
         :'draftJobID',
         'full-time',
+        null,
         'draft',
         'Draft Engineer',
         'remote'
@@ -125,6 +128,21 @@ select is(
     'Should not reject jobs outside the allowed statuses'
 );
 
+-- Should record only the effective rejections in the moderation audit trail
+select results_eq(
+    $$
+        select job_id, kind, moderator_id, reason, review_requested_at
+        from moderation_action
+        order by reason
+    $$,
+    $$
+        values
+            ('00000000-0000-0000-0000-000000000301'::uuid, 'reject-job', '00000000-0000-0000-0000-000000000201'::uuid, 'Needs more detail', '2025-01-01 00:00:00+00'::timestamptz),
+            ('00000000-0000-0000-0000-000000000302'::uuid, 'reject-job', '00000000-0000-0000-0000-000000000201'::uuid, 'Role was not approved for republishing', null::timestamptz)
+    $$,
+    'Should record only the effective rejections in the moderation audit trail'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -0,0 +1,45 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'moderator@example.com', true, 'Moderator', :'userID', 'moderator');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should remove the moderator rights of the user
+select revoke_moderator(:'userID'::uuid);
+
+select ok(
+    not (select moderator from "user" where user_id = :'userID'::uuid),
+    'Should remove the moderator rights of the user'
+);
+
+-- Should raise an error when the user is not a moderator
+select throws_ok(
+    $$ select revoke_moderator('00000000-0000-0000-0000-000000000101'::uuid) $$,
+    'moderator not found',
+    'Should raise an error when the user is not a moderator'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
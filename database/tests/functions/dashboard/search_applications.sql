@@ -171,6 +171,17 @@ select is(
                 'remote',
                 'last_position',
                 'Staff Engineer at Acme',
+                'match',
+                jsonb_build_object(
+                    'location_fit',
+                    'mismatch',
+                    'matched_skills',
+                    '[]'::jsonb,
+                    'missing_skills',
+                    '[]'::jsonb,
+                    'score',
+                    35
+                ),
                 'name',
                 'Alice',
                 'photo_id',
@@ -193,6 +204,17 @@ select is(
                 'hybrid',
                 'last_position',
                 'Backend Engineer at Beta',
+                'match',
+                jsonb_build_object(
+                    'location_fit',
+                    'mismatch',
+                    'matched_skills',
+                    '[]'::jsonb,
+                    'missing_skills',
+                    '[]'::jsonb,
+                    'score',
+                    35
+                ),
                 'name',
                 'Bob',
                 'photo_id',
@@ -231,6 +253,17 @@ select is(
                 'hybrid',
                 'last_position',
                 'Backend Engineer at Beta',
+                'match',
+                jsonb_build_object(
+                    'location_fit',
+                    'mismatch',
+                    'matched_skills',
+                    '[]'::jsonb,
+                    'missing_skills',
+                    '[]'::jsonb,
+                    'score',
+                    35
+                ),
                 'name',
                 'Bob',
                 'photo_id',
@@ -269,6 +302,17 @@ select is(
                 'hybrid',
                 'last_position',
                 'Backend Engineer at Beta',
+                'match',
+                jsonb_build_object(
+                    'location_fit',
+                    'mismatch',
+                    'matched_skills',
+                    '[]'::jsonb,
+                    'missing_skills',
+                    '[]'::jsonb,
+                    'score',
+                    35
+                ),
                 'name',
                 'Bob',
                 'photo_id',
@@ -0,0 +1,103 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employer1ID '00000000-0000-0000-0000-000000000101'
+\set employer2ID '00000000-0000-0000-0000-000000000102'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into employer (
+    company,
+    created_at,
+    description,
+    employer_id,
+    suspended_at,
+    suspended_until,
+    suspension_reason,
+    website_url
+) values
+    (
+        'Acme',
+        '2026-01-01 10:00:00+00',
+        'Employer one',
+        :'employer1ID',
+        null,
+        null,
+        null,
+        'https://acme.example'
+    ),
+    (
+        'Shady Corp',
+        '2025-12-31 10:00:00+00',
+        'Employer two',
+        :'employer2ID',
+        '2026-01-02 10:00:00+00',
+        '2099-01-01 00:00:00+00',
+        'Fake jobs',
+        null
+    );
+
+insert into job (description, employer_id, kind, status, title, workplace) values
+    ('Published role', :'employer1ID', 'full-time', 'published', 'Engineer', 'remote'),
+    ('Draft role', :'employer1ID', 'full-time', 'draft', 'Draft Engineer', 'remote');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return suspended employers first, with their published jobs count
+select is(
+    search_employers_for_moderation('{}'::jsonb)::jsonb,
+    jsonb_build_object(
+        'employers',
+        jsonb_build_array(
+            jsonb_build_object(
+                'company', 'Shady Corp',
+                'created_at', '2025-12-31 10:00:00+00'::timestamptz,
+                'employer_id', :'employer2ID'::uuid,
+                'published_jobs', 0,
+                'suspended', true,
+                'suspended_at', '2026-01-02 10:00:00+00'::timestamptz,
+                'suspended_until', '2099-01-01 00:00:00+00'::timestamptz,
+                'suspension_reason', 'Fake jobs'
+            ),
+            jsonb_build_object(
+                'company', 'Acme',
+                'created_at', '2026-01-01 10:00:00+00'::timestamptz,
+                'employer_id', :'employer1ID'::uuid,
+                'published_jobs', 1,
+                'suspended', false,
+                'website_url', 'https://acme.example'
+            )
+        ),
+        'total', 2
+    ),
+    'Should return suspended employers first, with their published jobs count'
+);
+
+-- Should filter employers by company or website
+select is(
+    jsonb_path_query_array(
+        search_employers_for_moderation('{"ts_query": "acme.example"}'::jsonb)::jsonb,
+        '$.employers[*].company'
+    ),
+    '["Acme"]'::jsonb,
+    'Should filter employers by company or website'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
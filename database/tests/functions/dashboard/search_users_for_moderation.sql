@@ -0,0 +1,143 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set user1ID '00000000-0000-0000-0000-000000000101'
+\set user2ID '00000000-0000-0000-0000-000000000102'
+\set user3ID '00000000-0000-0000-0000-000000000103'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (
+    auth_hash,
+    created_at,
+    email,
+    moderator,
+    name,
+    suspended_at,
+    suspension_reason,
+    user_id,
+    username
+) values
+    (
+        decode('01', 'hex'),
+        '2026-01-01 10:00:00+00',
+        'alice@example.com',
+        false,
+        'Alice',
+        null,
+        null,
+        :'user1ID',
+        'alice'
+    ),
+    (
+        decode('02', 'hex'),
+        '2026-01-02 10:00:00+00',
+        'bob@example.com',
+        true,
+        'Bob',
+        null,
+        null,
+        :'user2ID',
+        'bob'
+    ),
+    (
+        decode('03', 'hex'),
+        '2025-12-31 10:00:00+00',
+        'carol@spam.example',
+        false,
+        'Carol',
+        '2026-01-03 10:00:00+00',
+        'Spam',
+        :'user3ID',
+        'carol'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return suspended users first, then the most recent ones
+select is(
+    search_users_for_moderation('{}'::jsonb)::jsonb,
+    jsonb_build_object(
+        'users',
+        jsonb_build_array(
+            jsonb_build_object(
+                'created_at', '2025-12-31 10:00:00+00'::timestamptz,
+                'email', 'carol@spam.example',
+                'moderator', false,
+                'name', 'Carol',
+                'suspended', true,
+                'suspended_at', '2026-01-03 10:00:00+00'::timestamptz,
+                'suspension_reason', 'Spam',
+                'user_id', :'user3ID'::uuid,
+                'username', 'carol'
+            ),
+            jsonb_build_object(
+                'created_at', '2026-01-02 10:00:00+00'::timestamptz,
+                'email', 'bob@example.com',
+                'moderator', true,
+                'name', 'Bob',
+                'suspended', false,
+                'user_id', :'user2ID'::uuid,
+                'username', 'bob'
+            ),
+            jsonb_build_object(
+                'created_at', '2026-01-01 10:00:00+00'::timestamptz,
+                'email', 'alice@example.com',
+                'moderator', false,
+                'name', 'Alice',
+                'suspended', false,
+                'user_id', :'user1ID'::uuid,
+                'username', 'alice'
+            )
+        ),
+        'total', 3
+    ),
+    'Should return suspended users first, then the most recent ones'
+);
+
+-- Should filter users by email, name or username
+select is(
+    jsonb_path_query_array(
+        search_users_for_moderation('{"ts_query": "SPAM"}'::jsonb)::jsonb,
+        '$.users[*].username'
+    ),
+    '["carol"]'::jsonb,
+    'Should filter users by email, name or username'
+);
+
+-- Should paginate results while returning the total number of matches
+select is(
+    (
+        select jsonb_build_object(
+            'total', output->'total',
+            'usernames', jsonb_path_query_array(output, '$.users[*].username')
+        )
+        from (
+            select search_users_for_moderation('{"limit": 1, "offset": 1}'::jsonb)::jsonb as output
+        ) as search
+    ),
+    jsonb_build_object(
+        'total', 3,
+        'usernames', '["bob"]'::jsonb
+    ),
+    'Should paginate results while returning the total number of matches'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,91 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set moderatorID '00000000-0000-0000-0000-000000000201'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'moderator@example.com', true, 'Moderator', :'moderatorID', 'moderator');
+
+insert into employer (company, description, employer_id) values
+    ('Acme', 'Employer for suspend_employer tests', :'employerID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should mark the employer as suspended indefinitely when no expiry is provided
+select suspend_employer(:'employerID'::uuid, :'moderatorID'::uuid, 'Fake jobs', null);
+
+select is(
+    (
+        select jsonb_build_object(
+            'suspended_at_is_null', suspended_at is null,
+            'suspended_until', suspended_until,
+            'suspension_reason', suspension_reason
+        )
+        from employer
+        where employer_id = :'employerID'::uuid
+    ),
+    jsonb_build_object(
+        'suspended_at_is_null', false,
+        'suspended_until', null,
+        'suspension_reason', 'Fake jobs'
+    ),
+    'Should mark the employer as suspended indefinitely when no expiry is provided'
+);
+
+-- Should record the suspension in the moderation audit trail
+select is(
+    (
+        select jsonb_build_object(
+            'employer_id', employer_id,
+            'expires_at', expires_at,
+            'kind', kind,
+            'moderator_id', moderator_id,
+            'reason', reason
+        )
+        from moderation_action
+    ),
+    jsonb_build_object(
+        'employer_id', :'employerID'::uuid,
+        'expires_at', null,
+        'kind', 'suspend-employer',
+        'moderator_id', :'moderatorID'::uuid,
+        'reason', 'Fake jobs'
+    ),
+    'Should record the suspension in the moderation audit trail'
+);
+
+-- Should not allow suspensions that expire in the past
+select throws_ok(
+    $$ select suspend_employer('00000000-0000-0000-0000-000000000101'::uuid, '00000000-0000-0000-0000-000000000201'::uuid, 'Test', '2000-01-01 00:00:00+00'::timestamptz) $$,
+    'suspension expiry must be in the future',
+    'Should not allow suspensions that expire in the past'
+);
+
+-- Should raise an error when the employer does not exist
+select throws_ok(
+    $$ select suspend_employer('00000000-0000-0000-0000-999999999999'::uuid, '00000000-0000-0000-0000-000000000201'::uuid, 'Test', null) $$,
+    'employer not found',
+    'Should raise an error when the employer does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
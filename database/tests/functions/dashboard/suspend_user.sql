@@ -0,0 +1,122 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set moderatorID '00000000-0000-0000-0000-000000000201'
+\set otherUserID '00000000-0000-0000-0000-000000000102'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'user@example.com', false, 'User', :'userID', 'user'),
+    (decode('02', 'hex'), 'other@example.com', false, 'Other', :'otherUserID', 'other'),
+    (decode('03', 'hex'), 'moderator@example.com', true, 'Moderator', :'moderatorID', 'moderator');
+
+insert into session (session_id, data, expires_at) values
+    (
+        'session-user',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'userID')),
+        current_timestamp + interval '1 day'
+    ),
+    (
+        'session-other',
+        jsonb_build_object('axum-login.data', jsonb_build_object('user_id', :'otherUserID')),
+        current_timestamp + interval '1 day'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should mark the user as suspended with the reason and expiry provided
+select suspend_user(
+    :'userID'::uuid,
+    :'moderatorID'::uuid,
+    'Spam',
+    '2099-01-01 00:00:00+00'::timestamptz
+);
+
+select is(
+    (
+        select jsonb_build_object(
+            'suspended_at_is_null', suspended_at is null,
+            'suspended_until', suspended_until,
+            'suspension_reason', suspension_reason
+        )
+        from "user"
+        where user_id = :'userID'::uuid
+    ),
+    jsonb_build_object(
+        'suspended_at_is_null', false,
+        'suspended_until', '2099-01-01 00:00:00+00'::timestamptz,
+        'suspension_reason', 'Spam'
+    ),
+    'Should mark the user as suspended with the reason and expiry provided'
+);
+
+-- Should revoke only the sessions of the suspended user
+select results_eq(
+    $$ select session_id from session order by session_id $$,
+    $$ values ('session-other'::text) $$,
+    'Should revoke only the sessions of the suspended user'
+);
+
+-- Should record the suspension in the moderation audit trail
+select is(
+    (
+        select jsonb_build_object(
+            'expires_at', expires_at,
+            'kind', kind,
+            'moderator_id', moderator_id,
+            'reason', reason,
+            'user_id', user_id
+        )
+        from moderation_action
+    ),
+    jsonb_build_object(
+        'expires_at', '2099-01-01 00:00:00+00'::timestamptz,
+        'kind', 'suspend-user',
+        'moderator_id', :'moderatorID'::uuid,
+        'reason', 'Spam',
+        'user_id', :'userID'::uuid
+    ),
+    'Should record the suspension in the moderation audit trail'
+);
+
+-- Should not allow moderators to suspend their own account
+select throws_ok(
+    $$ select suspend_user('00000000-0000-0000-0000-000000000201'::uuid, '00000000-0000-0000-0000-000000000201'::uuid, 'Test', null) $$,
+    'cannot suspend your own account',
+    'Should not allow moderators to suspend their own account'
+);
+
+-- Should not allow suspensions that expire in the past
+select throws_ok(
+    $$ select suspend_user('00000000-0000-0000-0000-000000000102'::uuid, '00000000-0000-0000-0000-000000000201'::uuid, 'Test', '2000-01-01 00:00:00+00'::timestamptz) $$,
+    'suspension expiry must be in the future',
+    'Should not allow suspensions that expire in the past'
+);
+
+-- Should raise an error when the user does not exist
+select throws_ok(
+    $$ select suspend_user('00000000-0000-0000-0000-999999999999'::uuid, '00000000-0000-0000-0000-000000000201'::uuid, 'Test', null) $$,
+    'user not found',
+    'Should raise an error when the user does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
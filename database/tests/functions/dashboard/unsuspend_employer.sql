@@ -0,0 +1,87 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set moderatorID '00000000-0000-0000-0000-000000000201'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'moderator@example.com', true, 'Moderator', :'moderatorID', 'moderator');
+
+insert into employer (
+    company,
+    description,
+    employer_id,
+    suspended_at,
+    suspended_until,
+    suspension_reason
+) values (
+    'Acme',
+    'Employer for unsuspend_employer tests',
+    :'employerID',
+    current_timestamp,
+    current_timestamp + interval '7 days',
+    'Fake jobs'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should clear the suspension details of the employer
+select unsuspend_employer(:'employerID'::uuid, :'moderatorID'::uuid);
+
+select ok(
+    exists (
+        select 1
+        from employer
+        where employer_id = :'employerID'::uuid
+        and suspended_at is null
+        and suspended_until is null
+        and suspension_reason is null
+    ),
+    'Should clear the suspension details of the employer'
+);
+
+-- Should record the action in the moderation audit trail
+select is(
+    (
+        select jsonb_build_object(
+            'employer_id', employer_id,
+            'kind', kind,
+            'moderator_id', moderator_id
+        )
+        from moderation_action
+    ),
+    jsonb_build_object(
+        'employer_id', :'employerID'::uuid,
+        'kind', 'unsuspend-employer',
+        'moderator_id', :'moderatorID'::uuid
+    ),
+    'Should record the action in the moderation audit trail'
+);
+
+-- Should raise an error when the employer is not suspended
+select throws_ok(
+    $$ select unsuspend_employer('00000000-0000-0000-0000-000000000101'::uuid, '00000000-0000-0000-0000-000000000201'::uuid) $$,
+    'suspended employer not found',
+    'Should raise an error when the employer is not suspended'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,89 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set moderatorID '00000000-0000-0000-0000-000000000201'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, moderator, name, user_id, username) values
+    (decode('01', 'hex'), 'moderator@example.com', true, 'Moderator', :'moderatorID', 'moderator');
+
+insert into "user" (
+    auth_hash,
+    email,
+    name,
+    suspended_at,
+    suspension_reason,
+    user_id,
+    username
+) values (
+    decode('02', 'hex'),
+    'user@example.com',
+    'User',
+    current_timestamp,
+    'Spam',
+    :'userID',
+    'user'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should clear the suspension details of the user
+select unsuspend_user(:'userID'::uuid, :'moderatorID'::uuid);
+
+select ok(
+    exists (
+        select 1
+        from "user"
+        where user_id = :'userID'::uuid
+        and suspended_at is null
+        and suspended_until is null
+        and suspension_reason is null
+    ),
+    'Should clear the suspension details of the user'
+);
+
+-- Should record the action in the moderation audit trail
+select is(
+    (
+        select jsonb_build_object(
+            'kind', kind,
+            'moderator_id', moderator_id,
+            'user_id', user_id
+        )
+        from moderation_action
+    ),
+    jsonb_build_object(
+        'kind', 'unsuspend-user',
+        'moderator_id', :'moderatorID'::uuid,
+        'user_id', :'userID'::uuid
+    ),
+    'Should record the action in the moderation audit trail'
+);
+
+-- Should raise an error when the user is not suspended
+select throws_ok(
+    $$ select unsuspend_user('00000000-0000-0000-0000-000000000101'::uuid, '00000000-0000-0000-0000-000000000201'::uuid) $$,
+    'suspended user not found',
+    'Should raise an error when the user is not suspended'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
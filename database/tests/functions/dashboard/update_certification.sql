@@ -0,0 +1,84 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set certification1ID '00000000-0000-0000-0000-000000000401'
+\set certification2ID '00000000-0000-0000-0000-000000000402'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into certification (certification_id, name, provider, short_name) values
+    (:'certification1ID', 'Test Certified Associate', 'Test', 'TCA'),
+    (:'certification2ID', 'Test Certified Professional', 'Test', 'TCP');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should update the certification details
+select update_certification(
+    :'certification1ID'::uuid,
+    '{
+        "description": "Updated",
+        "name": "Test Certified Associate",
+        "provider": "Test Foundation",
+        "short_name": "TCA",
+        "url": "https://example.com/tca"
+    }'::jsonb
+);
+
+select is(
+    (
+        select jsonb_build_object(
+            'description', description,
+            'logo_url', logo_url,
+            'provider', provider,
+            'url', url
+        )
+        from certification
+        where certification_id = :'certification1ID'::uuid
+    ),
+    jsonb_build_object(
+        'description', 'Updated',
+        'logo_url', null,
+        'provider', 'Test Foundation',
+        'url', 'https://example.com/tca'
+    ),
+    'Should update the certification details'
+);
+
+-- Should raise an error when the name is used by another certification
+select throws_ok(
+    $$ select update_certification(
+        '00000000-0000-0000-0000-000000000401'::uuid,
+        '{"name": "Test Certified Professional", "provider": "Test", "short_name": "TCA"}'::jsonb
+    ) $$,
+    'certification name or short name already in use',
+    'Should raise an error when the name is used by another certification'
+);
+
+-- Should raise an error when the certification does not exist
+select throws_ok(
+    $$ select update_certification(
+        '00000000-0000-0000-0000-000000000499'::uuid,
+        '{"name": "Other", "provider": "Test", "short_name": "OTH"}'::jsonb
+    ) $$,
+    'certification not found',
+    'Should raise an error when the certification does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
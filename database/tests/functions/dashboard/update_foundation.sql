@@ -0,0 +1,49 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set foundationID '00000000-0000-0000-0000-000000000301'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into foundation (foundation_id, name) values
+    (:'foundationID', 'test-foundation');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should update the landscape url of the foundation
+select update_foundation(
+    :'foundationID'::uuid,
+    '{"landscape_url": "https://landscape.example.com"}'::jsonb
+);
+
+select is(
+    (select landscape_url from foundation where foundation_id = :'foundationID'::uuid),
+    'https://landscape.example.com',
+    'Should update the landscape url of the foundation'
+);
+
+-- Should raise an error when the foundation does not exist
+select throws_ok(
+    $$ select update_foundation('00000000-0000-0000-0000-000000000399'::uuid, '{}'::jsonb) $$,
+    'foundation not found',
+    'Should raise an error when the foundation does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -171,6 +171,7 @@ select ok(
         and j.tz_start = 'UTC-2'
         and j.upstream_commitment = 70
         and j.workplace = 'hybrid'
+        and j.review_requested_at is not null
         and j.updated_at is not null
     ),
     'Should update all fields for non-deleted jobs'
@@ -0,0 +1,62 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set image1ID '00000000-0000-0000-0000-000000000301'
+\set image2ID '00000000-0000-0000-0000-000000000302'
+\set user1ID '00000000-0000-0000-0000-000000000101'
+\set user2ID '00000000-0000-0000-0000-000000000102'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'user1@example.com', 'User 1', :'user1ID', 'user1'),
+    (decode('02', 'hex'), 'user2@example.com', 'User 2', :'user2ID', 'user2');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should add a new image using the identifier provided
+select is(
+    add_image(:'image1ID'::uuid, :'user1ID'::uuid, 'hash1'),
+    :'image1ID'::uuid,
+    'Should add a new image using the identifier provided'
+);
+select is(
+    (select created_by from image where image_id = :'image1ID' and hash = 'hash1'),
+    :'user1ID'::uuid,
+    'Should add a new image with the hash provided'
+);
+select ok(
+    exists (select 1 from image_upload where image_id = :'image1ID' and user_id = :'user1ID'),
+    'Should register the upload of the new image'
+);
+
+-- Should reuse the existing image when adding one with the same hash
+select is(
+    add_image(:'image2ID'::uuid, :'user2ID'::uuid, 'hash1'),
+    :'image1ID'::uuid,
+    'Should reuse the existing image when adding one with the same hash'
+);
+select is(
+    (select array_agg(user_id order by user_id) from image_upload where image_id = :'image1ID'),
+    array[:'user1ID', :'user2ID']::uuid[],
+    'Should register the upload for all the users who uploaded the image'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,99 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000201'
+\set logoID '00000000-0000-0000-0000-000000000301'
+\set orphanID '00000000-0000-0000-0000-000000000302'
+\set photoID '00000000-0000-0000-0000-000000000303'
+\set reuploadedID '00000000-0000-0000-0000-000000000304'
+\set unknownImageID '00000000-0000-0000-0000-000000000399'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'user@example.com', 'User', :'userID', 'user');
+
+insert into image (created_at, created_by, image_id) values
+    (current_timestamp - interval '2 days', :'userID', :'logoID'),
+    (current_timestamp - interval '2 days', :'userID', :'orphanID'),
+    (current_timestamp - interval '2 days', :'userID', :'photoID'),
+    (current_timestamp - interval '2 days', :'userID', :'reuploadedID');
+
+insert into image_upload (created_at, image_id, user_id) values
+    (current_timestamp - interval '1 hour', :'reuploadedID', :'userID');
+
+insert into image_version (data, image_id, version) values
+    (decode('0102', 'hex'), :'orphanID', 'small'),
+    (decode('030405', 'hex'), :'orphanID', 'medium');
+
+insert into employer (company, description, employer_id, logo_id) values
+    ('Acme', 'Employer for delete_unreferenced_image tests', :'employerID', :'logoID');
+
+insert into job_seeker_profile (email, name, photo_id, public, summary, user_id) values
+    ('user@example.com', 'User', :'photoID', false, 'Summary', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the size of the versions stored in the database
+select is(
+    delete_unreferenced_image(:'orphanID'::uuid, current_timestamp - interval '1 day'),
+    5::bigint,
+    'Should return the size of the versions stored in the database'
+);
+
+-- Should delete the image and its versions
+select ok(
+    not exists (select 1 from image where image_id = :'orphanID')
+    and not exists (select 1 from image_version where image_id = :'orphanID'),
+    'Should delete the image and its versions'
+);
+
+-- Should not delete images referenced by an employer or a profile
+select ok(
+    delete_unreferenced_image(:'logoID'::uuid, current_timestamp - interval '1 day') is null
+    and delete_unreferenced_image(:'photoID'::uuid, current_timestamp - interval '1 day') is null
+    and exists (select 1 from employer where logo_id = :'logoID')
+    and exists (select 1 from job_seeker_profile where photo_id = :'photoID'),
+    'Should not delete images referenced by an employer or a profile'
+);
+
+-- Should not delete images uploaded again after the timestamp provided
+select is(
+    delete_unreferenced_image(:'reuploadedID'::uuid, current_timestamp - interval '1 day'),
+    null::bigint,
+    'Should not delete images uploaded again after the timestamp provided'
+);
+
+-- Should keep the images that were not deleted
+select is(
+    (select count(*) from image),
+    3::bigint,
+    'Should keep the images that were not deleted'
+);
+
+-- Should return null when the image does not exist
+select is(
+    delete_unreferenced_image(:'unknownImageID'::uuid, current_timestamp - interval '1 day'),
+    null::bigint,
+    'Should return null when the image does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
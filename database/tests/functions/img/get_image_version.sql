@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(3);
+select plan(7);
 
 -- ============================================================================
 -- VARIABLES
@@ -24,6 +24,10 @@ insert into image (image_id, created_by) values
 -- Image versions
 insert into image_version (data, image_id, version) values
     (decode('0102', 'hex'), :'imageWithPngAndSvgID', 'small'),
+    (decode('0304', 'hex'), :'imageWithPngAndSvgID', 'small.webp'),
+    (decode('0506', 'hex'), :'imageWithPngAndSvgID', 'small@2x.avif'),
+    (decode('0708', 'hex'), :'imageWithPngAndSvgID', 'medium.webp'),
+    (decode('0910', 'hex'), :'imageWithPngAndSvgID', 'large'),
     (decode('aa55', 'hex'), :'imageWithPngAndSvgID', 'svg');
 
 -- ============================================================================
@@ -40,11 +44,51 @@ select is(
     'Should return requested PNG version when it exists'
 );
 
+-- Should return requested WebP version when it exists
+select is(
+    (
+        select format || ':' || encode(data, 'hex')
+        from get_image_version(:'imageWithPngAndSvgID'::uuid, 'small.webp')
+    ),
+    'webp:0304',
+    'Should return requested WebP version when it exists'
+);
+
+-- Should return requested AVIF version when it exists
+select is(
+    (
+        select format || ':' || encode(data, 'hex')
+        from get_image_version(:'imageWithPngAndSvgID'::uuid, 'small@2x.avif')
+    ),
+    'avif:0506',
+    'Should return requested AVIF version when it exists'
+);
+
+-- Should fallback to the next larger version when a retina version does not exist
+select is(
+    (
+        select format || ':' || encode(data, 'hex')
+        from get_image_version(:'imageWithPngAndSvgID'::uuid, 'small@2x.webp')
+    ),
+    'webp:0708',
+    'Should fallback to the next larger version when a retina version does not exist'
+);
+
+-- Should fallback to the largest version when the largest retina version does not exist
+select is(
+    (
+        select format || ':' || encode(data, 'hex')
+        from get_image_version(:'imageWithPngAndSvgID'::uuid, 'large@2x')
+    ),
+    'png:0910',
+    'Should fallback to the largest version when the largest retina version does not exist'
+);
+
 -- Should fallback to SVG when requested version does not exist
 select is(
     (
         select format || ':' || encode(data, 'hex')
-        from get_image_version(:'imageWithPngAndSvgID'::uuid, 'large')
+        from get_image_version(:'imageWithPngAndSvgID'::uuid, 'medium')
     ),
     'svg:aa55',
     'Should fallback to SVG when requested version does not exist'
@@ -0,0 +1,71 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000201'
+\set logoID '00000000-0000-0000-0000-000000000301'
+\set orphanID '00000000-0000-0000-0000-000000000302'
+\set photoID '00000000-0000-0000-0000-000000000303'
+\set recentID '00000000-0000-0000-0000-000000000304'
+\set reuploadedID '00000000-0000-0000-0000-000000000305'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'user@example.com', 'User', :'userID', 'user');
+
+insert into image (created_at, created_by, image_id) values
+    (current_timestamp - interval '2 days', :'userID', :'logoID'),
+    (current_timestamp - interval '2 days', :'userID', :'orphanID'),
+    (current_timestamp - interval '2 days', :'userID', :'photoID'),
+    (current_timestamp - interval '1 hour', :'userID', :'recentID'),
+    (current_timestamp - interval '2 days', :'userID', :'reuploadedID');
+
+insert into image_upload (created_at, image_id, user_id) values
+    (current_timestamp - interval '2 days', :'orphanID', :'userID'),
+    (current_timestamp - interval '1 hour', :'reuploadedID', :'userID');
+
+insert into image_version (data, image_id, version) values
+    (decode('0102', 'hex'), :'orphanID', 'small'),
+    (decode('030405', 'hex'), :'orphanID', 'medium');
+
+insert into employer (company, description, employer_id, logo_id) values
+    ('Acme', 'Employer for list_unreferenced_images tests', :'employerID', :'logoID');
+
+insert into job_seeker_profile (email, name, photo_id, public, summary, user_id) values
+    ('user@example.com', 'User', :'photoID', false, 'Summary', :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should list the unreferenced images uploaded before the timestamp provided
+select is(
+    (select array_agg(image_id) from list_unreferenced_images(current_timestamp - interval '1 day')),
+    array[:'orphanID']::uuid[],
+    'Should list the unreferenced images uploaded before the timestamp provided'
+);
+
+-- Should not delete any image
+select is(
+    (select count(*) from image),
+    5::bigint,
+    'Should not delete any image'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
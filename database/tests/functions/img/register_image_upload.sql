@@ -0,0 +1,64 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set imageID '00000000-0000-0000-0000-000000000301'
+\set user1ID '00000000-0000-0000-0000-000000000101'
+\set user2ID '00000000-0000-0000-0000-000000000102'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'user1@example.com', 'User 1', :'user1ID', 'user1'),
+    (decode('02', 'hex'), 'user2@example.com', 'User 2', :'user2ID', 'user2');
+
+insert into image (created_by, hash, image_id) values
+    (:'user1ID', 'hash1', :'imageID');
+
+insert into image_upload (created_at, image_id, user_id) values
+    (current_timestamp - interval '2 days', :'imageID', :'user1ID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return null when no image has the hash provided
+select is(
+    register_image_upload(:'user2ID'::uuid, 'hash2'),
+    null,
+    'Should return null when no image has the hash provided'
+);
+
+-- Should register a new upload of the image with the hash provided
+select is(
+    register_image_upload(:'user2ID'::uuid, 'hash1'),
+    :'imageID'::uuid,
+    'Should return the image with the hash provided'
+);
+
+-- Should refresh the upload timestamp when the user uploads the image again
+select register_image_upload(:'user1ID'::uuid, 'hash1');
+select ok(
+    (
+        select created_at > current_timestamp - interval '1 hour'
+        from image_upload
+        where image_id = :'imageID' and user_id = :'user1ID'
+    ),
+    'Should refresh the upload timestamp when the user uploads the image again'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,86 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(4);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set draftJobID '00000000-0000-0000-0000-000000000303'
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set internalJobID '00000000-0000-0000-0000-000000000302'
+\set publishedJobID '00000000-0000-0000-0000-000000000301'
+\set unknownJobID '00000000-0000-0000-0000-999999999999'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Employer and jobs
+insert into employer (company, description, employer_id)
+values ('Apply Employer', 'Employer for get_job_apply_url tests', :'employerID');
+
+insert into job (
+    job_id,
+    employer_id,
+    kind,
+    status,
+    title,
+    workplace,
+    description,
+    apply_url
+) values
+    (
+        :'publishedJobID',
+        :'employerID',
+        'full-time',
+        'published',
+        'Published Job',
+        'remote',
+        'Published role',
+        'https://example.com/apply'
+    ),
+    (:'internalJobID', :'employerID', 'full-time', 'published', 'Internal Job', 'remote', 'Internal role', null),
+    (:'draftJobID', :'employerID', 'full-time', 'draft', 'Draft Job', 'remote', 'Draft role', 'https://example.com/draft');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the apply URL of a published job
+select is(
+    get_job_apply_url(:'publishedJobID'::uuid),
+    'https://example.com/apply',
+    'Should return the apply URL of a published job'
+);
+
+-- Should return null for published jobs without an apply URL
+select is(
+    get_job_apply_url(:'internalJobID'::uuid),
+    null,
+    'Should return null for published jobs without an apply URL'
+);
+
+-- Should return null for jobs that are not published
+select is(
+    get_job_apply_url(:'draftJobID'::uuid),
+    null,
+    'Should return null for jobs that are not published'
+);
+
+-- Should return null for unknown jobs
+select is(
+    get_job_apply_url(:'unknownJobID'::uuid),
+    null,
+    'Should return null for unknown jobs'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
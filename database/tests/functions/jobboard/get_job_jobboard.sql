@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(2);
+select plan(3);
 
 -- ============================================================================
 -- VARIABLES
@@ -39,8 +39,16 @@ insert into employer (company, description, employer_id, location_id, website_ur
 insert into employer_member (employer_id, member_id) values
     (:'employerID', :'memberID');
 
-insert into project (foundation, logo_url, maturity, name, project_id) values
-    ('cncf', 'https://example.com/project.svg', 'graduated', 'Kubernetes', :'projectID');
+insert into project (category, description, foundation, logo_url, maturity, name, project_id) values
+    (
+        'Orchestration & Management',
+        'Production-grade container orchestration',
+        'cncf',
+        'https://example.com/project.svg',
+        'graduated',
+        'Kubernetes',
+        :'projectID'
+    );
 
 insert into certification (
     certification_id,
@@ -200,6 +208,8 @@ select is(
         'open_source', 80,
         'projects', jsonb_build_array(
             jsonb_build_object(
+                'category', 'Orchestration & Management',
+                'description', 'Production-grade container orchestration',
                 'foundation', 'cncf',
                 'logo_url', 'https://example.com/project.svg',
                 'maturity', 'graduated',
@@ -234,6 +244,17 @@ select is(
     'Should return null for non-published jobs'
 );
 
+-- Should return null for jobs of suspended employers
+update employer
+set suspended_at = current_timestamp
+where employer_id = :'employerID'::uuid;
+
+select is(
+    get_job_jobboard(:'jobPublishedID'::uuid)::jsonb,
+    null::jsonb,
+    'Should return null for jobs of suspended employers'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
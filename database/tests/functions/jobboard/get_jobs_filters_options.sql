@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(1);
+select plan(2);
 
 -- ============================================================================
 -- SEED DATA
@@ -13,6 +13,12 @@ insert into foundation (name) values
     ('test-foundation-a'),
     ('test-foundation-b');
 
+insert into project (category, foundation, logo_url, maturity, name) values
+    ('Observability', 'test-foundation-a', 'https://example.com/a.svg', 'graduated', 'Project A'),
+    ('Observability', 'test-foundation-b', 'https://example.com/b.svg', 'sandbox', 'Project B'),
+    ('Networking', 'test-foundation-b', 'https://example.com/c.svg', 'sandbox', 'Project C'),
+    (null, 'test-foundation-b', 'https://example.com/d.svg', 'sandbox', 'Project D');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -33,6 +39,13 @@ select is(
     'Should return foundations in a json payload sorted by name'
 );
 
+-- Should return distinct project categories sorted by name
+select is(
+    get_jobs_filters_options()::jsonb->'project_categories',
+    '["Networking", "Observability"]'::jsonb,
+    'Should return distinct project categories sorted by name'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
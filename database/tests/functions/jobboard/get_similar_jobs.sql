@@ -0,0 +1,107 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set draftJobID '00000000-0000-0000-0000-000000000306'
+\set duplicateJobID '00000000-0000-0000-0000-000000000302'
+\set employer1ID '00000000-0000-0000-0000-000000000101'
+\set employer2ID '00000000-0000-0000-0000-000000000102'
+\set job1ID '00000000-0000-0000-0000-000000000301'
+\set job3ID '00000000-0000-0000-0000-000000000303'
+\set job4ID '00000000-0000-0000-0000-000000000304'
+\set job5ID '00000000-0000-0000-0000-000000000305'
+\set projectID '00000000-0000-0000-0000-000000000501'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into employer (company, description, employer_id) values
+    ('Acme Corp', 'Employer one for get_similar_jobs tests', :'employer1ID'),
+    ('Beta Inc', 'Employer two for get_similar_jobs tests', :'employer2ID');
+
+insert into project (foundation, logo_url, maturity, name, project_id) values
+    ('cncf', 'https://example.com/kubernetes.svg', 'graduated', 'Kubernetes', :'projectID');
+
+insert into job (
+    description,
+    employer_id,
+    job_id,
+    kind,
+    published_at,
+    seniority,
+    skills,
+    status,
+    title,
+    workplace
+) values
+    (
+        'Build the platform', :'employer1ID', :'job1ID', 'full-time', '2026-01-01 10:00:00+00',
+        'senior', array['rust', 'kubernetes'], 'published', 'Platform Engineer', 'remote'
+    ),
+    (
+        'Build the platform again', :'employer1ID', :'duplicateJobID', 'full-time', '2026-01-02 10:00:00+00',
+        'senior', array['rust', 'kubernetes'], 'published', 'platform engineer', 'remote'
+    ),
+    (
+        'Operate clusters', :'employer2ID', :'job3ID', 'full-time', '2026-01-03 10:00:00+00',
+        'senior', array['kubernetes', 'go'], 'published', 'Kubernetes Operator', 'remote'
+    ),
+    (
+        'Build user interfaces', :'employer2ID', :'job4ID', 'full-time', '2026-01-04 10:00:00+00',
+        'junior', array['react'], 'published', 'Frontend Developer', 'on-site'
+    ),
+    (
+        'Write services', :'employer1ID', :'job5ID', 'full-time', '2026-01-05 10:00:00+00',
+        'junior', array['rust'], 'published', 'Rust Developer', 'on-site'
+    ),
+    (
+        'Draft role', :'employer2ID', :'draftJobID', 'full-time', null,
+        'senior', array['rust', 'kubernetes'], 'draft', 'Platform Engineer', 'remote'
+    );
+
+insert into job_project (job_id, project_id) values
+    (:'job1ID', :'projectID'),
+    (:'job3ID', :'projectID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return similar published jobs sorted by score, excluding duplicates
+select is(
+    (
+        select array_agg(j->>'job_id')
+        from json_array_elements(get_similar_jobs(:'job1ID'::uuid, 10)) j
+    ),
+    array[:'job3ID'::text, :'job5ID'::text],
+    'Should return similar published jobs sorted by score, excluding duplicates'
+);
+
+-- Should return up to the limit provided
+select is(
+    json_array_length(get_similar_jobs(:'job1ID'::uuid, 1)),
+    1,
+    'Should return up to the limit provided'
+);
+
+-- Should return an empty array when the job does not exist
+select is(
+    get_similar_jobs('00000000-0000-0000-0000-000000000399'::uuid, 10)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty array when the job does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(4);
+select plan(9);
 
 -- ============================================================================
 -- VARIABLES
@@ -14,6 +14,7 @@ select plan(4);
 \set job1ID '00000000-0000-0000-0000-000000000301'
 \set job2ID '00000000-0000-0000-0000-000000000302'
 \set job3ID '00000000-0000-0000-0000-000000000303'
+\set job4ID '00000000-0000-0000-0000-000000000304'
 \set projectCNCFID '00000000-0000-0000-0000-000000000201'
 \set projectLFID '00000000-0000-0000-0000-000000000202'
 
@@ -74,6 +75,39 @@ insert into job (
         'Job three'
     );
 
+-- Unpublished job, which should not be included in any stats
+insert into job (
+    job_id,
+    employer_id,
+    kind,
+    status,
+    title,
+    workplace,
+    description,
+    seniority,
+    skills,
+    salary_usd_year
+) values (
+    :'job4ID',
+    :'employer2ID',
+    'full-time',
+    'draft',
+    'Data Engineer',
+    'on-site',
+    'Job four',
+    'senior',
+    array['python'],
+    500000
+);
+
+-- Seniority, skills and normalized salaries
+update job set seniority = 'senior', skills = array['go', 'kubernetes'], salary_usd_year = 150000
+where job_id = :'job1ID';
+update job set seniority = 'senior', skills = array['go', 'rust'], salary_min_usd_year = 100000, salary_max_usd_year = 140000
+where job_id = :'job2ID';
+update job set seniority = 'mid', skills = array['go'], salary_usd_year = 90000
+where job_id = :'job3ID';
+
 insert into job_project (job_id, project_id) values
     (:'job1ID', :'projectCNCFID'),
     (:'job2ID', :'projectCNCFID'),
@@ -86,6 +120,11 @@ insert into job_views (day, job_id, total) values
     ((current_date - interval '70 days')::date, :'job3ID', 7),
     ((current_date - interval '3 years')::date, :'job1ID', 100);
 
+-- Views rolled up into monthly aggregates
+insert into job_views_monthly (job_id, month, total) values
+    (:'job2ID', date_trunc('month', current_date - interval '3 years')::date, 50),
+    (:'job3ID', date_trunc('month', current_date - interval '5 years')::date, 20);
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -102,6 +141,51 @@ select is(
     'Should aggregate published jobs per foundation'
 );
 
+-- Should count published jobs per workplace
+select is(
+    (select get_stats()::jsonb->'jobs'->'published_per_workplace'),
+    '[["remote", 2], ["hybrid", 1]]'::jsonb,
+    'Should count published jobs per workplace'
+);
+
+-- Should return the median normalized salary per seniority
+select is(
+    (select get_stats()::jsonb->'jobs'->'salary_median_per_seniority'),
+    '[["mid", 90000], ["senior", 135000]]'::jsonb,
+    'Should return the median normalized salary per seniority'
+);
+
+-- Should return the median normalized salary per workplace
+select is(
+    (select get_stats()::jsonb->'jobs'->'salary_median_per_workplace'),
+    '[["hybrid", 90000], ["remote", 135000]]'::jsonb,
+    'Should return the median normalized salary per workplace'
+);
+
+-- Should return the top skills along with their monthly series
+select is(
+    (
+        select jsonb_agg(
+            jsonb_build_array(
+                skill->0,
+                (select sum((month->>1)::int) from jsonb_array_elements(skill->1) as month)
+            )
+            order by position
+        )
+        from jsonb_array_elements(get_stats()::jsonb->'jobs'->'skills_monthly')
+            with ordinality as skills(skill, position)
+    ),
+    '[["go", 3], ["kubernetes", 1], ["rust", 1]]'::jsonb,
+    'Should return the top skills along with their monthly series'
+);
+
+-- Should return the projects referenced by most published jobs
+select is(
+    (select get_stats()::jsonb->'jobs'->'top_projects'),
+    '[["cncf", "Kubernetes", 2], ["lf", "OpenTofu", 1]]'::jsonb,
+    'Should return the projects referenced by most published jobs'
+);
+
 -- Should include only last-month data in views_daily
 select is(
     (
@@ -121,7 +205,7 @@ select is(
     'Should include only last-month data in views_daily'
 );
 
--- Should include only last-two-years data in views_monthly
+-- Should merge daily and rolled up views in views_monthly
 select is(
     (
         select stats->'jobs'->'views_monthly'
@@ -132,16 +216,24 @@ select is(
     (
         select jsonb_build_array(
             jsonb_build_array(
-                (extract(epoch from date_trunc('month', (current_date - interval '70 days')::date)) * 1000)::bigint,
+                (extract(epoch from date_trunc('month', current_date - interval '5 years')::date) * 1000)::bigint,
+                20
+            ),
+            jsonb_build_array(
+                (extract(epoch from date_trunc('month', current_date - interval '3 years')::date) * 1000)::bigint,
+                150
+            ),
+            jsonb_build_array(
+                (extract(epoch from date_trunc('month', current_date - interval '70 days')::date) * 1000)::bigint,
                 7
             ),
             jsonb_build_array(
-                (extract(epoch from date_trunc('month', (current_date - interval '10 days')::date)) * 1000)::bigint,
+                (extract(epoch from date_trunc('month', current_date - interval '10 days')::date) * 1000)::bigint,
                 5
             )
         )
     ),
-    'Should include only last-two-years data in views_monthly'
+    'Should merge daily and rolled up views in views_monthly'
 );
 
 -- Should return ordered timeline timestamps
@@ -0,0 +1,112 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set job1ID '00000000-0000-0000-0000-000000000301'
+\set job2ID '00000000-0000-0000-0000-000000000302'
+\set job3ID '00000000-0000-0000-0000-000000000303'
+\set locationID '00000000-0000-0000-0000-000000000201'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into employer (company, description, employer_id) values
+    ('Acme Corp', 'Employer for recommend_jobs tests', :'employerID');
+
+insert into location (city, country, location_id, state) values
+    ('Valencia', 'Spain', :'locationID', null);
+
+insert into job (
+    description,
+    employer_id,
+    job_id,
+    kind,
+    location_id,
+    published_at,
+    skills,
+    status,
+    title,
+    workplace
+) values
+    (
+        'Write services', :'employerID', :'job1ID', 'full-time', :'locationID', '2026-01-01 10:00:00+00',
+        array['rust', 'kubernetes'], 'published', 'Rust Engineer', 'remote'
+    ),
+    (
+        'Write more services', :'employerID', :'job2ID', 'full-time', null, '2026-01-02 10:00:00+00',
+        array['rust'], 'published', 'Rust Engineer', 'remote'
+    ),
+    (
+        'Write web apps', :'employerID', :'job3ID', 'full-time', null, '2026-01-03 10:00:00+00',
+        array['Go'], 'published', 'Go Engineer', 'hybrid'
+    );
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return full payload of the best scored job of each title
+select is(
+    recommend_jobs(array['rust', 'kubernetes'], null, null, null, null, null, 10)::jsonb,
+    jsonb_build_array(
+        jsonb_build_object(
+            'employer', jsonb_build_object('company', 'Acme Corp', 'employer_id', :'employerID'::text),
+            'job_id', :'job1ID'::text,
+            'kind', 'full-time',
+            'location', jsonb_build_object(
+                'city', 'Valencia',
+                'country', 'Spain',
+                'location_id', :'locationID'::text
+            ),
+            'open_source', null,
+            'published_at', to_jsonb('2026-01-01 10:00:00+00'::timestamptz),
+            'salary', null,
+            'salary_currency', null,
+            'salary_max', null,
+            'salary_min', null,
+            'salary_period', null,
+            'seniority', null,
+            'skills', jsonb_build_array('rust', 'kubernetes'),
+            'title', 'Rust Engineer',
+            'updated_at', null,
+            'upstream_commitment', null,
+            'workplace', 'remote'
+        )
+    ),
+    'Should return full payload of the best scored job of each title'
+);
+
+-- Should match skills case insensitively and skip excluded jobs
+select is(
+    (
+        select array_agg(j->>'job_id')
+        from json_array_elements(
+            recommend_jobs(array['RUST', 'go'], null, null, null, null, array[:'job1ID'::uuid], 10)
+        ) j
+    ),
+    array[:'job3ID'::text, :'job2ID'::text],
+    'Should match skills case insensitively and skip excluded jobs'
+);
+
+-- Should return an empty array when nothing matches
+select is(
+    recommend_jobs(array['cobol'], null, null, null, null, null, 10)::jsonb,
+    '[]'::jsonb,
+    'Should return an empty array when nothing matches'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(5);
+select plan(17);
 
 -- ============================================================================
 -- VARIABLES
@@ -19,7 +19,9 @@ select plan(5);
 \set memberCNCFID '00000000-0000-0000-0000-000000000401'
 \set memberLFID '00000000-0000-0000-0000-000000000402'
 \set projectCNCFID '00000000-0000-0000-0000-000000000501'
+\set profileID '00000000-0000-0000-0000-000000000601'
 \set projectLFID '00000000-0000-0000-0000-000000000502'
+\set userID '00000000-0000-0000-0000-000000000001'
 
 -- ============================================================================
 -- SEED DATA
@@ -45,9 +47,17 @@ insert into location (city, country, location_id, state) values
     ('San Francisco', 'United States', :'location1ID', 'CA'),
     ('New York', 'United States', :'location2ID', 'NY');
 
-insert into project (foundation, logo_url, maturity, name, project_id) values
-    ('cncf', 'https://example.com/project-cncf.svg', 'graduated', 'Kubernetes', :'projectCNCFID'),
-    ('lf', 'https://example.com/project-lf.svg', 'incubating', 'OpenTofu', :'projectLFID');
+insert into project (category, foundation, logo_url, maturity, name, project_id, subcategory) values
+    (
+        'Orchestration & Management',
+        'cncf',
+        'https://example.com/project-cncf.svg',
+        'graduated',
+        'Kubernetes',
+        :'projectCNCFID',
+        'Scheduling & Orchestration'
+    ),
+    (null, 'lf', 'https://example.com/project-lf.svg', 'incubating', 'OpenTofu', :'projectLFID', null);
 
 -- Jobs
 insert into job (
@@ -151,13 +161,26 @@ insert into job_project (job_id, project_id) values
     (:'job1ID', :'projectCNCFID'),
     (:'job2ID', :'projectLFID');
 
+-- Job seeker
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'seeker@example.com', 'Seeker', :'userID', 'seeker');
+
+insert into job_seeker_profile (email, job_seeker_profile_id, name, open_to_remote, skills, summary, user_id) values
+    ('seeker@example.com', :'profileID', 'Seeker', false, array['react'], 'Summary', :'userID');
+
+-- Timezone windows and coordinates
+update job set tz_start = 'UTC-3', tz_end = 'UTC+2' where job_id = :'job1ID';
+update location
+set coordinates = 'SRID=4326;POINT(-74.006 40.7128)'::geography
+where location_id = :'location2ID';
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
 
 -- Should return full payload for published jobs by default
 select is(
-    search_jobs('{}'::jsonb)::jsonb,
+    search_jobs('{}'::jsonb, null, false)::jsonb,
     jsonb_build_object(
         'jobs',
         jsonb_build_array(
@@ -186,15 +209,21 @@ select is(
                     'location_id', :'location1ID'::text,
                     'state', 'CA'
                 ),
+                'match', null,
                 'open_source', 80,
                 'projects',
                 jsonb_build_array(
                     jsonb_build_object(
+                        'category', 'Orchestration & Management',
+                        'description', null,
                         'foundation', 'cncf',
+                        'homepage_url', null,
                         'logo_url', 'https://example.com/project-cncf.svg',
                         'maturity', 'graduated',
                         'name', 'Kubernetes',
-                        'project_id', :'projectCNCFID'::text
+                        'project_id', :'projectCNCFID'::text,
+                        'repository_url', null,
+                        'subcategory', 'Scheduling & Orchestration'
                     )
                 ),
                 'published_at', to_jsonb('2026-01-03 10:00:00+00'::timestamptz),
@@ -235,15 +264,21 @@ select is(
                     'location_id', :'location2ID'::text,
                     'state', 'NY'
                 ),
+                'match', null,
                 'open_source', 20,
                 'projects',
                 jsonb_build_array(
                     jsonb_build_object(
+                        'category', null,
+                        'description', null,
                         'foundation', 'lf',
+                        'homepage_url', null,
                         'logo_url', 'https://example.com/project-lf.svg',
                         'maturity', 'incubating',
                         'name', 'OpenTofu',
-                        'project_id', :'projectLFID'::text
+                        'project_id', :'projectLFID'::text,
+                        'repository_url', null,
+                        'subcategory', null
                     )
                 ),
                 'published_at', to_jsonb('2026-01-02 10:00:00+00'::timestamptz),
@@ -260,6 +295,7 @@ select is(
                 'workplace', 'hybrid'
             )
         ),
+        'facets', null,
         'total', 2
     ),
     'Should return full payload for published jobs by default'
@@ -270,19 +306,31 @@ select is(
     (
         select total
         from (
-            select (search_jobs('{"foundation":"cncf"}'::jsonb)->>'total')::bigint as total
+            select (search_jobs('{"foundation":"cncf"}'::jsonb, null, false)->>'total')::bigint as total
         ) t
     ),
     1::bigint,
     'Should filter by foundation'
 );
 
+-- Should filter by landscape category of the job projects
+select is(
+    (
+        select total
+        from (
+            select (search_jobs('{"category":"Orchestration & Management"}'::jsonb, null, false)->>'total')::bigint as total
+        ) t
+    ),
+    1::bigint,
+    'Should filter by landscape category of the job projects'
+);
+
 -- Should filter by full text query with prefix matching
 select is(
     (
         select total
         from (
-            select (search_jobs('{"ts_query":"kuber"}'::jsonb)->>'total')::bigint as total
+            select (search_jobs('{"ts_query":"kuber"}'::jsonb, null, false)->>'total')::bigint as total
         ) t
     ),
     1::bigint,
@@ -294,7 +342,7 @@ select is(
     (
         select total
         from (
-            select (search_jobs('{"membership":"lf"}'::jsonb)->>'total')::bigint as total
+            select (search_jobs('{"membership":"lf"}'::jsonb, null, false)->>'total')::bigint as total
         ) t
     ),
     1::bigint,
@@ -305,12 +353,153 @@ select is(
 select is(
     (
         select (jobs::jsonb->0->>'job_id')::uuid
-        from (select search_jobs('{"sort":"salary"}'::jsonb)->'jobs' as jobs) t
+        from (select search_jobs('{"sort":"salary"}'::jsonb, null, false)->'jobs' as jobs) t
     ),
     :'job1ID'::uuid,
     'Should sort by salary when requested'
 );
 
+-- Should include how well each job matches the user's profile
+select is(
+    (
+        select array_agg((j->'match'->>'score')::int order by j->>'job_id')
+        from json_array_elements(search_jobs('{}'::jsonb, :'userID'::uuid, false)->'jobs') j
+    ),
+    array[0, 35],
+    'Should include how well each job matches the user''s profile'
+);
+
+-- Should include the match of the jobs in the page requested
+select ok(
+    (
+        select jobs::jsonb->0->'match' ? 'score'
+        from (select search_jobs('{"limit":1,"offset":1}'::jsonb, :'userID'::uuid, false)->'jobs' as jobs) t
+    ),
+    'Should include the match of the jobs in the page requested'
+);
+
+-- Should sort by match when requested
+select is(
+    (
+        select (jobs::jsonb->0->>'job_id')::uuid
+        from (select search_jobs('{"sort":"match"}'::jsonb, :'userID'::uuid, false)->'jobs' as jobs) t
+    ),
+    :'job2ID'::uuid,
+    'Should sort by match when requested'
+);
+
+-- Should return the number of jobs for each filter option when requested
+select is(
+    (search_jobs('{}'::jsonb, null, true)->'facets')::jsonb,
+    jsonb_build_object(
+        'benefits', '{}'::jsonb,
+        'foundation', jsonb_build_object('cncf', 1, 'lf', 1),
+        'kind', jsonb_build_object('full-time', 2),
+        'salary', jsonb_build_object('50000', 2, '100000', 1, '150000', 0, '200000', 0, '250000', 0),
+        'seniority', jsonb_build_object('junior', 1, 'senior', 1),
+        'skills', jsonb_build_object('javascript', 1, 'kubernetes', 1, 'react', 1, 'rust', 1),
+        'workplace', jsonb_build_object('hybrid', 1, 'remote', 1)
+    ),
+    'Should return the number of jobs for each filter option when requested'
+);
+
+-- Should count filter options ignoring their own filter
+select is(
+    (
+        select jsonb_build_object('kind', facets->'kind', 'workplace', facets->'workplace')
+        from (
+            select (search_jobs('{"workplace":["remote"]}'::jsonb, null, true)->'facets')::jsonb as facets
+        ) t
+    ),
+    jsonb_build_object(
+        'kind', jsonb_build_object('full-time', 1),
+        'workplace', jsonb_build_object('hybrid', 1, 'remote', 1)
+    ),
+    'Should count filter options ignoring their own filter'
+);
+
+-- Should filter remote jobs by timezone window overlap
+select is(
+    (
+        select array_agg(j->>'job_id')
+        from json_array_elements(search_jobs('{"tz_start":"UTC+1"}'::jsonb, null, false)->'jobs') j
+    ),
+    array[:'job1ID'::text],
+    'Should filter remote jobs by timezone window overlap'
+);
+
+-- Should exclude jobs whose timezone window does not overlap
+select is(
+    (
+        select total
+        from (
+            select (search_jobs('{"tz_start":"UTC+8","tz_end":"UTC+10"}'::jsonb, null, false)->>'total')::bigint as total
+        ) t
+    ),
+    0::bigint,
+    'Should exclude jobs whose timezone window does not overlap'
+);
+
+-- Should match hybrid jobs without timezone window by location
+select is(
+    (
+        select array_agg(j->>'job_id')
+        from json_array_elements(search_jobs(jsonb_build_object(
+            'location', jsonb_build_object('location_id', :'location2ID'::text),
+            'max_distance', 1000,
+            'tz_start', 'UTC+8'
+        ), null, false)->'jobs') j
+    ),
+    array[:'job2ID'::text],
+    'Should match hybrid jobs without timezone window by location'
+);
+
+-- Should match remote jobs without timezone window
+update job set tz_start = null, tz_end = null where job_id = :'job1ID';
+
+select is(
+    (
+        select array_agg(j->>'job_id')
+        from json_array_elements(search_jobs('{"tz_start":"UTC+8","tz_end":"UTC+10"}'::jsonb, null, false)->'jobs') j
+    ),
+    array[:'job1ID'::text],
+    'Should match remote jobs without timezone window'
+);
+
+update job set tz_start = 'UTC-3', tz_end = 'UTC+2' where job_id = :'job1ID';
+
+-- Should hide jobs from employers with an active suspension
+update employer
+set suspended_at = current_timestamp, suspended_until = null
+where employer_id = :'employer2ID'::uuid;
+
+select is(
+    (
+        select total
+        from (
+            select (search_jobs('{}'::jsonb, null, false)->>'total')::bigint as total
+        ) t
+    ),
+    1::bigint,
+    'Should hide jobs from employers with an active suspension'
+);
+
+-- Should show jobs again once the employer suspension expires
+update employer
+set suspended_until = current_timestamp - interval '1 minute'
+where employer_id = :'employer2ID'::uuid;
+
+select is(
+    (
+        select total
+        from (
+            select (search_jobs('{}'::jsonb, null, false)->>'total')::bigint as total
+        ) t
+    ),
+    2::bigint,
+    'Should show jobs again once the employer suspension expires'
+);
+
 -- ============================================================================
 -- CLEANUP
 -- ============================================================================
@@ -0,0 +1,173 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set draftJobID '00000000-0000-0000-0000-000000000302'
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set publishedJobID '00000000-0000-0000-0000-000000000301'
+\set unknownJobID '00000000-0000-0000-0000-999999999999'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+-- Employer and jobs
+insert into employer (company, description, employer_id)
+values ('Sources Employer', 'Employer for update_jobs_views_sources tests', :'employerID');
+
+insert into job (
+    job_id,
+    employer_id,
+    kind,
+    status,
+    title,
+    workplace,
+    description
+) values
+    (:'publishedJobID', :'employerID', 'full-time', 'published', 'Published Job', 'remote', 'Published role'),
+    (:'draftJobID', :'employerID', 'full-time', 'draft', 'Draft Job', 'remote', 'Draft role');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should insert counters only for published jobs
+select update_jobs_views_sources(
+    42,
+    jsonb_build_array(
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'direct', 3),
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'search', 2),
+        jsonb_build_array(:'draftJobID'::text, current_date::text, 'direct', 5),
+        jsonb_build_array(:'unknownJobID'::text, current_date::text, 'direct', 8)
+    )
+);
+
+select is(
+    (
+        select jsonb_agg(
+            jsonb_build_object(
+                'day', day::text,
+                'job_id', job_id::text,
+                'source', source,
+                'total', total
+            )
+            order by day, job_id, source
+        )
+        from job_views_sources
+    ),
+    jsonb_build_array(
+        jsonb_build_object(
+            'day', current_date::text,
+            'job_id', :'publishedJobID',
+            'source', 'direct',
+            'total', 3
+        ),
+        jsonb_build_object(
+            'day', current_date::text,
+            'job_id', :'publishedJobID',
+            'source', 'search',
+            'total', 2
+        )
+    ),
+    'Should insert counters only for published jobs'
+);
+
+-- Should ignore counters for non-published or unknown jobs
+select is(
+    (select count(*) from job_views_sources),
+    2::bigint,
+    'Should ignore counters for non-published or unknown jobs'
+);
+
+-- Should increment existing counters on conflict
+select update_jobs_views_sources(
+    43,
+    jsonb_build_array(
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'direct', 4)
+    )
+);
+
+select is(
+    (
+        select jsonb_agg(
+            jsonb_build_object(
+                'day', day::text,
+                'job_id', job_id::text,
+                'source', source,
+                'total', total
+            )
+            order by day, job_id, source
+        )
+        from job_views_sources
+    ),
+    jsonb_build_array(
+        jsonb_build_object(
+            'day', current_date::text,
+            'job_id', :'publishedJobID',
+            'source', 'direct',
+            'total', 7
+        ),
+        jsonb_build_object(
+            'day', current_date::text,
+            'job_id', :'publishedJobID',
+            'source', 'search',
+            'total', 2
+        )
+    ),
+    'Should increment existing counters on conflict'
+);
+
+-- Should track up to 25 detailed sources per job and day, counting the rest as other
+select update_jobs_views_sources(
+    44,
+    (
+        select jsonb_agg(
+            jsonb_build_array(:'publishedJobID'::text, current_date::text, 'ref:site' || i || '.com', 1)
+        )
+        from generate_series(1, 24) i
+    )
+);
+select update_jobs_views_sources(
+    45,
+    jsonb_build_array(
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'ref:site1.com', 1),
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'ref:popular.com', 5),
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'ref:rare1.com', 2),
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'ref:rare2.com', 3),
+        jsonb_build_array(:'publishedJobID'::text, current_date::text, 'utm:newsletter', 1)
+    )
+);
+
+select is(
+    (
+        select jsonb_object_agg(source, total)
+        from job_views_sources
+        where source in ('other', 'ref:popular.com', 'ref:rare1.com', 'ref:rare2.com', 'ref:site1.com', 'utm:newsletter')
+    ),
+    jsonb_build_object(
+        'other', 6,
+        'ref:popular.com', 5,
+        'ref:site1.com', 2
+    ),
+    'Should track up to 25 detailed sources per job and day, counting the rest as other'
+);
+
+select is(
+    (select count(*) from job_views_sources where source not in ('direct', 'other', 'search')),
+    25::bigint,
+    'Should not track more than 25 detailed sources per job and day'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,148 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(6);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set hybridJobID '00000000-0000-0000-0000-000000000302'
+\set location1ID '00000000-0000-0000-0000-000000000201'
+\set location2ID '00000000-0000-0000-0000-000000000202'
+\set profileID '00000000-0000-0000-0000-000000000401'
+\set remoteJobID '00000000-0000-0000-0000-000000000301'
+\set userID '00000000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'seeker@example.com', 'Seeker', :'userID', 'seeker');
+
+insert into location (city, country, location_id) values
+    ('Madrid', 'Spain', :'location1ID'),
+    ('Lisbon', 'Portugal', :'location2ID');
+
+insert into employer (company, description, employer_id) values
+    ('Acme Corp', 'Employer for job_match tests', :'employerID');
+
+insert into job (
+    description,
+    employer_id,
+    job_id,
+    kind,
+    location_id,
+    skills,
+    status,
+    title,
+    workplace
+) values
+    (
+        'Remote role',
+        :'employerID',
+        :'remoteJobID',
+        'full-time',
+        null,
+        array['Go', 'Kubernetes', 'Rust', 'rust'],
+        'published',
+        'Platform Engineer',
+        'remote'
+    ),
+    (
+        'Hybrid role',
+        :'employerID',
+        :'hybridJobID',
+        'full-time',
+        :'location1ID',
+        null,
+        'published',
+        'Support Engineer',
+        'hybrid'
+    );
+
+insert into job_seeker_profile (
+    email,
+    job_seeker_profile_id,
+    location_id,
+    name,
+    open_to_relocation,
+    open_to_remote,
+    skills,
+    summary,
+    user_id
+) values (
+    'seeker@example.com',
+    :'profileID',
+    :'location2ID',
+    'Seeker',
+    false,
+    true,
+    array['kubernetes', 'Rust'],
+    'Summary',
+    :'userID'
+);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should explain the skills and location fit of a remote job
+select is(
+    job_match(:'remoteJobID'::uuid, :'profileID'::uuid)::jsonb,
+    jsonb_build_object(
+        'location_fit', 'remote',
+        'matched_skills', jsonb_build_array('kubernetes', 'rust'),
+        'missing_skills', jsonb_build_array('go'),
+        'score', 77
+    ),
+    'Should explain the skills and location fit of a remote job'
+);
+
+-- Should give half the skills score when the job lists no skills
+select is(
+    (job_match(:'hybridJobID'::uuid, :'profileID'::uuid)->>'score')::int,
+    35,
+    'Should give half the skills score when the job lists no skills'
+);
+
+-- Should report a location mismatch for seekers far away
+select is(
+    job_match(:'hybridJobID'::uuid, :'profileID'::uuid)->>'location_fit',
+    'mismatch',
+    'Should report a location mismatch for seekers far away'
+);
+
+-- Should report a relocation fit for seekers open to relocation
+update job_seeker_profile set open_to_relocation = true where job_seeker_profile_id = :'profileID';
+select is(
+    job_match(:'hybridJobID'::uuid, :'profileID'::uuid)->>'location_fit',
+    'relocation',
+    'Should report a relocation fit for seekers open to relocation'
+);
+
+-- Should report a nearby fit for seekers in the same location
+update job_seeker_profile set location_id = :'location1ID' where job_seeker_profile_id = :'profileID';
+select is(
+    job_match(:'hybridJobID'::uuid, :'profileID'::uuid)->>'location_fit',
+    'nearby',
+    'Should report a nearby fit for seekers in the same location'
+);
+
+-- Should return null when the profile does not exist
+select is(
+    job_match(:'remoteJobID'::uuid, gen_random_uuid())::jsonb,
+    null,
+    'Should return null when the profile does not exist'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
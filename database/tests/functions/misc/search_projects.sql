@@ -19,8 +19,31 @@ select plan(3);
 
 insert into foundation (name) values ('lf');
 
+insert into project (
+    category,
+    description,
+    foundation,
+    homepage_url,
+    logo_url,
+    maturity,
+    name,
+    project_id,
+    repository_url,
+    subcategory
+) values (
+    'Orchestration & Management',
+    'Production-grade container orchestration',
+    'cncf',
+    'https://kubernetes.io',
+    'https://example.com/kube.svg',
+    'graduated',
+    'Kubernetes',
+    :'project1ID',
+    'https://github.com/kubernetes/kubernetes',
+    'Scheduling & Orchestration'
+);
+
 insert into project (foundation, logo_url, maturity, name, project_id) values
-    ('cncf', 'https://example.com/kube.svg', 'graduated', 'Kubernetes', :'project1ID'),
     ('cncf', 'https://example.com/envoy.svg', 'graduated', 'Envoy', :'project2ID'),
     ('lf', 'https://example.com/opentofu.svg', 'incubating', 'OpenTofu', :'project3ID');
 
@@ -41,29 +64,39 @@ select is(
     search_projects('lf', '')::jsonb,
     '[
         {
+            "category": null,
+            "description": null,
             "foundation": "lf",
+            "homepage_url": null,
             "logo_url": "https://example.com/opentofu.svg",
             "maturity": "incubating",
             "name": "OpenTofu",
-            "project_id": "00000000-0000-0000-0000-000000000503"
+            "project_id": "00000000-0000-0000-0000-000000000503",
+            "repository_url": null,
+            "subcategory": null
         }
     ]'::jsonb,
     'Should return only projects from the selected foundation'
 );
 
--- Should filter projects using case-insensitive partial matching
+-- Should filter projects using case-insensitive partial matching, including landscape metadata
 select is(
     search_projects('cncf', 'kuber')::jsonb,
     '[
         {
+            "category": "Orchestration & Management",
+            "description": "Production-grade container orchestration",
             "foundation": "cncf",
+            "homepage_url": "https://kubernetes.io",
             "logo_url": "https://example.com/kube.svg",
             "maturity": "graduated",
             "name": "Kubernetes",
-            "project_id": "00000000-0000-0000-0000-000000000501"
+            "project_id": "00000000-0000-0000-0000-000000000501",
+            "repository_url": "https://github.com/kubernetes/kubernetes",
+            "subcategory": "Scheduling & Orchestration"
         }
     ]'::jsonb,
-    'Should filter projects using case-insensitive partial matching'
+    'Should filter projects using case-insensitive partial matching, including landscape metadata'
 );
 
 -- Should cap returned projects at 20
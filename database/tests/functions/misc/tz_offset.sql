@@ -0,0 +1,41 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should parse offsets in hours
+select is(tz_offset('UTC+2'), 2::real, 'Should parse offsets in hours');
+
+-- Should parse negative offsets with minutes
+select is(tz_offset('UTC-03:30'), -3.5::real, 'Should parse negative offsets with minutes');
+
+-- Should parse a space as the plus sign
+select is(tz_offset('UTC 5'), 5::real, 'Should parse a space as the plus sign');
+
+-- Should parse UTC without offset as zero
+select is(tz_offset('UTC'), 0::real, 'Should parse UTC without offset as zero');
+
+-- Should parse offsets east of UTC+12
+select is(tz_offset('UTC+14'), 14::real, 'Should parse offsets east of UTC+12');
+
+-- Should return null for offsets out of range
+select is(tz_offset('UTC+15'), null::real, 'Should return null for offsets out of range');
+
+-- Should return null for values that cannot be parsed
+select is(tz_offset('CET'), null::real, 'Should return null for values that cannot be parsed');
+
+-- Should return null when no timezone is provided
+select is(tz_offset(null), null::real, 'Should return null when no timezone is provided');
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
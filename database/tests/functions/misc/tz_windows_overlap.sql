@@ -0,0 +1,69 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(8);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should match windows that overlap
+select ok(
+    tz_windows_overlap('UTC-3', 'UTC+2', 'UTC+1', 'UTC+5'),
+    'Should match windows that overlap'
+);
+
+-- Should not match windows that do not overlap
+select ok(
+    not tz_windows_overlap('UTC-8', 'UTC-5', 'UTC+1', 'UTC+3'),
+    'Should not match windows that do not overlap'
+);
+
+-- Should match a single timezone inside a window
+select ok(
+    tz_windows_overlap('UTC-3', 'UTC+02:00', 'UTC+1', null),
+    'Should match a single timezone inside a window'
+);
+
+-- Should match windows wrapping around the date line
+select ok(
+    tz_windows_overlap('UTC+10', 'UTC-8', 'UTC-10', null)
+    and tz_windows_overlap('UTC+10', 'UTC-8', 'UTC+11', 'UTC+12')
+    and tz_windows_overlap('UTC-11', 'UTC-9', 'UTC+10', 'UTC-10'),
+    'Should match windows wrapping around the date line'
+);
+
+-- Should not match timezones outside a wrapping window
+select ok(
+    not tz_windows_overlap('UTC+10', 'UTC-8', 'UTC+1', 'UTC+5'),
+    'Should not match timezones outside a wrapping window'
+);
+
+-- Should match two windows wrapping around the date line
+select ok(
+    tz_windows_overlap('UTC+12', 'UTC-12', 'UTC+9', 'UTC-9'),
+    'Should match two windows wrapping around the date line'
+);
+
+-- Should match windows east of UTC+12
+select ok(
+    tz_windows_overlap('UTC+12', 'UTC+14', 'UTC+13', null)
+    and tz_windows_overlap('UTC+14', 'UTC-10', 'UTC-11', 'UTC-11')
+    and not tz_windows_overlap('UTC+13', 'UTC+14', 'UTC+9', 'UTC+11'),
+    'Should match windows east of UTC+12'
+);
+
+-- Should not match when any of the windows is missing
+select ok(
+    not tz_windows_overlap(null, null, 'UTC+1', null),
+    'Should not match when any of the windows is missing'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,52 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set unverifiedUserID '00000000-0000-0000-0000-000000000102'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, email_verified, name, user_id, username) values
+    (decode('01', 'hex'), 'alice@example.com', true, 'Alice', :'userID', 'alice'),
+    (decode('02', 'hex'), 'bob@example.com', false, 'Bob', :'unverifiedUserID', 'bob');
+
+insert into notification (created_at, kind, processed, user_id) values
+    ('2025-01-01 10:00:00+00', 'email-verification', true, :'userID'),
+    ('2025-01-02 10:00:00+00', 'team-invitation', false, :'userID'),
+    ('2025-01-03 10:00:00+00', 'email-verification', false, :'userID'),
+    ('2025-01-01 12:00:00+00', 'team-invitation', false, :'unverifiedUserID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should count only the pending notifications that can be delivered
+select is(
+    (select pending from get_pending_notifications_stats()),
+    2::bigint,
+    'Should count only the pending notifications that can be delivered'
+);
+
+-- Should return the creation timestamp of the oldest deliverable notification
+select is(
+    (select oldest_created_at from get_pending_notifications_stats()),
+    '2025-01-02 10:00:00+00'::timestamptz,
+    'Should return the creation timestamp of the oldest deliverable notification'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -0,0 +1,62 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(3);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set notificationIDFailed '00000000-0000-0000-0000-000000000201'
+\set notificationIDOldFailed '00000000-0000-0000-0000-000000000202'
+\set notificationIDSent '00000000-0000-0000-0000-000000000203'
+\set userID '00000000-0000-0000-0000-000000000101'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into "user" (auth_hash, email, name, user_id, username) values
+    (decode('01', 'hex'), 'alice@example.com', 'Alice', :'userID', 'alice');
+
+insert into notification (created_at, error, kind, notification_id, processed, processed_at, user_id) values
+    (current_timestamp - interval '1 hour', 'smtp timeout', 'email-verification', :'notificationIDFailed', true, current_timestamp, :'userID'),
+    (current_timestamp - interval '1 week', 'smtp timeout', 'email-verification', :'notificationIDOldFailed', true, current_timestamp, :'userID'),
+    (current_timestamp - interval '1 hour', null, 'email-verification', :'notificationIDSent', true, current_timestamp, :'userID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should only retry failed notifications created after the timestamp provided
+select is(
+    retry_failed_notifications(current_timestamp - interval '1 day'),
+    1::bigint,
+    'Should only retry failed notifications created after the timestamp provided'
+);
+
+-- Should mark the failed notification as pending and clear its error
+select ok(
+    (
+        select not processed and processed_at is null and error is null
+        from notification
+        where notification_id = :'notificationIDFailed'::uuid
+    ),
+    'Should mark the failed notification as pending and clear its error'
+);
+
+-- Should retry all remaining failed notifications when no timestamp is provided
+select is(
+    retry_failed_notifications(null),
+    1::bigint,
+    'Should retry all remaining failed notifications when no timestamp is provided'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
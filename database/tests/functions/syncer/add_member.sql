@@ -10,13 +10,22 @@ select plan(1);
 -- ============================================================================
 
 -- Should add a member for the selected foundation
-select add_member('cncf', 'Acme', 'platinum', 'https://example.com/acme.svg');
+select add_member(
+    'cncf',
+    'Acme',
+    'platinum',
+    'https://example.com/acme.svg',
+    'https://acme.example.com',
+    'https://www.crunchbase.com/organization/acme'
+);
 
 select ok(
     exists (
         select 1
         from member
         where foundation = 'cncf'
+        and homepage_url = 'https://acme.example.com'
+        and crunchbase_url = 'https://www.crunchbase.com/organization/acme'
         and level = 'platinum'
         and logo_url = 'https://example.com/acme.svg'
         and name = 'Acme'
@@ -10,16 +10,31 @@ select plan(1);
 -- ============================================================================
 
 -- Should add a project for the selected foundation
-select add_project('cncf', 'Kubernetes', 'graduated', 'https://example.com/kube.svg');
+select add_project(
+    'cncf',
+    'Kubernetes',
+    'graduated',
+    'https://example.com/kube.svg',
+    'https://kubernetes.io',
+    'https://github.com/kubernetes/kubernetes',
+    'Orchestration & Management',
+    'Scheduling & Orchestration',
+    'Production-grade container orchestration'
+);
 
 select ok(
     exists (
         select 1
         from project
         where foundation = 'cncf'
+        and homepage_url = 'https://kubernetes.io'
         and logo_url = 'https://example.com/kube.svg'
         and maturity = 'graduated'
         and name = 'Kubernetes'
+        and repository_url = 'https://github.com/kubernetes/kubernetes'
+        and category = 'Orchestration & Management'
+        and subcategory = 'Scheduling & Orchestration'
+        and description = 'Production-grade container orchestration'
     ),
     'Should add a project for the selected foundation'
 );
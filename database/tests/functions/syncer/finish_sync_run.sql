@@ -0,0 +1,60 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(1);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set syncRunID '00000000-0000-0000-0000-000000000501'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into sync_run (foundation, sync_run_id) values ('cncf', :'syncRunID');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should register the end of a synchronization with its stats and error
+select finish_sync_run(
+    :'syncRunID',
+    '{
+        "members_added": 1,
+        "members_removed": 2,
+        "members_updated": 3,
+        "projects_added": 4,
+        "projects_removed": 5,
+        "projects_updated": 6
+    }'::jsonb,
+    'error fetching landscape projects'
+);
+
+select ok(
+    exists (
+        select 1
+        from sync_run
+        where sync_run_id = :'syncRunID'
+        and error = 'error fetching landscape projects'
+        and finished_at is not null
+        and members_added = 1
+        and members_removed = 2
+        and members_updated = 3
+        and projects_added = 4
+        and projects_removed = 5
+        and projects_updated = 6
+    ),
+    'Should register the end of a synchronization with its stats and error'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -19,6 +19,10 @@ update foundation
 set landscape_url = 'https://landscape.lf.example.com'
 where name = 'lf';
 
+insert into sync_run (foundation, started_at) values
+    ('cncf', '2026-01-01 10:00:00+00'),
+    ('cncf', '2026-01-02 10:00:00+00');
+
 -- ============================================================================
 -- TESTS
 -- ============================================================================
@@ -29,10 +33,12 @@ select is(
     '[
         {
             "landscape_url": "https://landscape.cncf.io",
+            "last_sync_started_at": "2026-01-02T10:00:00+00:00",
             "name": "cncf"
         },
         {
             "landscape_url": "https://landscape.lf.example.com",
+            "last_sync_started_at": null,
             "name": "lf"
         }
     ]'::jsonb,
@@ -11,10 +11,17 @@ select plan(1);
 
 insert into foundation (name) values ('lf');
 
-insert into member (foundation, level, logo_url, name) values
-    ('cncf', 'platinum', 'https://example.com/acme.svg', 'Acme'),
-    ('cncf', 'gold', 'https://example.com/beta.svg', 'Beta'),
-    ('lf', 'silver', 'https://example.com/other.svg', 'Other');
+insert into member (crunchbase_url, foundation, homepage_url, level, logo_url, name) values
+    (
+        'https://www.crunchbase.com/organization/acme',
+        'cncf',
+        'https://acme.example.com',
+        'platinum',
+        'https://example.com/acme.svg',
+        'Acme'
+    ),
+    (null, 'cncf', null, 'gold', 'https://example.com/beta.svg', 'Beta'),
+    (null, 'lf', null, 'silver', 'https://example.com/other.svg', 'Other');
 
 -- ============================================================================
 -- TESTS
@@ -25,13 +32,17 @@ select is(
     list_members('cncf')::jsonb,
     '[
         {
+            "crunchbase_url": "https://www.crunchbase.com/organization/acme",
             "foundation": "cncf",
+            "homepage_url": "https://acme.example.com",
             "level": "platinum",
             "logo_url": "https://example.com/acme.svg",
             "name": "Acme"
         },
         {
+            "crunchbase_url": null,
             "foundation": "cncf",
+            "homepage_url": null,
             "level": "gold",
             "logo_url": "https://example.com/beta.svg",
             "name": "Beta"
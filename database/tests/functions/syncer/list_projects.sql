@@ -11,10 +11,30 @@ select plan(1);
 
 insert into foundation (name) values ('lf');
 
-insert into project (foundation, logo_url, maturity, name) values
-    ('cncf', 'https://example.com/kube.svg', 'graduated', 'Kubernetes'),
-    ('cncf', 'https://example.com/envoy.svg', 'incubating', 'Envoy'),
-    ('lf', 'https://example.com/opentofu.svg', 'incubating', 'OpenTofu');
+insert into project (
+    category,
+    description,
+    foundation,
+    homepage_url,
+    logo_url,
+    maturity,
+    name,
+    repository_url,
+    subcategory
+) values
+    (
+        'Orchestration & Management',
+        'Production-grade container orchestration',
+        'cncf',
+        'https://kubernetes.io',
+        'https://example.com/kube.svg',
+        'graduated',
+        'Kubernetes',
+        'https://github.com/kubernetes/kubernetes',
+        'Scheduling & Orchestration'
+    ),
+    (null, null, 'cncf', null, 'https://example.com/envoy.svg', 'incubating', 'Envoy', null, null),
+    (null, null, 'lf', null, 'https://example.com/opentofu.svg', 'incubating', 'OpenTofu', null, null);
 
 -- ============================================================================
 -- TESTS
@@ -25,16 +45,26 @@ select is(
     list_projects('cncf')::jsonb,
     '[
         {
+            "category": null,
+            "description": null,
             "foundation": "cncf",
+            "homepage_url": null,
             "logo_url": "https://example.com/envoy.svg",
             "maturity": "incubating",
-            "name": "Envoy"
+            "name": "Envoy",
+            "repository_url": null,
+            "subcategory": null
         },
         {
+            "category": "Orchestration & Management",
+            "description": "Production-grade container orchestration",
             "foundation": "cncf",
+            "homepage_url": "https://kubernetes.io",
             "logo_url": "https://example.com/kube.svg",
             "maturity": "graduated",
-            "name": "Kubernetes"
+            "name": "Kubernetes",
+            "repository_url": "https://github.com/kubernetes/kubernetes",
+            "subcategory": "Scheduling & Orchestration"
         }
     ]'::jsonb,
     'Should return full projects payload for the requested foundation'
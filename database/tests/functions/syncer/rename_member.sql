@@ -0,0 +1,55 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set memberID '00000000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into member (member_id, foundation, level, logo_url, name) values
+    (:'memberID', 'cncf', 'platinum', 'https://example.com/acme.svg', 'Acme');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should rename the selected member keeping its identifier
+select rename_member('cncf', 'Acme', 'Acme Corp');
+
+select is(
+    (
+        select name
+        from member
+        where member_id = :'memberID'
+    ),
+    'Acme Corp',
+    'Should rename the selected member keeping its identifier'
+);
+
+-- Should not keep any member with the previous name
+select is(
+    (
+        select count(*)
+        from member
+        where foundation = 'cncf'
+        and name = 'Acme'
+    ),
+    0::bigint,
+    'Should not keep any member with the previous name'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
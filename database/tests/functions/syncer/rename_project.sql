@@ -0,0 +1,55 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(2);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set projectID '00000000-0000-0000-0000-000000000001'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into project (project_id, foundation, logo_url, maturity, name) values
+    (:'projectID', 'cncf', 'https://example.com/kube.svg', 'graduated', 'Kubernetes');
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should rename the selected project keeping its identifier
+select rename_project('cncf', 'Kubernetes', 'K8s');
+
+select is(
+    (
+        select name
+        from project
+        where project_id = :'projectID'
+    ),
+    'K8s',
+    'Should rename the selected project keeping its identifier'
+);
+
+-- Should not keep any project with the previous name
+select is(
+    (
+        select count(*)
+        from project
+        where foundation = 'cncf'
+        and name = 'Kubernetes'
+    ),
+    0::bigint,
+    'Should not keep any project with the previous name'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
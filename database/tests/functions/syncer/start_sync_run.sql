@@ -0,0 +1,31 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(1);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should register the start of a synchronization
+select start_sync_run('cncf') as "syncRunID" \gset
+
+select ok(
+    exists (
+        select 1
+        from sync_run
+        where sync_run_id = :'syncRunID'
+        and foundation = 'cncf'
+        and finished_at is null
+    ),
+    'Should register the start of a synchronization'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
@@ -16,8 +16,15 @@ insert into member (foundation, level, logo_url, name) values
 -- TESTS
 -- ============================================================================
 
--- Should update member level and logo URL
-select update_member('cncf', 'Acme', 'platinum', 'https://example.com/acme-new.svg');
+-- Should update member level, logo URL and landscape URLs
+select update_member(
+    'cncf',
+    'Acme',
+    'platinum',
+    'https://example.com/acme-new.svg',
+    'https://acme.example.com',
+    'https://www.crunchbase.com/organization/acme'
+);
 
 select ok(
     exists (
@@ -27,8 +34,10 @@ select ok(
         and name = 'Acme'
         and level = 'platinum'
         and logo_url = 'https://example.com/acme-new.svg'
+        and homepage_url = 'https://acme.example.com'
+        and crunchbase_url = 'https://www.crunchbase.com/organization/acme'
     ),
-    'Should update member level and logo URL'
+    'Should update member level, logo URL and landscape URLs'
 );
 
 -- ============================================================================
@@ -16,8 +16,18 @@ insert into project (foundation, logo_url, maturity, name) values
 -- TESTS
 -- ============================================================================
 
--- Should update project maturity and logo URL
-select update_project('cncf', 'Kubernetes', 'graduated', 'https://example.com/kube-new.svg');
+-- Should update project maturity, logo URL and landscape metadata
+select update_project(
+    'cncf',
+    'Kubernetes',
+    'graduated',
+    'https://example.com/kube-new.svg',
+    'https://kubernetes.io',
+    'https://github.com/kubernetes/kubernetes',
+    'Orchestration & Management',
+    'Scheduling & Orchestration',
+    'Production-grade container orchestration'
+);
 
 select ok(
     exists (
@@ -27,8 +37,13 @@ select ok(
         and name = 'Kubernetes'
         and maturity = 'graduated'
         and logo_url = 'https://example.com/kube-new.svg'
+        and homepage_url = 'https://kubernetes.io'
+        and repository_url = 'https://github.com/kubernetes/kubernetes'
+        and category = 'Orchestration & Management'
+        and subcategory = 'Scheduling & Orchestration'
+        and description = 'Production-grade container orchestration'
     ),
-    'Should update project maturity and logo URL'
+    'Should update project maturity, logo URL and landscape metadata'
 );
 
 -- ============================================================================
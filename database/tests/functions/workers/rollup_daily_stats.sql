@@ -0,0 +1,116 @@
+-- ============================================================================
+-- SETUP
+-- ============================================================================
+
+begin;
+select plan(5);
+
+-- ============================================================================
+-- VARIABLES
+-- ============================================================================
+
+\set employerID '00000000-0000-0000-0000-000000000101'
+\set jobID '00000000-0000-0000-0000-000000000301'
+
+-- ============================================================================
+-- SEED DATA
+-- ============================================================================
+
+insert into employer (company, description, employer_id) values
+    ('Acme', 'Employer for rollup_daily_stats tests', :'employerID');
+
+insert into job (
+    description,
+    employer_id,
+    job_id,
+    kind,
+    status,
+    title,
+    workplace
+) values (
+    'Published role',
+    :'employerID',
+    :'jobID',
+    'full-time',
+    'published',
+    'Job',
+    'remote'
+);
+
+-- Daily counters, two of them in a month older than the horizon
+insert into job_views (day, job_id, total) values
+    (date_trunc('month', current_date - interval '1 year')::date, :'jobID', 3),
+    (date_trunc('month', current_date - interval '1 year')::date + 1, :'jobID', 4),
+    ((current_date - interval '10 days')::date, :'jobID', 5);
+
+insert into search_appearances (day, job_id, total) values
+    (date_trunc('month', current_date - interval '1 year')::date, :'jobID', 30),
+    ((current_date - interval '10 days')::date, :'jobID', 50);
+
+-- Monthly aggregate from a previous rollup of the same month
+insert into job_views_monthly (job_id, month, total) values
+    (:'jobID', date_trunc('month', current_date - interval '1 year')::date, 10);
+
+-- ============================================================================
+-- TESTS
+-- ============================================================================
+
+-- Should return the number of daily rows compacted from each table
+select is(
+    rollup_daily_stats(60)::jsonb,
+    '{"job_views": 2, "search_appearances": 1}'::jsonb,
+    'Should return the number of daily rows compacted from each table'
+);
+
+-- Should add the compacted daily counters to the monthly aggregates
+select results_eq(
+    $$
+        select 'job_views' as kind, month, total from job_views_monthly
+        union all
+        select 'search_appearances', month, total from search_appearances_monthly
+        order by kind
+    $$,
+    $$
+        values
+            ('job_views', date_trunc('month', current_date - interval '1 year')::date, 17::bigint),
+            ('search_appearances', date_trunc('month', current_date - interval '1 year')::date, 30::bigint)
+    $$,
+    'Should add the compacted daily counters to the monthly aggregates'
+);
+
+-- Should keep the daily counters within the horizon
+select results_eq(
+    $$
+        select 'job_views' as kind, day, total from job_views
+        union all
+        select 'search_appearances', day, total from search_appearances
+        order by kind
+    $$,
+    $$
+        values
+            ('job_views', (current_date - interval '10 days')::date, 5),
+            ('search_appearances', (current_date - interval '10 days')::date, 50)
+    $$,
+    'Should keep the daily counters within the horizon'
+);
+
+-- Should not compact anything when run again
+select is(
+    rollup_daily_stats(60)::jsonb,
+    '{"job_views": 0, "search_appearances": 0}'::jsonb,
+    'Should not compact anything when run again'
+);
+
+-- Should keep the monthly aggregates unchanged when run again
+select is(
+    (select total from job_views_monthly where job_id = :'jobID'::uuid),
+    17::bigint,
+    'Should keep the monthly aggregates unchanged when run again'
+);
+
+-- ============================================================================
+-- CLEANUP
+-- ============================================================================
+
+select * from finish();
+rollback;
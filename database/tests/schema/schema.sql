@@ -3,7 +3,7 @@
 -- ============================================================================
 
 begin;
-select plan(157);
+select plan(224);
 
 -- ============================================================================
 -- TESTS
@@ -16,6 +16,7 @@ select has_extension('postgis');
 
 -- Test: check expected tables exist
 select has_table('application');
+select has_table('apply_clicks');
 select has_table('certification');
 select has_table('email_verification_code');
 select has_table('employer');
@@ -24,6 +25,7 @@ select has_table('employer_team');
 select has_table('faq');
 select has_table('foundation');
 select has_table('image');
+select has_table('image_upload');
 select has_table('image_version');
 select has_table('job');
 select has_table('job_certification');
@@ -32,15 +34,21 @@ select has_table('job_project');
 select has_table('job_seeker_profile');
 select has_table('job_status');
 select has_table('job_views');
+select has_table('job_views_monthly');
+select has_table('job_views_sources');
 select has_table('location');
 select has_table('member');
+select has_table('moderation_action');
+select has_table('moderation_action_kind');
 select has_table('notification');
 select has_table('notification_kind');
 select has_table('notification_template_data');
 select has_table('project');
 select has_table('search_appearances');
+select has_table('search_appearances_monthly');
 select has_table('seniority');
 select has_table('session');
+select has_table('sync_run');
 select has_table('user');
 select has_table('workplace');
 
@@ -54,6 +62,13 @@ select columns_are('application', array[
     'updated_at'
 ]);
 
+-- Test: apply_clicks columns should match expected
+select columns_are('apply_clicks', array[
+    'job_id',
+    'day',
+    'total'
+]);
+
 -- Test: certification columns should match expected
 select columns_are('certification', array[
     'certification_id',
@@ -82,7 +97,10 @@ select columns_are('employer', array[
     'description',
     'public',
     'updated_at',
-    'website_url'
+    'website_url',
+    'suspended_at',
+    'suspended_until',
+    'suspension_reason'
 ]);
 
 -- Test: employer_member columns should match expected
@@ -116,7 +134,16 @@ select columns_are('foundation', array[
 -- Test: image columns should match expected
 select columns_are('image', array[
     'image_id',
-    'created_by'
+    'created_by',
+    'created_at',
+    'hash'
+]);
+
+-- Test: image_upload columns should match expected
+select columns_are('image_upload', array[
+    'image_id',
+    'user_id',
+    'created_at'
 ]);
 
 -- Test: image_version columns should match expected
@@ -164,7 +191,8 @@ select columns_are('job', array[
     'reviewed_by',
     'reviewed_at',
     'first_published_at',
-    'deleted_at'
+    'deleted_at',
+    'review_requested_at'
 ]);
 
 -- Test: job_certification columns should match expected
@@ -225,6 +253,21 @@ select columns_are('job_views', array[
     'total'
 ]);
 
+-- Test: job_views_monthly columns should match expected
+select columns_are('job_views_monthly', array[
+    'job_id',
+    'month',
+    'total'
+]);
+
+-- Test: job_views_sources columns should match expected
+select columns_are('job_views_sources', array[
+    'job_id',
+    'day',
+    'source',
+    'total'
+]);
+
 -- Test: location columns should match expected
 select columns_are('location', array[
     'location_id',
@@ -241,7 +284,29 @@ select columns_are('member', array[
     'foundation',
     'name',
     'level',
-    'logo_url'
+    'logo_url',
+    'homepage_url',
+    'crunchbase_url'
+]);
+
+-- Test: moderation_action columns should match expected
+select columns_are('moderation_action', array[
+    'moderation_action_id',
+    'kind',
+    'moderator_id',
+    'created_at',
+    'employer_id',
+    'expires_at',
+    'job_id',
+    'reason',
+    'user_id',
+    'review_requested_at'
+]);
+
+-- Test: moderation_action_kind columns should match expected
+select columns_are('moderation_action_kind', array[
+    'moderation_action_kind_id',
+    'name'
 ]);
 
 -- Test: notification columns should match expected
@@ -276,7 +341,12 @@ select columns_are('project', array[
     'foundation',
     'name',
     'maturity',
-    'logo_url'
+    'logo_url',
+    'homepage_url',
+    'repository_url',
+    'category',
+    'description',
+    'subcategory'
 ]);
 
 -- Test: search_appearances columns should match expected
@@ -286,6 +356,13 @@ select columns_are('search_appearances', array[
     'total'
 ]);
 
+-- Test: search_appearances_monthly columns should match expected
+select columns_are('search_appearances_monthly', array[
+    'job_id',
+    'month',
+    'total'
+]);
+
 -- Test: seniority columns should match expected
 select columns_are('seniority', array[
     'seniority_id',
@@ -299,6 +376,21 @@ select columns_are('session', array[
     'expires_at'
 ]);
 
+-- Test: sync_run columns should match expected
+select columns_are('sync_run', array[
+    'sync_run_id',
+    'foundation',
+    'started_at',
+    'error',
+    'finished_at',
+    'members_added',
+    'members_removed',
+    'members_updated',
+    'projects_added',
+    'projects_removed',
+    'projects_updated'
+]);
+
 -- Test: user columns should match expected
 select columns_are('user', array[
     'user_id',
@@ -309,7 +401,11 @@ select columns_are('user', array[
     'name',
     'username',
     'password',
-    'moderator'
+    'moderator',
+    'suspended_at',
+    'suspended_until',
+    'suspension_reason',
+    'admin'
 ]);
 
 -- Test: workplace columns should match expected
@@ -319,6 +415,7 @@ select columns_are('workplace', array[
 ]);
 
 -- Test: check expected functions exist
+select has_function('delete_expired_sessions');
 select has_function('get_user_by_email');
 select has_function('get_user_by_id_verified');
 select has_function('get_user_by_username');
@@ -332,6 +429,7 @@ select has_function('user_has_profile_access');
 select has_function('user_owns_employer');
 select has_function('user_owns_job');
 select has_function('verify_email');
+select has_function('verify_user_email');
 select has_function('accept_team_member_invitation');
 select has_function('add_employer');
 select has_function('add_job');
@@ -341,10 +439,12 @@ select has_function('delete_job');
 select has_function('delete_team_member');
 select has_function('get_applications_filters_options');
 select has_function('get_employer');
+select has_function('get_employer_analytics');
 select has_function('get_job_dashboard');
 select has_function('get_job_salary');
 select has_function('get_job_seeker_user_id');
 select has_function('get_job_stats');
+select has_function('get_moderation_stats');
 select has_function('get_user_invitations_count');
 select has_function('list_certifications');
 select has_function('list_employer_jobs');
@@ -362,36 +462,75 @@ select has_function('list_job_seeker_applications');
 select has_function('upsert_job_seeker_profile');
 select has_function('approve_job');
 select has_function('list_jobs_for_moderation');
+select has_function('list_moderation_actions');
 select has_function('reject_job');
+select has_function('search_employers_for_moderation');
+select has_function('search_users_for_moderation');
+select has_function('suspend_employer');
+select has_function('suspend_user');
+select has_function('unsuspend_employer');
+select has_function('unsuspend_user');
+select has_function('add_certification');
+select has_function('add_foundation');
+select has_function('delete_certification');
+select has_function('grant_admin');
+select has_function('grant_moderator');
+select has_function('list_foundations_for_admin');
+select has_function('list_moderators');
+select has_function('list_recommended_jobs');
+select has_function('revoke_moderator');
+select has_function('update_certification');
+select has_function('update_foundation');
 select has_function('i_array_to_string');
+select has_function('add_image');
+select has_function('delete_unreferenced_image');
+select has_function('delete_unreferenced_images');
 select has_function('get_image_version');
+select has_function('list_unreferenced_images');
+select has_function('register_image_upload');
 select has_function('apply_to_job');
+select has_function('get_job_apply_url');
 select has_function('get_job_jobboard');
 select has_function('get_jobs_filters_options');
+select has_function('get_similar_jobs');
 select has_function('get_stats');
+select has_function('recommend_jobs');
 select has_function('search_jobs');
+select has_function('update_apply_clicks');
 select has_function('update_jobs_views');
+select has_function('update_jobs_views_sources');
 select has_function('update_search_appearances');
+select has_function('job_match');
 select has_function('search_locations');
 select has_function('search_locations_json');
 select has_function('search_members');
 select has_function('search_projects');
+select has_function('tz_offset');
+select has_function('tz_windows_overlap');
 select has_function('enqueue_notification');
 select has_function('get_pending_notification');
+select has_function('get_pending_notifications_stats');
+select has_function('retry_failed_notifications');
 select has_function('update_notification');
 select has_function('add_member');
 select has_function('add_project');
+select has_function('finish_sync_run');
 select has_function('syncer_list_foundations');
 select has_function('list_members');
 select has_function('list_projects');
 select has_function('remove_member');
 select has_function('remove_project');
+select has_function('rename_member');
+select has_function('rename_project');
+select has_function('start_sync_run');
 select has_function('update_member');
 select has_function('update_project');
 select has_function('archive_expired_jobs');
+select has_function('rollup_daily_stats');
 
 -- Test: check expected primary keys
 select has_pk('application');
+select hasnt_pk('apply_clicks');
 select has_pk('certification');
 select has_pk('email_verification_code');
 select has_pk('employer');
@@ -400,6 +539,7 @@ select has_pk('employer_team');
 select has_pk('faq');
 select has_pk('foundation');
 select has_pk('image');
+select has_pk('image_upload');
 select has_pk('image_version');
 select has_pk('job');
 select has_pk('job_certification');
@@ -408,15 +548,21 @@ select has_pk('job_project');
 select has_pk('job_seeker_profile');
 select has_pk('job_status');
 select hasnt_pk('job_views');
+select hasnt_pk('job_views_monthly');
+select hasnt_pk('job_views_sources');
 select has_pk('location');
 select has_pk('member');
+select has_pk('moderation_action');
+select has_pk('moderation_action_kind');
 select has_pk('notification');
 select has_pk('notification_kind');
 select has_pk('notification_template_data');
 select has_pk('project');
 select hasnt_pk('search_appearances');
+select hasnt_pk('search_appearances_monthly');
 select has_pk('seniority');
 select has_pk('session');
+select has_pk('sync_run');
 select has_pk('user');
 select has_pk('workplace');
 
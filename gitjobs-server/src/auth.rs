@@ -292,16 +292,23 @@ impl axum_login::AuthnBackend for AuthnBackend {
 
     /// Authenticate a user using the provided credentials.
     async fn authenticate(&self, creds: Self::Credentials) -> Result<Option<Self::User>, Self::Error> {
-        match creds {
-            Credentials::OAuth2(creds) => self.authenticate_oauth2(creds).await.map_err(AuthError),
-            Credentials::Oidc(creds) => self.authenticate_oidc(creds).await.map_err(AuthError),
-            Credentials::Password(creds) => self.authenticate_password(creds).await.map_err(AuthError),
+        let user = match creds {
+            Credentials::OAuth2(creds) => self.authenticate_oauth2(creds).await,
+            Credentials::Oidc(creds) => self.authenticate_oidc(creds).await,
+            Credentials::Password(creds) => self.authenticate_password(creds).await,
         }
+        .map_err(AuthError)?;
+
+        // Suspended users are not allowed to log in
+        Ok(user.filter(|user| !user.suspended))
     }
 
     /// Retrieve a user by user ID from the database.
     async fn get_user(&self, user_id: &axum_login::UserId<Self>) -> Result<Option<Self::User>, Self::Error> {
-        self.db.get_user_by_id(user_id).await.map_err(AuthError)
+        let user = self.db.get_user_by_id(user_id).await.map_err(AuthError)?;
+
+        // Suspended users are logged out on their next request
+        Ok(user.filter(|user| !user.suspended))
     }
 }
 
@@ -393,12 +400,15 @@ pub(crate) struct PasswordCredentials {
 // User types and implementations.
 
 /// Represents a user in the system.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct User {
     /// Unique user ID.
     pub user_id: Uuid,
     /// Authentication hash for session validation.
     pub auth_hash: Vec<u8>,
+    /// Whether the user is an administrator.
+    pub admin: bool,
     /// User's email address.
     pub email: String,
     /// Whether the user's email is verified.
@@ -409,6 +419,8 @@ pub(crate) struct User {
     pub moderator: bool,
     /// User's display name.
     pub name: String,
+    /// Whether the user account is currently suspended.
+    pub suspended: bool,
     /// User's username.
     pub username: String,
 
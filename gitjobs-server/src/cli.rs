@@ -0,0 +1,419 @@
+//! This module defines the subcommands supported by the server binary, which allow
+//! operators to run some maintenance tasks using the same configuration as the server.
+
+use anyhow::Result;
+use chrono::{TimeDelta, Utc};
+use clap::Subcommand;
+use tracing::info;
+
+use crate::{
+    config::{DEFAULT_STATS_ROLLUP_HORIZON_DAYS, MIN_STATS_ROLLUP_HORIZON_DAYS},
+    db::DynDB,
+    img::DynImageStore,
+};
+
+/// Subcommands supported by the server binary.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum Command {
+    /// Run the HTTP server (default when no subcommand is provided).
+    Serve,
+
+    #[command(flatten)]
+    Maintenance(MaintenanceCommand),
+}
+
+/// Maintenance subcommands, run once against the database.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum MaintenanceCommand {
+    /// Manage images.
+    #[command(subcommand)]
+    Images(ImagesCommand),
+
+    /// Manage jobs.
+    #[command(subcommand)]
+    Jobs(JobsCommand),
+
+    /// Manage notifications.
+    #[command(subcommand)]
+    Notifications(NotificationsCommand),
+
+    /// Manage sessions.
+    #[command(subcommand)]
+    Sessions(SessionsCommand),
+
+    /// Manage stats.
+    #[command(subcommand)]
+    Stats(StatsCommand),
+
+    /// Manage users.
+    #[command(subcommand)]
+    User(UserCommand),
+}
+
+/// Images maintenance subcommands.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum ImagesCommand {
+    /// Delete the images that are no longer referenced by any employer or profile.
+    Gc {
+        /// Number of hours recently uploaded images are kept, even if unreferenced.
+        #[arg(long, default_value_t = 24)]
+        grace_period_hours: u32,
+    },
+
+    /// Copy the images stored in the database to the configured storage backend.
+    Migrate {
+        /// Delete the images versions from the database once copied and verified.
+        #[arg(long)]
+        delete_source: bool,
+    },
+}
+
+/// Jobs maintenance subcommands.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum JobsCommand {
+    /// Archive the published jobs that have expired.
+    ArchiveExpired,
+}
+
+/// Notifications maintenance subcommands.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum NotificationsCommand {
+    /// Queue again for delivery the notifications that failed to be sent.
+    RetryFailed {
+        /// Only retry notifications created within this number of hours.
+        #[arg(long)]
+        max_age_hours: Option<u32>,
+    },
+}
+
+/// Sessions maintenance subcommands.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum SessionsCommand {
+    /// Delete the sessions that have expired.
+    Purge,
+}
+
+/// Stats maintenance subcommands.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum StatsCommand {
+    /// Roll up the daily stats older than the horizon into monthly aggregates.
+    Rollup {
+        /// Number of days daily stats are kept before being rolled up.
+        #[arg(
+            long,
+            default_value_t = DEFAULT_STATS_ROLLUP_HORIZON_DAYS,
+            value_parser = clap::value_parser!(u32).range(i64::from(MIN_STATS_ROLLUP_HORIZON_DAYS)..)
+        )]
+        horizon_days: u32,
+    },
+}
+
+/// Users maintenance subcommands.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(crate) enum UserCommand {
+    /// Grant admin rights to the verified user with the email provided.
+    PromoteAdmin {
+        /// Email address of the user.
+        email: String,
+    },
+
+    /// Grant moderator rights to the verified user with the email provided.
+    PromoteModerator {
+        /// Email address of the user.
+        email: String,
+    },
+
+    /// Mark the email address of the user provided as verified.
+    VerifyEmail {
+        /// Email address of the user.
+        email: String,
+    },
+}
+
+/// Runs the maintenance command provided.
+pub(crate) async fn run(db: DynDB, image_store: DynImageStore, command: MaintenanceCommand) -> Result<()> {
+    match command {
+        MaintenanceCommand::Images(ImagesCommand::Gc { grace_period_hours }) => {
+            let created_before = Utc::now() - TimeDelta::hours(i64::from(grace_period_hours));
+            let summary = image_store.delete_unreferenced(created_before).await?;
+            info!(
+                images = summary.images,
+                bytes = summary.bytes,
+                "unreferenced images deleted"
+            );
+        }
+        MaintenanceCommand::Images(ImagesCommand::Migrate { delete_source }) => {
+            let summary = image_store.migrate_from_db(delete_source).await?;
+            info!(
+                images = summary.images,
+                versions = summary.versions,
+                "images migrated"
+            );
+        }
+        MaintenanceCommand::Jobs(JobsCommand::ArchiveExpired) => {
+            db.archive_expired_jobs().await?;
+            info!("expired jobs archived");
+        }
+        MaintenanceCommand::Notifications(NotificationsCommand::RetryFailed { max_age_hours }) => {
+            let created_after = max_age_hours.map(|hours| Utc::now() - TimeDelta::hours(i64::from(hours)));
+            let updated = db.retry_failed_notifications(created_after).await?;
+            info!(updated, "failed notifications queued for delivery");
+        }
+        MaintenanceCommand::Sessions(SessionsCommand::Purge) => {
+            let deleted = db.delete_expired_sessions().await?;
+            info!(deleted, "expired sessions deleted");
+        }
+        MaintenanceCommand::Stats(StatsCommand::Rollup { horizon_days }) => {
+            let summary = db.rollup_daily_stats(horizon_days).await?;
+            info!(
+                job_views = summary.job_views,
+                search_appearances = summary.search_appearances,
+                "daily stats rolled up"
+            );
+        }
+        MaintenanceCommand::User(UserCommand::PromoteAdmin { email }) => {
+            let user_id = db.grant_admin(&email).await?;
+            info!(%user_id, "admin rights granted");
+        }
+        MaintenanceCommand::User(UserCommand::PromoteModerator { email }) => {
+            let user_id = db.grant_moderator(&email).await?;
+            info!(%user_id, "moderator rights granted");
+        }
+        MaintenanceCommand::User(UserCommand::VerifyEmail { email }) => {
+            let user_id = db.verify_user_email(&email).await?;
+            info!(%user_id, "user email verified");
+        }
+    }
+
+    Ok(())
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeDelta, Utc};
+    use clap::Parser;
+    use uuid::Uuid;
+
+    use crate::{
+        Args,
+        db::{mock::MockDB, workers::RollupSummary},
+        img::{CleanupSummary, DynImageStore, MigrationSummary, MockImageStore},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_args_without_subcommand_defaults_to_serve() {
+        let args = Args::try_parse_from(["gitjobs-server", "-c", "server.yml"]).unwrap();
+
+        assert_eq!(args.config_file.unwrap().to_str(), Some("server.yml"));
+        assert_eq!(args.command, None);
+    }
+
+    #[test]
+    fn test_args_accept_config_file_after_subcommand() {
+        let args = Args::try_parse_from([
+            "gitjobs-server",
+            "user",
+            "promote-moderator",
+            "user@example.com",
+            "-c",
+            "server.yml",
+        ])
+        .unwrap();
+
+        assert_eq!(args.config_file.unwrap().to_str(), Some("server.yml"));
+        assert_eq!(
+            args.command,
+            Some(Command::Maintenance(MaintenanceCommand::User(
+                UserCommand::PromoteModerator {
+                    email: "user@example.com".to_string()
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn test_args_images_gc_uses_default_grace_period() {
+        let args = Args::try_parse_from(["gitjobs-server", "images", "gc"]).unwrap();
+
+        assert_eq!(
+            args.command,
+            Some(Command::Maintenance(MaintenanceCommand::Images(
+                ImagesCommand::Gc {
+                    grace_period_hours: 24
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn test_args_stats_rollup_uses_default_horizon() {
+        let args = Args::try_parse_from(["gitjobs-server", "stats", "rollup"]).unwrap();
+
+        assert_eq!(
+            args.command,
+            Some(Command::Maintenance(MaintenanceCommand::Stats(
+                StatsCommand::Rollup {
+                    horizon_days: DEFAULT_STATS_ROLLUP_HORIZON_DAYS
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn test_args_stats_rollup_rejects_horizon_below_minimum() {
+        assert!(Args::try_parse_from(["gitjobs-server", "stats", "rollup", "--horizon-days", "30"]).is_err());
+    }
+
+    #[test]
+    fn test_args_user_command_requires_email() {
+        assert!(Args::try_parse_from(["gitjobs-server", "user", "verify-email"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_images_gc_applies_grace_period() {
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_delete_unreferenced()
+            .times(1)
+            .withf(|created_before| {
+                let expected = Utc::now() - TimeDelta::hours(48);
+                (*created_before - expected).num_seconds().abs() < 60
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(CleanupSummary {
+                        images: 3,
+                        bytes: 1024,
+                    })
+                })
+            });
+        let image_store: DynImageStore = Arc::new(image_store);
+
+        // Execute command
+        let command = MaintenanceCommand::Images(ImagesCommand::Gc {
+            grace_period_hours: 48,
+        });
+        let result = run(Arc::new(MockDB::new()), image_store, command).await;
+
+        // Check result matches expectations
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_images_migrate_deletes_source_when_requested() {
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_migrate_from_db()
+            .times(1)
+            .withf(|delete_source| *delete_source)
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(MigrationSummary {
+                        images: 2,
+                        versions: 8,
+                    })
+                })
+            });
+        let image_store: DynImageStore = Arc::new(image_store);
+
+        // Execute command
+        let command = MaintenanceCommand::Images(ImagesCommand::Migrate { delete_source: true });
+        let result = run(Arc::new(MockDB::new()), image_store, command).await;
+
+        // Check result matches expectations
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_notifications_retry_failed_without_max_age() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_retry_failed_notifications()
+            .times(1)
+            .withf(Option::is_none)
+            .returning(|_| Ok(2));
+        let db: DynDB = Arc::new(db);
+
+        // Execute command
+        let command =
+            MaintenanceCommand::Notifications(NotificationsCommand::RetryFailed { max_age_hours: None });
+        let result = run(db, Arc::new(MockImageStore::new()), command).await;
+
+        // Check result matches expectations
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_stats_rollup_uses_horizon_provided() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_rollup_daily_stats()
+            .times(1)
+            .withf(|horizon_days| *horizon_days == 500)
+            .returning(|_| {
+                Ok(RollupSummary {
+                    job_views: 10,
+                    search_appearances: 20,
+                })
+            });
+        let db: DynDB = Arc::new(db);
+
+        // Execute command
+        let command = MaintenanceCommand::Stats(StatsCommand::Rollup { horizon_days: 500 });
+        let result = run(db, Arc::new(MockImageStore::new()), command).await;
+
+        // Check result matches expectations
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_user_promote_moderator() {
+        // Setup identifiers and data structures
+        let user_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_grant_moderator()
+            .times(1)
+            .withf(|email| email == "user@example.com")
+            .returning(move |_| Ok(user_id));
+        let db: DynDB = Arc::new(db);
+
+        // Execute command
+        let command = MaintenanceCommand::User(UserCommand::PromoteModerator {
+            email: "user@example.com".to_string(),
+        });
+        let result = run(db, Arc::new(MockImageStore::new()), command).await;
+
+        // Check result matches expectations
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_user_verify_email_propagates_errors() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_verify_user_email()
+            .times(1)
+            .withf(|email| email == "missing@example.com")
+            .returning(|_| Err(anyhow::anyhow!("no user found with that email")));
+        let db: DynDB = Arc::new(db);
+
+        // Execute command
+        let command = MaintenanceCommand::User(UserCommand::VerifyEmail {
+            email: "missing@example.com".to_string(),
+        });
+        let result = run(db, Arc::new(MockImageStore::new()), command).await;
+
+        // Check result matches expectations
+        assert!(result.is_err());
+    }
+}
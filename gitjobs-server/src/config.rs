@@ -22,6 +22,16 @@ pub(crate) struct Config {
     pub log: LogConfig,
     /// HTTP server configuration.
     pub server: HttpServerConfig,
+
+    /// Images processing configuration.
+    #[serde(default)]
+    pub image_processing: ImageProcessingConfig,
+    /// Images storage configuration (images are stored in the database by default).
+    #[serde(default)]
+    pub image_storage: ImageStorageConfig,
+    /// Stats retention configuration.
+    #[serde(default)]
+    pub stats: StatsConfig,
 }
 
 impl Config {
@@ -70,6 +80,113 @@ pub(crate) struct SmtpConfig {
     pub password: String,
 }
 
+/// Images processing configuration.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub(crate) struct ImageProcessingConfig {
+    /// Generate avif versions of the images uploaded (encoding them is slow).
+    #[serde(default)]
+    pub avif: bool,
+}
+
+/// Default number of days daily stats are kept before being rolled up.
+pub(crate) const DEFAULT_STATS_ROLLUP_HORIZON_DAYS: u32 = 400;
+
+/// Minimum number of days daily stats must be kept, as some dashboard pages
+/// display daily series covering up to a year.
+pub(crate) const MIN_STATS_ROLLUP_HORIZON_DAYS: u32 = 366;
+
+/// Stats retention configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct StatsConfig {
+    /// Number of days daily job views and search appearances are kept before
+    /// being rolled up into monthly aggregates.
+    #[serde(default = "default_stats_rollup_horizon_days")]
+    pub rollup_horizon_days: u32,
+}
+
+impl StatsConfig {
+    /// Returns the rollup horizon in days, raised to the minimum supported
+    /// when the configured one is lower.
+    pub(crate) fn effective_rollup_horizon_days(&self) -> u32 {
+        self.rollup_horizon_days.max(MIN_STATS_ROLLUP_HORIZON_DAYS)
+    }
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            rollup_horizon_days: default_stats_rollup_horizon_days(),
+        }
+    }
+}
+
+/// Returns the default stats rollup horizon in days.
+fn default_stats_rollup_horizon_days() -> u32 {
+    DEFAULT_STATS_ROLLUP_HORIZON_DAYS
+}
+
+/// Default maximum number of hours a job should wait for review.
+pub(crate) const DEFAULT_REVIEW_SLA_HOURS: u32 = 48;
+
+/// Moderation configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct ModerationConfig {
+    /// Maximum number of hours a job should wait for review.
+    #[serde(default = "default_review_sla_hours")]
+    pub review_sla_hours: u32,
+}
+
+impl Default for ModerationConfig {
+    fn default() -> Self {
+        Self {
+            review_sla_hours: default_review_sla_hours(),
+        }
+    }
+}
+
+/// Returns the default maximum number of hours a job should wait for review.
+fn default_review_sla_hours() -> u32 {
+    DEFAULT_REVIEW_SLA_HOURS
+}
+
+/// Images storage configuration.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub(crate) enum ImageStorageConfig {
+    /// Store images in the database.
+    #[default]
+    Db,
+    /// Store images in the local filesystem.
+    Filesystem(FilesystemStorageConfig),
+    /// Store images in an S3-compatible object storage service.
+    S3(S3StorageConfig),
+}
+
+/// Filesystem images storage configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct FilesystemStorageConfig {
+    /// Directory where images will be stored.
+    pub path: PathBuf,
+}
+
+/// S3-compatible images storage configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct S3StorageConfig {
+    /// Bucket where images will be stored.
+    pub bucket: String,
+    /// Region where the bucket is located.
+    pub region: String,
+
+    /// Access key id (credentials are read from the environment when not set).
+    pub access_key_id: Option<String>,
+    /// Allow connecting to the endpoint using plain HTTP (e.g. a local `MinIO` instance).
+    pub allow_http: Option<bool>,
+    /// Custom endpoint URL, required for services other than AWS S3.
+    pub endpoint: Option<String>,
+    /// Secret access key (credentials are read from the environment when not set).
+    pub secret_access_key: Option<String>,
+}
+
 /// Logging configuration.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub(crate) struct LogConfig {
@@ -103,6 +220,14 @@ pub(crate) struct HttpServerConfig {
     pub oauth2: OAuth2Config,
     /// `Oidc` providers configuration.
     pub oidc: OidcConfig,
+    /// Moderation configuration.
+    #[serde(default)]
+    pub moderation: ModerationConfig,
+    /// Number of reverse proxies in front of the server whose `X-Forwarded-For`
+    /// entries are trusted. When zero, the header is ignored and the address of
+    /// the connection peer is used as the client address.
+    #[serde(default)]
+    pub trusted_proxies: usize,
 
     /// Optional analytics configuration.
     pub analytics: Option<AnalyticsConfig>,
@@ -110,6 +235,9 @@ pub(crate) struct HttpServerConfig {
     pub basic_auth: Option<BasicAuth>,
     /// Optional cookie configuration.
     pub cookie: Option<CookieConfig>,
+    /// Optional address where the metrics endpoint is served. It is kept apart
+    /// from the public server, and metrics are not exposed when not set.
+    pub metrics_addr: Option<String>,
     /// Optional Slack webhook URL to post new jobs published notifications.
     pub slack_webhook_url: Option<String>,
 }
@@ -25,6 +25,9 @@ pub(crate) trait DBAuth {
     /// Deletes a session from the database.
     async fn delete_session(&self, session_id: &session::Id) -> Result<()>;
 
+    /// Deletes all expired sessions, returning how many were deleted.
+    async fn delete_expired_sessions(&self) -> Result<u64>;
+
     /// Retrieves a session by its ID.
     async fn get_session(&self, session_id: &session::Id) -> Result<Option<session::Record>>;
 
@@ -73,6 +76,9 @@ pub(crate) trait DBAuth {
 
     /// Verifies a user's email address using a verification code.
     async fn verify_email(&self, code: &Uuid) -> Result<()>;
+
+    /// Marks the email address of the user with the given email as verified.
+    async fn verify_user_email(&self, email: &str) -> Result<Uuid>;
 }
 
 /// Implementation of `DBAuth` for `PgDB`, providing all authentication and authorization
@@ -121,6 +127,16 @@ impl DBAuth for PgDB {
         Ok(())
     }
 
+    #[instrument(skip(self), err)]
+    async fn delete_expired_sessions(&self) -> Result<u64> {
+        trace!("db: delete expired sessions");
+
+        let db = self.pool.get().await?;
+        let deleted: i64 = db.query_one("select delete_expired_sessions();", &[]).await?.get(0);
+
+        Ok(u64::try_from(deleted)?)
+    }
+
     #[instrument(skip(self, session_id), err)]
     async fn get_session(&self, session_id: &session::Id) -> Result<Option<session::Record>> {
         trace!("db: get session");
@@ -156,6 +172,7 @@ impl DBAuth for PgDB {
             .map(|row| User {
                 user_id: row.get("user_id"),
                 auth_hash: row.get("auth_hash"),
+                admin: row.get("admin"),
                 email: row.get("email"),
                 email_verified: row.get("email_verified"),
                 has_password: row.get("has_password"),
@@ -163,6 +180,7 @@ impl DBAuth for PgDB {
                 moderator: row.get("moderator"),
                 name: row.get("name"),
                 password: None,
+                suspended: row.get("suspended"),
                 username: row.get("username"),
             });
 
@@ -180,6 +198,7 @@ impl DBAuth for PgDB {
             .map(|row| User {
                 user_id: row.get("user_id"),
                 auth_hash: row.get("auth_hash"),
+                admin: row.get("admin"),
                 email: row.get("email"),
                 email_verified: row.get("email_verified"),
                 has_password: row.get("has_password"),
@@ -187,6 +206,7 @@ impl DBAuth for PgDB {
                 moderator: row.get("moderator"),
                 name: row.get("name"),
                 password: None,
+                suspended: row.get("suspended"),
                 username: row.get("username"),
             });
 
@@ -204,6 +224,7 @@ impl DBAuth for PgDB {
             .map(|row| User {
                 user_id: row.get("user_id"),
                 auth_hash: row.get("auth_hash"),
+                admin: row.get("admin"),
                 email: row.get("email"),
                 email_verified: row.get("email_verified"),
                 has_password: row.get("has_password"),
@@ -211,6 +232,7 @@ impl DBAuth for PgDB {
                 moderator: row.get("moderator"),
                 name: row.get("name"),
                 password: row.get("password"),
+                suspended: row.get("suspended"),
                 username: row.get("username"),
             });
 
@@ -271,6 +293,7 @@ impl DBAuth for PgDB {
         let user = User {
             user_id: row.get("user_id"),
             auth_hash: row.get("auth_hash"),
+            admin: false,
             email: row.get("email"),
             email_verified: row.get("email_verified"),
             has_password: row.get("has_password"),
@@ -278,6 +301,7 @@ impl DBAuth for PgDB {
             moderator: row.get("moderator"),
             name: row.get("name"),
             password: None,
+            suspended: false,
             username: row.get("username"),
         };
         let email_verification_code = row.get("verification_code");
@@ -402,6 +426,19 @@ impl DBAuth for PgDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self, email), err)]
+    async fn verify_user_email(&self, email: &str) -> Result<Uuid> {
+        trace!("db: verify user email");
+
+        let db = self.pool.get().await?;
+        let user_id = db
+            .query_one("select verify_user_email($1::text);", &[&email])
+            .await?
+            .get(0);
+
+        Ok(user_id)
+    }
 }
 
 /// Type alias for the email verification code (UUID).
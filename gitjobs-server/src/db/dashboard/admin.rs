@@ -0,0 +1,182 @@
+//! This module defines database operations for the admin dashboard.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio_postgres::types::Json;
+use tracing::{instrument, trace};
+use uuid::Uuid;
+
+use crate::{
+    PgDB,
+    templates::dashboard::admin::{
+        certifications::CertificationInput,
+        foundations::{Foundation, FoundationUpdate, NewFoundation},
+        moderators::Moderator,
+    },
+};
+
+/// Trait for admin dashboard database operations.
+#[async_trait]
+pub(crate) trait DBDashBoardAdmin {
+    /// Adds a new certification to the catalogue.
+    async fn add_certification(&self, certification: &CertificationInput) -> Result<Uuid>;
+
+    /// Adds a new foundation.
+    async fn add_foundation(&self, foundation: &NewFoundation) -> Result<Uuid>;
+
+    /// Deletes a certification that is not required by any job.
+    async fn delete_certification(&self, certification_id: &Uuid) -> Result<()>;
+
+    /// Grants admin rights to the verified user with the given email.
+    async fn grant_admin(&self, email: &str) -> Result<Uuid>;
+
+    /// Grants moderator rights to the verified user with the given email.
+    async fn grant_moderator(&self, email: &str) -> Result<Uuid>;
+
+    /// Lists all foundations with their synced projects and members counts.
+    async fn list_foundations_for_admin(&self) -> Result<Vec<Foundation>>;
+
+    /// Lists the users with moderator rights.
+    async fn list_moderators(&self) -> Result<Vec<Moderator>>;
+
+    /// Revokes the moderator rights of a user.
+    async fn revoke_moderator(&self, user_id: &Uuid) -> Result<()>;
+
+    /// Updates a certification in the catalogue.
+    async fn update_certification(
+        &self,
+        certification_id: &Uuid,
+        certification: &CertificationInput,
+    ) -> Result<()>;
+
+    /// Updates a foundation's details.
+    async fn update_foundation(&self, foundation_id: &Uuid, foundation: &FoundationUpdate) -> Result<()>;
+}
+
+#[async_trait]
+impl DBDashBoardAdmin for PgDB {
+    #[instrument(skip(self, certification), err)]
+    async fn add_certification(&self, certification: &CertificationInput) -> Result<Uuid> {
+        trace!("db: add certification");
+
+        let db = self.pool.get().await?;
+        let certification_id = db
+            .query_one("select add_certification($1::jsonb);", &[&Json(certification)])
+            .await?
+            .get(0);
+
+        Ok(certification_id)
+    }
+
+    #[instrument(skip(self, foundation), err)]
+    async fn add_foundation(&self, foundation: &NewFoundation) -> Result<Uuid> {
+        trace!("db: add foundation");
+
+        let db = self.pool.get().await?;
+        let foundation_id = db
+            .query_one("select add_foundation($1::jsonb);", &[&Json(foundation)])
+            .await?
+            .get(0);
+
+        Ok(foundation_id)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_certification(&self, certification_id: &Uuid) -> Result<()> {
+        trace!("db: delete certification");
+
+        let db = self.pool.get().await?;
+        db.execute("select delete_certification($1::uuid);", &[&certification_id])
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, email), err)]
+    async fn grant_admin(&self, email: &str) -> Result<Uuid> {
+        trace!("db: grant admin");
+
+        let db = self.pool.get().await?;
+        let user_id = db.query_one("select grant_admin($1::text);", &[&email]).await?.get(0);
+
+        Ok(user_id)
+    }
+
+    #[instrument(skip(self, email), err)]
+    async fn grant_moderator(&self, email: &str) -> Result<Uuid> {
+        trace!("db: grant moderator");
+
+        let db = self.pool.get().await?;
+        let user_id = db
+            .query_one("select grant_moderator($1::text);", &[&email])
+            .await?
+            .get(0);
+
+        Ok(user_id)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_foundations_for_admin(&self) -> Result<Vec<Foundation>> {
+        trace!("db: list foundations for admin");
+
+        let db = self.pool.get().await?;
+        let json_data: String = db
+            .query_one("select list_foundations_for_admin()::text;", &[])
+            .await?
+            .get(0);
+
+        Ok(serde_json::from_str(&json_data)?)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_moderators(&self) -> Result<Vec<Moderator>> {
+        trace!("db: list moderators");
+
+        let db = self.pool.get().await?;
+        let json_data: String = db.query_one("select list_moderators()::text;", &[]).await?.get(0);
+
+        Ok(serde_json::from_str(&json_data)?)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn revoke_moderator(&self, user_id: &Uuid) -> Result<()> {
+        trace!("db: revoke moderator");
+
+        let db = self.pool.get().await?;
+        db.execute("select revoke_moderator($1::uuid);", &[&user_id]).await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, certification), err)]
+    async fn update_certification(
+        &self,
+        certification_id: &Uuid,
+        certification: &CertificationInput,
+    ) -> Result<()> {
+        trace!("db: update certification");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_certification($1::uuid, $2::jsonb);",
+            &[&certification_id, &Json(certification)],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, foundation), err)]
+    async fn update_foundation(&self, foundation_id: &Uuid, foundation: &FoundationUpdate) -> Result<()> {
+        trace!("db: update foundation");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_foundation($1::uuid, $2::jsonb);",
+            &[&foundation_id, &Json(foundation)],
+        )
+        .await?;
+
+        Ok(())
+    }
+}
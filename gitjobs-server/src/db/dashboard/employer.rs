@@ -11,6 +11,7 @@ use crate::{
     PgDB,
     templates::{
         dashboard::employer::{
+            analytics::{EmployerAnalytics, Range},
             applications::{self, Application},
             employers::{Employer, EmployerSummary},
             jobs::{Job, JobStats, JobSummary},
@@ -56,6 +57,9 @@ pub(crate) trait DBDashBoardEmployer {
     /// Retrieves an employer's details.
     async fn get_employer(&self, employer_id: &Uuid) -> Result<Employer>;
 
+    /// Retrieves the analytics of an employer's jobs for the range provided.
+    async fn get_employer_analytics(&self, employer_id: &Uuid, range: &Range) -> Result<EmployerAnalytics>;
+
     /// Retrieves a job's details for the dashboard.
     async fn get_job_dashboard(&self, job_id: &Uuid) -> Result<Job>;
 
@@ -241,6 +245,22 @@ impl DBDashBoardEmployer for PgDB {
         Ok(serde_json::from_str(&json_data)?)
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_employer_analytics(&self, employer_id: &Uuid, range: &Range) -> Result<EmployerAnalytics> {
+        trace!("db: get employer analytics");
+
+        let db = self.pool.get().await?;
+        let json_data: String = db
+            .query_one(
+                "select get_employer_analytics($1::uuid, $2::int)::text;",
+                &[&employer_id, &range.days()],
+            )
+            .await?
+            .get(0);
+
+        Ok(serde_json::from_str(&json_data)?)
+    }
+
     #[instrument(skip(self), err)]
     async fn get_job_dashboard(&self, job_id: &Uuid) -> Result<Job> {
         trace!("db: get job dashboard");
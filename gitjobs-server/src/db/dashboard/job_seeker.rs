@@ -8,9 +8,15 @@ use uuid::Uuid;
 
 use crate::{
     PgDB,
-    templates::dashboard::job_seeker::{applications::Application, profile::JobSeekerProfile},
+    templates::{
+        dashboard::job_seeker::{applications::Application, profile::JobSeekerProfile},
+        jobboard::jobs::JobSummary,
+    },
 };
 
+/// Maximum number of jobs recommended to a job seeker.
+const RECOMMENDED_JOBS_LIMIT: i32 = 10;
+
 /// Trait for job seeker dashboard database operations.
 #[async_trait]
 pub(crate) trait DBDashBoardJobSeeker {
@@ -23,6 +29,9 @@ pub(crate) trait DBDashBoardJobSeeker {
     /// Lists all job applications for the given user.
     async fn list_job_seeker_applications(&self, user_id: &Uuid) -> Result<Vec<Application>>;
 
+    /// Lists the jobs recommended to the given user based on their profile.
+    async fn list_recommended_jobs(&self, user_id: &Uuid) -> Result<Vec<JobSummary>>;
+
     /// Updates the job seeker profile for the given user.
     async fn update_job_seeker_profile(&self, user_id: &Uuid, profile: &JobSeekerProfile) -> Result<()>;
 }
@@ -72,6 +81,22 @@ impl DBDashBoardJobSeeker for PgDB {
         Ok(applications)
     }
 
+    #[instrument(skip(self), err)]
+    async fn list_recommended_jobs(&self, user_id: &Uuid) -> Result<Vec<JobSummary>> {
+        trace!("db: list recommended jobs");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select list_recommended_jobs($1::uuid, $2::int)::text",
+                &[&user_id, &RECOMMENDED_JOBS_LIMIT],
+            )
+            .await?;
+        let jobs = serde_json::from_str(&row.get::<_, String>(0))?;
+
+        Ok(jobs)
+    }
+
     #[instrument(skip(self), err)]
     async fn update_job_seeker_profile(&self, user_id: &Uuid, profile: &JobSeekerProfile) -> Result<()> {
         trace!("db: update job seeker profile");
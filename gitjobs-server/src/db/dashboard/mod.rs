@@ -1,5 +1,6 @@
 //! This module defines some database functionality for the dashboards.
 
+use admin::DBDashBoardAdmin;
 use async_trait::async_trait;
 use employer::DBDashBoardEmployer;
 use job_seeker::DBDashBoardJobSeeker;
@@ -7,6 +8,7 @@ use moderator::DBDashBoardModerator;
 
 use crate::PgDB;
 
+pub(crate) mod admin;
 pub(crate) mod employer;
 pub(crate) mod job_seeker;
 pub(crate) mod moderator;
@@ -14,7 +16,7 @@ pub(crate) mod moderator;
 /// Trait that defines database operations used in the dashboards.
 #[async_trait]
 pub(crate) trait DBDashBoard:
-    DBDashBoardEmployer + DBDashBoardJobSeeker + DBDashBoardModerator
+    DBDashBoardAdmin + DBDashBoardEmployer + DBDashBoardJobSeeker + DBDashBoardModerator
 {
 }
 
@@ -3,25 +3,77 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::Json;
 use tracing::{instrument, trace};
 use uuid::Uuid;
 
 use crate::{
     PgDB,
-    templates::dashboard::{employer::jobs::JobStatus, moderator::jobs::JobSummary},
+    templates::dashboard::{
+        employer::jobs::JobStatus,
+        moderator::{
+            accounts::{Employer, EmployersFilters, ModerationAction, User, UsersFilters},
+            jobs::JobSummary,
+            stats::Stats,
+        },
+    },
 };
 
+/// Maximum number of moderation actions returned from the audit trail.
+const MODERATION_ACTIONS_LIMIT: i32 = 100;
+
 /// Trait for moderator dashboard database operations.
 #[async_trait]
 pub(crate) trait DBDashBoardModerator {
     /// Approves a job and updates its status and review metadata.
     async fn approve_job(&self, job_id: &Uuid, reviewer: &Uuid) -> Result<Option<DateTime<Utc>>>;
 
+    /// Retrieves statistics about the moderation queue and review throughput,
+    /// using the review SLA provided (in hours).
+    async fn get_moderation_stats(&self, review_sla_hours: u32) -> Result<Stats>;
+
     /// Lists jobs for moderation filtered by the given status.
     async fn list_jobs_for_moderation(&self, status: JobStatus) -> Result<Vec<JobSummary>>;
 
+    /// Lists the most recent actions recorded in the moderation audit trail.
+    async fn list_moderation_actions(&self) -> Result<Vec<ModerationAction>>;
+
     /// Rejects a job, optionally adding review notes and updating review metadata.
     async fn reject_job(&self, job_id: &Uuid, reviewer: &Uuid, review_notes: Option<String>) -> Result<()>;
+
+    /// Searches employers for moderation using the provided filters.
+    async fn search_employers_for_moderation(
+        &self,
+        filters: &EmployersFilters,
+    ) -> Result<EmployersSearchOutput>;
+
+    /// Searches users for moderation using the provided filters.
+    async fn search_users_for_moderation(&self, filters: &UsersFilters) -> Result<UsersSearchOutput>;
+
+    /// Suspends an employer, hiding its jobs from the job board.
+    async fn suspend_employer(
+        &self,
+        employer_id: &Uuid,
+        moderator_id: &Uuid,
+        reason: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()>;
+
+    /// Suspends a user, revoking all their sessions.
+    async fn suspend_user(
+        &self,
+        user_id: &Uuid,
+        moderator_id: &Uuid,
+        reason: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()>;
+
+    /// Lifts the suspension of an employer.
+    async fn unsuspend_employer(&self, employer_id: &Uuid, moderator_id: &Uuid) -> Result<()>;
+
+    /// Lifts the suspension of a user.
+    async fn unsuspend_user(&self, user_id: &Uuid, moderator_id: &Uuid) -> Result<()>;
 }
 
 #[async_trait]
@@ -39,6 +91,20 @@ impl DBDashBoardModerator for PgDB {
         Ok(first_published_at)
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_moderation_stats(&self, review_sla_hours: u32) -> Result<Stats> {
+        trace!("db: get moderation stats");
+
+        let db = self.pool.get().await?;
+        let review_sla_hours = i32::try_from(review_sla_hours)?;
+        let row = db
+            .query_one("select get_moderation_stats($1::int)::text", &[&review_sla_hours])
+            .await?;
+        let stats = serde_json::from_str(&row.get::<_, String>(0))?;
+
+        Ok(stats)
+    }
+
     #[instrument(skip(self), err)]
     async fn list_jobs_for_moderation(&self, status: JobStatus) -> Result<Vec<JobSummary>> {
         trace!("db: list jobs for moderation");
@@ -55,6 +121,22 @@ impl DBDashBoardModerator for PgDB {
         Ok(jobs)
     }
 
+    #[instrument(skip(self), err)]
+    async fn list_moderation_actions(&self) -> Result<Vec<ModerationAction>> {
+        trace!("db: list moderation actions");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select list_moderation_actions($1::int)::text",
+                &[&MODERATION_ACTIONS_LIMIT],
+            )
+            .await?;
+        let actions = serde_json::from_str(&row.get::<_, String>(0))?;
+
+        Ok(actions)
+    }
+
     #[instrument(skip(self), err)]
     async fn reject_job(&self, job_id: &Uuid, reviewer: &Uuid, review_notes: Option<String>) -> Result<()> {
         trace!("db: reject job");
@@ -68,4 +150,125 @@ impl DBDashBoardModerator for PgDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self), err)]
+    async fn search_employers_for_moderation(
+        &self,
+        filters: &EmployersFilters,
+    ) -> Result<EmployersSearchOutput> {
+        trace!("db: search employers for moderation");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select search_employers_for_moderation($1::jsonb)::text",
+                &[&Json(filters)],
+            )
+            .await?;
+        let output = serde_json::from_str(&row.get::<_, String>(0))?;
+
+        Ok(output)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn search_users_for_moderation(&self, filters: &UsersFilters) -> Result<UsersSearchOutput> {
+        trace!("db: search users for moderation");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select search_users_for_moderation($1::jsonb)::text",
+                &[&Json(filters)],
+            )
+            .await?;
+        let output = serde_json::from_str(&row.get::<_, String>(0))?;
+
+        Ok(output)
+    }
+
+    #[instrument(skip(self, reason), err)]
+    async fn suspend_employer(
+        &self,
+        employer_id: &Uuid,
+        moderator_id: &Uuid,
+        reason: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        trace!("db: suspend employer");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select suspend_employer($1::uuid, $2::uuid, $3::text, $4::timestamptz);",
+            &[employer_id, moderator_id, &reason, &expires_at],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, reason), err)]
+    async fn suspend_user(
+        &self,
+        user_id: &Uuid,
+        moderator_id: &Uuid,
+        reason: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        trace!("db: suspend user");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select suspend_user($1::uuid, $2::uuid, $3::text, $4::timestamptz);",
+            &[user_id, moderator_id, &reason, &expires_at],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn unsuspend_employer(&self, employer_id: &Uuid, moderator_id: &Uuid) -> Result<()> {
+        trace!("db: unsuspend employer");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select unsuspend_employer($1::uuid, $2::uuid);",
+            &[employer_id, moderator_id],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn unsuspend_user(&self, user_id: &Uuid, moderator_id: &Uuid) -> Result<()> {
+        trace!("db: unsuspend user");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select unsuspend_user($1::uuid, $2::uuid);",
+            &[user_id, moderator_id],
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Output for employers search in the moderator dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmployersSearchOutput {
+    /// List of employers matching the search.
+    pub employers: Vec<Employer>,
+    /// Total number of employers found.
+    pub total: usize,
+}
+
+/// Output for users search in the moderator dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UsersSearchOutput {
+    /// Total number of users found.
+    pub total: usize,
+    /// List of users matching the search.
+    pub users: Vec<User>,
 }
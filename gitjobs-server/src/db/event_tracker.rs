@@ -1,5 +1,6 @@
 //! This module defines database functionality used in the event tracker, including
-//! operations for updating job view and search appearance counts.
+//! operations for updating job view, view source, search appearance and apply click
+//! counts.
 
 use std::sync::Arc;
 
@@ -10,7 +11,7 @@ use tracing::{instrument, trace};
 
 use crate::{
     db::PgDB,
-    event_tracker::{Day, JobId, Total},
+    event_tracker::{Day, JobId, Source, Total},
 };
 
 /// Lock key used to synchronize updates to job views in the database.
@@ -19,12 +20,24 @@ const LOCK_KEY_UPDATE_JOBS_VIEWS: i64 = 1;
 /// Lock key used to synchronize updates to search appearances in the database.
 const LOCK_KEY_UPDATE_SEARCH_APPEARANCES: i64 = 2;
 
+/// Lock key used to synchronize updates to apply clicks in the database.
+const LOCK_KEY_UPDATE_APPLY_CLICKS: i64 = 3;
+
+/// Lock key used to synchronize updates to job views sources in the database.
+const LOCK_KEY_UPDATE_JOBS_VIEWS_SOURCES: i64 = 4;
+
 /// Trait that defines database operations used in the event tracker.
 #[async_trait]
 pub(crate) trait DBEventTracker {
+    /// Updates the number of apply clicks for the provided jobs and days.
+    async fn update_apply_clicks(&self, data: Vec<(JobId, Day, Total)>) -> Result<()>;
+
     /// Updates the number of views for the provided jobs and days.
     async fn update_jobs_views(&self, data: Vec<(JobId, Day, Total)>) -> Result<()>;
 
+    /// Updates the number of views per traffic source for the provided jobs and days.
+    async fn update_jobs_views_sources(&self, data: Vec<(JobId, Day, Source, Total)>) -> Result<()>;
+
     /// Updates the number of search appearances for the provided jobs and days.
     async fn update_search_appearances(&self, data: Vec<(JobId, Day, Total)>) -> Result<()>;
 }
@@ -34,6 +47,20 @@ pub(crate) type DynDBEventTracker = Arc<dyn DBEventTracker + Send + Sync>;
 
 #[async_trait]
 impl DBEventTracker for PgDB {
+    #[instrument(skip(self), err)]
+    async fn update_apply_clicks(&self, data: Vec<(JobId, Day, Total)>) -> Result<()> {
+        trace!("db: update apply clicks");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_apply_clicks($1::bigint, $2::jsonb)",
+            &[&LOCK_KEY_UPDATE_APPLY_CLICKS, &Json(&data)],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self), err)]
     async fn update_jobs_views(&self, data: Vec<(JobId, Day, Total)>) -> Result<()> {
         trace!("db: update jobs views");
@@ -48,6 +75,20 @@ impl DBEventTracker for PgDB {
         Ok(())
     }
 
+    #[instrument(skip(self), err)]
+    async fn update_jobs_views_sources(&self, data: Vec<(JobId, Day, Source, Total)>) -> Result<()> {
+        trace!("db: update jobs views sources");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "select update_jobs_views_sources($1::bigint, $2::jsonb)",
+            &[&LOCK_KEY_UPDATE_JOBS_VIEWS_SOURCES, &Json(&data)],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self), err)]
     async fn update_search_appearances(&self, data: Vec<(JobId, Day, Total)>) -> Result<()> {
         trace!("db: update search appearances");
@@ -4,17 +4,39 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use tracing::{instrument, trace};
 use uuid::Uuid;
 
 use crate::{
     PgDB,
-    img::{ImageFormat, ImageVersion},
+    img::{DeletedImage, ImageFormat, ImageVersion},
 };
 
 /// Trait for database operations related to image management.
 #[async_trait]
 pub(crate) trait DBImage {
+    /// Registers a new image whose versions are kept outside the database using
+    /// the identifier provided. The existing image is returned when one with the
+    /// same hash already exists.
+    async fn add_image(&self, image_id: &Uuid, user_id: &Uuid, hash: &str) -> Result<Uuid>;
+
+    /// Deletes all the versions of an image stored in the database.
+    async fn delete_image_versions(&self, image_id: &Uuid) -> Result<()>;
+
+    /// Deletes an image if it is still not referenced anywhere and was uploaded
+    /// before the given timestamp, returning the size of the versions that were
+    /// stored in the database when it was deleted.
+    async fn delete_unreferenced_image(
+        &self,
+        image_id: &Uuid,
+        created_before: DateTime<Utc>,
+    ) -> Result<Option<u64>>;
+
+    /// Deletes the images not referenced anywhere that were uploaded before the
+    /// given timestamp, returning the images deleted.
+    async fn delete_unreferenced_images(&self, created_before: DateTime<Utc>) -> Result<Vec<DeletedImage>>;
+
     /// Retrieves a specific version of an image from the database.
     async fn get_image_version(
         &self,
@@ -22,8 +44,27 @@ pub(crate) trait DBImage {
         version: &str,
     ) -> Result<Option<(Vec<u8>, ImageFormat)>>;
 
+    /// Retrieves all the versions of an image stored in the database.
+    async fn get_image_versions(&self, image_id: &Uuid) -> Result<Vec<ImageVersion>>;
+
+    /// Lists the images that have some versions stored in the database.
+    async fn list_images_with_versions(&self) -> Result<Vec<Uuid>>;
+
+    /// Lists the images not referenced anywhere that were uploaded before the
+    /// given timestamp.
+    async fn list_unreferenced_images(&self, created_before: DateTime<Utc>) -> Result<Vec<Uuid>>;
+
+    /// Registers a new upload of the image with the given hash by the user,
+    /// returning its identifier if such an image exists.
+    async fn register_image_upload(&self, user_id: &Uuid, hash: &str) -> Result<Option<Uuid>>;
+
     /// Saves multiple image versions in the database.
-    async fn save_image_versions(&self, user_id: &Uuid, versions: Vec<ImageVersion>) -> Result<Uuid>;
+    async fn save_image_versions(
+        &self,
+        user_id: &Uuid,
+        hash: &str,
+        versions: Vec<ImageVersion>,
+    ) -> Result<Uuid>;
 }
 
 /// Shared pointer to a thread-safe, async `DBImage` trait object.
@@ -32,6 +73,78 @@ pub(crate) type DynDBImage = Arc<dyn DBImage + Send + Sync>;
 /// Implementation of `DBImage` for the `PgDB` database backend.
 #[async_trait]
 impl DBImage for PgDB {
+    #[instrument(skip(self), err)]
+    async fn add_image(&self, image_id: &Uuid, user_id: &Uuid, hash: &str) -> Result<Uuid> {
+        trace!("db: add image");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select add_image($1::uuid, $2::uuid, $3::text);",
+                &[&image_id, &user_id, &hash],
+            )
+            .await?;
+
+        Ok(row.get(0))
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_image_versions(&self, image_id: &Uuid) -> Result<()> {
+        trace!("db: delete image versions");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "delete from image_version where image_id = $1::uuid;",
+            &[&image_id],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_unreferenced_image(
+        &self,
+        image_id: &Uuid,
+        created_before: DateTime<Utc>,
+    ) -> Result<Option<u64>> {
+        trace!("db: delete unreferenced image");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select delete_unreferenced_image($1::uuid, $2::timestamptz);",
+                &[&image_id, &created_before],
+            )
+            .await?;
+        let size = row.get::<_, Option<i64>>(0).map(u64::try_from).transpose()?;
+
+        Ok(size)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_unreferenced_images(&self, created_before: DateTime<Utc>) -> Result<Vec<DeletedImage>> {
+        trace!("db: delete unreferenced images");
+
+        let db = self.pool.get().await?;
+        let deleted_images = db
+            .query(
+                "select image_id, size from delete_unreferenced_images($1::timestamptz);",
+                &[&created_before],
+            )
+            .await?
+            .iter()
+            .map(|row| {
+                Ok(DeletedImage {
+                    image_id: row.get("image_id"),
+                    size: u64::try_from(row.get::<_, i64>("size"))?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(deleted_images)
+    }
+
     #[instrument(skip(self), err)]
     async fn get_image_version(
         &self,
@@ -56,28 +169,93 @@ impl DBImage for PgDB {
         Ok(Some((data, format)))
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_image_versions(&self, image_id: &Uuid) -> Result<Vec<ImageVersion>> {
+        trace!("db: get image versions");
+
+        let db = self.pool.get().await?;
+        let versions = db
+            .query(
+                "select version, data from image_version where image_id = $1::uuid;",
+                &[&image_id],
+            )
+            .await?
+            .iter()
+            .map(|row| ImageVersion {
+                data: row.get("data"),
+                version: row.get("version"),
+            })
+            .collect();
+
+        Ok(versions)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_images_with_versions(&self) -> Result<Vec<Uuid>> {
+        trace!("db: list images with versions");
+
+        let db = self.pool.get().await?;
+        let image_ids = db
+            .query("select distinct image_id from image_version;", &[])
+            .await?
+            .iter()
+            .map(|row| row.get("image_id"))
+            .collect();
+
+        Ok(image_ids)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_unreferenced_images(&self, created_before: DateTime<Utc>) -> Result<Vec<Uuid>> {
+        trace!("db: list unreferenced images");
+
+        let db = self.pool.get().await?;
+        let image_ids = db
+            .query(
+                "select image_id from list_unreferenced_images($1::timestamptz);",
+                &[&created_before],
+            )
+            .await?
+            .iter()
+            .map(|row| row.get("image_id"))
+            .collect();
+
+        Ok(image_ids)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn register_image_upload(&self, user_id: &Uuid, hash: &str) -> Result<Option<Uuid>> {
+        trace!("db: register image upload");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select register_image_upload($1::uuid, $2::text);",
+                &[&user_id, &hash],
+            )
+            .await?;
+
+        Ok(row.get(0))
+    }
+
     #[instrument(skip(self, versions), err)]
-    async fn save_image_versions(&self, user_id: &Uuid, versions: Vec<ImageVersion>) -> Result<Uuid> {
+    async fn save_image_versions(
+        &self,
+        user_id: &Uuid,
+        hash: &str,
+        versions: Vec<ImageVersion>,
+    ) -> Result<Uuid> {
         trace!("db: save image versions");
 
         // Begin transaction
         let mut db = self.pool.get().await?;
         let tx = db.transaction().await?;
 
-        // Insert image identifier
-        let image_id = Uuid::new_v4();
-        tx.execute(
-            "
-            insert into image (
-                image_id,
-                created_by
-            ) values (
-                $1::uuid,
-                $2::uuid
-            )",
-            &[&image_id, &user_id],
-        )
-        .await?;
+        // Insert image (an identical image may have been saved concurrently)
+        let row = tx
+            .query_one("select add_image($1::uuid, $2::text);", &[&user_id, &hash])
+            .await?;
+        let image_id: Uuid = row.get(0);
 
         // Insert image versions
         for v in versions {
@@ -85,6 +263,7 @@ impl DBImage for PgDB {
                 "
                 insert into image_version (image_id, version, data)
                 values ($1::uuid, $2::text, $3::bytea)
+                on conflict (image_id, version) do nothing
                 ",
                 &[&image_id, &v.version, &v.data],
             )
@@ -14,11 +14,14 @@ use uuid::Uuid;
 use crate::{
     PgDB,
     templates::jobboard::{
-        jobs::{Filters, FiltersOptions, Job, JobSummary},
+        jobs::{Facets, Filters, FiltersOptions, Job, JobSummary},
         stats::Stats,
     },
 };
 
+/// Maximum number of similar jobs returned for a job.
+const SIMILAR_JOBS_LIMIT: i32 = 5;
+
 /// Trait for database operations used by the job board, such as applying and searching jobs.
 #[async_trait]
 pub(crate) trait DBJobBoard {
@@ -26,17 +29,30 @@ pub(crate) trait DBJobBoard {
     /// application was successfully recorded or `false` otherwise.
     async fn apply_to_job(&self, job_id: &Uuid, user_id: &Uuid) -> Result<bool>;
 
+    /// Returns the external apply URL of a published job, if it has one.
+    async fn get_job_apply_url(&self, job_id: &Uuid) -> Result<Option<String>>;
+
     /// Fetches a job for the job board by its unique identifier.
     async fn get_job_jobboard(&self, job_id: &Uuid) -> Result<Option<Job>>;
 
     /// Retrieves available filter options for job searches.
     async fn get_jobs_filters_options(&self) -> Result<FiltersOptions>;
 
+    /// Retrieves the published jobs most similar to the provided one.
+    async fn get_similar_jobs(&self, job_id: &Uuid) -> Result<Vec<JobSummary>>;
+
     /// Retrieves statistics about the job board.
     async fn get_stats(&self) -> Result<Stats>;
 
-    /// Searches for jobs using the provided filter criteria.
-    async fn search_jobs(&self, filters: &Filters) -> Result<JobsSearchOutput>;
+    /// Searches for jobs using the provided filter criteria, including how well
+    /// each job matches the profile of the user provided, if any, and the
+    /// number of jobs available for each filter option, when requested.
+    async fn search_jobs(
+        &self,
+        filters: &Filters,
+        user_id: Option<Uuid>,
+        include_facets: bool,
+    ) -> Result<JobsSearchOutput>;
 }
 
 /// Implementation of `DBJobBoard` for the `PostgreSQL` database backend.
@@ -54,6 +70,19 @@ impl DBJobBoard for PgDB {
         Ok(applied.get(0))
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_job_apply_url(&self, job_id: &Uuid) -> Result<Option<String>> {
+        trace!("db: get job apply url");
+
+        let db = self.pool.get().await?;
+        let apply_url = db
+            .query_one("select get_job_apply_url($1::uuid);", &[&job_id])
+            .await?
+            .get(0);
+
+        Ok(apply_url)
+    }
+
     #[instrument(skip(self), err)]
     async fn get_job_jobboard(&self, job_id: &Uuid) -> Result<Option<Job>> {
         trace!("db: get job for jobboard");
@@ -90,6 +119,33 @@ impl DBJobBoard for PgDB {
         inner(db).await
     }
 
+    #[instrument(skip(self))]
+    async fn get_similar_jobs(&self, job_id: &Uuid) -> Result<Vec<JobSummary>> {
+        #[cached(
+            time = 3600,
+            key = "Uuid",
+            convert = r#"{ job_id }"#,
+            sync_writes = "by_key",
+            result = true
+        )]
+        async fn inner(db: Object, job_id: Uuid) -> Result<Vec<JobSummary>> {
+            trace!("db: get similar jobs");
+
+            let row = db
+                .query_one(
+                    "select get_similar_jobs($1::uuid, $2::int)::text;",
+                    &[&job_id, &SIMILAR_JOBS_LIMIT],
+                )
+                .await?;
+            let jobs = serde_json::from_str(&row.get::<_, String>(0))?;
+
+            Ok(jobs)
+        }
+
+        let db = self.pool.get().await?;
+        inner(db, *job_id).await
+    }
+
     #[instrument(skip(self))]
     async fn get_stats(&self) -> Result<Stats> {
         trace!("db: get stats");
@@ -106,13 +162,21 @@ impl DBJobBoard for PgDB {
     }
 
     #[instrument(skip(self))]
-    async fn search_jobs(&self, filters: &Filters) -> Result<JobsSearchOutput> {
+    async fn search_jobs(
+        &self,
+        filters: &Filters,
+        user_id: Option<Uuid>,
+        include_facets: bool,
+    ) -> Result<JobsSearchOutput> {
         trace!("db: search jobs");
 
         // Query database
         let db = self.pool.get().await?;
         let row = db
-            .query_one("select search_jobs($1::jsonb)::text", &[&Json(filters)])
+            .query_one(
+                "select search_jobs($1::jsonb, $2::uuid, $3::boolean)::text",
+                &[&Json(filters), &user_id, &include_facets],
+            )
             .await?;
         let output = serde_json::from_str(&row.get::<_, String>(0))?;
 
@@ -127,4 +191,7 @@ pub(crate) struct JobsSearchOutput {
     pub jobs: Vec<JobSummary>,
     /// Total number of jobs matching the search criteria.
     pub total: usize,
+
+    /// Number of jobs available for each filter option, if requested.
+    pub facets: Option<Facets>,
 }
@@ -12,6 +12,8 @@ mock! {
 
     #[async_trait]
     impl crate::db::DB for DB {
+        async fn check_connection(&self) -> Result<()>;
+        fn pool_status(&self) -> deadpool_postgres::Status;
         async fn tx_begin(&self) -> Result<Uuid>;
         async fn tx_commit(&self, client_id: Uuid) -> Result<()>;
         async fn tx_rollback(&self, client_id: Uuid) -> Result<()>;
@@ -27,6 +29,7 @@ mock! {
             &self,
             session_id: &axum_login::tower_sessions::session::Id,
         ) -> Result<()>;
+        async fn delete_expired_sessions(&self) -> Result<u64>;
         async fn get_session(
             &self,
             session_id: &axum_login::tower_sessions::session::Id,
@@ -81,10 +84,43 @@ mock! {
         ) -> Result<bool>;
         async fn user_owns_job(&self, user_id: &Uuid, job_id: &Uuid) -> Result<bool>;
         async fn verify_email(&self, code: &Uuid) -> Result<()>;
+        async fn verify_user_email(&self, email: &str) -> Result<Uuid>;
     }
 
     impl crate::db::dashboard::DBDashBoard for DB {}
 
+    #[async_trait]
+    impl crate::db::dashboard::admin::DBDashBoardAdmin for DB {
+        async fn add_certification(
+            &self,
+            certification: &crate::templates::dashboard::admin::certifications::CertificationInput,
+        ) -> Result<Uuid>;
+        async fn add_foundation(
+            &self,
+            foundation: &crate::templates::dashboard::admin::foundations::NewFoundation,
+        ) -> Result<Uuid>;
+        async fn delete_certification(&self, certification_id: &Uuid) -> Result<()>;
+        async fn grant_admin(&self, email: &str) -> Result<Uuid>;
+        async fn grant_moderator(&self, email: &str) -> Result<Uuid>;
+        async fn list_foundations_for_admin(
+            &self,
+        ) -> Result<Vec<crate::templates::dashboard::admin::foundations::Foundation>>;
+        async fn list_moderators(
+            &self,
+        ) -> Result<Vec<crate::templates::dashboard::admin::moderators::Moderator>>;
+        async fn revoke_moderator(&self, user_id: &Uuid) -> Result<()>;
+        async fn update_certification(
+            &self,
+            certification_id: &Uuid,
+            certification: &crate::templates::dashboard::admin::certifications::CertificationInput,
+        ) -> Result<()>;
+        async fn update_foundation(
+            &self,
+            foundation_id: &Uuid,
+            foundation: &crate::templates::dashboard::admin::foundations::FoundationUpdate,
+        ) -> Result<()>;
+    }
+
     #[async_trait]
     impl crate::db::dashboard::employer::DBDashBoardEmployer for DB {
         async fn accept_team_member_invitation(
@@ -122,6 +158,11 @@ mock! {
             &self,
             employer_id: &Uuid,
         ) -> Result<crate::templates::dashboard::employer::employers::Employer>;
+        async fn get_employer_analytics(
+            &self,
+            employer_id: &Uuid,
+            range: &crate::templates::dashboard::employer::analytics::Range,
+        ) -> Result<crate::templates::dashboard::employer::analytics::EmployerAnalytics>;
         async fn get_job_dashboard(
             &self,
             job_id: &Uuid,
@@ -190,6 +231,10 @@ mock! {
             &self,
             user_id: &Uuid,
         ) -> Result<Vec<crate::templates::dashboard::job_seeker::applications::Application>>;
+        async fn list_recommended_jobs(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Vec<crate::templates::jobboard::jobs::JobSummary>>;
         async fn update_job_seeker_profile(
             &self,
             user_id: &Uuid,
@@ -204,20 +249,67 @@ mock! {
             job_id: &Uuid,
             reviewer: &Uuid,
         ) -> Result<Option<DateTime<Utc>>>;
+        async fn get_moderation_stats(
+            &self,
+            review_sla_hours: u32,
+        ) -> Result<crate::templates::dashboard::moderator::stats::Stats>;
         async fn list_jobs_for_moderation(
             &self,
             status: crate::templates::dashboard::employer::jobs::JobStatus,
         ) -> Result<Vec<crate::templates::dashboard::moderator::jobs::JobSummary>>;
+        async fn list_moderation_actions(
+            &self,
+        ) -> Result<Vec<crate::templates::dashboard::moderator::accounts::ModerationAction>>;
         async fn reject_job(
             &self,
             job_id: &Uuid,
             reviewer: &Uuid,
             review_notes: Option<String>,
         ) -> Result<()>;
+        async fn search_employers_for_moderation(
+            &self,
+            filters: &crate::templates::dashboard::moderator::accounts::EmployersFilters,
+        ) -> Result<crate::db::dashboard::moderator::EmployersSearchOutput>;
+        async fn search_users_for_moderation(
+            &self,
+            filters: &crate::templates::dashboard::moderator::accounts::UsersFilters,
+        ) -> Result<crate::db::dashboard::moderator::UsersSearchOutput>;
+        async fn suspend_employer(
+            &self,
+            employer_id: &Uuid,
+            moderator_id: &Uuid,
+            reason: &str,
+            expires_at: Option<DateTime<Utc>>,
+        ) -> Result<()>;
+        async fn suspend_user(
+            &self,
+            user_id: &Uuid,
+            moderator_id: &Uuid,
+            reason: &str,
+            expires_at: Option<DateTime<Utc>>,
+        ) -> Result<()>;
+        async fn unsuspend_employer(
+            &self,
+            employer_id: &Uuid,
+            moderator_id: &Uuid,
+        ) -> Result<()>;
+        async fn unsuspend_user(
+            &self,
+            user_id: &Uuid,
+            moderator_id: &Uuid,
+        ) -> Result<()>;
     }
 
     #[async_trait]
     impl crate::db::event_tracker::DBEventTracker for DB {
+        async fn update_apply_clicks(
+            &self,
+            data: Vec<(
+                crate::event_tracker::JobId,
+                crate::event_tracker::Day,
+                crate::event_tracker::Total,
+            )>,
+        ) -> Result<()>;
         async fn update_jobs_views(
             &self,
             data: Vec<(
@@ -226,6 +318,15 @@ mock! {
                 crate::event_tracker::Total,
             )>,
         ) -> Result<()>;
+        async fn update_jobs_views_sources(
+            &self,
+            data: Vec<(
+                crate::event_tracker::JobId,
+                crate::event_tracker::Day,
+                crate::event_tracker::Source,
+                crate::event_tracker::Total,
+            )>,
+        ) -> Result<()>;
         async fn update_search_appearances(
             &self,
             data: Vec<(
@@ -238,14 +339,33 @@ mock! {
 
     #[async_trait]
     impl crate::db::img::DBImage for DB {
+        async fn add_image(&self, image_id: &Uuid, user_id: &Uuid, hash: &str) -> Result<Uuid>;
+        async fn delete_image_versions(&self, image_id: &Uuid) -> Result<()>;
+        async fn delete_unreferenced_image(
+            &self,
+            image_id: &Uuid,
+            created_before: chrono::DateTime<chrono::Utc>,
+        ) -> Result<Option<u64>>;
+        async fn delete_unreferenced_images(
+            &self,
+            created_before: chrono::DateTime<chrono::Utc>,
+        ) -> Result<Vec<crate::img::DeletedImage>>;
         async fn get_image_version(
             &self,
             image_id: Uuid,
             version: &str,
         ) -> Result<Option<(Vec<u8>, crate::img::ImageFormat)>>;
+        async fn get_image_versions(&self, image_id: &Uuid) -> Result<Vec<crate::img::ImageVersion>>;
+        async fn list_images_with_versions(&self) -> Result<Vec<Uuid>>;
+        async fn list_unreferenced_images(
+            &self,
+            created_before: chrono::DateTime<chrono::Utc>,
+        ) -> Result<Vec<Uuid>>;
+        async fn register_image_upload(&self, user_id: &Uuid, hash: &str) -> Result<Option<Uuid>>;
         async fn save_image_versions(
             &self,
             user_id: &Uuid,
+            hash: &str,
             versions: Vec<crate::img::ImageVersion>,
         ) -> Result<Uuid>;
     }
@@ -253,6 +373,7 @@ mock! {
     #[async_trait]
     impl crate::db::jobboard::DBJobBoard for DB {
         async fn apply_to_job(&self, job_id: &Uuid, user_id: &Uuid) -> Result<bool>;
+        async fn get_job_apply_url(&self, job_id: &Uuid) -> Result<Option<String>>;
         async fn get_job_jobboard(
             &self,
             job_id: &Uuid,
@@ -260,10 +381,16 @@ mock! {
         async fn get_jobs_filters_options(
             &self,
         ) -> Result<crate::templates::jobboard::jobs::FiltersOptions>;
+        async fn get_similar_jobs(
+            &self,
+            job_id: &Uuid,
+        ) -> Result<Vec<crate::templates::jobboard::jobs::JobSummary>>;
         async fn get_stats(&self) -> Result<crate::templates::jobboard::stats::Stats>;
         async fn search_jobs(
             &self,
             filters: &crate::templates::jobboard::jobs::Filters,
+            user_id: Option<Uuid>,
+            include_facets: bool,
         ) -> Result<crate::db::jobboard::JobsSearchOutput>;
     }
 
@@ -295,6 +422,13 @@ mock! {
             &self,
             client_id: Uuid,
         ) -> Result<Option<crate::notifications::Notification>>;
+        async fn get_pending_notifications_stats(
+            &self,
+        ) -> Result<crate::notifications::PendingNotificationsStats>;
+        async fn retry_failed_notifications(
+            &self,
+            created_after: Option<chrono::DateTime<chrono::Utc>>,
+        ) -> Result<u64>;
         async fn update_notification(
             &self,
             client_id: Uuid,
@@ -306,5 +440,9 @@ mock! {
     #[async_trait]
     impl crate::db::workers::DBWorkers for DB {
         async fn archive_expired_jobs(&self) -> Result<()>;
+        async fn rollup_daily_stats(
+            &self,
+            horizon_days: u32,
+        ) -> Result<crate::db::workers::RollupSummary>;
     }
 }
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use auth::DBAuth;
 use chrono::{DateTime, TimeDelta, Utc};
 use dashboard::DBDashBoard;
-use deadpool_postgres::{Client, Pool};
+use deadpool_postgres::{Client, Pool, Status};
 use event_tracker::DBEventTracker;
 use img::DBImage;
 use jobboard::DBJobBoard;
@@ -45,6 +45,12 @@ const TXS_CLIENT_TIMEOUT: TimeDelta = TimeDelta::seconds(30);
 pub(crate) trait DB:
     DBJobBoard + DBDashBoard + DBAuth + DBImage + DBNotifications + DBWorkers + DBEventTracker + DBMisc
 {
+    /// Checks that a connection can be obtained from the pool and used.
+    async fn check_connection(&self) -> Result<()>;
+
+    /// Returns the current status of the connections pool.
+    fn pool_status(&self) -> Status;
+
     /// Begins a new transaction and returns a unique client identifier.
     async fn tx_begin(&self) -> Result<Uuid>;
 
@@ -108,6 +114,18 @@ impl PgDB {
 
 #[async_trait]
 impl DB for PgDB {
+    #[instrument(skip(self), err)]
+    async fn check_connection(&self) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.batch_execute("select 1;").await?;
+
+        Ok(())
+    }
+
+    fn pool_status(&self) -> Status {
+        self.pool.status()
+    }
+
     #[instrument(skip(self), err)]
     async fn tx_begin(&self) -> Result<Uuid> {
         // Get client from pool and begin transaction
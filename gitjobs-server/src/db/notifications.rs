@@ -5,13 +5,14 @@ use std::sync::Arc;
 
 use anyhow::{Result, bail};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use tracing::{instrument, trace};
 use uuid::Uuid;
 
 use crate::{
     PgDB,
     db::TX_CLIENT_NOT_FOUND,
-    notifications::{NewNotification, Notification},
+    notifications::{NewNotification, Notification, PendingNotificationsStats},
 };
 
 /// Trait that defines database operations used to manage notifications.
@@ -23,6 +24,14 @@ pub(crate) trait DBNotifications {
     /// Retrieves a pending notification for delivery.
     async fn get_pending_notification(&self, client_id: Uuid) -> Result<Option<Notification>>;
 
+    /// Returns some stats about the notifications waiting to be delivered.
+    async fn get_pending_notifications_stats(&self) -> Result<PendingNotificationsStats>;
+
+    /// Marks failed notifications as pending again so they are delivered once more,
+    /// returning how many were updated. When provided, only notifications created
+    /// after the given timestamp are considered.
+    async fn retry_failed_notifications(&self, created_after: Option<DateTime<Utc>>) -> Result<u64>;
+
     /// Updates a notification after a delivery attempt.
     async fn update_notification(
         &self,
@@ -88,6 +97,38 @@ impl DBNotifications for PgDB {
         Ok(notification)
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_pending_notifications_stats(&self) -> Result<PendingNotificationsStats> {
+        trace!("db: get pending notifications stats");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one("select * from get_pending_notifications_stats();", &[])
+            .await?;
+        let stats = PendingNotificationsStats {
+            pending: u64::try_from(row.get::<_, i64>("pending"))?,
+            oldest_created_at: row.get("oldest_created_at"),
+        };
+
+        Ok(stats)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn retry_failed_notifications(&self, created_after: Option<DateTime<Utc>>) -> Result<u64> {
+        trace!("db: retry failed notifications");
+
+        let db = self.pool.get().await?;
+        let updated: i64 = db
+            .query_one(
+                "select retry_failed_notifications($1::timestamptz);",
+                &[&created_after],
+            )
+            .await?
+            .get(0);
+
+        Ok(u64::try_from(updated)?)
+    }
+
     /// Updates the notification record after processing, marking it as processed and
     /// recording any error.
     #[instrument(skip(self, notification), err)]
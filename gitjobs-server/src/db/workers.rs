@@ -1,8 +1,9 @@
 //! This module defines database operations used by background task workers, such as
-//! archiving expired jobs.
+//! archiving expired jobs or rolling up daily stats.
 
 use anyhow::Result;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
 use crate::db::PgDB;
@@ -12,6 +13,10 @@ use crate::db::PgDB;
 pub(crate) trait DBWorkers {
     /// Archives jobs that have expired based on their published date.
     async fn archive_expired_jobs(&self) -> Result<()>;
+
+    /// Rolls up the daily stats older than the horizon provided into monthly
+    /// aggregates.
+    async fn rollup_daily_stats(&self, horizon_days: u32) -> Result<RollupSummary>;
 }
 
 #[async_trait]
@@ -23,4 +28,27 @@ impl DBWorkers for PgDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self), err)]
+    async fn rollup_daily_stats(&self, horizon_days: u32) -> Result<RollupSummary> {
+        let db = self.pool.get().await?;
+        let json_data: String = db
+            .query_one(
+                "select rollup_daily_stats($1::int)::text",
+                &[&i32::try_from(horizon_days)?],
+            )
+            .await?
+            .get(0);
+
+        Ok(serde_json::from_str(&json_data)?)
+    }
+}
+
+/// Summary of a daily stats rollup.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RollupSummary {
+    /// Number of daily job views rows compacted.
+    pub job_views: u64,
+    /// Number of daily search appearances rows compacted.
+    pub search_appearances: u64,
 }
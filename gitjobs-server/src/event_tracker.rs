@@ -3,13 +3,27 @@
 //! It provides an asynchronous, batched mechanism for tracking and persisting event
 //! counts to the database. Events are aggregated in memory and flushed periodically or
 //! on shutdown, minimizing database writes and improving performance.
+//!
+//! Events from known bots are discarded, visitors are rate limited, and each event
+//! is counted only once per visitor, job and day. Visitors are identified by a hash
+//! of their IP address and user agent salted with a value rotated daily, so no raw
+//! personal information is kept.
 
-use std::{collections::HashMap, sync::Arc, sync::LazyLock, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    sync::Arc,
+    sync::LazyLock,
+    time::Duration,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 #[cfg(test)]
 use mockall::automock;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use time::{
     OffsetDateTime,
     format_description::{self, FormatItem},
@@ -22,7 +36,7 @@ use tokio_util::{sync::CancellationToken, task::TaskTracker};
 use tracing::error;
 use uuid::Uuid;
 
-use crate::db::event_tracker::DynDBEventTracker;
+use crate::{db::event_tracker::DynDBEventTracker, metrics};
 
 /// Format used to represent the date in the tracker.
 /// The format is `[year]-[month]-[day]`, e.g., "2024-06-01".
@@ -32,10 +46,44 @@ static DATE_FORMAT: LazyLock<Vec<FormatItem<'static>>> =
 /// How often events will be written to the database.
 /// In production, this is 5 minutes; in tests, 100ms.
 #[cfg(not(test))]
-const FLUSH_FREQUENCY: Duration = Duration::from_secs(300);
+const FLUSH_FREQUENCY: Duration = Duration::from_mins(5);
 #[cfg(test)]
 const FLUSH_FREQUENCY: Duration = Duration::from_millis(100);
 
+/// Window used to rate limit the events tracked per visitor.
+const RATE_LIMIT_WINDOW: Duration = Duration::from_mins(1);
+
+/// Maximum number of events tracked per visitor in each rate limit window.
+const RATE_LIMIT_MAX_EVENTS: u32 = 60;
+
+/// Maximum number of entries kept to deduplicate events. Once reached, events are
+/// counted without deduplication until the next day.
+const MAX_SEEN_EVENTS: usize = 1_000_000;
+
+/// Substrings identifying the user agents of known bots, crawlers, link
+/// unfurlers and scripts.
+const BOT_USER_AGENT_MARKERS: [&str; 19] = [
+    "bot",
+    "crawl",
+    "curl",
+    "embedly",
+    "facebookexternalhit",
+    "go-http-client",
+    "headless",
+    "httpclient",
+    "java/",
+    "lighthouse",
+    "python",
+    "scrapy",
+    "skypeuripreview",
+    "slackbot-linkexpanding",
+    "slurp",
+    "spider",
+    "wget",
+    "whatsapp",
+    "okhttp",
+];
+
 /// Type alias for a thread-safe reference-counted `EventTracker` trait object.
 pub(crate) type DynEventTracker = Arc<dyn EventTracker + Send + Sync>;
 
@@ -48,11 +96,42 @@ pub(crate) type Day = String;
 /// Type alias representing the total number of events for a job on a given day.
 pub(crate) type Total = u32;
 
+/// Type alias representing the normalized traffic source of a job view.
+pub(crate) type Source = String;
+
+/// Type alias representing the anonymous identifier of a visitor for a day.
+type VisitorId = [u8; 16];
+
+/// Maximum length of the free-form part of a traffic source.
+const MAX_SOURCE_DETAIL_LEN: usize = 50;
+
+/// Second level labels used to register domains under country code top level
+/// domains (e.g. `co` in `bbc.co.uk`).
+const COUNTRY_CODE_SECOND_LEVEL_LABELS: [&str; 10] =
+    ["ac", "co", "com", "edu", "gob", "gov", "ne", "net", "or", "org"];
+
+/// Names of the search engines whose referrals are grouped in the search source.
+const SEARCH_ENGINES: [&str; 9] = [
+    "baidu",
+    "bing",
+    "brave",
+    "duckduckgo",
+    "ecosia",
+    "google",
+    "qwant",
+    "yahoo",
+    "yandex",
+];
+
 /// Container for batches of aggregated events, separated by event type.
 #[derive(Debug, Clone)]
 struct Batches {
+    /// Aggregated apply click events.
+    apply_clicks: HashMap<(JobId, Day), Total>,
     /// Aggregated job view events.
     job_views: HashMap<(JobId, Day), Total>,
+    /// Aggregated job view events, broken down by traffic source.
+    job_views_sources: HashMap<(JobId, Day, Source), Total>,
     /// Aggregated search appearance events.
     search_appearances: HashMap<(JobId, Day), Total>,
 }
@@ -61,19 +140,26 @@ impl Batches {
     /// Creates a new empty Batches container.
     fn new() -> Self {
         Self {
+            apply_clicks: HashMap::new(),
             job_views: HashMap::new(),
+            job_views_sources: HashMap::new(),
             search_appearances: HashMap::new(),
         }
     }
 
-    /// Returns true if both containers are empty.
+    /// Returns true if all containers are empty.
     fn is_empty(&self) -> bool {
-        self.job_views.is_empty() && self.search_appearances.is_empty()
+        self.apply_clicks.is_empty()
+            && self.job_views.is_empty()
+            && self.job_views_sources.is_empty()
+            && self.search_appearances.is_empty()
     }
 
-    /// Clears both containers.
+    /// Clears all containers.
     fn clear(&mut self) {
+        self.apply_clicks.clear();
         self.job_views.clear();
+        self.job_views_sources.clear();
         self.search_appearances.clear();
     }
 }
@@ -81,12 +167,215 @@ impl Batches {
 /// Represents different types of events that can be tracked.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum Event {
-    /// A single job view event.
-    JobView { job_id: JobId },
+    /// A click on a job's apply button, either internal or external.
+    ApplyClick { job_id: JobId },
+    /// A single job view event, along with its traffic source.
+    JobView { job_id: JobId, source: Source },
     /// Multiple jobs appearing in search results.
     SearchAppearances { job_ids: Vec<JobId> },
 }
 
+/// Kind of event, used to deduplicate events per visitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum EventKind {
+    ApplyClick,
+    JobView,
+    SearchAppearance,
+}
+
+/// Information about the visitor that triggered an event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Visitor {
+    /// IP address of the visitor.
+    pub ip: Option<IpAddr>,
+    /// User agent of the visitor's client.
+    pub user_agent: Option<String>,
+}
+
+impl Visitor {
+    /// Returns true if the visitor looks like a bot, crawler or script (requests
+    /// without user agent are considered to be from bots as well).
+    pub(crate) fn is_bot(&self) -> bool {
+        let Some(user_agent) = self.user_agent.as_deref().map(str::trim) else {
+            return true;
+        };
+        if user_agent.is_empty() {
+            return true;
+        }
+        let user_agent = user_agent.to_lowercase();
+        BOT_USER_AGENT_MARKERS
+            .iter()
+            .any(|marker| user_agent.contains(marker))
+    }
+}
+
+/// Filter applied to the events before aggregating them. It rate limits the events
+/// of each visitor and makes sure they are counted only once per visitor, job and
+/// day.
+struct VisitorsFilter {
+    /// Day the current salt and seen events belong to.
+    day: Day,
+    /// Number of events received from each visitor in the current rate limit window.
+    events_per_visitor: HashMap<VisitorId, u32>,
+    /// Salt used to hash the visitors' information, rotated daily.
+    salt: [u8; 32],
+    /// Events already counted today.
+    seen: HashSet<(EventKind, JobId, VisitorId)>,
+}
+
+impl VisitorsFilter {
+    /// Creates a new filter for the given day.
+    fn new(day: Day) -> Self {
+        Self {
+            day,
+            events_per_visitor: HashMap::new(),
+            salt: rand::random(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Rotates the salt and forgets the events seen when the day changes.
+    fn rotate(&mut self, day: &Day) {
+        if self.day != *day {
+            *self = Self::new(day.clone());
+        }
+    }
+
+    /// Resets the rate limit counters, starting a new window.
+    fn reset_rate_limits(&mut self) {
+        self.events_per_visitor.clear();
+    }
+
+    /// Returns the anonymous identifier of the visitor for the current day.
+    fn visitor_id(&self, visitor: &Visitor) -> VisitorId {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt);
+        if let Some(ip) = visitor.ip {
+            hasher.update(ip.to_string());
+        }
+        hasher.update([0]);
+        if let Some(user_agent) = &visitor.user_agent {
+            hasher.update(user_agent);
+        }
+        let mut visitor_id = VisitorId::default();
+        visitor_id.copy_from_slice(&hasher.finalize()[..16]);
+        visitor_id
+    }
+
+    /// Returns true if the visitor has not exceeded the rate limit yet.
+    fn check_rate_limit(&mut self, visitor_id: VisitorId) -> bool {
+        let events = self.events_per_visitor.entry(visitor_id).or_default();
+        *events += 1;
+        *events <= RATE_LIMIT_MAX_EVENTS
+    }
+
+    /// Returns true if the event has not been counted for the visitor today.
+    fn first_seen(&mut self, kind: EventKind, job_id: JobId, visitor_id: VisitorId) -> bool {
+        if self.seen.len() >= MAX_SEEN_EVENTS {
+            return true;
+        }
+        self.seen.insert((kind, job_id, visitor_id))
+    }
+}
+
+/// Referral information provided by the client when a job view is tracked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ViewReferral {
+    /// URL of the page that referred the visitor to the job board.
+    pub referrer: Option<String>,
+    /// UTM medium of the landing URL (`embed` for jobs opened from the embed).
+    pub utm_medium: Option<String>,
+    /// UTM source of the landing URL (the embedding host for embed referrals).
+    pub utm_source: Option<String>,
+}
+
+impl ViewReferral {
+    /// Returns the normalized traffic source of the view.
+    ///
+    /// Sources are bucketed to keep their cardinality low: `direct`, `search`,
+    /// `embed` or `embed:<host>`, `utm:<source>` and `ref:<domain>`. Referrals
+    /// from the job board itself are considered direct traffic. The number of
+    /// detailed sources tracked per job and day is capped when they are stored,
+    /// and views from any additional ones are counted as `other`.
+    pub(crate) fn source(&self, base_url: &str) -> Source {
+        // Jobs opened from the embed, tagged with the embedding host
+        if self.utm_medium.as_deref().map(str::trim) == Some("embed") {
+            return match self.utm_source.as_deref().and_then(normalize_host) {
+                Some(host) => format!("embed:{host}"),
+                None => "embed".to_string(),
+            };
+        }
+
+        // Campaigns tagged explicitly with a UTM source
+        if let Some(utm_source) = self.utm_source.as_deref().and_then(normalize_source_detail) {
+            return format!("utm:{utm_source}");
+        }
+
+        // Referrer domain, if any
+        let referrer_domain = self
+            .referrer
+            .as_deref()
+            .and_then(|referrer| Url::parse(referrer).ok())
+            .and_then(|url| url.host_str().and_then(normalize_host));
+        let Some(referrer_domain) = referrer_domain else {
+            return "direct".to_string();
+        };
+        let board_domain = Url::parse(base_url)
+            .ok()
+            .and_then(|url| url.host_str().and_then(normalize_host));
+        if board_domain.as_ref() == Some(&referrer_domain) {
+            return "direct".to_string();
+        }
+        let name = referrer_domain.split('.').next().unwrap_or_default();
+        if SEARCH_ENGINES.contains(&name) {
+            return "search".to_string();
+        }
+        format!("ref:{referrer_domain}")
+    }
+}
+
+/// Normalizes a host into its registrable domain (e.g. `app.slack.com` becomes
+/// `slack.com`), returning `None` when it is not a valid domain name.
+fn normalize_host(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('.').to_lowercase();
+    let valid_chars = host
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    if !valid_chars || host.len() > MAX_SOURCE_DETAIL_LEN {
+        return None;
+    }
+
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() < 2 || labels.iter().any(|label| label.is_empty()) {
+        return None;
+    }
+
+    // Keep an extra label for country code second level domains (e.g. co.uk)
+    let tld = labels[labels.len() - 1];
+    let sld = labels[labels.len() - 2];
+    let keep = if labels.len() > 2 && tld.len() == 2 && COUNTRY_CODE_SECOND_LEVEL_LABELS.contains(&sld) {
+        3
+    } else {
+        2
+    };
+
+    Some(labels[labels.len() - keep..].join("."))
+}
+
+/// Normalizes a free-form source value (e.g. a UTM source), returning `None`
+/// when nothing is left after removing unsupported characters.
+fn normalize_source_detail(value: &str) -> Option<String> {
+    let detail: String = value
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_whitespace() { '-' } else { c })
+        .filter(|c| c.is_ascii_alphanumeric() || ['-', '_', '.'].contains(c))
+        .take(MAX_SOURCE_DETAIL_LEN)
+        .collect();
+    if detail.is_empty() { None } else { Some(detail) }
+}
+
 /// Trait defining the interface for tracking events.
 ///
 /// Implementations are responsible for asynchronously tracking events and ensuring they
@@ -94,8 +383,8 @@ pub(crate) enum Event {
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub(crate) trait EventTracker {
-    /// Track an event.
-    async fn track(&self, event: Event) -> Result<()>;
+    /// Track an event triggered by the visitor provided.
+    async fn track(&self, event: Event, visitor: Visitor) -> Result<()>;
 }
 
 /// Implementation of `EventTracker` backed by a `PostgreSQL` database.
@@ -104,7 +393,7 @@ pub(crate) trait EventTracker {
 /// database in batches.
 pub(crate) struct EventTrackerDB {
     /// Channel for sending events to the aggregator worker.
-    events_tx: mpsc::Sender<Event>,
+    events_tx: mpsc::Sender<(Event, Visitor)>,
 }
 
 impl EventTrackerDB {
@@ -130,9 +419,13 @@ impl EventTrackerDB {
 
 #[async_trait]
 impl EventTracker for EventTrackerDB {
-    /// Track an event by sending it to the aggregator.
-    async fn track(&self, event: Event) -> Result<()> {
-        self.events_tx.send(event).await.map_err(Into::into)
+    /// Track an event by sending it to the aggregator, unless it comes from a bot.
+    async fn track(&self, event: Event, visitor: Visitor) -> Result<()> {
+        if visitor.is_bot() {
+            metrics::record_event_tracker_discarded("bot");
+            return Ok(());
+        }
+        self.events_tx.send((event, visitor)).await.map_err(Into::into)
     }
 }
 
@@ -140,16 +433,21 @@ impl EventTracker for EventTrackerDB {
 /// periodically sends batches to the flusher.
 ///
 /// Batches are flushed either on a fixed interval or when the system is shutting down.
+/// Events are filtered by visitor before being aggregated.
 async fn aggregator(
-    mut events_rx: mpsc::Receiver<Event>,
+    mut events_rx: mpsc::Receiver<(Event, Visitor)>,
     batches_tx: mpsc::Sender<Batches>,
     cancellation_token: CancellationToken,
 ) {
     let first_flush = Instant::now() + FLUSH_FREQUENCY;
     let mut flush_interval = tokio::time::interval_at(first_flush, FLUSH_FREQUENCY);
     flush_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let first_reset = Instant::now() + RATE_LIMIT_WINDOW;
+    let mut rate_limit_interval = tokio::time::interval_at(first_reset, RATE_LIMIT_WINDOW);
+    rate_limit_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
     let mut batches = Batches::new();
+    let mut filter = VisitorsFilter::new(current_day());
     loop {
         tokio::select! {
             biased;
@@ -161,21 +459,49 @@ async fn aggregator(
                 }
             }
 
+            // Start a new rate limit window every RATE_LIMIT_WINDOW.
+            _ = rate_limit_interval.tick() => {
+                filter.reset_rate_limits();
+            }
+
             // Pick next event from queue and aggregate it.
-            Some(event) = events_rx.recv() => {
-                let day = OffsetDateTime::now_utc()
-                    .format(&DATE_FORMAT)
-                    .expect("format to succeed");
+            Some((event, visitor)) = events_rx.recv() => {
+                let day = current_day();
+                filter.rotate(&day);
+
+                // Discard events from visitors exceeding the rate limit
+                let visitor_id = filter.visitor_id(&visitor);
+                if !filter.check_rate_limit(visitor_id) {
+                    metrics::record_event_tracker_discarded("rate_limited");
+                    continue;
+                }
 
+                // Aggregate events not counted yet for this visitor today
                 match event {
-                    Event::JobView { job_id } => {
-                        *batches.job_views.entry((job_id, day)).or_default() += 1;
+                    Event::ApplyClick { job_id } => {
+                        if filter.first_seen(EventKind::ApplyClick, job_id, visitor_id) {
+                            *batches.apply_clicks.entry((job_id, day)).or_default() += 1;
+                        } else {
+                            metrics::record_event_tracker_discarded("duplicate");
+                        }
+                    }
+                    Event::JobView { job_id, source } => {
+                        if filter.first_seen(EventKind::JobView, job_id, visitor_id) {
+                            *batches.job_views.entry((job_id, day.clone())).or_default() += 1;
+                            *batches.job_views_sources.entry((job_id, day, source)).or_default() += 1;
+                        } else {
+                            metrics::record_event_tracker_discarded("duplicate");
+                        }
                     }
                     Event::SearchAppearances { job_ids } => {
                         for job_id in job_ids {
-                            *batches.search_appearances
-                                .entry((job_id, day.clone()))
-                                .or_default() += 1;
+                            if filter.first_seen(EventKind::SearchAppearance, job_id, visitor_id) {
+                                *batches.search_appearances
+                                    .entry((job_id, day.clone()))
+                                    .or_default() += 1;
+                            } else {
+                                metrics::record_event_tracker_discarded("duplicate");
+                            }
                         }
                     }
                 }
@@ -192,6 +518,13 @@ async fn aggregator(
     }
 }
 
+/// Returns the current day in the format used by the tracker.
+fn current_day() -> Day {
+    OffsetDateTime::now_utc()
+        .format(&DATE_FORMAT)
+        .expect("format to succeed")
+}
+
 /// Flusher worker that receives batches of aggregated events and writes them to the
 /// database.
 async fn flusher(db: DynDBEventTracker, mut batches_rx: mpsc::Receiver<Batches>) {
@@ -199,18 +532,38 @@ async fn flusher(db: DynDBEventTracker, mut batches_rx: mpsc::Receiver<Batches>)
         // Process job views.
         if !batches.job_views.is_empty() {
             let job_views = prepare_batch_data(&batches.job_views);
+            metrics::record_event_tracker_flush("job_views", job_views.len());
             if let Err(err) = db.update_jobs_views(job_views).await {
                 error!(?err, "error writing job views to database");
             }
         }
 
+        // Process job views sources.
+        if !batches.job_views_sources.is_empty() {
+            let job_views_sources = prepare_sources_batch_data(&batches.job_views_sources);
+            metrics::record_event_tracker_flush("job_views_sources", job_views_sources.len());
+            if let Err(err) = db.update_jobs_views_sources(job_views_sources).await {
+                error!(?err, "error writing job views sources to database");
+            }
+        }
+
         // Process search appearances.
         if !batches.search_appearances.is_empty() {
             let search_appearances = prepare_batch_data(&batches.search_appearances);
+            metrics::record_event_tracker_flush("search_appearances", search_appearances.len());
             if let Err(err) = db.update_search_appearances(search_appearances).await {
                 error!(?err, "error writing search appearances to database");
             }
         }
+
+        // Process apply clicks.
+        if !batches.apply_clicks.is_empty() {
+            let apply_clicks = prepare_batch_data(&batches.apply_clicks);
+            metrics::record_event_tracker_flush("apply_clicks", apply_clicks.len());
+            if let Err(err) = db.update_apply_clicks(apply_clicks).await {
+                error!(?err, "error writing apply clicks to database");
+            }
+        }
     }
 }
 
@@ -225,12 +578,26 @@ fn prepare_batch_data(data: &HashMap<(JobId, Day), Total>) -> Vec<(JobId, Day, T
     db_ready_data
 }
 
+/// Converts a `HashMap` of aggregated events by source into a sorted vector ready
+/// for database insertion.
+fn prepare_sources_batch_data(
+    data: &HashMap<(JobId, Day, Source), Total>,
+) -> Vec<(JobId, Day, Source, Total)> {
+    let mut db_ready_data: Vec<(JobId, Day, Source, Total)> = data
+        .iter()
+        .map(|((job_id, day, source), total)| (*job_id, day.clone(), source.clone(), *total))
+        .collect();
+    db_ready_data.sort();
+    db_ready_data
+}
+
 #[cfg(test)]
 mod tests {
     //! Tests for the event tracking module.
     //!
-    //! These tests verify that events are flushed both periodically and on shutdown, and
-    //! that no flush occurs if no events are tracked.
+    //! These tests verify that events are flushed both periodically and on shutdown,
+    //! that no flush occurs if no events are tracked, and that events from bots,
+    //! rate limited visitors or already counted are discarded.
 
     use mockall::predicate::eq;
     use tokio::time::{Duration, sleep};
@@ -245,6 +612,29 @@ mod tests {
     static JOB2_ID: LazyLock<Uuid> =
         LazyLock::new(|| Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap());
 
+    /// Board base URL used for testing.
+    const BASE_URL: &str = "https://gitjobs.dev";
+
+    /// User agent of a regular browser used for testing.
+    const BROWSER_USER_AGENT: &str =
+        "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0 Safari/537.36";
+
+    /// Builds a visitor using a regular browser from the given IP address.
+    fn visitor(ip: &str) -> Visitor {
+        Visitor {
+            ip: Some(ip.parse().unwrap()),
+            user_agent: Some(BROWSER_USER_AGENT.to_string()),
+        }
+    }
+
+    /// Builds a job view event for the given job and source.
+    fn job_view(job_id: Uuid, source: &str) -> Event {
+        Event::JobView {
+            job_id,
+            source: source.to_string(),
+        }
+    }
+
     /// Test that job view events are flushed when the tracker is stopped.
     #[tokio::test]
     async fn flush_job_views_on_stop() {
@@ -253,7 +643,16 @@ mod tests {
         let mut mock_db = MockDB::new();
         mock_db
             .expect_update_jobs_views()
-            .with(eq(vec![(*JOB1_ID, day.clone(), 2), (*JOB2_ID, day, 1)]))
+            .with(eq(vec![(*JOB1_ID, day.clone(), 2), (*JOB2_ID, day.clone(), 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_db
+            .expect_update_jobs_views_sources()
+            .with(eq(vec![
+                (*JOB1_ID, day.clone(), "direct".to_string(), 1),
+                (*JOB1_ID, day.clone(), "search".to_string(), 1),
+                (*JOB2_ID, day, "direct".to_string(), 1),
+            ]))
             .times(1)
             .returning(|_| Ok(()));
         let mock_db = Arc::new(mock_db);
@@ -262,9 +661,15 @@ mod tests {
         let tracker = TaskTracker::new();
         let cancellation_token = CancellationToken::new();
         let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
-        t.track(Event::JobView { job_id: *JOB1_ID }).await.unwrap();
-        t.track(Event::JobView { job_id: *JOB1_ID }).await.unwrap();
-        t.track(Event::JobView { job_id: *JOB2_ID }).await.unwrap();
+        t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        t.track(job_view(*JOB1_ID, "search"), visitor("10.0.0.2"))
+            .await
+            .unwrap();
+        t.track(job_view(*JOB2_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
 
         // Stop the tracker and wait for the workers to complete.
         tracker.close();
@@ -280,7 +685,16 @@ mod tests {
         let mut mock_db = MockDB::new();
         mock_db
             .expect_update_jobs_views()
-            .with(eq(vec![(*JOB1_ID, day.clone(), 2), (*JOB2_ID, day, 1)]))
+            .with(eq(vec![(*JOB1_ID, day.clone(), 2), (*JOB2_ID, day.clone(), 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_db
+            .expect_update_jobs_views_sources()
+            .with(eq(vec![
+                (*JOB1_ID, day.clone(), "direct".to_string(), 1),
+                (*JOB1_ID, day.clone(), "search".to_string(), 1),
+                (*JOB2_ID, day, "direct".to_string(), 1),
+            ]))
             .times(1)
             .returning(|_| Ok(()));
         let mock_db = Arc::new(mock_db);
@@ -289,9 +703,15 @@ mod tests {
         let tracker = TaskTracker::new();
         let cancellation_token = CancellationToken::new();
         let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
-        t.track(Event::JobView { job_id: *JOB1_ID }).await.unwrap();
-        t.track(Event::JobView { job_id: *JOB1_ID }).await.unwrap();
-        t.track(Event::JobView { job_id: *JOB2_ID }).await.unwrap();
+        t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        t.track(job_view(*JOB1_ID, "search"), visitor("10.0.0.2"))
+            .await
+            .unwrap();
+        t.track(job_view(*JOB2_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
 
         // Wait for the periodic flush to complete.
         sleep(Duration::from_millis(500)).await;
@@ -329,9 +749,12 @@ mod tests {
         let tracker = TaskTracker::new();
         let cancellation_token = CancellationToken::new();
         let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
-        t.track(Event::SearchAppearances {
-            job_ids: vec![*JOB1_ID, *JOB2_ID],
-        })
+        t.track(
+            Event::SearchAppearances {
+                job_ids: vec![*JOB1_ID, *JOB2_ID],
+            },
+            visitor("10.0.0.1"),
+        )
         .await
         .unwrap();
 
@@ -341,6 +764,39 @@ mod tests {
         tracker.wait().await;
     }
 
+    /// Test that apply clicks are flushed correctly.
+    #[tokio::test]
+    async fn flush_apply_clicks() {
+        // Setup mock database.
+        let day = OffsetDateTime::now_utc().format(&DATE_FORMAT).unwrap();
+        let mut mock_db = MockDB::new();
+        mock_db
+            .expect_update_apply_clicks()
+            .with(eq(vec![(*JOB1_ID, day.clone(), 2), (*JOB2_ID, day, 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        let mock_db = Arc::new(mock_db);
+
+        // Setup tracker and track some apply clicks.
+        let tracker = TaskTracker::new();
+        let cancellation_token = CancellationToken::new();
+        let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
+        t.track(Event::ApplyClick { job_id: *JOB1_ID }, visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        t.track(Event::ApplyClick { job_id: *JOB2_ID }, visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        t.track(Event::ApplyClick { job_id: *JOB1_ID }, visitor("10.0.0.2"))
+            .await
+            .unwrap();
+
+        // Stop the tracker and wait for the workers to complete.
+        tracker.close();
+        cancellation_token.cancel();
+        tracker.wait().await;
+    }
+
     /// Test that mixed events are flushed to their respective tables.
     #[tokio::test]
     async fn flush_mixed_events() {
@@ -352,6 +808,11 @@ mod tests {
             .with(eq(vec![(*JOB1_ID, day.clone(), 2)]))
             .times(1)
             .returning(|_| Ok(()));
+        mock_db
+            .expect_update_jobs_views_sources()
+            .with(eq(vec![(*JOB1_ID, day.clone(), "direct".to_string(), 2)]))
+            .times(1)
+            .returning(|_| Ok(()));
         mock_db
             .expect_update_search_appearances()
             .with(eq(vec![(*JOB1_ID, day.clone(), 1), (*JOB2_ID, day, 1)]))
@@ -363,17 +824,308 @@ mod tests {
         let tracker = TaskTracker::new();
         let cancellation_token = CancellationToken::new();
         let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
-        t.track(Event::JobView { job_id: *JOB1_ID }).await.unwrap();
-        t.track(Event::SearchAppearances {
-            job_ids: vec![*JOB1_ID, *JOB2_ID],
-        })
+        t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        t.track(
+            Event::SearchAppearances {
+                job_ids: vec![*JOB1_ID, *JOB2_ID],
+            },
+            visitor("10.0.0.1"),
+        )
         .await
         .unwrap();
-        t.track(Event::JobView { job_id: *JOB1_ID }).await.unwrap();
+        t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.2"))
+            .await
+            .unwrap();
 
         // Stop the tracker and wait for the workers to complete.
         tracker.close();
         cancellation_token.cancel();
         tracker.wait().await;
     }
+
+    /// Test that events from bots are discarded.
+    #[tokio::test]
+    async fn bot_events_are_discarded() {
+        // Setup mock database.
+        let day = OffsetDateTime::now_utc().format(&DATE_FORMAT).unwrap();
+        let mut mock_db = MockDB::new();
+        mock_db
+            .expect_update_jobs_views()
+            .with(eq(vec![(*JOB1_ID, day.clone(), 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_db
+            .expect_update_jobs_views_sources()
+            .with(eq(vec![(*JOB1_ID, day, "direct".to_string(), 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        let mock_db = Arc::new(mock_db);
+
+        // Setup tracker and track views from a browser and some bots.
+        let tracker = TaskTracker::new();
+        let cancellation_token = CancellationToken::new();
+        let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
+        t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        for user_agent in [
+            None,
+            Some("Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"),
+            Some("curl/8.5.0"),
+        ] {
+            let bot = Visitor {
+                ip: Some("10.0.0.2".parse().unwrap()),
+                user_agent: user_agent.map(ToString::to_string),
+            };
+            t.track(job_view(*JOB1_ID, "direct"), bot).await.unwrap();
+        }
+
+        // Stop the tracker and wait for the workers to complete.
+        tracker.close();
+        cancellation_token.cancel();
+        tracker.wait().await;
+    }
+
+    /// Test that link unfurlers are detected as bots, but not browsers whose user
+    /// agent mentions a preview build.
+    #[test]
+    fn link_unfurlers_are_bots_but_preview_browsers_are_not() {
+        let is_bot = |user_agent: &str| {
+            Visitor {
+                ip: None,
+                user_agent: Some(user_agent.to_string()),
+            }
+            .is_bot()
+        };
+
+        assert!(is_bot(
+            "Slackbot-LinkExpanding 1.0 (+https://api.slack.com/robots)"
+        ));
+        assert!(is_bot("facebookexternalhit/1.1"));
+        assert!(is_bot("Twitterbot/1.0"));
+        assert!(is_bot("WhatsApp/2.23.20.0 A"));
+        assert!(is_bot(
+            "Mozilla/5.0 (compatible; Discordbot/2.0; +https://discordapp.com)"
+        ));
+        assert!(!is_bot(
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) \
+             Version/18.0 Safari/605.1.15 Safari Technology Preview"
+        ));
+        assert!(!is_bot(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:132.0) Gecko/20100101 Firefox/132.0 Preview"
+        ));
+    }
+
+    /// Test that events are counted only once per visitor, job and day.
+    #[tokio::test]
+    async fn duplicated_events_are_discarded() {
+        // Setup mock database.
+        let day = OffsetDateTime::now_utc().format(&DATE_FORMAT).unwrap();
+        let mut mock_db = MockDB::new();
+        mock_db
+            .expect_update_jobs_views()
+            .with(eq(vec![(*JOB1_ID, day.clone(), 2), (*JOB2_ID, day.clone(), 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_db
+            .expect_update_jobs_views_sources()
+            .with(eq(vec![
+                (*JOB1_ID, day.clone(), "direct".to_string(), 2),
+                (*JOB2_ID, day.clone(), "direct".to_string(), 1),
+            ]))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_db
+            .expect_update_search_appearances()
+            .with(eq(vec![(*JOB1_ID, day.clone(), 1), (*JOB2_ID, day, 1)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        let mock_db = Arc::new(mock_db);
+
+        // Setup tracker and track the same events several times.
+        let tracker = TaskTracker::new();
+        let cancellation_token = CancellationToken::new();
+        let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
+        for _ in 0..3 {
+            t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.1"))
+                .await
+                .unwrap();
+            t.track(
+                Event::SearchAppearances {
+                    job_ids: vec![*JOB1_ID, *JOB2_ID],
+                },
+                visitor("10.0.0.1"),
+            )
+            .await
+            .unwrap();
+        }
+        t.track(job_view(*JOB2_ID, "direct"), visitor("10.0.0.1"))
+            .await
+            .unwrap();
+        let other_browser = Visitor {
+            user_agent: Some("Mozilla/5.0 (Macintosh) Firefox/128.0".to_string()),
+            ..visitor("10.0.0.1")
+        };
+        t.track(job_view(*JOB1_ID, "direct"), other_browser).await.unwrap();
+
+        // Stop the tracker and wait for the workers to complete.
+        tracker.close();
+        cancellation_token.cancel();
+        tracker.wait().await;
+    }
+
+    /// Test that events from visitors exceeding the rate limit are discarded.
+    #[tokio::test]
+    async fn rate_limited_events_are_discarded() {
+        // Setup mock database.
+        let day = OffsetDateTime::now_utc().format(&DATE_FORMAT).unwrap();
+        let mut mock_db = MockDB::new();
+        mock_db
+            .expect_update_jobs_views()
+            .withf(move |data| {
+                data.len() == RATE_LIMIT_MAX_EVENTS as usize + 1
+                    && data.iter().all(|(_, d, total)| *d == day && *total == 1)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_db
+            .expect_update_jobs_views_sources()
+            .times(1)
+            .returning(|_| Ok(()));
+        let mock_db = Arc::new(mock_db);
+
+        // Setup tracker and track more views than allowed from a single visitor.
+        let tracker = TaskTracker::new();
+        let cancellation_token = CancellationToken::new();
+        let t = EventTrackerDB::new(mock_db, &tracker, &cancellation_token);
+        for _ in 0..RATE_LIMIT_MAX_EVENTS + 10 {
+            t.track(job_view(Uuid::new_v4(), "direct"), visitor("10.0.0.1"))
+                .await
+                .unwrap();
+        }
+        t.track(job_view(*JOB1_ID, "direct"), visitor("10.0.0.2"))
+            .await
+            .unwrap();
+
+        // Stop the tracker and wait for the workers to complete.
+        tracker.close();
+        cancellation_token.cancel();
+        tracker.wait().await;
+    }
+
+    /// Test that visitors are identified by their IP address and user agent, and
+    /// that their identifiers change when the salt is rotated.
+    #[test]
+    fn visitor_id_rotates_daily() {
+        let mut filter = VisitorsFilter::new("2024-06-01".to_string());
+        let visitor_id = filter.visitor_id(&visitor("10.0.0.1"));
+        assert_eq!(visitor_id, filter.visitor_id(&visitor("10.0.0.1")));
+        assert_ne!(visitor_id, filter.visitor_id(&visitor("10.0.0.2")));
+
+        filter.rotate(&"2024-06-01".to_string());
+        assert_eq!(visitor_id, filter.visitor_id(&visitor("10.0.0.1")));
+
+        assert!(filter.first_seen(EventKind::JobView, *JOB1_ID, visitor_id));
+        filter.rotate(&"2024-06-02".to_string());
+        assert_ne!(visitor_id, filter.visitor_id(&visitor("10.0.0.1")));
+        assert!(filter.first_seen(EventKind::JobView, *JOB1_ID, visitor_id));
+    }
+
+    /// Test that views from the board itself or without referrer are direct.
+    #[test]
+    fn view_referral_source_direct() {
+        assert_eq!(ViewReferral::default().source(BASE_URL), "direct");
+
+        let referral = ViewReferral {
+            referrer: Some("https://gitjobs.dev/?ts_query=rust".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "direct");
+
+        let referral = ViewReferral {
+            referrer: Some("not a url".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "direct");
+    }
+
+    /// Test that views from the embed are tagged with the embedding host.
+    #[test]
+    fn view_referral_source_embed() {
+        let referral = ViewReferral {
+            referrer: Some("https://www.cncf.io/jobs/".to_string()),
+            utm_medium: Some("embed".to_string()),
+            utm_source: Some("www.cncf.io".to_string()),
+        };
+        assert_eq!(referral.source(BASE_URL), "embed:cncf.io");
+
+        let referral = ViewReferral {
+            utm_medium: Some("embed".to_string()),
+            utm_source: Some("<script>".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "embed");
+    }
+
+    /// Test that views from search engines are grouped together.
+    #[test]
+    fn view_referral_source_search() {
+        for referrer in [
+            "https://www.google.com/",
+            "https://www.google.co.uk/",
+            "https://search.brave.com/search?q=jobs",
+        ] {
+            let referral = ViewReferral {
+                referrer: Some(referrer.to_string()),
+                ..Default::default()
+            };
+            assert_eq!(referral.source(BASE_URL), "search");
+        }
+    }
+
+    /// Test that views from other sites use the referrer domain.
+    #[test]
+    fn view_referral_source_referrer_domain() {
+        let referral = ViewReferral {
+            referrer: Some("https://app.slack.com/client/T01/C02".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "ref:slack.com");
+
+        let referral = ViewReferral {
+            referrer: Some("https://news.bbc.co.uk/".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "ref:bbc.co.uk");
+    }
+
+    /// Test that short domains under country code top level domains are not
+    /// mistaken for country code second level domains.
+    #[test]
+    fn view_referral_source_referrer_domain_short_cc_domain() {
+        let referral = ViewReferral {
+            referrer: Some("https://www.bit.ly/abc".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "ref:bit.ly");
+
+        let referral = ViewReferral {
+            referrer: Some("https://jobs.t.co/".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(referral.source(BASE_URL), "ref:t.co");
+    }
+
+    /// Test that UTM sources take precedence over the referrer.
+    #[test]
+    fn view_referral_source_utm() {
+        let referral = ViewReferral {
+            referrer: Some("https://www.linkedin.com/".to_string()),
+            utm_medium: Some("social".to_string()),
+            utm_source: Some(" Weekly Newsletter! ".to_string()),
+        };
+        assert_eq!(referral.source(BASE_URL), "utm:weekly-newsletter");
+    }
 }
@@ -534,6 +534,13 @@ pub struct OAuth2AuthorizationResponse {
     state: oauth2::CsrfToken,
 }
 
+/// Path parameters of the routes of a specific image.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ImagePath {
+    /// Image identifier.
+    pub image_id: Uuid,
+}
+
 /// Next URL to redirect to after authentication.
 #[derive(Debug, Deserialize)]
 pub(crate) struct NextUrl {
@@ -565,7 +572,7 @@ pub(crate) async fn image_is_public(
 #[instrument(skip_all)]
 pub(crate) async fn user_has_image_access(
     State(db): State<DynDB>,
-    Path((image_id, _)): Path<(Uuid, String)>,
+    Path(ImagePath { image_id }): Path<ImagePath>,
     auth_session: AuthSession,
     request: Request,
     next: Next,
@@ -611,6 +618,26 @@ pub(crate) async fn user_has_profile_access(
     next.run(request).await.into_response()
 }
 
+/// Check if the user is an administrator.
+#[instrument(skip_all)]
+pub(crate) async fn user_is_admin(
+    auth_session: AuthSession,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    // Check if user is logged in
+    let Some(user) = auth_session.user else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    // Check if the user is an administrator
+    if !user.admin {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    next.run(request).await.into_response()
+}
+
 /// Check if the user is a moderator.
 #[instrument(skip_all)]
 pub(crate) async fn user_is_moderator(
@@ -881,11 +908,13 @@ mod tests {
         let password_hash = password_auth::generate_hash("secret");
         let user = crate::auth::User {
             auth_hash: b"hash".to_vec(),
+            admin: false,
             email: "user@example.test".to_string(),
             email_verified: true,
             has_profile: true,
             moderator: false,
             name: "Test User".to_string(),
+            suspended: false,
             user_id,
             username: "user".to_string(),
 
@@ -922,6 +951,39 @@ mod tests {
         assert_eq!(response.headers()["location"], "/");
     }
 
+    #[tokio::test]
+    async fn test_log_in_redirects_when_user_is_suspended() {
+        // Setup identifiers and data structures
+        let mut user = sample_auth_user(Uuid::new_v4(), "hash");
+        user.password = Some(password_auth::generate_hash("secret"));
+        user.suspended = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        allow_session_store_updates(&mut db);
+        db.expect_get_user_by_username()
+            .times(1)
+            .withf(|username| username == "test-user")
+            .returning(move |_| Ok(Some(user.clone())));
+        db.expect_list_employers().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/log-in")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("username=test-user&password=secret"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], "/log-in");
+    }
+
     #[tokio::test]
     async fn test_log_out_redirects_to_log_in() {
         // Setup identifiers and data structures
@@ -1029,11 +1091,13 @@ mod tests {
         let user_id = Uuid::new_v4();
         let new_user = crate::auth::User {
             auth_hash: b"hash".to_vec(),
+            admin: false,
             email: "user@example.test".to_string(),
             email_verified: false,
             has_profile: false,
             moderator: false,
             name: "Test User".to_string(),
+            suspended: false,
             user_id,
             username: "user".to_string(),
 
@@ -0,0 +1,136 @@
+//! This module defines the HTTP handlers for the admin dashboard certifications page.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::ValidatedForm},
+    templates::dashboard::admin::certifications::{self, CertificationInput},
+};
+
+// Pages handlers.
+
+/// Returns the page listing the certifications catalogue.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    let certifications = db.list_certifications().await?;
+    let template = certifications::CertificationsPage { certifications };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions.
+
+/// Adds a new certification to the catalogue and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    State(db): State<DynDB>,
+    ValidatedForm(certification): ValidatedForm<CertificationInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.add_certification(&certification).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+/// Deletes a certification from the catalogue and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    State(db): State<DynDB>,
+    Path(certification_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.delete_certification(&certification_id).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+/// Updates a certification in the catalogue and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn update(
+    State(db): State<DynDB>,
+    Path(certification_id): Path<Uuid>,
+    ValidatedForm(certification): ValidatedForm<CertificationInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_certification(&certification_id, &certification).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::body::to_bytes;
+
+    use crate::{db::mock::MockDB, templates::misc::Certification};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_page_renders_certifications() {
+        // Setup identifiers and data structures
+        let certification = Certification {
+            certification_id: Uuid::new_v4(),
+            name: "Certified Kubernetes Administrator".to_string(),
+            provider: "CNCF".to_string(),
+            short_name: "CKA".to_string(),
+
+            description: None,
+            logo_url: None,
+            url: Some("https://example.test/cka".to_string()),
+        };
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_list_certifications()
+            .times(1)
+            .returning(move || Ok(vec![certification.clone()]));
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let response = page(State(db)).await.unwrap().into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains("Certified Kubernetes Administrator"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_triggers_content_refresh() {
+        // Setup identifiers and data structures
+        let certification_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_delete_certification()
+            .times(1)
+            .withf(move |id| *id == certification_id)
+            .returning(|_| Ok(()));
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let response = delete(State(db), Path(certification_id))
+            .await
+            .unwrap()
+            .into_response();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.headers().get("HX-Trigger").unwrap(),
+            "refresh-admin-content"
+        );
+    }
+}
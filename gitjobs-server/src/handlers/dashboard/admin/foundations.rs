@@ -0,0 +1,174 @@
+//! This module defines the HTTP handlers for the admin dashboard foundations page.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::ValidatedForm},
+    templates::dashboard::admin::foundations::{self, FoundationUpdate, NewFoundation},
+};
+
+// Pages handlers.
+
+/// Returns the page listing the foundations.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    let foundations = db.list_foundations_for_admin().await?;
+    let template = foundations::FoundationsPage { foundations };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions.
+
+/// Adds a new foundation and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    State(db): State<DynDB>,
+    ValidatedForm(foundation): ValidatedForm<NewFoundation>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.add_foundation(&foundation).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+/// Updates a foundation and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn update(
+    State(db): State<DynDB>,
+    Path(foundation_id): Path<Uuid>,
+    ValidatedForm(foundation): ValidatedForm<FoundationUpdate>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.update_foundation(&foundation_id, &foundation).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{
+            Request,
+            header::{CONTENT_TYPE, COOKIE},
+        },
+    };
+    use axum_login::tower_sessions::session;
+    use chrono::{TimeZone, Utc};
+    use tower::ServiceExt;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::{TestRouterBuilder, sample_auth_user, sample_session_record},
+        notifications::MockNotificationsManager,
+        templates::dashboard::admin::foundations::Foundation,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_add_rejects_invalid_landscape_url() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut admin = sample_auth_user(user_id, auth_hash);
+        admin.admin = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(admin.clone())));
+        db.expect_add_foundation().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/dashboard/admin/foundations/add")
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from("name=lfai&landscape_url=not-a-url"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_page_shows_last_successful_sync() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_list_foundations_for_admin().times(1).returning(|| {
+            Ok(vec![Foundation {
+                foundation_id: Uuid::new_v4(),
+                members: 1,
+                name: "cncf".to_string(),
+                projects: 2,
+                landscape_url: Some("https://landscape.cncf.io".to_string()),
+                last_synced_at: Some(Utc.with_ymd_and_hms(2026, 1, 2, 10, 5, 0).unwrap()),
+            }])
+        });
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let response = page(State(db)).await.unwrap().into_response();
+        let (parts, body) = response.into_parts();
+        let body = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(String::from_utf8_lossy(&body).contains("2026-01-02 10:05 UTC"));
+    }
+
+    #[tokio::test]
+    async fn test_update_saves_landscape_url() {
+        // Setup identifiers and data structures
+        let foundation_id = Uuid::new_v4();
+        let foundation = FoundationUpdate {
+            landscape_url: Some("https://landscape.example.test".to_string()),
+        };
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_update_foundation()
+            .times(1)
+            .withf(move |id, update| {
+                *id == foundation_id
+                    && update.landscape_url.as_deref() == Some("https://landscape.example.test")
+            })
+            .returning(|_, _| Ok(()));
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let response = update(State(db), Path(foundation_id), ValidatedForm(foundation))
+            .await
+            .unwrap()
+            .into_response();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+}
@@ -0,0 +1,184 @@
+//! This module defines the HTTP handlers for the admin dashboard home page.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Query, State},
+    response::{Html, IntoResponse},
+};
+use axum_messages::Messages;
+use tower_sessions::Session;
+use tracing::instrument;
+
+use crate::{
+    auth::AuthSession,
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError},
+    templates::{
+        PageId,
+        dashboard::admin::{
+            certifications, foundations,
+            home::{self, Content, Tab},
+            moderators,
+        },
+    },
+};
+
+// Pages handlers.
+
+/// Handler that returns the admin dashboard home page.
+///
+/// This function handles the HTTP request for the admin dashboard home page.
+/// It determines the selected tab, fetches the relevant data from the
+/// database, and renders the appropriate template.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(
+    auth_session: AuthSession,
+    messages: Messages,
+    session: Session,
+    State(db): State<DynDB>,
+    State(cfg): State<HttpServerConfig>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare content for the selected tab
+    let tab: Tab = query.get("tab").unwrap_or(&String::new()).parse().unwrap_or_default();
+    let content = match tab {
+        Tab::Certifications => {
+            let certifications = db.list_certifications().await?;
+            Content::Certifications(certifications::CertificationsPage { certifications })
+        }
+        Tab::Foundations => {
+            let foundations = db.list_foundations_for_admin().await?;
+            Content::Foundations(foundations::FoundationsPage { foundations })
+        }
+        Tab::Moderators => {
+            let moderators = db.list_moderators().await?;
+            Content::Moderators(moderators::ModeratorsPage { moderators })
+        }
+    };
+
+    // Prepare template
+    let template = home::Page {
+        auth_provider: session.get(AUTH_PROVIDER_KEY).await?,
+        cfg: cfg.into(),
+        content,
+        messages: messages.into_iter().collect(),
+        page_id: PageId::AdminDashboard,
+        user: auth_session.into(),
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header::COOKIE},
+    };
+    use axum_login::tower_sessions::session;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::{TestRouterBuilder, sample_auth_user, sample_session_record},
+        notifications::MockNotificationsManager,
+        templates::dashboard::admin::moderators::Moderator,
+    };
+
+    #[tokio::test]
+    async fn test_page_renders_moderators_tab_by_default() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut admin = sample_auth_user(user_id, auth_hash);
+        admin.admin = true;
+        let moderator = Moderator {
+            admin: false,
+            email: "moderator@example.test".to_string(),
+            name: "Jane Moderator".to_string(),
+            user_id: Uuid::new_v4(),
+            username: "jane".to_string(),
+        };
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(admin.clone())));
+        db.expect_list_moderators()
+            .times(1)
+            .returning(move || Ok(vec![moderator.clone()]));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/admin")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains("Jane Moderator"));
+        assert!(html.contains("Admin dashboard"));
+    }
+
+    #[tokio::test]
+    async fn test_page_returns_forbidden_for_moderator() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_list_moderators().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/admin")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
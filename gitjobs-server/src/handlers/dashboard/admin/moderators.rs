@@ -0,0 +1,158 @@
+//! This module defines the HTTP handlers for the admin dashboard moderators page.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::ValidatedForm},
+    templates::dashboard::admin::moderators::{self, NewModerator},
+};
+
+// Pages handlers.
+
+/// Returns the page listing the users with moderator rights.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    let moderators = db.list_moderators().await?;
+    let template = moderators::ModeratorsPage { moderators };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions.
+
+/// Grants moderator rights to a user and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    State(db): State<DynDB>,
+    ValidatedForm(moderator): ValidatedForm<NewModerator>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.grant_moderator(&moderator.email).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+/// Revokes the moderator rights of a user and triggers a page refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn revoke(
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.revoke_moderator(&user_id).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-admin-content")]))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{
+            Request, StatusCode,
+            header::{CONTENT_TYPE, COOKIE},
+        },
+    };
+    use axum_login::tower_sessions::session;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::{TestRouterBuilder, sample_auth_user, sample_session_record},
+        notifications::MockNotificationsManager,
+    };
+
+    #[tokio::test]
+    async fn test_add_grants_moderator_rights() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut admin = sample_auth_user(user_id, auth_hash);
+        admin.admin = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(admin.clone())));
+        db.expect_grant_moderator()
+            .times(1)
+            .withf(|email| email == "moderator@example.test")
+            .returning(|_| Ok(Uuid::new_v4()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/dashboard/admin/moderators/add")
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from("email=moderator%40example.test"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.headers().get("HX-Trigger").unwrap(),
+            "refresh-admin-content"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_returns_forbidden_for_non_admin() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_revoke_moderator().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/admin/moderators/{user_id}/revoke"))
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,277 @@
+//! HTTP handlers for the employer analytics page and its data export.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+};
+use chrono::Duration;
+use serde_qs::axum::QsQuery;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB,
+    handlers::{error::HandlerError, extractors::SelectedEmployerIdRequired, prepare_headers},
+    templates::dashboard::employer::analytics::{AnalyticsPage, ExportFormat, ExportOptions, Filters},
+};
+
+// Pages handlers.
+
+/// Renders the analytics page for the selected employer.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    QsQuery(filters): QsQuery<Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_page(&db, &employer_id, filters).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Actions handlers.
+
+/// Exports the daily analytics series of the selected employer as CSV or JSON.
+#[instrument(skip_all, err)]
+pub(crate) async fn export(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    QsQuery(options): QsQuery<ExportOptions>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get analytics from the database
+    let analytics = db.get_employer_analytics(&employer_id, &options.range).await?;
+
+    // Prepare export body
+    let (content_type, body) = match options.format {
+        ExportFormat::Csv => ("text/csv; charset=utf-8", analytics.daily_csv()),
+        ExportFormat::Json => ("application/json", serde_json::to_string(&analytics.daily)?),
+    };
+
+    // Prepare response headers
+    let content_disposition = format!(
+        r#"attachment; filename="analytics-{}.{}""#,
+        options.range, options.format
+    );
+    let headers = prepare_headers(
+        Duration::zero(),
+        &[
+            ("content-type", content_type),
+            ("content-disposition", &content_disposition),
+        ],
+    )?;
+
+    Ok((headers, body))
+}
+
+// Helpers.
+
+/// Prepares the analytics page of the employer for the filters provided. It is
+/// shared with the employer dashboard home page, so both render the same content.
+pub(crate) async fn prepare_page(db: &DynDB, employer_id: &Uuid, filters: Filters) -> Result<AnalyticsPage> {
+    let analytics = db.get_employer_analytics(employer_id, &filters.range).await?;
+
+    Ok(AnalyticsPage { analytics, filters })
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{
+            Request, StatusCode,
+            header::{CONTENT_DISPOSITION, CONTENT_TYPE, COOKIE},
+        },
+    };
+    use axum_login::tower_sessions::session;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::{
+            TestRouterBuilder, sample_auth_user, sample_employer_analytics, sample_session_record,
+        },
+        notifications::MockNotificationsManager,
+        templates::dashboard::employer::analytics::Range,
+    };
+
+    #[tokio::test]
+    async fn test_page_renders_selected_range() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, Some(employer_id));
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_user_owns_employer()
+            .times(1)
+            .withf(move |uid, eid| *uid == user_id && *eid == employer_id)
+            .returning(|_, _| Ok(true));
+        db.expect_get_employer_analytics()
+            .times(1)
+            .withf(move |id, range| *id == employer_id && *range == Range::Week)
+            .returning(move |_, _| Ok(sample_employer_analytics(job_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/employer/analytics?range=7d")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("Search appearances"));
+        assert!(body.contains("Rust Engineer"));
+    }
+
+    #[tokio::test]
+    async fn test_export_returns_daily_series_as_csv() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, Some(employer_id));
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_user_owns_employer()
+            .times(1)
+            .withf(move |uid, eid| *uid == user_id && *eid == employer_id)
+            .returning(|_, _| Ok(true));
+        db.expect_get_employer_analytics()
+            .times(1)
+            .withf(move |id, range| *id == employer_id && *range == Range::Month)
+            .returning(move |_, _| Ok(sample_employer_analytics(job_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/employer/analytics/export")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[CONTENT_TYPE], "text/csv; charset=utf-8");
+        assert_eq!(
+            headers[CONTENT_DISPOSITION],
+            r#"attachment; filename="analytics-30d.csv""#
+        );
+        assert_eq!(
+            String::from_utf8(body.to_vec()).unwrap(),
+            format!(
+                "day,job_id,title,search_appearances,views,apply_clicks,applications\n\
+                 2025-01-01,{job_id},\"Rust Engineer, Platform\",40,10,4,2\n"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_returns_daily_series_as_json() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, Some(employer_id));
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_user_owns_employer()
+            .times(1)
+            .withf(move |uid, eid| *uid == user_id && *eid == employer_id)
+            .returning(|_, _| Ok(true));
+        db.expect_get_employer_analytics()
+            .times(1)
+            .withf(move |id, range| *id == employer_id && *range == Range::Quarter)
+            .returning(move |_, _| Ok(sample_employer_analytics(job_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/employer/analytics/export?range=90d&format=json")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let daily: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[CONTENT_TYPE], "application/json");
+        assert_eq!(
+            headers[CONTENT_DISPOSITION],
+            r#"attachment; filename="analytics-90d.json""#
+        );
+        assert_eq!(
+            daily,
+            serde_json::json!([{
+                "applications": 2,
+                "apply_clicks": 4,
+                "day": "2025-01-01",
+                "job_id": job_id,
+                "search_appearances": 40,
+                "title": "Rust Engineer, Platform",
+                "views": 10,
+            }])
+        );
+    }
+}
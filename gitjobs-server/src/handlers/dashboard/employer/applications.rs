@@ -74,13 +74,14 @@ mod tests {
     use std::sync::Arc;
 
     use axum::{
-        body::Body,
+        body::{Body, to_bytes},
         extract::{Path, State},
         http::{Request, header::COOKIE},
         response::IntoResponse,
     };
     use axum_login::tower_sessions::session;
     use reqwest::StatusCode;
+    use serde_json::json;
     use serde_qs::axum::QsQuery;
     use tower::ServiceExt;
     use uuid::Uuid;
@@ -129,6 +130,62 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_list_page_shows_applicants_match() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let applications_output: ApplicationsSearchOutput = serde_json::from_value(json!({
+            "applications": [{
+                "application_id": Uuid::new_v4(),
+                "applied_at": "2024-01-02T12:00:00Z",
+                "job_id": job_id,
+                "job_seeker_profile_id": Uuid::new_v4(),
+                "job_title": "Rust Engineer",
+                "job_workplace": "remote",
+                "match": {
+                    "location_fit": "remote",
+                    "matched_skills": ["rust"],
+                    "missing_skills": ["sql"],
+                    "score": 65
+                },
+                "name": "Jane Doe"
+            }],
+            "total": 1
+        }))
+        .unwrap();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_applications_filters_options()
+            .times(1)
+            .withf(move |id| *id == employer_id)
+            .returning(move |_| Ok(sample_employer_applications_filters_options(job_id)));
+        db.expect_search_applications()
+            .times(1)
+            .withf(move |id, _| *id == employer_id)
+            .returning(move |_, _| Ok(applications_output.clone()));
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let response = list_page(
+            State(db),
+            crate::handlers::extractors::SelectedEmployerIdRequired(employer_id),
+            QsQuery(Filters::default()),
+        )
+        .await
+        .unwrap()
+        .into_response();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains("Jane Doe"));
+        assert!(html.contains("65% match"));
+    }
+
     #[tokio::test]
     async fn test_profile_preview_page_returns_not_found_when_profile_is_missing() {
         // Setup identifiers and data structures
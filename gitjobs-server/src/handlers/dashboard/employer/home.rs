@@ -18,11 +18,14 @@ use crate::{
     auth::AuthSession,
     config::HttpServerConfig,
     db::{DynDB, dashboard::employer::ApplicationsSearchOutput},
-    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError, extractors::SelectedEmployerIdOptional},
+    handlers::{
+        auth::AUTH_PROVIDER_KEY, dashboard::employer, error::HandlerError,
+        extractors::SelectedEmployerIdOptional,
+    },
     templates::{
         PageId, auth,
         dashboard::employer::{
-            applications, employers,
+            analytics, applications, employers,
             home::{self, Content, Tab},
             jobs, team,
         },
@@ -35,8 +38,8 @@ use crate::{
 /// Handler that returns the employer dashboard home page.
 ///
 /// This handler manages the main employer dashboard page, selecting the appropriate tab
-/// and preparing the content for each dashboard section, such as account, applications,
-/// invitations, jobs, profile, and team.
+/// and preparing the content for each dashboard section, such as account, analytics,
+/// applications, invitations, jobs, profile, and team.
 #[instrument(skip_all, err)]
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn page(
@@ -47,6 +50,7 @@ pub(crate) async fn page(
     State(cfg): State<HttpServerConfig>,
     Query(query): Query<HashMap<String, String>>,
     QsQuery(filters): QsQuery<applications::Filters>,
+    QsQuery(analytics_filters): QsQuery<analytics::Filters>,
     SelectedEmployerIdOptional(employer_id): SelectedEmployerIdOptional,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
@@ -76,6 +80,11 @@ pub(crate) async fn page(
             let user_summary = user.clone().into();
             Content::Account(auth::UpdateUserPage { user_summary })
         }
+        Tab::Analytics => {
+            let employer_id = employer_id.expect("to be some");
+            let page = employer::analytics::prepare_page(&db, &employer_id, analytics_filters).await?;
+            Content::Analytics(page)
+        }
         Tab::Applications => {
             let employer_id = employer_id.expect("to be some");
             let (filters_options, ApplicationsSearchOutput { applications, total }) = tokio::try_join!(
@@ -147,12 +156,99 @@ mod tests {
     use crate::{
         db::mock::MockDB,
         handlers::tests::{
-            TestRouterBuilder, sample_auth_user, sample_employer_job_summary, sample_employer_summary,
-            sample_session_record, sample_team_invitation,
+            TestRouterBuilder, sample_auth_user, sample_employer_analytics, sample_employer_job_summary,
+            sample_employer_summary, sample_session_record, sample_team_invitation,
         },
         notifications::MockNotificationsManager,
+        templates::dashboard::employer::analytics::Range,
     };
 
+    #[tokio::test]
+    async fn test_page_renders_analytics_tab_with_selected_range() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, Some(employer_id));
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_get_user_invitations_count()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(|_| Ok(0));
+        db.expect_list_employers()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(vec![sample_employer_summary(employer_id)]));
+        db.expect_get_employer_analytics()
+            .times(1)
+            .withf(move |id, range| *id == employer_id && *range == Range::Year)
+            .returning(move |_, _| Ok(sample_employer_analytics(job_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/employer?tab=analytics&range=365d")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_page_rejects_analytics_tab_with_invalid_range() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, Some(employer_id));
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_get_employer_analytics().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/employer?tab=analytics&range=invalid")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[tokio::test]
     async fn test_page_renders_jobs_tab_for_authenticated_user() {
         // Setup identifiers and data structures
@@ -23,7 +23,7 @@ use crate::{
         dashboard::job_seeker::{
             applications,
             home::{self, Content, Tab},
-            profile,
+            profile, recommended,
         },
     },
 };
@@ -60,6 +60,10 @@ pub(crate) async fn page(
             let profile = db.get_job_seeker_profile(&user.user_id).await?;
             Content::Profile(profile::UpdatePage { profile })
         }
+        Tab::Recommended => {
+            let jobs = db.list_recommended_jobs(&user.user_id).await?;
+            Content::Recommended(recommended::RecommendedPage { jobs })
+        }
     };
 
     // Prepare template
@@ -90,7 +94,8 @@ mod tests {
     use crate::{
         db::mock::MockDB,
         handlers::tests::{
-            TestRouterBuilder, sample_auth_user, sample_job_seeker_application, sample_session_record,
+            TestRouterBuilder, sample_auth_user, sample_job_seeker_application, sample_jobboard_job_summary,
+            sample_session_record,
         },
         notifications::MockNotificationsManager,
     };
@@ -135,4 +140,45 @@ mod tests {
         // Check response matches expectations
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_page_renders_recommended_tab() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_list_recommended_jobs()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(vec![sample_jobboard_job_summary(job_id, employer_id)]));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/job-seeker?tab=recommended")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }
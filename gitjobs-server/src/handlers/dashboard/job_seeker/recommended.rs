@@ -0,0 +1,101 @@
+//! This module defines the HTTP handlers for the recommended jobs page.
+
+use askama::Template;
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+};
+use reqwest::StatusCode;
+use tracing::instrument;
+
+use crate::{
+    auth::AuthSession, db::DynDB, handlers::error::HandlerError,
+    templates::dashboard::job_seeker::recommended::RecommendedPage,
+};
+
+// Pages handlers.
+
+/// Handler that returns the recommended jobs list page.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Prepare template
+    let jobs = db.list_recommended_jobs(&user.user_id).await?;
+    let template = RecommendedPage { jobs };
+
+    Ok(Html(template.render()?).into_response())
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header::COOKIE},
+    };
+    use axum_login::tower_sessions::session;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::{
+            TestRouterBuilder, sample_auth_user, sample_jobboard_job_summary, sample_session_record,
+        },
+        notifications::MockNotificationsManager,
+    };
+
+    #[tokio::test]
+    async fn test_list_page_renders_recommended_jobs() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_list_recommended_jobs()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(vec![sample_jobboard_job_summary(job_id, employer_id)]));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/job-seeker/recommended/list")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("Recommended for you"));
+        assert!(body.contains(&format!("/?job_id={job_id}")));
+    }
+}
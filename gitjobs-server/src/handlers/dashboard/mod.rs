@@ -1,5 +1,6 @@
 //! This module defines the HTTP handlers for the dashboards.
 
+pub(crate) mod admin;
 pub(crate) mod employer;
 pub(crate) mod job_seeker;
 pub(crate) mod moderator;
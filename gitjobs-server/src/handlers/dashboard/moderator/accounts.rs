@@ -0,0 +1,699 @@
+//! This module defines the HTTP handlers for the accounts moderation dashboard pages.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use garde::Validate;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_qs::axum::QsQuery;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthSession,
+    db::{
+        DynDB,
+        dashboard::moderator::{EmployersSearchOutput, UsersSearchOutput},
+    },
+    handlers::{error::HandlerError, extractors::ValidatedForm},
+    templates::{
+        dashboard::moderator::accounts::{self, EmployersFilters, UsersFilters},
+        helpers::empty_string_as_none_date,
+        pagination::NavigationLinks,
+    },
+    validation::{MAX_LEN_DESCRIPTION_SHORT, trimmed_non_empty},
+};
+
+/// Error message returned when the suspension expiry date is not in the future.
+const EXPIRY_NOT_IN_FUTURE: &str = "suspension expiry must be in the future";
+
+// Pages handlers.
+
+/// Returns the page listing the most recent moderation actions.
+#[instrument(skip_all, err)]
+pub(crate) async fn activity_page(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    let actions = db.list_moderation_actions().await?;
+    let template = accounts::ActivityPage { actions };
+
+    Ok(Html(template.render()?))
+}
+
+/// Returns the page listing the employers that match the filters provided.
+#[instrument(skip_all, err)]
+pub(crate) async fn employers_page(
+    State(db): State<DynDB>,
+    QsQuery(filters): QsQuery<EmployersFilters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let EmployersSearchOutput { employers, total } = db.search_employers_for_moderation(&filters).await?;
+    let navigation_links = NavigationLinks::from_filters(&filters, total)?;
+    let template = accounts::EmployersPage {
+        employers,
+        filters,
+        navigation_links,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+/// Returns the page listing the users that match the filters provided.
+#[instrument(skip_all, err)]
+pub(crate) async fn users_page(
+    State(db): State<DynDB>,
+    QsQuery(filters): QsQuery<UsersFilters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let UsersSearchOutput { total, users } = db.search_users_for_moderation(&filters).await?;
+    let navigation_links = NavigationLinks::from_filters(&filters, total)?;
+    let template = accounts::UsersPage {
+        filters,
+        navigation_links,
+        users,
+    };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions.
+
+/// Suspends an employer as a moderator and triggers a table refresh in the UI.
+///
+/// Only the employer is suspended: the accounts of its team members, which may
+/// belong to other employers too, are not affected and keep their sessions.
+#[instrument(skip_all, err)]
+pub(crate) async fn suspend_employer(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(employer_id): Path<Uuid>,
+    ValidatedForm(input): ValidatedForm<SuspendInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Check the suspension can be applied
+    if !input.expires_in_future() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, EXPIRY_NOT_IN_FUTURE).into_response());
+    }
+    if db.user_owns_employer(&user.user_id, &employer_id).await? {
+        return Ok((StatusCode::FORBIDDEN, "cannot suspend your own employer").into_response());
+    }
+
+    // Suspend employer
+    db.suspend_employer(
+        &employer_id,
+        &user.user_id,
+        input.reason.trim(),
+        input.expires_at(),
+    )
+    .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-moderator-accounts")],
+    )
+        .into_response())
+}
+
+/// Suspends a user as a moderator and triggers a table refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn suspend_user(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+    ValidatedForm(input): ValidatedForm<SuspendInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Check the suspension can be applied
+    if !input.expires_in_future() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, EXPIRY_NOT_IN_FUTURE).into_response());
+    }
+    if user_id == user.user_id {
+        return Ok((StatusCode::FORBIDDEN, "cannot suspend your own account").into_response());
+    }
+
+    // Suspend user
+    db.suspend_user(&user_id, &user.user_id, input.reason.trim(), input.expires_at())
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-moderator-accounts")],
+    )
+        .into_response())
+}
+
+/// Lifts the suspension of an employer and triggers a table refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn unsuspend_employer(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(employer_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Unsuspend employer
+    db.unsuspend_employer(&employer_id, &user.user_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-moderator-accounts")],
+    )
+        .into_response())
+}
+
+/// Lifts the suspension of a user and triggers a table refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn unsuspend_user(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Unsuspend user
+    db.unsuspend_user(&user_id, &user.user_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-moderator-accounts")],
+    )
+        .into_response())
+}
+
+// Types.
+
+/// Input data for suspending a user or an employer.
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+pub(crate) struct SuspendInput {
+    /// Reason provided by the moderator for the suspension.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_DESCRIPTION_SHORT))]
+    pub reason: String,
+
+    /// Date when the suspension expires. Suspensions without it are permanent.
+    #[garde(skip)]
+    #[serde(default, deserialize_with = "empty_string_as_none_date")]
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl SuspendInput {
+    /// Returns true if the suspension is permanent or expires after today.
+    fn expires_in_future(&self) -> bool {
+        self.expires_on.is_none_or(|date| date > Utc::now().date_naive())
+    }
+
+    /// Returns the time when the suspension expires (start of the day, UTC).
+    fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_on.map(|date| date.and_time(NaiveTime::MIN).and_utc())
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        extract::State,
+        http::{Request, StatusCode, header::COOKIE},
+        response::IntoResponse,
+    };
+    use axum_login::tower_sessions::session;
+    use chrono::{TimeZone, Utc};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::{
+            DynDB,
+            dashboard::moderator::{EmployersSearchOutput, UsersSearchOutput},
+            mock::MockDB,
+        },
+        handlers::tests::{
+            TestRouterBuilder, sample_auth_user, sample_moderation_action,
+            sample_moderator_accounts_employer, sample_moderator_accounts_user, sample_session_record,
+        },
+        notifications::MockNotificationsManager,
+        templates::dashboard::moderator::accounts::ModerationActionKind,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_activity_page_renders_successfully() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_list_moderation_actions().times(1).returning(|| {
+            Ok(vec![
+                sample_moderation_action(ModerationActionKind::RejectJob),
+                sample_moderation_action(ModerationActionKind::SuspendUser),
+            ])
+        });
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let response = activity_page(State(db)).await.unwrap().into_response();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_employers_page_renders_successfully() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_search_employers_for_moderation()
+            .times(1)
+            .withf(|filters| filters.ts_query.as_deref() == Some("example"))
+            .returning(move |_| {
+                Ok(EmployersSearchOutput {
+                    employers: vec![sample_moderator_accounts_employer(employer_id)],
+                    total: 1,
+                })
+            });
+        let db: DynDB = Arc::new(db);
+
+        // Execute handler
+        let filters = EmployersFilters {
+            ts_query: Some("example".to_string()),
+            ..Default::default()
+        };
+        let response = employers_page(State(db), QsQuery(filters))
+            .await
+            .unwrap()
+            .into_response();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_users_page_treats_empty_search_as_no_filter() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_search_users_for_moderation()
+            .times(1)
+            .withf(|filters| filters.ts_query.is_none())
+            .returning(|_| {
+                Ok(UsersSearchOutput {
+                    total: 1,
+                    users: vec![sample_moderator_accounts_user(Uuid::new_v4())],
+                })
+            });
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/moderator/users?ts_query=")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_suspend_employer_returns_no_content_for_moderator() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_user_owns_employer()
+            .times(1)
+            .withf(move |id, employer| *id == user_id && *employer == employer_id)
+            .returning(|_, _| Ok(false));
+        db.expect_suspend_employer()
+            .times(1)
+            .withf(move |id, moderator_id, reason, expires_at| {
+                *id == employer_id
+                    && *moderator_id == user_id
+                    && reason == "fake jobs"
+                    && *expires_at == Some(Utc.with_ymd_and_hms(2030, 1, 15, 0, 0, 0).unwrap())
+            })
+            .returning(|_, _, _, _| Ok(()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/employers/{employer_id}/suspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("reason=+fake+jobs+&expires_on=2030-01-15"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.headers().get("HX-Trigger").unwrap(),
+            "refresh-moderator-accounts"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_suspend_employer_returns_forbidden_for_own_employer() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_user_owns_employer()
+            .times(1)
+            .withf(move |id, employer| *id == user_id && *employer == employer_id)
+            .returning(|_, _| Ok(true));
+        db.expect_suspend_employer().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/employers/{employer_id}/suspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("reason=fake+jobs"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_suspend_user_returns_forbidden_for_own_account() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_suspend_user().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/users/{user_id}/suspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("reason=spam"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_suspend_user_requires_expiry_after_today() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let target_user_id = Uuid::new_v4();
+        let today = Utc::now().date_naive();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_suspend_user().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/users/{target_user_id}/suspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from(format!("reason=spam&expires_on={today}")))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_suspend_user_without_expiry_is_permanent() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let target_user_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_suspend_user()
+            .times(1)
+            .withf(move |id, moderator_id, reason, expires_at| {
+                *id == target_user_id && *moderator_id == user_id && reason == "spam" && expires_at.is_none()
+            })
+            .returning(|_, _, _, _| Ok(()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/users/{target_user_id}/suspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("reason=spam&expires_on="))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_suspend_user_requires_reason() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let target_user_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_suspend_user().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/users/{target_user_id}/suspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("reason=+++"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_unsuspend_employer_returns_no_content_for_moderator() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_unsuspend_employer()
+            .times(1)
+            .withf(move |id, moderator_id| *id == employer_id && *moderator_id == user_id)
+            .returning(|_, _| Ok(()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/employers/{employer_id}/unsuspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_unsuspend_user_returns_forbidden_for_non_moderator() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let target_user_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let user = sample_auth_user(user_id, auth_hash);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(user.clone())));
+        db.expect_unsuspend_user().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(format!("/dashboard/moderator/users/{target_user_id}/unsuspend"))
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -10,23 +10,29 @@ use axum::{
     response::{Html, IntoResponse},
 };
 use axum_messages::Messages;
+use serde_qs::axum::QsQuery;
 use tower_sessions::Session;
 use tracing::instrument;
 
 use crate::{
     auth::AuthSession,
     config::HttpServerConfig,
-    db::DynDB,
+    db::{
+        DynDB,
+        dashboard::moderator::{EmployersSearchOutput, UsersSearchOutput},
+    },
     handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError},
     templates::{
         PageId,
         dashboard::{
             employer::jobs::JobStatus,
             moderator::{
+                accounts::{self, EmployersFilters, UsersFilters},
                 home::{self, Content, Tab},
-                jobs,
+                jobs, stats,
             },
         },
+        pagination::NavigationLinks,
     },
 };
 
@@ -38,6 +44,7 @@ use crate::{
 /// It retrieves the user from the session, determines the selected tab, fetches
 /// the relevant data from the database, and renders the appropriate template.
 #[instrument(skip_all, err)]
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn page(
     auth_session: AuthSession,
     messages: Messages,
@@ -45,6 +52,8 @@ pub(crate) async fn page(
     State(db): State<DynDB>,
     State(cfg): State<HttpServerConfig>,
     Query(query): Query<HashMap<String, String>>,
+    QsQuery(employers_filters): QsQuery<EmployersFilters>,
+    QsQuery(users_filters): QsQuery<UsersFilters>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
     let Some(_user) = auth_session.user.clone() else {
@@ -54,6 +63,21 @@ pub(crate) async fn page(
     // Prepare content for the selected tab
     let tab: Tab = query.get("tab").unwrap_or(&String::new()).parse().unwrap_or_default();
     let content = match tab {
+        Tab::Activity => {
+            let actions = db.list_moderation_actions().await?;
+            Content::Activity(accounts::ActivityPage { actions })
+        }
+        Tab::Employers => {
+            let filters = employers_filters;
+            let EmployersSearchOutput { employers, total } =
+                db.search_employers_for_moderation(&filters).await?;
+            let navigation_links = NavigationLinks::from_filters(&filters, total)?;
+            Content::Employers(accounts::EmployersPage {
+                employers,
+                filters,
+                navigation_links,
+            })
+        }
         Tab::LiveJobs => {
             let jobs = db.list_jobs_for_moderation(JobStatus::Published).await?;
             Content::LiveJobs(jobs::LivePage { jobs })
@@ -62,6 +86,20 @@ pub(crate) async fn page(
             let jobs = db.list_jobs_for_moderation(JobStatus::PendingApproval).await?;
             Content::PendingJobs(jobs::PendingPage { jobs })
         }
+        Tab::Stats => {
+            let stats = db.get_moderation_stats(cfg.moderation.review_sla_hours).await?;
+            Content::Stats(stats::StatsPage { stats })
+        }
+        Tab::Users => {
+            let filters = users_filters;
+            let UsersSearchOutput { total, users } = db.search_users_for_moderation(&filters).await?;
+            let navigation_links = NavigationLinks::from_filters(&filters, total)?;
+            Content::Users(accounts::UsersPage {
+                filters,
+                navigation_links,
+                users,
+            })
+        }
     };
 
     // Prepare template
@@ -0,0 +1,136 @@
+//! This module defines the HTTP handlers for the moderator dashboard stats page.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+
+use crate::{
+    config::HttpServerConfig, db::DynDB, handlers::error::HandlerError,
+    templates::dashboard::moderator::stats,
+};
+
+// Pages handlers.
+
+/// Returns the page with the moderation queue and review throughput stats.
+#[instrument(skip_all, err)]
+pub(crate) async fn page(
+    State(db): State<DynDB>,
+    State(cfg): State<HttpServerConfig>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let stats = db.get_moderation_stats(cfg.moderation.review_sla_hours).await?;
+    let template = stats::StatsPage { stats };
+
+    Ok(Html(template.render()?))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header::COOKIE},
+    };
+    use axum_login::tower_sessions::session;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::{
+            TestRouterBuilder, sample_auth_user, sample_moderator_stats, sample_session_record,
+            test_http_server_cfg,
+        },
+        notifications::MockNotificationsManager,
+    };
+
+    #[tokio::test]
+    async fn test_page_renders_stats_for_moderator() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut moderator = sample_auth_user(user_id, auth_hash);
+        moderator.moderator = true;
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(moderator.clone())));
+        db.expect_get_moderation_stats()
+            .times(1)
+            .withf(|review_sla_hours| *review_sla_hours == 36)
+            .returning(|_| Ok(sample_moderator_stats()));
+
+        // Setup router and send request
+        let mut cfg = test_http_server_cfg();
+        cfg.moderation.review_sla_hours = 36;
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_cfg(cfg)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/moderator/stats")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains("1d 2h"));
+        assert!(html.contains("Over SLA"));
+    }
+
+    #[tokio::test]
+    async fn test_page_returns_forbidden_for_non_moderator() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let user = sample_auth_user(user_id, auth_hash);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(user.clone())));
+        db.expect_get_moderation_stats().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/dashboard/moderator/stats")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -1,12 +1,15 @@
 //! Custom extractors for handlers.
 
-use std::sync::Arc;
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
 
 use anyhow::Result;
 use axum::{
     Form,
-    extract::{FromRequest, FromRequestParts, Path, Request},
-    http::{StatusCode, request::Parts},
+    extract::{ConnectInfo, FromRequest, FromRequestParts, Path, Request},
+    http::{HeaderMap, StatusCode, header::USER_AGENT, request::Parts},
 };
 use garde::Validate;
 use serde::de::DeserializeOwned;
@@ -17,6 +20,7 @@ use uuid::Uuid;
 use crate::{
     auth::{AuthSession, OAuth2ProviderDetails, OidcProviderDetails},
     config::{OAuth2Provider, OidcProvider},
+    event_tracker::Visitor,
     handlers::auth::SELECTED_EMPLOYER_ID_KEY,
     router,
 };
@@ -102,6 +106,49 @@ impl FromRequestParts<router::State> for SelectedEmployerIdRequired {
     }
 }
 
+/// Extractor for the information of the visitor that sent the request, used by
+/// the event tracker. The IP address is taken from the `X-Forwarded-For` entry
+/// added by the outermost trusted proxy, or from the address of the connection
+/// peer when no proxies are trusted.
+pub(crate) struct ClientVisitor(pub Visitor);
+
+impl FromRequestParts<router::State> for ClientVisitor {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, state: &router::State) -> Result<Self, Self::Rejection> {
+        let ip = if state.cfg.trusted_proxies == 0 {
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip())
+        } else {
+            forwarded_for_ip(&parts.headers, state.cfg.trusted_proxies)
+        };
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        Ok(ClientVisitor(Visitor { ip, user_agent }))
+    }
+}
+
+/// Returns the client IP address from the `X-Forwarded-For` header. Each proxy
+/// appends the address it received the request from, so the client address is
+/// the one added by the outermost trusted proxy, counting from the right. Any
+/// entries to its left are set by the client and cannot be trusted.
+fn forwarded_for_ip(headers: &HeaderMap, trusted_proxies: usize) -> Option<IpAddr> {
+    let entries: Vec<&str> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect();
+    let index = entries.len().checked_sub(trusted_proxies)?;
+    entries.get(index)?.parse().ok()
+}
+
 /// Extractor that deserializes and validates form data using Axum's `Form`.
 pub(crate) struct ValidatedForm<T>(pub T);
 
@@ -190,6 +237,105 @@ mod tests {
 
     use super::*;
 
+    #[tokio::test]
+    async fn test_client_visitor_extractor_uses_forwarded_for_ip_and_user_agent() {
+        // Setup state and router
+        let db: DynDB = Arc::new(MockDB::new());
+        let image_store: DynImageStore = Arc::new(MockImageStore::new());
+        let notifications_manager: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+        let mut state = build_state(db, image_store, notifications_manager);
+        state.cfg.trusted_proxies = 2;
+        let router = visitor_router(state);
+
+        // Send request and check response
+        let request = Request::builder()
+            .method("GET")
+            .uri("/visitor")
+            .header("x-forwarded-for", "203.0.113.7, 10.0.0.1")
+            .header("user-agent", "Mozilla/5.0")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "Some(203.0.113.7)|Some(\"Mozilla/5.0\")");
+    }
+
+    #[tokio::test]
+    async fn test_client_visitor_extractor_ignores_spoofed_forwarded_for_entries() {
+        // Setup state and router
+        let db: DynDB = Arc::new(MockDB::new());
+        let image_store: DynImageStore = Arc::new(MockImageStore::new());
+        let notifications_manager: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+        let mut state = build_state(db, image_store, notifications_manager);
+        state.cfg.trusted_proxies = 1;
+        let router = visitor_router(state);
+
+        // Send request and check response
+        let request = Request::builder()
+            .method("GET")
+            .uri("/visitor")
+            .header("x-forwarded-for", "198.51.100.1, 203.0.113.7")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "Some(203.0.113.7)|None");
+    }
+
+    #[tokio::test]
+    async fn test_client_visitor_extractor_uses_peer_ip_when_no_proxies_are_trusted() {
+        // Setup state and router
+        let db: DynDB = Arc::new(MockDB::new());
+        let image_store: DynImageStore = Arc::new(MockImageStore::new());
+        let notifications_manager: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+        let state = build_state(db, image_store, notifications_manager);
+        let router = visitor_router(state);
+
+        // Send request and check response
+        let mut request = Request::builder()
+            .method("GET")
+            .uri("/visitor")
+            .header("x-forwarded-for", "198.51.100.1")
+            .body(Body::empty())
+            .unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([192, 0, 2, 1], 4000))));
+        let response = router.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "Some(192.0.2.1)|None");
+    }
+
+    #[tokio::test]
+    async fn test_client_visitor_extractor_handles_missing_headers() {
+        // Setup state and router
+        let db: DynDB = Arc::new(MockDB::new());
+        let image_store: DynImageStore = Arc::new(MockImageStore::new());
+        let notifications_manager: DynNotificationsManager = Arc::new(MockNotificationsManager::new());
+        let mut state = build_state(db, image_store, notifications_manager);
+        state.cfg.trusted_proxies = 1;
+        let router = visitor_router(state);
+
+        // Send request and check response
+        let request = Request::builder()
+            .method("GET")
+            .uri("/visitor")
+            .header("x-forwarded-for", "unknown")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "None|None");
+    }
+
     #[tokio::test]
     async fn test_oauth2_extractor_returns_bad_request_when_provider_is_missing() {
         // Setup state and router
@@ -648,6 +794,17 @@ mod tests {
         }
     }
 
+    fn visitor_router(state: router::State) -> Router {
+        Router::new()
+            .route(
+                "/visitor",
+                get(|ClientVisitor(visitor): ClientVisitor| async move {
+                    format!("{:?}|{:?}", visitor.ip, visitor.user_agent)
+                }),
+            )
+            .with_state(state)
+    }
+
     fn session_cookie(response: &axum::response::Response) -> String {
         response
             .headers()
@@ -0,0 +1,223 @@
+//! HTTP handlers used to check the server health and expose its metrics.
+
+use std::time::Duration;
+
+use axum::{
+    extract::State,
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::IntoResponse,
+};
+use metrics_exporter_prometheus::PrometheusHandle;
+use tokio::time::timeout;
+use tracing::{error, instrument};
+
+use crate::{db::DynDB, metrics};
+
+/// Time allowed to get a database connection when checking readiness.
+const DB_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Content type of the Prometheus text exposition format.
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Responds to health check requests with HTTP 200 OK.
+///
+/// This is the liveness check, so it does not verify any dependencies.
+#[instrument(skip_all)]
+pub(crate) async fn health_check() -> impl IntoResponse {
+    StatusCode::OK
+}
+
+/// Returns the metrics in Prometheus text format.
+#[instrument(skip_all)]
+pub(crate) async fn metrics(
+    State(db): State<DynDB>,
+    State(metrics_handle): State<PrometheusHandle>,
+) -> impl IntoResponse {
+    // Refresh the metrics collected when they are requested
+    metrics::record_db_pool_status(&db.pool_status());
+    match db.get_pending_notifications_stats().await {
+        Ok(stats) => metrics::record_pending_notifications(&stats),
+        Err(err) => error!(?err, "error getting pending notifications stats"),
+    }
+
+    ([(CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], metrics_handle.render())
+}
+
+/// Responds to readiness check requests with HTTP 200 OK when the database can
+/// be used, or HTTP 503 otherwise.
+///
+/// Notifications delivery is not checked here, as a stalled delivery does not
+/// prevent serving requests. It is reported through the metrics instead.
+#[instrument(skip_all)]
+pub(crate) async fn readiness_check(State(db): State<DynDB>) -> impl IntoResponse {
+    // Check a database connection can be used
+    match timeout(DB_CHECK_TIMEOUT, db.check_connection()).await {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => {
+            error!(?err, "readiness check failed: database unavailable");
+            return (StatusCode::SERVICE_UNAVAILABLE, "database unavailable");
+        }
+        Err(_) => {
+            error!("readiness check failed: database connection timed out");
+            return (StatusCode::SERVICE_UNAVAILABLE, "database unavailable");
+        }
+    }
+
+    (StatusCode::OK, "")
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header::CONTENT_TYPE},
+        response::IntoResponse,
+    };
+    use deadpool_postgres::Status;
+    use metrics_exporter_prometheus::PrometheusBuilder;
+    use tower::ServiceExt;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::tests::TestRouterBuilder,
+        notifications::{MockNotificationsManager, PendingNotificationsStats},
+        router,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_health_check_returns_ok() {
+        let response = health_check().await.into_response();
+        let (parts, body) = response.into_parts();
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(to_bytes(body, usize::MAX).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_is_not_served_by_the_public_router() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_pool_status().times(0);
+        db.expect_get_pending_notifications_stats().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/metrics")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations (the not found page is rendered)
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_returns_prometheus_text() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_pool_status().times(1).returning(|| Status {
+            max_size: 16,
+            size: 2,
+            available: 1,
+            waiting: 0,
+        });
+        db.expect_get_pending_notifications_stats()
+            .times(1)
+            .returning(|| Ok(PendingNotificationsStats::default()));
+
+        // Setup router and send request
+        let metrics_handle = PrometheusBuilder::new().build_recorder().handle();
+        let router = router::setup_metrics(Arc::new(db), metrics_handle);
+        let request = Request::builder()
+            .method("GET")
+            .uri("/metrics")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], PROMETHEUS_CONTENT_TYPE);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_check_returns_ok_when_dependencies_are_healthy() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_check_connection().times(1).returning(|| Ok(()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/readiness-check")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_check_returns_unavailable_when_database_fails() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_check_connection()
+            .times(1)
+            .returning(|| Err(anyhow!("connection refused")));
+        db.expect_get_pending_notifications_stats().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/readiness-check")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(bytes, "database unavailable");
+    }
+
+    #[tokio::test]
+    async fn test_readiness_check_ignores_notifications_delivery() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_check_connection().times(1).returning(|| Ok(()));
+        db.expect_get_pending_notifications_stats().times(0);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/readiness-check")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
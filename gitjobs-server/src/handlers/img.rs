@@ -7,12 +7,18 @@ use axum::{
     extract::{Multipart, Path, State},
     http::{
         HeaderMap, HeaderValue, StatusCode, Uri,
-        header::{CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, REFERER},
+        header::{
+            ACCEPT, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_SECURITY_POLICY, CONTENT_TYPE, ETAG,
+            IF_NONE_MATCH, REFERER, VARY, X_CONTENT_TYPE_OPTIONS,
+        },
     },
     response::IntoResponse,
 };
+use axum_extra::extract::Form;
 use image::{ImageFormat as DetectedImageFormat, ImageReader};
 use quick_xml::{Reader, events::Event};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use tracing::instrument;
 use uuid::Uuid;
 
@@ -20,41 +26,130 @@ use crate::{
     auth::AuthSession,
     config::HttpServerConfig,
     handlers::error::HandlerError,
-    img::{DynImageStore, ImageFormat},
+    img::{DynImageStore, ImageCrop, ImageFormat, ORIGINAL_VERSION, svg},
 };
 
 /// Cache-Control header for immutable responses.
 const CACHE_CONTROL_IMMUTABLE: &str = "max-age=2592000, immutable";
 
+/// Content-Security-Policy header for svg images.
+const CONTENT_SECURITY_POLICY_SVG: &str =
+    "default-src 'none'; img-src data:; style-src 'unsafe-inline'; sandbox";
+
 /// Maximum payload size allowed for image uploads (1 MiB).
 const MAX_IMAGE_SIZE_BYTES: usize = 1024 * 1024;
 
 // Handlers
 
 /// Returns an image from the store, setting headers for cache and content type.
+///
+/// The best format supported by the client (as advertised in the `Accept` header)
+/// is returned, falling back to png when no other format is available. Responses
+/// include a strong `ETag`, so that clients can revalidate them using the
+/// `If-None-Match` header.
 #[instrument(skip_all, err)]
 pub(crate) async fn get(
     State(image_store): State<DynImageStore>,
     Path((image_id, version)): Path<(Uuid, String)>,
+    request_headers: HeaderMap,
 ) -> Result<impl IntoResponse, HandlerError> {
-    // Get image from the store
-    let Some((data, format)) = image_store.get(image_id, &version).await? else {
+    // The original image is only kept to crop it again, it's not served
+    if version == ORIGINAL_VERSION {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    }
+
+    // Get image from the store, trying the preferred formats first
+    let mut image = None;
+    for format in preferred_formats(&request_headers) {
+        image = image_store.get(image_id, &format.version_name(&version)).await?;
+        if image.is_some() {
+            break;
+        }
+    }
+    let Some((data, format)) = image else {
         return Ok(StatusCode::NOT_FOUND.into_response());
     };
 
     // Prepare response headers
+    let etag = image_etag(&data)?;
     let mut headers = HeaderMap::new();
-    let content_type = match format {
-        ImageFormat::Png => "image/png",
-        ImageFormat::Svg => "image/svg+xml",
-    };
     headers.insert(CACHE_CONTROL, HeaderValue::from_static(CACHE_CONTROL_IMMUTABLE));
+    headers.insert(ETAG, etag.clone());
+    headers.insert(VARY, HeaderValue::from_static("Accept"));
+
+    // The client already has this version of the image
+    if etag_matches(&request_headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, headers).into_response());
+    }
+
     headers.insert(CONTENT_LENGTH, data.len().into());
-    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
+    if format == ImageFormat::Svg {
+        // Prevent svg images from running scripts or loading external resources
+        headers.insert(
+            CONTENT_SECURITY_POLICY,
+            HeaderValue::from_static(CONTENT_SECURITY_POLICY_SVG),
+        );
+        headers.insert(X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    }
 
     Ok((headers, data).into_response())
 }
 
+/// Crops again the original version of an image, saving the result as a new image
+/// and returning its identifier.
+#[instrument(skip_all, err)]
+pub(crate) async fn crop(
+    auth_session: AuthSession,
+    State(server_cfg): State<HttpServerConfig>,
+    State(image_store): State<DynImageStore>,
+    Path(image_id): Path<Uuid>,
+    headers: HeaderMap,
+    Form(input): Form<CropInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Validate referer header matches configured hostname
+    if !referer_matches_site(&server_cfg, &headers)? {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    // Validate input
+    let target = match ImageTarget::from_str(&input.target) {
+        Ok(target) => target,
+        Err(err) => return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response()),
+    };
+    let crop = match parse_crop(input.crop.as_deref(), input.focal_point.as_deref()) {
+        Ok(Some(crop)) => crop,
+        Ok(None) => {
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, "missing crop or focal point").into_response());
+        }
+        Err(err) => return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response()),
+    };
+
+    // Get original image from the store (svg images are returned when the image
+    // has no original version, as they can't be cropped)
+    let data = match image_store.get(image_id, ORIGINAL_VERSION).await? {
+        Some((data, format)) if format != ImageFormat::Svg => data,
+        _ => {
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, "original image not available").into_response());
+        }
+    };
+    if let Err(err) = validate_crop(&data, target, crop) {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response());
+    }
+
+    // Save cropped image to store
+    let image_id = image_store
+        .save(&user.user_id, ORIGINAL_VERSION, data, Some(crop))
+        .await?;
+
+    Ok((StatusCode::OK, image_id.to_string()).into_response())
+}
+
 /// Handles image upload from authenticated users, saving the image to the store.
 #[instrument(skip_all, err)]
 pub(crate) async fn upload(
@@ -78,6 +173,8 @@ pub(crate) async fn upload(
     let mut target: Option<ImageTarget> = None;
     let mut file_name: Option<String> = None;
     let mut data: Option<Vec<u8>> = None;
+    let mut crop_rect: Option<String> = None;
+    let mut focal_point: Option<String> = None;
 
     while let Ok(Some(field)) = multipart.next_field().await {
         match field.name() {
@@ -92,6 +189,18 @@ pub(crate) async fn upload(
                     }
                 };
             }
+            Some("crop") => {
+                let Ok(value) = field.text().await else {
+                    return Ok((StatusCode::BAD_REQUEST).into_response());
+                };
+                crop_rect = Some(value);
+            }
+            Some("focal_point") => {
+                let Ok(value) = field.text().await else {
+                    return Ok((StatusCode::BAD_REQUEST).into_response());
+                };
+                focal_point = Some(value);
+            }
             Some("file" | "logo") => {
                 file_name = field.file_name().map(str::to_string);
                 let Ok(bytes) = field.bytes().await else {
@@ -118,6 +227,13 @@ pub(crate) async fn upload(
             .into_response());
     };
 
+    let crop = match parse_crop(crop_rect.as_deref(), focal_point.as_deref()) {
+        Ok(crop) => crop,
+        Err(err) => {
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response());
+        }
+    };
+
     // Enforce maximum file size
     if data.len() > MAX_IMAGE_SIZE_BYTES {
         return Ok((StatusCode::PAYLOAD_TOO_LARGE, "image exceeds 1MB limit").into_response());
@@ -144,21 +260,45 @@ pub(crate) async fn upload(
             .into_response());
     }
 
-    // Validate target dimensions for non-SVG uploads
-    if !matches!(format, SupportedImageFormat::Svg)
-        && let Err(err) = validate_image_dimensions(&data, target)
-    {
-        return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response());
-    }
+    // Sanitize SVG images or validate the dimensions of other formats
+    let data = match check_image_content(data, &format, target, crop) {
+        Ok(data) => data,
+        Err(err) => {
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response());
+        }
+    };
 
     // Save image to store
-    let image_id = image_store.save(&user.user_id, &file_name, data).await?;
+    let image_id = image_store.save(&user.user_id, &file_name, data, crop).await?;
 
     Ok((StatusCode::OK, image_id.to_string()).into_response())
 }
 
 // Helpers
 
+/// Checks the content of an uploaded image, returning the data to store.
+///
+/// SVG images are sanitized, rejecting those that cannot be cleaned safely. For
+/// other formats, the target dimensions are validated (when the image is cropped,
+/// the dimensions of the cropped area are validated instead).
+fn check_image_content(
+    data: Vec<u8>,
+    format: &SupportedImageFormat,
+    target: ImageTarget,
+    crop: Option<ImageCrop>,
+) -> Result<Vec<u8>> {
+    if matches!(format, SupportedImageFormat::Svg) {
+        return svg::sanitize(&data);
+    }
+
+    match crop {
+        Some(crop) => validate_crop(&data, target, crop)?,
+        None => validate_image_dimensions(&data, target)?,
+    }
+
+    Ok(data)
+}
+
 /// Detects the image format using the `image` crate with a fallback for SVGs.
 fn detect_image_format(bytes: &[u8], extension: &str) -> Result<SupportedImageFormat> {
     match image::guess_format(bytes) {
@@ -173,6 +313,22 @@ fn detect_image_format(bytes: &[u8], extension: &str) -> Result<SupportedImageFo
     }
 }
 
+/// Checks if any of the entity tags in the `If-None-Match` header matches the one
+/// provided (using the weak comparison, as required for this header).
+fn etag_matches(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let Ok(etag) = etag.to_str() else {
+        return false;
+    };
+
+    headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
 /// Returns the accepted extensions for the provided format.
 fn expected_extensions(format: &SupportedImageFormat) -> &'static [&'static str] {
     match format {
@@ -192,6 +348,11 @@ fn extension_matches(format: &SupportedImageFormat, extension: &str) -> bool {
         .any(|candidate| candidate == &extension)
 }
 
+/// Returns a strong entity tag for the image data provided.
+fn image_etag(data: &[u8]) -> Result<HeaderValue> {
+    Ok(HeaderValue::try_from(format!("\"{:x}\"", Sha256::digest(data)))?)
+}
+
 /// Extracts the lowercase file extension from a file name.
 fn image_extension(file_name: &str) -> Result<Cow<'_, str>> {
     let (_, extension) = file_name
@@ -203,10 +364,12 @@ fn image_extension(file_name: &str) -> Result<Cow<'_, str>> {
     Ok(Cow::from(extension.to_ascii_lowercase()))
 }
 
-/// Determines whether the provided bytes and extension represent a valid SVG asset.
+/// Determines whether the provided bytes and extension represent an SVG image.
+///
+/// This only checks the document root is an svg element, the content is checked
+/// when the image is sanitized.
 fn is_svg(bytes: &[u8], extension: &str) -> bool {
     const SVG_NAMESPACE: &[u8] = b"http://www.w3.org/2000/svg";
-    const DANGEROUS_ELEMENTS: &[&[u8]] = &[b"script", b"foreignObject"];
 
     // Check extension first for a fast reject path
     if !extension.eq_ignore_ascii_case("svg") {
@@ -217,70 +380,87 @@ fn is_svg(bytes: &[u8], extension: &str) -> bool {
     reader.config_mut().trim_text(true);
 
     let mut buf = Vec::new();
-    let mut found_svg_root = false;
-    let mut in_root = false;
-
     loop {
         match reader.read_event_into(&mut buf) {
-            Ok(Event::Eof) => break,
             Ok(Event::Start(ref event) | Event::Empty(ref event)) => {
-                let tag_name = event.name();
-
-                if !in_root {
-                    if tag_name.as_ref() != b"svg" {
-                        return false;
-                    }
-
-                    let has_svg_namespace = event.attributes().filter_map(Result::ok).any(|attr| {
-                        (attr.key.as_ref() == b"xmlns" || attr.key.local_name().as_ref() == b"xmlns")
-                            && attr.value.as_ref() == SVG_NAMESPACE
-                    });
-
-                    if !has_svg_namespace {
-                        return false;
-                    }
-
-                    found_svg_root = true;
-                    in_root = true;
-                }
-
-                for dangerous in DANGEROUS_ELEMENTS {
-                    if tag_name.as_ref().eq_ignore_ascii_case(dangerous) {
-                        return false;
-                    }
-                }
-
-                for attr in event.attributes().filter_map(Result::ok) {
-                    let key = attr.key.as_ref();
-                    let value = attr.value.as_ref();
-
-                    if key.len() >= 2 && key[..2].eq_ignore_ascii_case(b"on") {
-                        return false;
-                    }
-
-                    if (key == b"href" || key == b"xlink:href")
-                        && value.len() >= 11
-                        && value[..11].eq_ignore_ascii_case(b"javascript:")
-                    {
-                        return false;
-                    }
-
-                    if (key == b"href" || key == b"xlink:href")
-                        && value.len() >= 5
-                        && value[..5].eq_ignore_ascii_case(b"data:")
-                        && (value.len() < 11 || !value[5..11].eq_ignore_ascii_case(b"image/"))
-                    {
-                        return false;
-                    }
-                }
+                let has_svg_namespace = event.attributes().filter_map(Result::ok).any(|attr| {
+                    (attr.key.as_ref() == b"xmlns" || attr.key.local_name().as_ref() == b"xmlns")
+                        && attr.value.as_ref() == SVG_NAMESPACE
+                });
+                return event.name().as_ref() == b"svg" && has_svg_namespace;
             }
+            Ok(Event::Eof) | Err(_) => return false,
             Ok(_) => {}
-            Err(_) => return false,
         }
         buf.clear();
     }
+}
+
+/// Parses the crop rectangle ("x,y,width,height") or focal point ("x,y") provided.
+fn parse_crop(crop_rect: Option<&str>, focal_point: Option<&str>) -> Result<Option<ImageCrop>> {
+    let crop_rect = crop_rect.map(str::trim).filter(|value| !value.is_empty());
+    let focal_point = focal_point.map(str::trim).filter(|value| !value.is_empty());
+
+    match (crop_rect, focal_point) {
+        (None, None) => Ok(None),
+        (Some(_), Some(_)) => Err(anyhow!("crop and focal point cannot be used together")),
+        (Some(crop_rect), None) => {
+            let values = crop_rect
+                .split(',')
+                .map(|value| value.trim().parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_default();
+            let Ok([x, y, width, height]) = <[u32; 4]>::try_from(values) else {
+                return Err(anyhow!("invalid crop, expected x,y,width,height"));
+            };
+            Ok(Some(ImageCrop::Rect { x, y, width, height }))
+        }
+        (None, Some(focal_point)) => {
+            let values = focal_point
+                .split(',')
+                .map(|value| value.trim().parse::<f64>())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_default();
+            let Ok([x, y]) = <[f64; 2]>::try_from(values) else {
+                return Err(anyhow!("invalid focal point, expected x,y"));
+            };
+            Ok(Some(ImageCrop::FocalPoint { x, y }))
+        }
+    }
+}
+
+/// Returns the image formats accepted by the client, sorted by preference.
+///
+/// Png is always included as the last option, as all clients support it.
+fn preferred_formats(headers: &HeaderMap) -> Vec<ImageFormat> {
+    let accept = headers
+        .get(ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let accepts = |content_type: &str| {
+        accept.split(',').any(|media_range| {
+            let mut parts = media_range.split(';').map(str::trim);
+            parts
+                .next()
+                .is_some_and(|media_type| media_type.eq_ignore_ascii_case(content_type))
+                && !parts.any(|param| {
+                    param
+                        .strip_prefix("q=")
+                        .and_then(|q| q.parse::<f32>().ok())
+                        .is_some_and(|q| q <= 0.0)
+                })
+        })
+    };
+
+    let mut formats = vec![];
+    for format in [ImageFormat::Avif, ImageFormat::Webp] {
+        if accepts(format.content_type()) {
+            formats.push(format);
+        }
+    }
+    formats.push(ImageFormat::Png);
 
-    found_svg_root
+    formats
 }
 
 /// Checks whether the referer header matches the configured site hostname.
@@ -322,8 +502,37 @@ fn validate_image_dimensions(bytes: &[u8], target: ImageTarget) -> Result<()> {
     Ok(())
 }
 
+/// Validates the crop fits in the image, is square and the cropped area is large
+/// enough for the target.
+fn validate_crop(bytes: &[u8], target: ImageTarget, crop: ImageCrop) -> Result<()> {
+    let reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+    let (width, height) = reader.into_dimensions()?;
+    let (_, _, crop_width, crop_height) = crop.rect(width, height)?;
+
+    let (min_width, min_height) = target.dimensions();
+    if crop_width < min_width || crop_height < min_height {
+        return Err(anyhow!(
+            "cropped image dimensions {crop_width}x{crop_height} are smaller than required {min_width}x{min_height}"
+        ));
+    }
+
+    Ok(())
+}
+
 // Types
 
+/// Crop input used to crop again an existing image.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CropInput {
+    /// Image target the cropped image is for.
+    target: String,
+
+    /// Crop rectangle ("x,y,width,height").
+    crop: Option<String>,
+    /// Focal point ("x,y", relative to the image dimensions).
+    focal_point: Option<String>,
+}
+
 /// Image target defining expected dimensions.
 #[derive(Clone, Copy, Debug)]
 enum ImageTarget {
@@ -372,7 +581,9 @@ mod tests {
         body::{Body, to_bytes},
         http::{
             HeaderMap, HeaderValue, Request, StatusCode,
-            header::{CACHE_CONTROL, CONTENT_TYPE, COOKIE, REFERER},
+            header::{
+                CACHE_CONTROL, CONTENT_SECURITY_POLICY, CONTENT_TYPE, COOKIE, ETAG, IF_NONE_MATCH, REFERER,
+            },
         },
     };
     use axum_login::tower_sessions::session;
@@ -385,7 +596,7 @@ mod tests {
         handlers::tests::{
             TestRouterBuilder, sample_auth_user, sample_image, sample_session_record, test_http_server_cfg,
         },
-        img::{ImageFormat, MockImageStore},
+        img::{ImageCrop, ImageFormat, MockImageStore, ORIGINAL_VERSION},
         notifications::MockNotificationsManager,
     };
 
@@ -401,6 +612,161 @@ mod tests {
         br#"<svg xmlns="http://www.w3.org/2000/svg"><circle cx="50" cy="50" r="40"/></svg>"#;
     const SVG_BYTES_UNSAFE: &[u8] =
         br#"<svg xmlns="http://www.w3.org/2000/svg"><script>alert(1)</script></svg>"#;
+    const SVG_BYTES_EXTERNAL_ENTITY: &[u8] = br#"<!DOCTYPE svg [<!ENTITY x SYSTEM "file:///etc/passwd">]><svg xmlns="http://www.w3.org/2000/svg"><text>&x;</text></svg>"#;
+
+    #[tokio::test]
+    async fn test_crop_returns_new_image_id_when_original_is_available() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let image_id = Uuid::new_v4();
+        let cropped_image_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let original = png_bytes(600, 400);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+        db.expect_user_has_image_access()
+            .times(1)
+            .withf(move |user, image| *user == user_id && *image == image_id)
+            .returning(|_, _| Ok(true));
+
+        // Setup image store mock
+        let original_for_mock = original.clone();
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_get()
+            .times(1)
+            .withf(move |id, version| *id == image_id && version == ORIGINAL_VERSION)
+            .returning(move |_, _| {
+                let original = original_for_mock.clone();
+                Box::pin(async move { Ok(Some((original, ImageFormat::Png))) })
+            });
+        image_store
+            .expect_save()
+            .times(1)
+            .withf(move |id, _, data, crop| {
+                *id == user_id
+                    && data == &original
+                    && *crop
+                        == Some(ImageCrop::Rect {
+                            x: 100,
+                            y: 0,
+                            width: 400,
+                            height: 400,
+                        })
+            })
+            .returning(move |_, _, _, _| Box::pin(async move { Ok(cropped_image_id) }));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/dashboard/images/{image_id}/crop"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(REFERER, "http://localhost:9000/dashboard")
+            .body(Body::from("target=photo&crop=100,0,400,400"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(bytes.as_ref(), cropped_image_id.to_string().as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_crop_returns_unprocessable_entity_when_original_is_not_available() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let image_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+        db.expect_user_has_image_access()
+            .times(1)
+            .withf(move |user, image| *user == user_id && *image == image_id)
+            .returning(|_, _| Ok(true));
+
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_get()
+            .times(1)
+            .withf(move |id, version| *id == image_id && version == ORIGINAL_VERSION)
+            .returning(|_, _| Box::pin(async { Ok(Some((b"<svg></svg>".to_vec(), ImageFormat::Svg))) }));
+        image_store.expect_save().never();
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/dashboard/images/{image_id}/crop"))
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(REFERER, "http://localhost:9000/dashboard")
+            .body(Body::from("target=logo&focal_point=0.5,0.5"))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(bytes, "original image not available");
+    }
+
+    #[tokio::test]
+    async fn test_get_dashboard_image_returns_not_found_for_original_version() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let image_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+        db.expect_user_has_image_access()
+            .times(1)
+            .withf(move |user, image| *user == user_id && *image == image_id)
+            .returning(|_, _| Ok(true));
+
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store.expect_get().never();
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/dashboard/images/{image_id}/original"))
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 
     #[tokio::test]
     async fn test_get_dashboard_image_returns_svg_for_authorized_user() {
@@ -452,6 +818,10 @@ mod tests {
         // Check response matches expectations
         assert_eq!(parts.status, StatusCode::OK);
         assert_eq!(parts.headers[CONTENT_TYPE], "image/svg+xml");
+        assert_eq!(
+            parts.headers[CONTENT_SECURITY_POLICY],
+            CONTENT_SECURITY_POLICY_SVG
+        );
         assert!(!bytes.is_empty());
     }
 
@@ -556,9 +926,157 @@ mod tests {
         assert_eq!(parts.status, StatusCode::OK);
         assert_eq!(parts.headers[CACHE_CONTROL], CACHE_CONTROL_IMMUTABLE);
         assert_eq!(parts.headers[CONTENT_TYPE], "image/png");
+        assert_eq!(parts.headers[ETAG], image_etag(&sample_image().0).unwrap());
         assert!(!bytes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_get_public_image_returns_not_modified_when_etag_matches() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+        let etag = image_etag(&sample_image().0).unwrap();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_is_image_public()
+            .times(1)
+            .withf(move |id| *id == image_id)
+            .returning(|_| Ok(true));
+
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_get()
+            .times(1)
+            .withf(move |id, version| *id == image_id && version == "small")
+            .returning(|_, _| Box::pin(async { Ok(Some(sample_image())) }));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/jobboard/images/{image_id}/small"))
+            .header(IF_NONE_MATCH, format!("\"other\", {}", etag.to_str().unwrap()))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::NOT_MODIFIED);
+        assert_eq!(parts.headers[ETAG], etag);
+        assert_eq!(parts.headers[CACHE_CONTROL], CACHE_CONTROL_IMMUTABLE);
+        assert!(bytes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_public_image_returns_preferred_format_available() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_is_image_public()
+            .times(1)
+            .withf(move |id| *id == image_id)
+            .returning(|_| Ok(true));
+
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_get()
+            .times(1)
+            .withf(move |id, version| *id == image_id && version == "small@2x.avif")
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        image_store
+            .expect_get()
+            .times(1)
+            .withf(move |id, version| *id == image_id && version == "small@2x.webp")
+            .returning(|_, _| Box::pin(async { Ok(Some((vec![1, 2, 3], ImageFormat::Webp))) }));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/jobboard/images/{image_id}/small@2x"))
+            .header(ACCEPT, "image/avif,image/webp,image/*;q=0.8")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(parts.headers[CONTENT_TYPE], "image/webp");
+        assert_eq!(parts.headers[VARY], "Accept");
+        assert_eq!(bytes.as_ref(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_crop_parses_focal_point() {
+        let crop = parse_crop(None, Some("0.25, 0.75")).unwrap();
+        assert_eq!(crop, Some(ImageCrop::FocalPoint { x: 0.25, y: 0.75 }));
+    }
+
+    #[test]
+    fn test_parse_crop_rejects_crop_and_focal_point_together() {
+        let error = parse_crop(Some("0,0,400,400"), Some("0.5,0.5")).unwrap_err();
+        assert_eq!(error.to_string(), "crop and focal point cannot be used together");
+    }
+
+    #[test]
+    fn test_parse_crop_rejects_invalid_rect() {
+        let error = parse_crop(Some("0,0,400"), None).unwrap_err();
+        assert_eq!(error.to_string(), "invalid crop, expected x,y,width,height");
+    }
+
+    #[test]
+    fn test_parse_crop_returns_none_when_empty() {
+        assert_eq!(parse_crop(Some(""), None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_preferred_formats_ignores_rejected_formats() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static("image/avif;q=0, image/webp;q=0.9"),
+        );
+
+        assert_eq!(
+            preferred_formats(&headers),
+            vec![ImageFormat::Webp, ImageFormat::Png]
+        );
+    }
+
+    #[test]
+    fn test_preferred_formats_defaults_to_png() {
+        assert_eq!(preferred_formats(&HeaderMap::new()), vec![ImageFormat::Png]);
+    }
+
+    #[test]
+    fn test_etag_matches_accepts_weak_and_wildcard_tags() {
+        let etag = HeaderValue::from_static("\"abc\"");
+        let headers_with = |value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(IF_NONE_MATCH, HeaderValue::from_static(value));
+            headers
+        };
+
+        assert!(etag_matches(&headers_with("W/\"abc\""), &etag));
+        assert!(etag_matches(&headers_with("*"), &etag));
+        assert!(!etag_matches(&headers_with("\"abcd\""), &etag));
+        assert!(!etag_matches(&HeaderMap::new(), &etag));
+    }
+
     #[test]
     fn test_image_extension_extracts_lowercase_extension() {
         let extension = image_extension("avatar.SVG").unwrap();
@@ -577,8 +1095,8 @@ mod tests {
     }
 
     #[test]
-    fn test_is_svg_rejects_script_element() {
-        assert!(!is_svg(SVG_BYTES_UNSAFE, "svg"));
+    fn test_is_svg_rejects_missing_namespace() {
+        assert!(!is_svg(br#"<svg><circle r="1"/></svg>"#, "svg"));
     }
 
     #[test]
@@ -603,6 +1121,56 @@ mod tests {
         assert!(matches);
     }
 
+    #[tokio::test]
+    async fn test_upload_applies_focal_point_to_non_square_images() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let image_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let boundary = "X-BOUNDARY";
+        let body = build_multipart_body_with_fields(
+            boundary,
+            "avatar.png",
+            "image/png",
+            &png_bytes(600, 400),
+            &[("target", "photo"), ("focal_point", "0.3,0.5")],
+        );
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_save()
+            .times(1)
+            .withf(move |id, _, _, crop| {
+                *id == user_id && *crop == Some(ImageCrop::FocalPoint { x: 0.3, y: 0.5 })
+            })
+            .returning(move |_, _, _, _| Box::pin(async move { Ok(image_id) }));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/dashboard/images")
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+            .header(REFERER, "http://localhost:9000/dashboard")
+            .body(Body::from(body))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn test_upload_allows_missing_referer_when_checks_disabled() {
         // Setup identifiers and data structures
@@ -629,10 +1197,10 @@ mod tests {
         image_store
             .expect_save()
             .times(1)
-            .withf(move |id, filename, data| {
-                *id == user_id && filename == "avatar.svg" && data == SVG_BYTES_SAFE
+            .withf(move |id, filename, data, crop| {
+                *id == user_id && filename == "avatar.svg" && data == SVG_BYTES_SAFE && crop.is_none()
             })
-            .returning(move |_, _, _| Box::pin(async move { Ok(image_id) }));
+            .returning(move |_, _, _, _| Box::pin(async move { Ok(image_id) }));
 
         // Setup router and send request
         let mut cfg = test_http_server_cfg();
@@ -740,7 +1308,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_upload_rejects_unsafe_svg() {
+    async fn test_upload_rejects_svg_that_cannot_be_sanitized() {
         // Setup identifiers and data structures
         let auth_hash = "hash";
         let session_id = session::Id::default();
@@ -751,7 +1319,7 @@ mod tests {
             boundary,
             "avatar.svg",
             "image/svg+xml",
-            SVG_BYTES_UNSAFE,
+            SVG_BYTES_EXTERNAL_ENTITY,
             Some("logo"),
         );
 
@@ -775,9 +1343,12 @@ mod tests {
             .body(Body::from(body))
             .unwrap();
         let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
 
         // Check response matches expectations
-        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(bytes, "svg image references unknown entities");
     }
 
     #[tokio::test]
@@ -821,6 +1392,56 @@ mod tests {
         assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
 
+    #[tokio::test]
+    async fn test_upload_removes_unsafe_content_from_svg() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let image_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let boundary = "X-BOUNDARY";
+        let body = build_multipart_body(
+            boundary,
+            "avatar.svg",
+            "image/svg+xml",
+            SVG_BYTES_UNSAFE,
+            Some("logo"),
+        );
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+
+        // Setup image store mock
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_save()
+            .times(1)
+            .withf(move |id, _, data, _| {
+                *id == user_id && data == br#"<svg xmlns="http://www.w3.org/2000/svg"></svg>"#
+            })
+            .returning(move |_, _, _, _| Box::pin(async move { Ok(image_id) }));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/dashboard/images")
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+            .header(REFERER, "http://localhost:9000/dashboard")
+            .body(Body::from(body))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn test_upload_returns_bad_request_when_no_file_is_sent() {
         // Setup identifiers and data structures
@@ -878,10 +1499,10 @@ mod tests {
         image_store
             .expect_save()
             .times(1)
-            .withf(move |id, filename, data| {
-                *id == user_id && filename == "avatar.png" && data == &valid_png_for_mock
+            .withf(move |id, filename, data, crop| {
+                *id == user_id && filename == "avatar.png" && data == &valid_png_for_mock && crop.is_none()
             })
-            .returning(move |_, _, _| Box::pin(async move { Ok(image_id) }));
+            .returning(move |_, _, _, _| Box::pin(async move { Ok(image_id) }));
 
         // Setup router and send request
         let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -978,6 +1599,97 @@ mod tests {
         assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
 
+    #[tokio::test]
+    async fn test_upload_returns_unprocessable_entity_when_crop_is_not_square() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let boundary = "X-BOUNDARY";
+        let body = build_multipart_body_with_fields(
+            boundary,
+            "avatar.png",
+            "image/png",
+            &png_bytes(800, 400),
+            &[("target", "photo"), ("crop", "0,0,800,400")],
+        );
+
+        // Setup database and image store mocks
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+        let mut image_store = MockImageStore::new();
+        image_store.expect_save().never();
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/dashboard/images")
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+            .header(REFERER, "http://localhost:9000/dashboard")
+            .body(Body::from(body))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(bytes, "crop rectangle must be square");
+    }
+
+    #[tokio::test]
+    async fn test_upload_returns_unprocessable_entity_when_crop_is_too_small() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let boundary = "X-BOUNDARY";
+        let body = build_multipart_body_with_fields(
+            boundary,
+            "avatar.png",
+            "image/png",
+            &png_bytes(400, 400),
+            &[("target", "photo"), ("crop", "0,0,200,200")],
+        );
+
+        // Setup database and image store mocks
+        let mut db = MockDB::new();
+        setup_authenticated_user(&mut db, auth_hash, session_id, user_id, session_record.clone());
+        let mut image_store = MockImageStore::new();
+        image_store.expect_save().never();
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_image_store(image_store)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/dashboard/images")
+            .header(COOKIE, format!("id={session_id}"))
+            .header(CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+            .header(REFERER, "http://localhost:9000/dashboard")
+            .body(Body::from(body))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            bytes,
+            "cropped image dimensions 200x200 are smaller than required 400x400"
+        );
+    }
+
     #[tokio::test]
     async fn test_upload_returns_unprocessable_entity_when_target_is_missing() {
         // Setup identifiers and data structures
@@ -1036,15 +1748,26 @@ mod tests {
         content_type: &str,
         bytes: &[u8],
         target: Option<&str>,
+    ) -> Vec<u8> {
+        let fields: Vec<(&str, &str)> = target.map(|target| ("target", target)).into_iter().collect();
+        build_multipart_body_with_fields(boundary, file_name, content_type, bytes, &fields)
+    }
+
+    fn build_multipart_body_with_fields(
+        boundary: &str,
+        file_name: &str,
+        content_type: &str,
+        bytes: &[u8],
+        fields: &[(&str, &str)],
     ) -> Vec<u8> {
         let mut body = Vec::new();
 
-        if let Some(target) = target {
+        for (name, value) in fields {
             body.extend_from_slice(
                 format!(
                     "--{boundary}\r\n\
-                     Content-Disposition: form-data; name=\"target\"\r\n\r\n\
-                     {target}\r\n"
+                     Content-Disposition: form-data; name=\"{name}\"\r\n\r\n\
+                     {value}\r\n"
                 )
                 .as_bytes(),
             );
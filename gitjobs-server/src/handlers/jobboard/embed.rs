@@ -29,7 +29,7 @@ pub(crate) async fn jobs_page(
     QsQuery(filters): QsQuery<Filters>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get jobs that match the query
-    let JobsSearchOutput { jobs, total: _ } = db.search_jobs(&filters).await?;
+    let JobsSearchOutput { jobs, .. } = db.search_jobs(&filters, None, false).await?;
 
     // Prepare template
     let template = JobsPage {
@@ -93,7 +93,7 @@ mod tests {
         let mut db = MockDB::new();
         db.expect_search_jobs()
             .times(1)
-            .returning(move |_| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
+            .returning(move |_, _, _| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
 
         // Setup router and send request
         let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -1,10 +1,12 @@
 //! HTTP handlers for the jobs pages.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use askama::Template;
 use axum::{
-    extract::{Json, Path, State},
-    response::{Html, IntoResponse},
+    extract::{Json, Path, Query, State},
+    response::{Html, IntoResponse, Redirect},
 };
 use chrono::Duration;
 use reqwest::StatusCode;
@@ -17,8 +19,8 @@ use crate::{
     auth::AuthSession,
     config::HttpServerConfig,
     db::{DynDB, jobboard::JobsSearchOutput},
-    event_tracker::{DynEventTracker, Event},
-    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError, prepare_headers},
+    event_tracker::{DynEventTracker, Event, ViewReferral},
+    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError, extractors::ClientVisitor, prepare_headers},
     templates::{
         PageId,
         auth::User,
@@ -32,14 +34,18 @@ use crate::{
 /// Returns the main jobs page with filters and results.
 #[instrument(skip_all, err)]
 pub(crate) async fn jobs_page(
+    auth_session: AuthSession,
     session: Session,
     State(db): State<DynDB>,
     State(cfg): State<HttpServerConfig>,
     QsQuery(filters): QsQuery<Filters>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get filter options and jobs that match the query
-    let (filters_options, JobsSearchOutput { jobs, total }) =
-        tokio::try_join!(db.get_jobs_filters_options(), db.search_jobs(&filters))?;
+    let match_user_id = match_user_id(&auth_session);
+    let (filters_options, JobsSearchOutput { jobs, total, facets }) = tokio::try_join!(
+        db.get_jobs_filters_options(),
+        db.search_jobs(&filters, match_user_id, true)
+    )?;
 
     // Prepare template
     let template = JobsPage {
@@ -48,10 +54,12 @@ pub(crate) async fn jobs_page(
         explore_section: ExploreSection {
             filters: filters.clone(),
             filters_options,
+            match_available: match_user_id.is_some(),
             results_section: ResultsSection {
                 jobs,
                 navigation_links: NavigationLinks::from_filters(&filters, total)?,
                 total,
+                facets,
                 offset: filters.offset,
             },
         },
@@ -60,32 +68,42 @@ pub(crate) async fn jobs_page(
     };
 
     // Prepare response headers
-    let headers = prepare_headers(Duration::minutes(10), &[])?;
+    let headers = prepare_headers(cache_duration(match_user_id), &[])?;
 
     Ok((headers, Html(template.render()?)))
 }
 
 /// Returns the results section for filtered jobs.
+///
+/// Facets are only computed when requested explicitly (`facets=true`), which
+/// the filters forms do when submitted. Pagination requests only change the
+/// offset, so the facets already displayed are still valid.
 #[instrument(skip_all, err)]
 pub(crate) async fn results_section(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
+    Query(query): Query<HashMap<String, String>>,
     QsQuery(filters): QsQuery<Filters>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get jobs that match the query
-    let JobsSearchOutput { jobs, total } = db.search_jobs(&filters).await?;
+    let match_user_id = match_user_id(&auth_session);
+    let include_facets = query.get("facets").is_some_and(|facets| facets == "true");
+    let JobsSearchOutput { jobs, total, facets } =
+        db.search_jobs(&filters, match_user_id, include_facets).await?;
 
     // Prepare template
     let template = ResultsSection {
         navigation_links: NavigationLinks::from_filters(&filters, total)?,
         jobs,
         total,
+        facets,
         offset: filters.offset,
     };
 
     // Prepare response headers
     let url = build_url("/", &filters)?;
     let extra_headers = [("HX-Replace-Url", url.as_str())];
-    let headers = prepare_headers(Duration::minutes(10), &extra_headers)?;
+    let headers = prepare_headers(cache_duration(match_user_id), &extra_headers)?;
 
     Ok((headers, Html(template.render()?)))
 }
@@ -102,10 +120,14 @@ pub(crate) async fn job_section(
         return Ok(StatusCode::NOT_FOUND.into_response());
     };
 
+    // Get similar jobs
+    let similar_jobs = db.get_similar_jobs(&job_id).await?;
+
     // Prepare template
     let template = JobSection {
         base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
         job,
+        similar_jobs,
     };
 
     // Prepare response headers
@@ -120,8 +142,10 @@ pub(crate) async fn job_section(
 #[instrument(skip_all, err)]
 pub(crate) async fn apply(
     State(db): State<DynDB>,
+    State(event_tracker): State<DynEventTracker>,
     Path(job_id): Path<Uuid>,
     auth_session: AuthSession,
+    ClientVisitor(visitor): ClientVisitor,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
     let Some(user) = auth_session.user else {
@@ -134,16 +158,46 @@ pub(crate) async fn apply(
         return Ok(StatusCode::CONFLICT);
     }
 
+    // Track apply click
+    event_tracker.track(Event::ApplyClick { job_id }, visitor).await?;
+
     Ok(StatusCode::NO_CONTENT)
 }
 
-/// Tracks a view for a specific job in the job board.
+/// Tracks an apply click for a job and redirects the user to its external
+/// apply URL.
+#[instrument(skip_all, err)]
+pub(crate) async fn apply_redirect(
+    State(db): State<DynDB>,
+    State(event_tracker): State<DynEventTracker>,
+    Path(job_id): Path<Uuid>,
+    ClientVisitor(visitor): ClientVisitor,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get job apply URL
+    let Some(apply_url) = db.get_job_apply_url(&job_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Track apply click
+    event_tracker.track(Event::ApplyClick { job_id }, visitor).await?;
+
+    Ok(Redirect::to(&apply_url).into_response())
+}
+
+/// Tracks a view for a specific job in the job board, along with its traffic
+/// source.
 #[instrument(skip_all, err)]
 pub(crate) async fn track_view(
+    State(cfg): State<HttpServerConfig>,
     State(event_tracker): State<DynEventTracker>,
     Path(job_id): Path<Uuid>,
+    ClientVisitor(visitor): ClientVisitor,
+    QsQuery(referral): QsQuery<ViewReferral>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    event_tracker.track(Event::JobView { job_id }).await?;
+    let source = referral.source(&cfg.base_url);
+    event_tracker
+        .track(Event::JobView { job_id, source }, visitor)
+        .await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -152,17 +206,44 @@ pub(crate) async fn track_view(
 #[instrument(skip_all, err)]
 pub(crate) async fn track_search_appearances(
     State(event_tracker): State<DynEventTracker>,
+    ClientVisitor(visitor): ClientVisitor,
     Json(job_ids): Json<Vec<Uuid>>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    event_tracker.track(Event::SearchAppearances { job_ids }).await?;
+    event_tracker
+        .track(Event::SearchAppearances { job_ids }, visitor)
+        .await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
 
+// Helpers.
+
+/// Returns the cache duration for job search responses. Responses including
+/// job matches are specific to the user, so they must not be cached.
+fn cache_duration(match_user_id: Option<Uuid>) -> Duration {
+    if match_user_id.is_some() {
+        Duration::zero()
+    } else {
+        Duration::minutes(10)
+    }
+}
+
+/// Returns the user jobs should be matched against, if the user is logged in
+/// and has a job seeker profile.
+fn match_user_id(auth_session: &AuthSession) -> Option<Uuid> {
+    auth_session
+        .user
+        .as_ref()
+        .filter(|user| user.has_profile)
+        .map(|user| user.user_id)
+}
+
 // Tests.
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use axum::{
         body::{Body, to_bytes},
         http::{
@@ -179,11 +260,15 @@ mod tests {
         db::mock::MockDB,
         event_tracker::MockEventTracker,
         handlers::tests::{
-            TestRouterBuilder, expect_track_search_appearances, expect_track_view, sample_auth_user,
-            sample_jobboard_filters_options, sample_jobboard_job, sample_jobboard_jobs_output,
-            sample_session_record,
+            TestRouterBuilder, expect_track_apply_click, expect_track_search_appearances, expect_track_view,
+            sample_auth_user, sample_jobboard_filters_options, sample_jobboard_job,
+            sample_jobboard_job_summary, sample_jobboard_jobs_output, sample_session_record,
         },
         notifications::MockNotificationsManager,
+        templates::{
+            jobboard::jobs::{Facets, Sort},
+            misc::{JobMatch, LocationFit},
+        },
     };
 
     #[tokio::test]
@@ -199,7 +284,7 @@ mod tests {
             .returning(|| Ok(sample_jobboard_filters_options()));
         db.expect_search_jobs()
             .times(1)
-            .returning(move |_| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
+            .returning(move |_, _, _| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
 
         // Setup router and send request
         let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -217,6 +302,139 @@ mod tests {
         assert!(!bytes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_jobs_page_filters_by_landscape_category() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_jobs_filters_options()
+            .times(1)
+            .returning(|| Ok(sample_jobboard_filters_options()));
+        db.expect_search_jobs()
+            .times(1)
+            .withf(|filters, _, _| filters.category.as_deref() == Some("Observability"))
+            .returning(move |_, _, _| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/?category=Observability")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains(r#"name="category""#));
+        assert!(html.contains("Observability"));
+    }
+
+    #[tokio::test]
+    async fn test_jobs_page_filters_by_timezone() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_jobs_filters_options()
+            .times(1)
+            .returning(|| Ok(sample_jobboard_filters_options()));
+        db.expect_search_jobs()
+            .times(1)
+            .withf(|filters, _, _| {
+                filters.tz_start.as_deref() == Some("UTC+1") && filters.tz_end.as_deref() == Some("UTC-3")
+            })
+            .returning(move |_, _, _| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/?tz_start=UTC%2B1&tz_end=UTC-3")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains(r#"name="tz_start""#));
+        assert!(html.contains(r#"name="tz_end""#));
+        assert!(html.contains(r#"value="UTC+14""#));
+        assert!(html.contains(r#"value="UTC-12""#));
+    }
+
+    #[tokio::test]
+    async fn test_jobs_page_shows_job_match_for_job_seekers() {
+        // Setup identifiers and data structures
+        let auth_hash = "hash";
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let session_id = session::Id::default();
+        let user_id = Uuid::new_v4();
+        let session_record = sample_session_record(session_id, user_id, auth_hash, None);
+        let mut jobs_output = sample_jobboard_jobs_output(job_id, employer_id);
+        jobs_output.jobs[0].job_match = Some(JobMatch {
+            location_fit: LocationFit::Remote,
+            matched_skills: vec!["rust".to_string()],
+            missing_skills: vec!["sql".to_string()],
+            score: 65,
+        });
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_session()
+            .times(1)
+            .withf(move |id| *id == session_id)
+            .returning(move |_| Ok(Some(session_record.clone())));
+        db.expect_get_user_by_id()
+            .times(1)
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(Some(sample_auth_user(user_id, auth_hash))));
+        db.expect_get_jobs_filters_options()
+            .times(1)
+            .returning(|| Ok(sample_jobboard_filters_options()));
+        db.expect_search_jobs()
+            .times(1)
+            .withf(move |filters, id, _| filters.sort == Some(Sort::Match) && *id == Some(user_id))
+            .returning(move |_, _, _| Ok(jobs_output.clone()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/?sort=match")
+            .header(COOKIE, format!("id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains(r#"<option value="match""#));
+        assert!(html.contains("65% match"));
+        assert!(html.contains("Skills you are missing"));
+    }
+
     #[tokio::test]
     async fn test_results_section_returns_html() {
         // Setup identifiers and data structures
@@ -227,7 +445,7 @@ mod tests {
         let mut db = MockDB::new();
         db.expect_search_jobs()
             .times(1)
-            .returning(move |_| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
+            .returning(move |_, _, _| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
 
         // Setup router and send request
         let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -249,6 +467,78 @@ mod tests {
         assert!(!bytes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_results_section_includes_facets() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let mut jobs_output = sample_jobboard_jobs_output(job_id, employer_id);
+        jobs_output.facets = Some(Facets {
+            kind: BTreeMap::from([("full-time".to_string(), 3)]),
+            workplace: BTreeMap::from([("remote".to_string(), 2)]),
+            ..Default::default()
+        });
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_search_jobs()
+            .times(1)
+            .withf(|_, user_id, include_facets| user_id.is_none() && *include_facets)
+            .returning(move |_, _, _| Ok(jobs_output.clone()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/section/jobs/results?facets=true&workplace[0]=remote")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains(r#"id="results-facets""#));
+        assert!(html.contains("&#34;full-time&#34;:3"));
+    }
+
+    #[tokio::test]
+    async fn test_results_section_skips_facets_when_not_requested() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_search_jobs()
+            .times(1)
+            .withf(|filters, _, include_facets| filters.offset == Some(20) && !*include_facets)
+            .returning(move |_, _, _| Ok(sample_jobboard_jobs_output(job_id, employer_id)));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/section/jobs/results?workplace[0]=remote&offset=20")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(!html.contains(r#"id="results-facets""#));
+        assert!(!parts.headers["HX-Replace-Url"].to_str().unwrap().contains("facets"));
+    }
+
     #[tokio::test]
     async fn test_job_section_returns_not_found_when_job_is_missing() {
         // Setup identifiers and data structures
@@ -288,6 +578,10 @@ mod tests {
             .times(1)
             .withf(move |id| *id == job_id)
             .returning(move |_| Ok(Some(sample_jobboard_job(job_id, employer_id))));
+        db.expect_get_similar_jobs()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(|_| Ok(vec![]));
 
         // Setup router and send request
         let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
@@ -309,6 +603,81 @@ mod tests {
         assert!(!bytes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_job_section_shows_project_landscape_details() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_job_jobboard()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(move |_| Ok(Some(sample_jobboard_job(job_id, employer_id))));
+        db.expect_get_similar_jobs()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(|_| Ok(vec![]));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/section/jobs/{job_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains("Scheduling"));
+        assert!(html.contains("Production-grade container orchestration"));
+    }
+
+    #[tokio::test]
+    async fn test_job_section_shows_similar_jobs() {
+        // Setup identifiers and data structures
+        let employer_id = Uuid::new_v4();
+        let job_id = Uuid::new_v4();
+        let similar_job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_job_jobboard()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(move |_| Ok(Some(sample_jobboard_job(job_id, employer_id))));
+        db.expect_get_similar_jobs()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(move |_| Ok(vec![sample_jobboard_job_summary(similar_job_id, employer_id)]));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/section/jobs/{job_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert!(html.contains("Similar jobs"));
+        assert!(html.contains(&format!("/?job_id={similar_job_id}")));
+    }
+
     #[tokio::test]
     async fn test_apply_returns_no_content_when_application_is_created() {
         // Setup identifiers and data structures
@@ -333,8 +702,13 @@ mod tests {
             .withf(move |id, user| *id == job_id && *user == user_id)
             .returning(|_, _| Ok(true));
 
+        // Setup event tracker mock
+        let mut event_tracker = MockEventTracker::new();
+        expect_track_apply_click(&mut event_tracker, job_id);
+
         // Setup router and send request
         let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_event_tracker(event_tracker)
             .build()
             .await;
         let request = Request::builder()
@@ -414,6 +788,67 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_apply_redirect_redirects_to_apply_url_and_tracks_click() {
+        // Setup identifiers and data structures
+        let apply_url = "https://example.com/apply";
+        let job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_job_apply_url()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(move |_| Ok(Some(apply_url.to_string())));
+
+        // Setup event tracker mock
+        let mut event_tracker = MockEventTracker::new();
+        expect_track_apply_click(&mut event_tracker, job_id);
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_event_tracker(event_tracker)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/jobs/{job_id}/apply-redirect"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], apply_url);
+    }
+
+    #[tokio::test]
+    async fn test_apply_redirect_returns_not_found_when_job_has_no_apply_url() {
+        // Setup identifiers and data structures
+        let job_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_job_apply_url()
+            .times(1)
+            .withf(move |id| *id == job_id)
+            .returning(|_| Ok(None));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/jobs/{job_id}/apply-redirect"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_track_view_returns_no_content() {
         // Setup identifiers and data structures
@@ -421,7 +856,7 @@ mod tests {
 
         // Setup event tracker mock
         let mut event_tracker = MockEventTracker::new();
-        expect_track_view(&mut event_tracker, job_id);
+        expect_track_view(&mut event_tracker, job_id, "direct");
 
         // Setup router and send request
         let db = MockDB::new();
@@ -440,6 +875,34 @@ mod tests {
         assert_eq!(response.status(), StatusCode::NO_CONTENT);
     }
 
+    #[tokio::test]
+    async fn test_track_view_tracks_embed_traffic_source() {
+        // Setup identifiers and data structures
+        let job_id = Uuid::new_v4();
+
+        // Setup event tracker mock
+        let mut event_tracker = MockEventTracker::new();
+        expect_track_view(&mut event_tracker, job_id, "embed:cncf.io");
+
+        // Setup router and send request
+        let db = MockDB::new();
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .with_event_tracker(event_tracker)
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!(
+                "/jobs/{job_id}/views?referrer=https%3A%2F%2Fwww.cncf.io%2F&utm_medium=embed&utm_source=www.cncf.io"
+            ))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        // Check response matches expectations
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
     #[tokio::test]
     async fn test_track_search_appearances_returns_no_content() {
         // Setup identifiers and data structures
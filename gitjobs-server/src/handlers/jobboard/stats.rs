@@ -4,7 +4,7 @@ use anyhow::Result;
 use askama::Template;
 use axum::{
     extract::State,
-    response::{Html, IntoResponse},
+    response::{Html, IntoResponse, Json},
 };
 use chrono::Duration;
 use tower_sessions::Session;
@@ -42,6 +42,18 @@ pub(crate) async fn page(
     Ok((headers, Html(template.render()?)))
 }
 
+/// Handler that returns the stats in JSON format.
+#[instrument(skip_all, err)]
+pub(crate) async fn data(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    // Get stats information from the database
+    let stats = db.get_stats().await?;
+
+    // Prepare response headers
+    let headers = prepare_headers(Duration::hours(1), &[])?;
+
+    Ok((headers, Json(stats)))
+}
+
 // Tests.
 
 #[cfg(test)]
@@ -88,4 +100,40 @@ mod tests {
         assert_eq!(parts.headers[CONTENT_TYPE], "text/html; charset=utf-8");
         assert!(!bytes.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_data_returns_stats_as_json() {
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_stats()
+            .times(1)
+            .returning(|| Ok(sample_jobboard_stats()));
+
+        // Setup router and send request
+        let router = TestRouterBuilder::new(db, MockNotificationsManager::new())
+            .build()
+            .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/stats.json")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let stats: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        // Check response matches expectations
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(parts.headers[CACHE_CONTROL], "max-age=0");
+        assert_eq!(parts.headers[CONTENT_TYPE], "application/json");
+        assert_eq!(
+            stats["jobs"]["salary_median_per_seniority"],
+            serde_json::json!([["senior", 135_000]])
+        );
+        assert_eq!(
+            stats["jobs"]["skills_monthly"],
+            serde_json::json!([["kubernetes", [[1_704_067_200_000_u64, 2]]]])
+        );
+    }
 }
@@ -13,6 +13,8 @@ pub(crate) mod dashboard;
 pub(crate) mod error;
 /// Custom extractors for HTTP handlers.
 pub(crate) mod extractors;
+/// Health checks and metrics HTTP handlers.
+pub(crate) mod health;
 /// Image-related HTTP handlers.
 pub(crate) mod img;
 /// Job board HTTP handlers.
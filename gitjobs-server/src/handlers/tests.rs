@@ -5,7 +5,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use axum::Router;
 use axum_login::tower_sessions::session;
-use chrono::{TimeZone, Utc};
+use chrono::{NaiveDate, TimeZone, Utc};
 use serde_json::json;
 use serde_qs::Config;
 use time::{Duration as TimeDuration, OffsetDateTime};
@@ -13,7 +13,7 @@ use uuid::Uuid;
 
 use crate::{
     auth::User as AuthUser,
-    config::{CookieConfig, HttpServerConfig, LoginOptions},
+    config::{CookieConfig, HttpServerConfig, LoginOptions, ModerationConfig},
     db::{DynDB, dashboard::employer::ApplicationsSearchOutput, jobboard::JobsSearchOutput, mock::MockDB},
     event_tracker::{Event, MockEventTracker},
     handlers::auth::{AUTH_PROVIDER_KEY, SELECTED_EMPLOYER_ID_KEY},
@@ -23,13 +23,21 @@ use crate::{
     templates::{
         dashboard::{
             employer::{
+                analytics::{DailyJobFunnel, EmployerAnalytics, Funnel, JobFunnel},
                 applications::FiltersOptions as ApplicationsFiltersOptions,
                 employers::{Employer, EmployerSummary},
                 jobs::{Job, JobKind, JobStats, JobStatus, JobSummary, Workplace},
                 team::{TeamInvitation, TeamMember},
             },
             job_seeker::{applications::Application as JobSeekerApplication, profile::JobSeekerProfile},
-            moderator::jobs::{Employer as ModeratorEmployer, JobSummary as ModeratorJobSummary},
+            moderator::{
+                accounts::{
+                    Employer as ModeratorAccountsEmployer, ModerationAction, ModerationActionKind,
+                    User as ModeratorAccountsUser,
+                },
+                jobs::{Employer as ModeratorEmployer, JobSummary as ModeratorJobSummary},
+                stats::{PendingJob, ReviewsStats, Stats as ModeratorStats},
+            },
         },
         jobboard::{
             jobs::{
@@ -122,11 +130,13 @@ pub(crate) fn message_matches(record: &session::Record, expected_message: &str)
 pub(crate) fn sample_auth_user(user_id: Uuid, auth_hash: &str) -> AuthUser {
     AuthUser {
         auth_hash: auth_hash.as_bytes().to_vec(),
+        admin: false,
         email: "user@example.test".to_string(),
         email_verified: true,
         has_profile: true,
         moderator: false,
         name: "Test User".to_string(),
+        suspended: false,
         user_id,
         username: "test-user".to_string(),
 
@@ -173,6 +183,33 @@ pub(crate) fn sample_employer_summary(employer_id: Uuid) -> EmployerSummary {
     }
 }
 
+/// Sample employer analytics with a single job and day of activity.
+pub(crate) fn sample_employer_analytics(job_id: Uuid) -> EmployerAnalytics {
+    let funnel = Funnel {
+        applications: 2,
+        apply_clicks: 4,
+        search_appearances: 40,
+        views: 10,
+    };
+    let title = "Rust Engineer, Platform".to_string();
+
+    EmployerAnalytics {
+        daily: vec![DailyJobFunnel {
+            day: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            funnel,
+            job_id,
+            title: title.clone(),
+        }],
+        jobs: vec![JobFunnel {
+            funnel,
+            job_id,
+            status: JobStatus::Published,
+            title,
+        }],
+        totals: funnel,
+    }
+}
+
 /// Sample employer application list output.
 pub(crate) fn sample_employer_applications_output() -> ApplicationsSearchOutput {
     ApplicationsSearchOutput {
@@ -300,6 +337,7 @@ pub(crate) fn sample_jobboard_employer(employer_id: Uuid) -> JobboardEmployer {
 pub(crate) fn sample_jobboard_filters_options() -> FiltersOptions {
     FiltersOptions {
         foundations: sample_foundations(),
+        project_categories: vec!["Observability".to_string()],
     }
 }
 
@@ -342,6 +380,8 @@ pub(crate) fn sample_jobboard_jobs_output(job_id: Uuid, employer_id: Uuid) -> Jo
     JobsSearchOutput {
         jobs: vec![sample_jobboard_job_summary(job_id, employer_id)],
         total: 1,
+
+        facets: None,
     }
 }
 
@@ -355,6 +395,7 @@ pub(crate) fn sample_jobboard_job_summary(job_id: Uuid, employer_id: Uuid) -> Jo
         title: "Rust Engineer".to_string(),
         workplace: Workplace::Remote,
 
+        job_match: None,
         location: Some(sample_location()),
         open_source: Some(5),
         projects: Some(vec![sample_project()]),
@@ -376,7 +417,12 @@ pub(crate) fn sample_jobboard_stats() -> Stats {
         jobs: JobsStats {
             published_per_foundation: Some(vec![("CNCF".to_string(), 1)]),
             published_per_month: Some(vec![("2024".to_string(), "01".to_string(), 1)]),
+            published_per_workplace: Some(vec![("remote".to_string(), 1)]),
             published_running_total: Some(vec![(1_704_067_200_000, 1)]),
+            salary_median_per_seniority: Some(vec![("senior".to_string(), 135_000)]),
+            salary_median_per_workplace: Some(vec![("remote".to_string(), 135_000)]),
+            skills_monthly: Some(vec![("kubernetes".to_string(), vec![(1_704_067_200_000, 2)])]),
+            top_projects: Some(vec![("cncf".to_string(), "Kubernetes".to_string(), 1)]),
             views_daily: Some(vec![(1_704_067_200_000, 5)]),
             views_monthly: Some(vec![(1_704_067_200_000, 20)]),
         },
@@ -389,9 +435,14 @@ pub(crate) fn sample_jobboard_stats() -> Stats {
 /// Sample job stats used by employer dashboard job stats handler.
 pub(crate) fn sample_job_stats() -> JobStats {
     JobStats {
+        apply_clicks_daily: Some(vec![(1_704_067_200_000, 3)]),
+        apply_clicks_total_last_month: 3,
         search_appearances_daily: Some(vec![(1_704_067_200_000, 10)]),
+        search_appearances_total: 120,
         search_appearances_total_last_month: 10,
         views_daily: Some(vec![(1_704_067_200_000, 8)]),
+        views_sources_last_month: Some(vec![("direct".to_string(), 5), ("search".to_string(), 3)]),
+        views_total: 96,
         views_total_last_month: 8,
     }
 }
@@ -418,6 +469,56 @@ pub(crate) fn sample_member() -> Member {
     }
 }
 
+/// Sample moderation action used by moderator handlers.
+pub(crate) fn sample_moderation_action(kind: ModerationActionKind) -> ModerationAction {
+    ModerationAction {
+        created_at: Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap(),
+        kind,
+        moderation_action_id: Uuid::new_v4(),
+
+        employer_company: Some("Example Corp".to_string()),
+        expires_at: None,
+        job_title: Some("Rust Engineer".to_string()),
+        moderator_name: Some("Moderator".to_string()),
+        reason: Some("Spam".to_string()),
+        user_name: Some("Test User".to_string()),
+    }
+}
+
+/// Sample employer used by moderator accounts handlers.
+pub(crate) fn sample_moderator_accounts_employer(employer_id: Uuid) -> ModeratorAccountsEmployer {
+    ModeratorAccountsEmployer {
+        company: "Example Corp".to_string(),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap(),
+        employer_id,
+        published_jobs: 3,
+        suspended: false,
+
+        logo_id: Some(Uuid::new_v4()),
+        suspended_at: None,
+        suspended_until: None,
+        suspension_reason: None,
+        website_url: Some("https://example.test".to_string()),
+    }
+}
+
+/// Sample user used by moderator accounts handlers.
+pub(crate) fn sample_moderator_accounts_user(user_id: Uuid) -> ModeratorAccountsUser {
+    ModeratorAccountsUser {
+        created_at: Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap(),
+        email: "user@example.test".to_string(),
+        moderator: false,
+        name: "Test User".to_string(),
+        suspended: true,
+        user_id,
+        username: "test-user".to_string(),
+
+        suspended_at: Some(Utc.with_ymd_and_hms(2024, 1, 3, 12, 0, 0).unwrap()),
+        suspended_until: None,
+        suspension_reason: Some("Spam".to_string()),
+    }
+}
+
 /// Sample moderator job summary used by moderator handlers.
 pub(crate) fn sample_moderator_job_summary(job_id: Uuid, employer_id: Uuid) -> ModeratorJobSummary {
     ModeratorJobSummary {
@@ -434,6 +535,31 @@ pub(crate) fn sample_moderator_job_summary(job_id: Uuid, employer_id: Uuid) -> M
     }
 }
 
+/// Sample moderation stats used by the moderator stats page handler.
+pub(crate) fn sample_moderator_stats() -> ModeratorStats {
+    ModeratorStats {
+        reviews: ReviewsStats {
+            pending: 2,
+            pending_over_sla: 1,
+            decisions_per_moderator: Some(vec![("Moderator".to_string(), 3, 1)]),
+            decisions_weekly: Some(vec![(1_704_067_200_000, 3, 1)]),
+            median_review_time: Some(93_600),
+            median_review_time_weekly: Some(vec![(1_704_067_200_000, 93_600)]),
+            oldest_pending: Some(vec![PendingJob {
+                company: "Example Corp".to_string(),
+                job_id: Uuid::new_v4(),
+                over_sla: true,
+                review_requested_at: Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap(),
+                title: "Rust Engineer".to_string(),
+            }]),
+            pending_daily: Some(vec![(1_704_067_200_000, 2)]),
+        },
+        review_sla_hours: 48,
+        ts_now: 1_706_745_600_000,
+        ts_three_months_ago: 1_698_796_800_000,
+    }
+}
+
 /// Sample project used by job objects.
 pub(crate) fn sample_project() -> Project {
     Project {
@@ -442,6 +568,12 @@ pub(crate) fn sample_project() -> Project {
         maturity: "graduated".to_string(),
         name: "Kubernetes".to_string(),
         project_id: Uuid::new_v4(),
+
+        category: Some("Orchestration & Management".to_string()),
+        description: Some("Production-grade container orchestration".to_string()),
+        homepage_url: Some("https://kubernetes.io".to_string()),
+        repository_url: Some("https://github.com/kubernetes/kubernetes".to_string()),
+        subcategory: Some("Scheduling & Orchestration".to_string()),
     }
 }
 
@@ -503,11 +635,14 @@ pub(crate) fn test_http_server_cfg() -> HttpServerConfig {
             linuxfoundation: false,
         },
         oauth2: HashMap::new(),
+        moderation: ModerationConfig::default(),
         oidc: HashMap::new(),
+        trusted_proxies: 0,
 
         analytics: None,
         basic_auth: None,
         cookie: Some(CookieConfig { secure: Some(false) }),
+        metrics_addr: None,
         slack_webhook_url: None,
     }
 }
@@ -516,23 +651,39 @@ pub(crate) fn test_http_server_cfg() -> HttpServerConfig {
 pub(crate) fn expect_track_search_appearances(event_tracker: &mut MockEventTracker, job_ids: Vec<Uuid>) {
     event_tracker
         .expect_track()
-        .withf(move |event| {
+        .withf(move |event, _| {
             *event
                 == Event::SearchAppearances {
                     job_ids: job_ids.clone(),
                 }
         })
         .times(1)
-        .returning(|_| Box::pin(async { Ok(()) }));
+        .returning(|_, _| Box::pin(async { Ok(()) }));
+}
+
+/// Configures mocks for tracking a single apply click.
+pub(crate) fn expect_track_apply_click(event_tracker: &mut MockEventTracker, job_id: Uuid) {
+    event_tracker
+        .expect_track()
+        .withf(move |event, _| *event == Event::ApplyClick { job_id })
+        .times(1)
+        .returning(|_, _| Box::pin(async { Ok(()) }));
 }
 
 /// Configures mocks for tracking a single job view.
-pub(crate) fn expect_track_view(event_tracker: &mut MockEventTracker, job_id: Uuid) {
+pub(crate) fn expect_track_view(event_tracker: &mut MockEventTracker, job_id: Uuid, source: &str) {
+    let source = source.to_string();
     event_tracker
         .expect_track()
-        .withf(move |event| *event == Event::JobView { job_id })
+        .withf(move |event, _| {
+            *event
+                == Event::JobView {
+                    job_id,
+                    source: source.clone(),
+                }
+        })
         .times(1)
-        .returning(|_| Box::pin(async { Ok(()) }));
+        .returning(|_, _| Box::pin(async { Ok(()) }));
 }
 
 /// Verifies an enqueued notification payload.
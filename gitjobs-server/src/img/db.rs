@@ -1,49 +1,70 @@
 //! This module implements a database-backed image store.
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
+    config::ImageProcessingConfig,
     db::img::DynDBImage,
-    img::{ImageFormat, ImageStore, ImageVersion, generate_versions, is_svg},
+    img::{
+        CleanupSummary, ImageCrop, ImageFormat, ImageStore, MigrationSummary, content_hash, prepare_versions,
+    },
 };
 
 /// Database-backed image store implementation.
 pub(crate) struct DbImageStore {
     /// Database image interface for storing and retrieving images.
     db: DynDBImage,
+    /// Configuration used when generating the image versions.
+    processing_cfg: ImageProcessingConfig,
 }
 
 impl DbImageStore {
     /// Create a new `DbImageStore` instance.
-    pub(crate) fn new(db: DynDBImage) -> Self {
-        Self { db }
+    pub(crate) fn new(db: DynDBImage, processing_cfg: ImageProcessingConfig) -> Self {
+        Self { db, processing_cfg }
     }
 }
 
 #[async_trait]
 impl ImageStore for DbImageStore {
+    /// Delete unreferenced images (their versions are deleted with them).
+    async fn delete_unreferenced(&self, created_before: DateTime<Utc>) -> Result<CleanupSummary> {
+        let deleted_images = self.db.delete_unreferenced_images(created_before).await?;
+
+        Ok(CleanupSummary {
+            images: deleted_images.len() as u64,
+            bytes: deleted_images.iter().map(|image| image.size).sum(),
+        })
+    }
+
     /// Retrieve an image version by its ID and version name.
     async fn get(&self, image_id: Uuid, version: &str) -> Result<Option<(Vec<u8>, ImageFormat)>> {
         self.db.get_image_version(image_id, version).await
     }
 
-    /// Save an image and its generated versions to the database.
-    async fn save(&self, user_id: &Uuid, filename: &str, data: Vec<u8>) -> Result<Uuid> {
-        // Prepare image versions
-        let versions = if is_svg(filename) {
-            // Use the original svg image, no need to generate other versions
-            vec![ImageVersion {
-                data,
-                version: "svg".to_string(),
-            }]
-        } else {
-            // Generate versions for different sizes in png format
-            tokio::task::spawn_blocking(move || generate_versions(&data)).await??
-        };
-
-        // Save image versions to the database
-        self.db.save_image_versions(user_id, versions).await
+    /// Images are already stored in the database, so there is nothing to migrate.
+    async fn migrate_from_db(&self, _delete_source: bool) -> Result<MigrationSummary> {
+        bail!("images are already stored in the database, select another storage backend")
+    }
+
+    /// Save an image and its generated versions to the database, reusing the
+    /// existing image when an identical one has already been saved.
+    async fn save(
+        &self,
+        user_id: &Uuid,
+        filename: &str,
+        data: Vec<u8>,
+        crop: Option<ImageCrop>,
+    ) -> Result<Uuid> {
+        let hash = content_hash(&data, crop.as_ref());
+        if let Some(image_id) = self.db.register_image_upload(user_id, &hash).await? {
+            return Ok(image_id);
+        }
+
+        let versions = prepare_versions(&self.processing_cfg, filename, data, crop).await?;
+        self.db.save_image_versions(user_id, &hash, versions).await
     }
 }
@@ -2,71 +2,344 @@
 
 use std::{io::Cursor, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 #[cfg(test)]
 use mockall::automock;
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+use crate::{
+    config::{ImageProcessingConfig, ImageStorageConfig},
+    db::img::DynDBImage,
+    img::{db::DbImageStore, object::ObjectImageStore},
+};
+
 pub(crate) mod db;
+pub(crate) mod object;
+pub(crate) mod svg;
 
 /// Trait for image storage backends supporting get and save operations.
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub(crate) trait ImageStore {
+    /// Delete the images not referenced anywhere that were uploaded before the
+    /// given timestamp, returning how many were deleted and the space reclaimed.
+    async fn delete_unreferenced(&self, created_before: DateTime<Utc>) -> Result<CleanupSummary>;
+
     /// Retrieve an image version from the store.
     async fn get(&self, image_id: Uuid, version: &str) -> Result<Option<(Vec<u8>, ImageFormat)>>;
 
-    /// Save an image to the store and return its unique identifier.
-    async fn save(&self, user_id: &Uuid, filename: &str, data: Vec<u8>) -> Result<Uuid>;
+    /// Copy the image versions stored in the database into this store.
+    async fn migrate_from_db(&self, delete_source: bool) -> Result<MigrationSummary>;
+
+    /// Save an image to the store and return its unique identifier. The crop
+    /// provided, if any, is applied before generating the image versions. When
+    /// an identical image has already been saved, its identifier is returned.
+    async fn save(
+        &self,
+        user_id: &Uuid,
+        filename: &str,
+        data: Vec<u8>,
+        crop: Option<ImageCrop>,
+    ) -> Result<Uuid>;
 }
 
 /// Thread-safe trait object alias for image storage implementations.
 pub(crate) type DynImageStore = Arc<dyn ImageStore + Send + Sync>;
 
-/// Generate resized versions of an image for multiple predefined sizes.
-pub(crate) fn generate_versions(data: &[u8]) -> Result<Vec<ImageVersion>> {
+/// Setup the image store for the backend selected in the configuration.
+pub(crate) fn setup(
+    storage_cfg: &ImageStorageConfig,
+    processing_cfg: &ImageProcessingConfig,
+    db: DynDBImage,
+) -> Result<DynImageStore> {
+    let processing_cfg = processing_cfg.clone();
+    let image_store: DynImageStore = match storage_cfg {
+        ImageStorageConfig::Db => Arc::new(DbImageStore::new(db, processing_cfg)),
+        ImageStorageConfig::Filesystem(cfg) => {
+            Arc::new(ObjectImageStore::filesystem(db, processing_cfg, cfg)?)
+        }
+        ImageStorageConfig::S3(cfg) => Arc::new(ObjectImageStore::s3(db, processing_cfg, cfg)?),
+    };
+
+    Ok(image_store)
+}
+
+/// Sizes (in pixels) of the versions generated for each image.
+const SIZES: &[(&str, u32)] = &[("small", 100), ("medium", 200), ("large", 400)];
+
+/// Name of the version where the image originally uploaded is kept, so that it
+/// can be cropped again later. This version is not served to clients.
+pub(crate) const ORIGINAL_VERSION: &str = "original";
+
+/// Suffix added to the size name of the retina (2x) versions.
+pub(crate) const RETINA_SUFFIX: &str = "@2x";
+
+/// AVIF encoder speed (1-10, higher is faster but produces larger files).
+const AVIF_SPEED: u8 = 8;
+
+/// AVIF encoder quality (1-100).
+const AVIF_QUALITY: u8 = 80;
+
+/// Generate resized versions of an image for multiple predefined sizes, at 1x and
+/// 2x density, in png and webp formats (and avif when enabled). The crop provided
+/// is applied to the image before resizing it.
+pub(crate) fn generate_versions(
+    cfg: &ImageProcessingConfig,
+    data: &[u8],
+    crop: Option<ImageCrop>,
+) -> Result<Vec<ImageVersion>> {
     // Read image data
-    let img = image::ImageReader::new(Cursor::new(data))
+    let mut img = image::ImageReader::new(Cursor::new(data))
         .with_guessed_format()?
         .decode()?;
 
-    // Generate versions for different sizes
+    // Crop image if requested
+    if let Some(crop) = crop {
+        let (x, y, width, height) = crop.rect(img.width(), img.height())?;
+        img = img.crop_imm(x, y, width, height);
+    }
+
+    // Formats the versions will be encoded to
+    let mut formats = vec![ImageFormat::Png, ImageFormat::Webp];
+    if cfg.avif {
+        formats.push(ImageFormat::Avif);
+    }
+
+    // Generate versions for different sizes and densities
     let mut versions = vec![];
-    for (size_name, size) in &[("small", 100), ("medium", 200), ("large", 400)] {
-        // Resize image
-        let version = img.resize(*size, *size, image::imageops::FilterType::Lanczos3);
+    for (size_name, size) in SIZES {
+        for (density_suffix, density) in [("", 1), (RETINA_SUFFIX, 2)] {
+            // Resize image
+            let version = img.resize(
+                size * density,
+                size * density,
+                image::imageops::FilterType::Lanczos3,
+            );
+
+            // Encode resized version of the image to the supported formats
+            let size_name = format!("{size_name}{density_suffix}");
+            for format in &formats {
+                versions.push(ImageVersion {
+                    data: encode(&version, format)?,
+                    version: format.version_name(&size_name),
+                });
+            }
+        }
+    }
 
-        // Encode resized version of the image to png format
-        let mut buf = vec![];
-        version.write_to(&mut Cursor::new(&mut buf), image::ImageFormat::Png)?;
+    Ok(versions)
+}
+
+/// Returns the version to serve when a retina (2x) version is not available, as
+/// it happens with images uploaded before retina versions were generated. The
+/// next larger size has the same dimensions (e.g. small@2x -> medium), so it is
+/// used instead, or the largest size for the largest retina version.
+pub(crate) fn retina_fallback_version(version: &str) -> Option<String> {
+    let (size_name, format_suffix) = version.split_once(RETINA_SUFFIX)?;
+    let position = SIZES.iter().position(|(name, _)| *name == size_name)?;
+    let (fallback_size_name, _) = SIZES.get(position + 1).unwrap_or(&SIZES[position]);
+
+    Some(format!("{fallback_size_name}{format_suffix}"))
+}
 
-        versions.push(ImageVersion {
-            data: buf,
-            version: (*size_name).to_string(),
-        });
+/// Encode an image in the format provided.
+fn encode(img: &image::DynamicImage, format: &ImageFormat) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    match format {
+        ImageFormat::Avif => {
+            let encoder =
+                image::codecs::avif::AvifEncoder::new_with_speed_quality(&mut buf, AVIF_SPEED, AVIF_QUALITY);
+            img.write_with_encoder(encoder)?;
+        }
+        ImageFormat::Png => img.write_to(&mut Cursor::new(&mut buf), image::ImageFormat::Png)?,
+        ImageFormat::Svg => bail!("svg is not a supported output format"),
+        ImageFormat::Webp => img.write_to(&mut Cursor::new(&mut buf), image::ImageFormat::WebP)?,
     }
 
+    Ok(buf)
+}
+
+/// Returns the hash used to identify the content of an uploaded image, so that
+/// identical uploads can reuse the same image. The crop is part of the content,
+/// as different crops of the same image produce different versions.
+pub(crate) fn content_hash(data: &[u8], crop: Option<&ImageCrop>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    match crop {
+        Some(ImageCrop::FocalPoint { x, y }) => hasher.update(format!("focal_point:{x},{y}")),
+        Some(ImageCrop::Rect { x, y, width, height }) => {
+            hasher.update(format!("crop:{x},{y},{width},{height}"));
+        }
+        None => {}
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Prepare the versions of an image that will be stored.
+///
+/// Svg images are stored as they are (cropping does not apply to them). For other
+/// formats, the original image is kept along with the generated versions.
+pub(crate) async fn prepare_versions(
+    cfg: &ImageProcessingConfig,
+    filename: &str,
+    data: Vec<u8>,
+    crop: Option<ImageCrop>,
+) -> Result<Vec<ImageVersion>> {
+    let versions = if is_svg(filename) {
+        // Use the original svg image, no need to generate other versions
+        vec![ImageVersion {
+            data,
+            version: "svg".to_string(),
+        }]
+    } else {
+        // Generate versions for different sizes and formats
+        let cfg = cfg.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut versions = generate_versions(&cfg, &data, crop)?;
+            versions.push(ImageVersion {
+                data,
+                version: ORIGINAL_VERSION.to_string(),
+            });
+            Ok::<_, anyhow::Error>(versions)
+        })
+        .await??
+    };
+
     Ok(versions)
 }
 
+/// Crop applied to an image before generating its versions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ImageCrop {
+    /// Point the image is centered on when cropping it to the largest square
+    /// possible. Coordinates are relative to the image dimensions (0 to 1).
+    FocalPoint { x: f64, y: f64 },
+    /// Square rectangle to crop from the image (in pixels). A difference of one
+    /// pixel between its sides is tolerated to allow for rounding on clients.
+    Rect { x: u32, y: u32, width: u32, height: u32 },
+}
+
+impl ImageCrop {
+    /// Returns the square (x, y, width, height) to crop from an image of the
+    /// dimensions provided, or an error if it does not fit in the image or the
+    /// rectangle requested is not square.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(crate) fn rect(&self, image_width: u32, image_height: u32) -> Result<(u32, u32, u32, u32)> {
+        match *self {
+            ImageCrop::FocalPoint { x, y } => {
+                if !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y) {
+                    bail!("focal point coordinates must be between 0 and 1");
+                }
+
+                // Center the square on the focal point, keeping it inside the image
+                let side = image_width.min(image_height);
+                let offset = |position: f64, length: u32| {
+                    let center = (position * f64::from(length)).round() as u32;
+                    center.saturating_sub(side / 2).min(length - side)
+                };
+                Ok((offset(x, image_width), offset(y, image_height), side, side))
+            }
+            ImageCrop::Rect { x, y, width, height } => {
+                let fits = |start: u32, length: u32, max: u32| {
+                    length > 0 && start.checked_add(length).is_some_and(|end| end <= max)
+                };
+                if !fits(x, width, image_width) || !fits(y, height, image_height) {
+                    bail!("crop rectangle does not fit in the {image_width}x{image_height} image");
+                }
+                if width.abs_diff(height) > 1 {
+                    bail!("crop rectangle must be square");
+                }
+                let side = width.min(height);
+                Ok((x, y, side, side))
+            }
+        }
+    }
+}
+
+/// Summary of the unreferenced images deleted from the store.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CleanupSummary {
+    /// Number of images deleted.
+    pub images: u64,
+    /// Storage space reclaimed (in bytes).
+    pub bytes: u64,
+}
+
+/// Image deleted from the database.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DeletedImage {
+    /// Image identifier.
+    pub image_id: Uuid,
+    /// Size of the image versions that were stored in the database (in bytes).
+    pub size: u64,
+}
+
+/// Summary of the images copied from the database into another store.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct MigrationSummary {
+    /// Number of images copied.
+    pub images: u64,
+    /// Number of image versions copied.
+    pub versions: u64,
+}
+
 /// Represents a version of an image of a specific size (or format).
 #[derive(Debug, Clone)]
 pub(crate) struct ImageVersion {
     /// Raw image data in the specified format.
     pub data: Vec<u8>,
-    /// Version label, e.g., "small", "medium", or "large".
+    /// Version label, e.g., "small", "medium@2x", or "large.webp".
     pub version: String,
 }
 
 /// Supported image formats for storage and processing.
-#[derive(Debug, Clone, strum::Display, strum::EnumString)]
+#[derive(Debug, Clone, PartialEq, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "lowercase")]
 pub(crate) enum ImageFormat {
+    /// AVIF image format.
+    Avif,
     /// PNG image format.
     Png,
     /// SVG image format.
     Svg,
+    /// WebP image format.
+    Webp,
+}
+
+impl ImageFormat {
+    /// Returns the format of an image version from its name.
+    ///
+    /// Png versions are named after their size only (e.g. "small"), whereas other
+    /// formats add an extension to it (e.g. "small.webp").
+    pub(crate) fn from_version(version: &str) -> Self {
+        match version.rsplit_once('.') {
+            Some((_, "avif")) => ImageFormat::Avif,
+            Some((_, "webp")) => ImageFormat::Webp,
+            _ if version == "svg" => ImageFormat::Svg,
+            _ => ImageFormat::Png,
+        }
+    }
+
+    /// Returns the content type of the format.
+    pub(crate) fn content_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Avif => "image/avif",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Svg => "image/svg+xml",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+
+    /// Returns the name of the version of the size provided in this format.
+    pub(crate) fn version_name(&self, size_name: &str) -> String {
+        match self {
+            ImageFormat::Png | ImageFormat::Svg => size_name.to_string(),
+            _ => format!("{size_name}.{self}"),
+        }
+    }
 }
 
 /// Returns true if the file name has an SVG extension (case-insensitive).
@@ -78,3 +351,178 @@ pub(crate) fn is_svg(file_name: &str) -> bool {
     }
     false
 }
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use image::{DynamicImage, ImageFormat as EncodedImageFormat};
+
+    use crate::config::ImageProcessingConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_generate_versions_includes_retina_and_webp_versions() {
+        // Setup identifiers and data structures
+        let mut data = vec![];
+        DynamicImage::new_rgb8(20, 20)
+            .write_to(&mut Cursor::new(&mut data), EncodedImageFormat::Png)
+            .unwrap();
+
+        // Generate versions
+        let versions = generate_versions(&ImageProcessingConfig::default(), &data, None).unwrap();
+
+        // Check result matches expectations
+        let names: Vec<&str> = versions.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "small",
+                "small.webp",
+                "small@2x",
+                "small@2x.webp",
+                "medium",
+                "medium.webp",
+                "medium@2x",
+                "medium@2x.webp",
+                "large",
+                "large.webp",
+                "large@2x",
+                "large@2x.webp",
+            ]
+        );
+        let large_2x = image::load_from_memory(&versions[10].data).unwrap();
+        assert_eq!((large_2x.width(), large_2x.height()), (800, 800));
+        let small_webp = image::load_from_memory_with_format(&versions[1].data, EncodedImageFormat::WebP);
+        assert!(small_webp.is_ok());
+    }
+
+    #[test]
+    fn test_generate_versions_applies_crop() {
+        // Setup identifiers and data structures
+        let mut data = vec![];
+        DynamicImage::new_rgb8(300, 100)
+            .write_to(&mut Cursor::new(&mut data), EncodedImageFormat::Png)
+            .unwrap();
+        let crop = ImageCrop::Rect {
+            x: 0,
+            y: 0,
+            width: 101,
+            height: 100,
+        };
+
+        // Generate versions
+        let versions = generate_versions(&ImageProcessingConfig::default(), &data, Some(crop)).unwrap();
+
+        // Check result matches expectations
+        for version in &versions {
+            let img = image::load_from_memory(&version.data).unwrap();
+            assert_eq!(
+                img.width(),
+                img.height(),
+                "version {} is not square",
+                version.version
+            );
+        }
+        let large = image::load_from_memory(&versions[8].data).unwrap();
+        assert_eq!((large.width(), large.height()), (400, 400));
+    }
+
+    #[test]
+    fn test_content_hash_depends_on_crop() {
+        let crop = ImageCrop::FocalPoint { x: 0.5, y: 0.5 };
+
+        assert_eq!(content_hash(b"image", None), content_hash(b"image", None));
+        assert_ne!(content_hash(b"image", None), content_hash(b"image", Some(&crop)));
+        assert_ne!(content_hash(b"image", None), content_hash(b"other", None));
+    }
+
+    #[test]
+    fn test_image_crop_focal_point_rect_is_kept_inside_image() {
+        let crop = ImageCrop::FocalPoint { x: 0.9, y: 0.5 };
+
+        assert_eq!(crop.rect(300, 100).unwrap(), (200, 0, 100, 100));
+    }
+
+    #[test]
+    fn test_image_crop_focal_point_rect_is_centered_on_point() {
+        let crop = ImageCrop::FocalPoint { x: 0.5, y: 0.25 };
+
+        assert_eq!(crop.rect(100, 400).unwrap(), (0, 50, 100, 100));
+    }
+
+    #[test]
+    fn test_image_crop_rect_must_fit_in_image() {
+        let crop = ImageCrop::Rect {
+            x: 50,
+            y: 0,
+            width: 100,
+            height: 100,
+        };
+
+        assert_eq!(
+            crop.rect(120, 120).unwrap_err().to_string(),
+            "crop rectangle does not fit in the 120x120 image"
+        );
+    }
+
+    #[test]
+    fn test_image_crop_rect_must_be_square() {
+        let crop = ImageCrop::Rect {
+            x: 0,
+            y: 0,
+            width: 400,
+            height: 200,
+        };
+
+        assert_eq!(
+            crop.rect(400, 400).unwrap_err().to_string(),
+            "crop rectangle must be square"
+        );
+    }
+
+    #[test]
+    fn test_image_crop_rect_tolerates_one_pixel_difference() {
+        let crop = ImageCrop::Rect {
+            x: 10,
+            y: 20,
+            width: 101,
+            height: 100,
+        };
+
+        assert_eq!(crop.rect(200, 200).unwrap(), (10, 20, 100, 100));
+    }
+
+    #[test]
+    fn test_retina_fallback_version() {
+        assert_eq!(retina_fallback_version("small@2x").as_deref(), Some("medium"));
+        assert_eq!(
+            retina_fallback_version("medium@2x.webp").as_deref(),
+            Some("large.webp")
+        );
+        assert_eq!(
+            retina_fallback_version("large@2x.avif").as_deref(),
+            Some("large.avif")
+        );
+        assert_eq!(retina_fallback_version("small"), None);
+        assert_eq!(retina_fallback_version("svg"), None);
+    }
+
+    #[test]
+    fn test_image_format_from_version() {
+        assert_eq!(ImageFormat::from_version("small"), ImageFormat::Png);
+        assert_eq!(ImageFormat::from_version("small@2x.avif"), ImageFormat::Avif);
+        assert_eq!(ImageFormat::from_version("medium.webp"), ImageFormat::Webp);
+        assert_eq!(ImageFormat::from_version("svg"), ImageFormat::Svg);
+    }
+
+    #[test]
+    fn test_image_format_version_name() {
+        assert_eq!(ImageFormat::Png.version_name("small@2x"), "small@2x");
+        assert_eq!(ImageFormat::Webp.version_name("small@2x"), "small@2x.webp");
+        assert_eq!(ImageFormat::Avif.version_name("large"), "large.avif");
+    }
+}
@@ -0,0 +1,573 @@
+//! This module implements an image store backed by an object storage service,
+//! like the local filesystem or an S3-compatible service.
+//!
+//! Images metadata is still kept in the database, so that access checks and
+//! references to images work the same regardless of where the versions are stored.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use object_store::{ObjectStore, PutPayload, aws::AmazonS3Builder, local::LocalFileSystem, path::Path};
+use sha2::{Digest, Sha256};
+use tracing::{debug, instrument, warn};
+use uuid::Uuid;
+
+use crate::{
+    config::{FilesystemStorageConfig, ImageProcessingConfig, S3StorageConfig},
+    db::img::DynDBImage,
+    img::{
+        CleanupSummary, ImageCrop, ImageFormat, ImageStore, ImageVersion, MigrationSummary, content_hash,
+        prepare_versions, retina_fallback_version,
+    },
+};
+
+/// Object storage backed image store implementation.
+pub(crate) struct ObjectImageStore {
+    /// Database image interface used to manage images metadata.
+    db: DynDBImage,
+    /// Configuration used when generating the image versions.
+    processing_cfg: ImageProcessingConfig,
+    /// Object store where the image versions are kept.
+    store: Arc<dyn ObjectStore>,
+}
+
+impl ObjectImageStore {
+    /// Create a new `ObjectImageStore` instance.
+    pub(crate) fn new(
+        db: DynDBImage,
+        processing_cfg: ImageProcessingConfig,
+        store: Arc<dyn ObjectStore>,
+    ) -> Self {
+        Self {
+            db,
+            processing_cfg,
+            store,
+        }
+    }
+
+    /// Create a new `ObjectImageStore` that keeps images in the local filesystem.
+    pub(crate) fn filesystem(
+        db: DynDBImage,
+        processing_cfg: ImageProcessingConfig,
+        cfg: &FilesystemStorageConfig,
+    ) -> Result<Self> {
+        std::fs::create_dir_all(&cfg.path).context("error creating images directory")?;
+        let store = LocalFileSystem::new_with_prefix(&cfg.path)?;
+
+        Ok(Self::new(db, processing_cfg, Arc::new(store)))
+    }
+
+    /// Create a new `ObjectImageStore` that keeps images in an S3-compatible service.
+    pub(crate) fn s3(
+        db: DynDBImage,
+        processing_cfg: ImageProcessingConfig,
+        cfg: &S3StorageConfig,
+    ) -> Result<Self> {
+        let mut builder = AmazonS3Builder::from_env()
+            .with_bucket_name(&cfg.bucket)
+            .with_region(&cfg.region);
+        if let Some(access_key_id) = &cfg.access_key_id {
+            builder = builder.with_access_key_id(access_key_id);
+        }
+        if let Some(allow_http) = cfg.allow_http {
+            builder = builder.with_allow_http(allow_http);
+        }
+        if let Some(endpoint) = &cfg.endpoint {
+            builder = builder.with_endpoint(endpoint);
+        }
+        if let Some(secret_access_key) = &cfg.secret_access_key {
+            builder = builder.with_secret_access_key(secret_access_key);
+        }
+
+        Ok(Self::new(db, processing_cfg, Arc::new(builder.build()?)))
+    }
+
+    /// Delete all the versions of an image from the object store, returning
+    /// their total size.
+    async fn delete_versions(&self, image_id: Uuid) -> Result<u64> {
+        let prefix = Path::from(image_id.to_string());
+        let versions = self.store.list_with_delimiter(Some(&prefix)).await?;
+        let mut size = 0;
+        for object in versions.objects {
+            self.store.delete(&object.location).await?;
+            size += object.size;
+        }
+
+        Ok(size)
+    }
+
+    /// Store the versions of an image in the object store.
+    async fn put_versions(&self, image_id: Uuid, versions: Vec<ImageVersion>) -> Result<()> {
+        for version in versions {
+            let path = version_path(image_id, &version.version);
+            self.store.put(&path, PutPayload::from(version.data)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get an image version from the object store, if available.
+    async fn get_object(&self, image_id: Uuid, version: &str) -> Result<Option<Vec<u8>>> {
+        match self.store.get(&version_path(image_id, version)).await {
+            Ok(result) => Ok(Some(result.bytes().await?.to_vec())),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl ImageStore for ObjectImageStore {
+    /// Delete unreferenced images from the database and their versions from the
+    /// object store. Each image is deleted from the database only if it is still
+    /// unreferenced, and its versions are deleted once that has been confirmed,
+    /// so that images referenced after being listed are kept intact.
+    #[instrument(skip(self), err)]
+    async fn delete_unreferenced(&self, created_before: DateTime<Utc>) -> Result<CleanupSummary> {
+        let mut summary = CleanupSummary::default();
+
+        for image_id in self.db.list_unreferenced_images(created_before).await? {
+            // Images not migrated yet may still have some versions in the database
+            let Some(db_size) = self.db.delete_unreferenced_image(&image_id, created_before).await? else {
+                debug!(%image_id, "image referenced after being listed, skipping");
+                continue;
+            };
+            let objects_size = self.delete_versions(image_id).await?;
+
+            summary.images += 1;
+            summary.bytes += objects_size + db_size;
+        }
+
+        Ok(summary)
+    }
+
+    /// Retrieve an image version from the object store, falling back to the next
+    /// larger version for missing retina versions and to the svg version when the
+    /// one requested is not available, and to the database for images that
+    /// haven't been migrated yet.
+    async fn get(&self, image_id: Uuid, version: &str) -> Result<Option<(Vec<u8>, ImageFormat)>> {
+        let retina_fallback = retina_fallback_version(version);
+        let candidates = [Some(version), retina_fallback.as_deref(), Some("svg")];
+        for version in candidates.into_iter().flatten() {
+            if let Some(data) = self.get_object(image_id, version).await? {
+                return Ok(Some((data, ImageFormat::from_version(version))));
+            }
+        }
+
+        self.db.get_image_version(image_id, version).await
+    }
+
+    /// Copy the image versions stored in the database into the object store,
+    /// verifying the checksum of each copy. Versions are deleted from the database
+    /// once verified when requested.
+    #[instrument(skip(self), err)]
+    async fn migrate_from_db(&self, delete_source: bool) -> Result<MigrationSummary> {
+        let mut summary = MigrationSummary::default();
+
+        for image_id in self.db.list_images_with_versions().await? {
+            for version in self.db.get_image_versions(&image_id).await? {
+                // Copy version and verify the data stored matches the original
+                let expected_checksum = Sha256::digest(&version.data);
+                let path = version_path(image_id, &version.version);
+                self.store.put(&path, PutPayload::from(version.data)).await?;
+                let Some(copy) = self.get_object(image_id, &version.version).await? else {
+                    bail!(
+                        "image {image_id} version {} not found after copying it",
+                        version.version
+                    );
+                };
+                if Sha256::digest(&copy) != expected_checksum {
+                    bail!("image {image_id} version {} checksum mismatch", version.version);
+                }
+                summary.versions += 1;
+            }
+            if delete_source {
+                self.db.delete_image_versions(&image_id).await?;
+            }
+            summary.images += 1;
+            debug!(%image_id, "image migrated");
+        }
+
+        Ok(summary)
+    }
+
+    /// Save an image, storing its generated versions in the object store and
+    /// registering it in the database once all of them have been stored, so that
+    /// a failure never leaves an image registered without its versions.
+    /// Identical images are only stored once.
+    #[instrument(skip(self, data), err)]
+    async fn save(
+        &self,
+        user_id: &Uuid,
+        filename: &str,
+        data: Vec<u8>,
+        crop: Option<ImageCrop>,
+    ) -> Result<Uuid> {
+        let hash = content_hash(&data, crop.as_ref());
+        if let Some(image_id) = self.db.register_image_upload(user_id, &hash).await? {
+            return Ok(image_id);
+        }
+
+        let versions = prepare_versions(&self.processing_cfg, filename, data, crop).await?;
+        let new_image_id = Uuid::new_v4();
+        let result = async {
+            self.put_versions(new_image_id, versions).await?;
+            self.db.add_image(&new_image_id, user_id, &hash).await
+        }
+        .await;
+
+        // Versions stored are not needed if the image could not be registered or
+        // an identical one was saved concurrently
+        if !result.as_ref().is_ok_and(|image_id| *image_id == new_image_id)
+            && let Err(err) = self.delete_versions(new_image_id).await
+        {
+            warn!(image_id = %new_image_id, ?err, "error deleting image versions not registered");
+        }
+
+        result
+    }
+}
+
+/// Returns the path where an image version is stored in the object store.
+fn version_path(image_id: Uuid, version: &str) -> Path {
+    Path::from(format!("{image_id}/{version}"))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::Utc;
+    use futures::executor::block_on;
+    use object_store::{ObjectStore, PutPayload, memory::InMemory};
+    use uuid::Uuid;
+
+    use crate::{
+        config::ImageProcessingConfig,
+        db::mock::MockDB,
+        img::{CleanupSummary, ImageFormat, ImageStore, ImageVersion, MigrationSummary, content_hash},
+    };
+
+    use super::{ObjectImageStore, version_path};
+
+    #[tokio::test]
+    async fn test_delete_unreferenced_keeps_images_referenced_after_listing() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+        store
+            .put(&version_path(image_id, "small"), PutPayload::from(vec![1]))
+            .await
+            .unwrap();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_list_unreferenced_images()
+            .times(1)
+            .returning(move |_| Ok(vec![image_id]));
+        db.expect_delete_unreferenced_image()
+            .times(1)
+            .withf(move |id, _| *id == image_id)
+            .returning(|_, _| Ok(None));
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let summary = image_store.delete_unreferenced(Utc::now()).await.unwrap();
+
+        // Check result matches expectations
+        assert_eq!(summary, CleanupSummary::default());
+        assert!(store.get(&version_path(image_id, "small")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_unreferenced_removes_versions() {
+        // Setup identifiers and data structures
+        let created_before = Utc::now();
+        let image_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+        for version in ["small", "medium"] {
+            store
+                .put(&version_path(image_id, version), PutPayload::from(vec![1]))
+                .await
+                .unwrap();
+        }
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_list_unreferenced_images()
+            .times(1)
+            .withf(move |ts| *ts == created_before)
+            .returning(move |_| Ok(vec![image_id]));
+        let store_copy = store.clone();
+        db.expect_delete_unreferenced_image()
+            .times(1)
+            .withf(move |id, ts| {
+                // Image must be deleted before its versions
+                let small = block_on(store_copy.get(&version_path(image_id, "small")));
+                *id == image_id && *ts == created_before && small.is_ok()
+            })
+            .returning(|_, _| Ok(Some(10)));
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let summary = image_store.delete_unreferenced(created_before).await.unwrap();
+
+        // Check result matches expectations
+        assert_eq!(summary, CleanupSummary { images: 1, bytes: 12 });
+        assert!(store.get(&version_path(image_id, "small")).await.is_err());
+        assert!(store.get(&version_path(image_id, "medium")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_falls_back_to_svg_version() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+        store
+            .put(
+                &version_path(image_id, "svg"),
+                PutPayload::from(b"<svg/>".to_vec()),
+            )
+            .await
+            .unwrap();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_image_version().times(0);
+
+        // Execute operation
+        let image_store = ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store);
+        let (data, format) = image_store.get(image_id, "small").await.unwrap().unwrap();
+
+        // Check result matches expectations
+        assert_eq!(data, b"<svg/>");
+        assert!(matches!(format, ImageFormat::Svg));
+    }
+
+    #[tokio::test]
+    async fn test_get_falls_back_to_next_larger_version_for_retina() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+        for (version, data) in [("medium.webp", vec![2]), ("svg", b"<svg/>".to_vec())] {
+            store
+                .put(&version_path(image_id, version), PutPayload::from(data))
+                .await
+                .unwrap();
+        }
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_image_version().times(0);
+
+        // Execute operation
+        let image_store = ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store);
+        let (data, format) = image_store.get(image_id, "small@2x.webp").await.unwrap().unwrap();
+
+        // Check result matches expectations
+        assert_eq!(data, vec![2]);
+        assert!(matches!(format, ImageFormat::Webp));
+    }
+
+    #[tokio::test]
+    async fn test_get_falls_back_to_database() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_get_image_version()
+            .times(1)
+            .withf(move |id, version| *id == image_id && version == "small")
+            .returning(|_, _| Ok(Some((vec![1, 2, 3], ImageFormat::Png))));
+
+        // Execute operation
+        let image_store = ObjectImageStore::new(
+            Arc::new(db),
+            ImageProcessingConfig::default(),
+            Arc::new(InMemory::new()),
+        );
+        let (data, format) = image_store.get(image_id, "small").await.unwrap().unwrap();
+
+        // Check result matches expectations
+        assert_eq!(data, vec![1, 2, 3]);
+        assert!(matches!(format, ImageFormat::Png));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_from_db_copies_versions() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_list_images_with_versions()
+            .times(1)
+            .returning(move || Ok(vec![image_id]));
+        db.expect_get_image_versions()
+            .times(1)
+            .withf(move |id| *id == image_id)
+            .returning(|_| {
+                Ok(vec![
+                    ImageVersion {
+                        data: vec![1],
+                        version: "small".to_string(),
+                    },
+                    ImageVersion {
+                        data: vec![2],
+                        version: "large".to_string(),
+                    },
+                ])
+            });
+        db.expect_delete_image_versions()
+            .times(1)
+            .withf(move |id| *id == image_id)
+            .returning(|_| Ok(()));
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let summary = image_store.migrate_from_db(true).await.unwrap();
+
+        // Check result matches expectations
+        assert_eq!(
+            summary,
+            MigrationSummary {
+                images: 1,
+                versions: 2
+            }
+        );
+        let large = store.get(&version_path(image_id, "large")).await.unwrap();
+        assert_eq!(large.bytes().await.unwrap().to_vec(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_save_reuses_identical_image() {
+        // Setup identifiers and data structures
+        let image_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_register_image_upload()
+            .times(1)
+            .withf(move |id, hash| *id == user_id && *hash == content_hash(b"<svg/>", None))
+            .returning(move |_, _| Ok(Some(image_id)));
+        db.expect_add_image().times(0);
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let saved_image_id = image_store
+            .save(&user_id, "logo.svg", b"<svg/>".to_vec(), None)
+            .await
+            .unwrap();
+
+        // Check result matches expectations
+        assert_eq!(saved_image_id, image_id);
+        assert!(store.get(&version_path(image_id, "svg")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_save_deletes_versions_when_registration_fails() {
+        // Setup identifiers and data structures
+        let user_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_register_image_upload()
+            .times(1)
+            .withf(move |id, _| *id == user_id)
+            .returning(|_, _| Ok(None));
+        db.expect_add_image()
+            .times(1)
+            .returning(|_, _, _| Err(anyhow!("db error")));
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let result = image_store.save(&user_id, "logo.svg", b"<svg/>".to_vec(), None).await;
+
+        // Check result matches expectations
+        assert!(result.is_err());
+        let objects = store.list_with_delimiter(None).await.unwrap();
+        assert!(objects.objects.is_empty());
+        assert!(objects.common_prefixes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_reuses_image_saved_concurrently() {
+        // Setup identifiers and data structures
+        let existing_image_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_register_image_upload()
+            .times(1)
+            .withf(move |id, _| *id == user_id)
+            .returning(|_, _| Ok(None));
+        db.expect_add_image()
+            .times(1)
+            .returning(move |_, _, _| Ok(existing_image_id));
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let saved_image_id = image_store
+            .save(&user_id, "logo.svg", b"<svg/>".to_vec(), None)
+            .await
+            .unwrap();
+
+        // Check result matches expectations
+        assert_eq!(saved_image_id, existing_image_id);
+        let objects = store.list_with_delimiter(None).await.unwrap();
+        assert!(objects.common_prefixes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_stores_versions() {
+        // Setup identifiers and data structures
+        let user_id = Uuid::new_v4();
+        let store = Arc::new(InMemory::new());
+
+        // Setup database mock
+        let mut db = MockDB::new();
+        db.expect_register_image_upload()
+            .times(1)
+            .withf(move |id, _| *id == user_id)
+            .returning(|_, _| Ok(None));
+        let store_copy = store.clone();
+        db.expect_add_image()
+            .times(1)
+            .withf(move |image_id, id, hash| {
+                // Versions must have been stored before registering the image
+                let svg = block_on(store_copy.get(&version_path(*image_id, "svg")));
+                *id == user_id && *hash == content_hash(b"<svg/>", None) && svg.is_ok()
+            })
+            .returning(|image_id, _, _| Ok(*image_id));
+
+        // Execute operation
+        let image_store =
+            ObjectImageStore::new(Arc::new(db), ImageProcessingConfig::default(), store.clone());
+        let image_id = image_store
+            .save(&user_id, "logo.svg", b"<svg/>".to_vec(), None)
+            .await
+            .unwrap();
+
+        // Check result matches expectations
+        let svg = store.get(&version_path(image_id, "svg")).await.unwrap();
+        assert_eq!(svg.bytes().await.unwrap().to_vec(), b"<svg/>");
+    }
+}
@@ -0,0 +1,350 @@
+//! This module provides a sanitizer for SVG images uploaded by users.
+//!
+//! SVG images are served as they are uploaded, so anything that could run code or
+//! load external resources when they are displayed must be removed from them.
+
+use anyhow::{Result, bail};
+use quick_xml::{
+    Reader, Writer,
+    events::{BytesStart, Event, attributes::Attribute},
+};
+
+/// Maximum size of the SVG documents accepted (512 KiB).
+const MAX_SIZE_BYTES: usize = 512 * 1024;
+
+/// Maximum number of elements in the SVG documents accepted.
+const MAX_ELEMENTS: usize = 10_000;
+
+/// Maximum nesting depth of the elements in the SVG documents accepted.
+const MAX_DEPTH: usize = 64;
+
+/// SVG namespace, required in the root element.
+const SVG_NAMESPACE: &[u8] = b"http://www.w3.org/2000/svg";
+
+/// Elements removed from the documents, along with all their content.
+const REMOVED_ELEMENTS: &[&str] = &[
+    "embed",
+    "foreignobject",
+    "handler",
+    "iframe",
+    "listener",
+    "object",
+    "script",
+];
+
+/// Entities that can be referenced in the documents (besides character references).
+const PREDEFINED_ENTITIES: &[&str] = &["amp", "apos", "gt", "lt", "quot"];
+
+/// Data URL prefixes allowed in references (embedded raster images only).
+const ALLOWED_DATA_URLS: &[&str] = &[
+    "data:image/gif",
+    "data:image/jpeg",
+    "data:image/png",
+    "data:image/webp",
+];
+
+/// Sanitize the SVG document provided, returning a cleaned copy of it.
+///
+/// Scripts, event handlers, foreign objects and references to external resources
+/// are removed, as well as the doctype (and any entities declared in it), comments
+/// and processing instructions. Documents that cannot be cleaned safely (i.e.
+/// invalid, too large, or using unknown entities or external stylesheets) are
+/// rejected.
+pub(crate) fn sanitize(data: &[u8]) -> Result<Vec<u8>> {
+    if data.len() > MAX_SIZE_BYTES {
+        bail!("svg image exceeds {} KiB limit", MAX_SIZE_BYTES / 1024);
+    }
+
+    let mut reader = Reader::from_reader(data);
+    let mut writer = Writer::new(Vec::with_capacity(data.len()));
+    let mut buf = Vec::new();
+    let mut depth = 0;
+    let mut elements = 0;
+    let mut found_root = false;
+    let mut removed_depth: Option<usize> = None;
+    let mut in_style = false;
+
+    loop {
+        let event = match reader.read_event_into(&mut buf) {
+            Ok(event) => event,
+            Err(err) => bail!("invalid svg image: {err}"),
+        };
+
+        match event {
+            Event::Eof => break,
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                let is_start = matches!(event, Event::Start(_));
+
+                // Enforce document limits
+                elements += 1;
+                if elements > MAX_ELEMENTS {
+                    bail!("svg image has too many elements");
+                }
+                if is_start {
+                    depth += 1;
+                    if depth > MAX_DEPTH {
+                        bail!("svg image is nested too deeply");
+                    }
+                }
+
+                // Check the root element is a valid svg element
+                if !found_root {
+                    check_root(element)?;
+                    found_root = true;
+                }
+
+                // Skip removed elements and their content
+                if removed_depth.is_some() {
+                    continue;
+                }
+                let name = local_name(element);
+                if REMOVED_ELEMENTS.contains(&name.as_str()) {
+                    if is_start {
+                        removed_depth = Some(depth);
+                    }
+                    continue;
+                }
+
+                // Write the element without the unsafe attributes
+                let element = clean_element(&reader, element)?;
+                in_style = is_start && name == "style";
+                if is_start {
+                    writer.write_event(Event::Start(element))?;
+                } else {
+                    writer.write_event(Event::Empty(element))?;
+                }
+            }
+            Event::End(ref element) => {
+                if removed_depth == Some(depth) {
+                    removed_depth = None;
+                } else if removed_depth.is_none() {
+                    writer.write_event(Event::End(element.borrow()))?;
+                }
+                depth = depth.saturating_sub(1);
+                in_style = false;
+            }
+            Event::Text(ref text) => {
+                if removed_depth.is_none() {
+                    if in_style {
+                        check_style(&text.xml_content()?)?;
+                    }
+                    writer.write_event(Event::Text(text.borrow()))?;
+                }
+            }
+            Event::CData(ref cdata) => {
+                if removed_depth.is_none() {
+                    if in_style {
+                        check_style(&String::from_utf8_lossy(cdata))?;
+                    }
+                    writer.write_event(Event::CData(cdata.borrow()))?;
+                }
+            }
+            Event::GeneralRef(ref reference) => {
+                if !reference.is_char_ref() && !PREDEFINED_ENTITIES.contains(&reference.decode()?.as_ref()) {
+                    bail!("svg image references unknown entities");
+                }
+                if removed_depth.is_none() {
+                    writer.write_event(Event::GeneralRef(reference.borrow()))?;
+                }
+            }
+            Event::Decl(ref decl) => writer.write_event(Event::Decl(decl.borrow()))?,
+            Event::Comment(_) | Event::DocType(_) | Event::PI(_) => {}
+        }
+        buf.clear();
+    }
+
+    if !found_root {
+        bail!("invalid svg image: root element not found");
+    }
+
+    Ok(writer.into_inner())
+}
+
+/// Check the root element is an svg element in the svg namespace.
+fn check_root(element: &BytesStart) -> Result<()> {
+    let has_svg_namespace = element
+        .attributes()
+        .filter_map(Result::ok)
+        .any(|attr| attr.key.as_ref() == b"xmlns" && attr.value.as_ref() == SVG_NAMESPACE);
+    if element.name().as_ref() != b"svg" || !has_svg_namespace {
+        bail!("invalid svg image: root element must be an svg element");
+    }
+
+    Ok(())
+}
+
+/// Check the content of a style element does not load external resources.
+fn check_style(css: &str) -> Result<()> {
+    let css = normalize(css);
+    if css.contains("@import") || !urls_are_local(&css) {
+        bail!("svg image styles reference external resources");
+    }
+
+    Ok(())
+}
+
+/// Returns a copy of the element provided without the unsafe attributes.
+fn clean_element(reader: &Reader<&[u8]>, element: &BytesStart) -> Result<BytesStart<'static>> {
+    let mut clean = BytesStart::new(String::from_utf8_lossy(element.name().as_ref()).into_owned());
+
+    for attr in element.attributes() {
+        let Ok(attr) = attr else {
+            bail!("invalid svg image: malformed attribute");
+        };
+        let Ok(value) = attr.decode_and_unescape_value(reader.decoder()) else {
+            bail!("svg image references unknown entities");
+        };
+        if is_safe_attribute(&attr, &value) {
+            clean.push_attribute(attr);
+        }
+    }
+
+    Ok(clean)
+}
+
+/// Checks if the attribute provided is safe to keep.
+fn is_safe_attribute(attr: &Attribute, value: &str) -> bool {
+    let name = String::from_utf8_lossy(attr.key.local_name().as_ref()).to_ascii_lowercase();
+    let value = normalize(value);
+
+    // Event handlers
+    if name.starts_with("on") {
+        return false;
+    }
+
+    // Script URLs can be used in many attributes (e.g. animations)
+    if value.contains("javascript:") || value.contains("vbscript:") {
+        return false;
+    }
+
+    // References must point to elements in the document or embedded images
+    if name == "href" {
+        return value.starts_with('#') || ALLOWED_DATA_URLS.iter().any(|prefix| value.starts_with(prefix));
+    }
+
+    urls_are_local(&value)
+}
+
+/// Returns the local name of an element, in lowercase.
+fn local_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.local_name().as_ref()).to_ascii_lowercase()
+}
+
+/// Returns a lowercase copy of the value provided without whitespaces, so that
+/// checks can't be bypassed using them (e.g. "java script:").
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Checks that all the `url()` references in the normalized value provided point
+/// to elements in the document.
+fn urls_are_local(value: &str) -> bool {
+    value
+        .split("url(")
+        .skip(1)
+        .all(|reference| reference.trim_start_matches(['"', '\'']).starts_with('#'))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_keeps_safe_content() {
+        let svg = br##"<?xml version="1.0"?><svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><defs><linearGradient id="g"/></defs><rect fill="url(#g)" width="10"/><use xlink:href="#g"/><text>A &amp; B</text></svg>"##;
+
+        let clean = sanitize(svg).unwrap();
+
+        assert_eq!(clean, svg);
+    }
+
+    #[test]
+    fn test_sanitize_removes_dangerous_elements() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg"><script>alert(1)</script><foreignObject><div><script/></div></foreignObject><circle r="1"/></svg>"#;
+
+        let clean = sanitize(svg).unwrap();
+
+        assert_eq!(
+            clean,
+            br#"<svg xmlns="http://www.w3.org/2000/svg"><circle r="1"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn test_sanitize_removes_event_handlers_and_unsafe_references() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" onload="alert(1)"><a href="java&#x09;script:alert(1)"><image href="https://example.com/x.png"/></a><rect style="fill:url(https://example.com/x)"/><set attributeName="href" to="javascript:alert(1)"/></svg>"#;
+
+        let clean = sanitize(svg).unwrap();
+
+        assert_eq!(
+            clean,
+            br#"<svg xmlns="http://www.w3.org/2000/svg"><a><image/></a><rect/><set attributeName="href"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn test_sanitize_removes_doctype_comments_and_processing_instructions() {
+        let svg = br#"<!DOCTYPE svg [<!ENTITY x SYSTEM "file:///etc/passwd">]><?xml-stylesheet href="https://example.com/x.css"?><svg xmlns="http://www.w3.org/2000/svg"><!-- comment --></svg>"#;
+
+        let clean = sanitize(svg).unwrap();
+
+        assert_eq!(clean, br#"<svg xmlns="http://www.w3.org/2000/svg"></svg>"#);
+    }
+
+    #[test]
+    fn test_sanitize_rejects_external_entity_references() {
+        let svg = br#"<!DOCTYPE svg [<!ENTITY x SYSTEM "file:///etc/passwd">]><svg xmlns="http://www.w3.org/2000/svg"><text>&x;</text></svg>"#;
+
+        let err = sanitize(svg).unwrap_err();
+
+        assert_eq!(err.to_string(), "svg image references unknown entities");
+    }
+
+    #[test]
+    fn test_sanitize_rejects_external_stylesheets() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg"><style>@import url(https://example.com/x.css);</style></svg>"#;
+
+        let err = sanitize(svg).unwrap_err();
+
+        assert_eq!(err.to_string(), "svg image styles reference external resources");
+    }
+
+    #[test]
+    fn test_sanitize_rejects_oversized_documents() {
+        let mut svg = br#"<svg xmlns="http://www.w3.org/2000/svg">"#.to_vec();
+        svg.resize(MAX_SIZE_BYTES + 1, b' ');
+
+        let err = sanitize(&svg).unwrap_err();
+
+        assert_eq!(err.to_string(), "svg image exceeds 512 KiB limit");
+    }
+
+    #[test]
+    fn test_sanitize_rejects_deeply_nested_documents() {
+        let svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg">{}{}</svg>"#,
+            "<g>".repeat(MAX_DEPTH),
+            "</g>".repeat(MAX_DEPTH)
+        );
+
+        let err = sanitize(svg.as_bytes()).unwrap_err();
+
+        assert_eq!(err.to_string(), "svg image is nested too deeply");
+    }
+
+    #[test]
+    fn test_sanitize_rejects_documents_without_svg_root() {
+        let err = sanitize(br"<html><body/></html>").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid svg image: root element must be an svg element"
+        );
+    }
+}
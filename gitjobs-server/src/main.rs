@@ -1,13 +1,12 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::struct_field_names)]
 
-use std::{path::PathBuf, sync::Arc};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
 
 use anyhow::{Context, Result};
 use clap::Parser;
 use deadpool_postgres::Runtime;
 use event_tracker::EventTrackerDB;
-use img::db::DbImageStore;
 use notifications::{DynEmailSender, LettreEmailSender, PgNotificationsManager};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
@@ -18,16 +17,20 @@ use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 
 use crate::{
+    cli::Command,
     config::{Config, LogFormat},
     db::PgDB,
+    img::DynImageStore,
 };
 
 mod auth;
+mod cli;
 mod config;
 mod db;
 mod event_tracker;
 mod handlers;
 mod img;
+mod metrics;
 mod notifications;
 mod router;
 mod templates;
@@ -39,8 +42,12 @@ mod workers;
 #[clap(author, version, about)]
 struct Args {
     /// Path to the configuration file.
-    #[clap(short, long)]
+    #[clap(short, long, global = true)]
     config_file: Option<PathBuf>,
+
+    /// Command to run (the HTTP server is started when none is provided).
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
 /// Main entry point for the application.
@@ -66,16 +73,35 @@ async fn main() -> Result<()> {
         LogFormat::Pretty => ts.init(),
     }
 
-    // Setup task tracker and cancellation token for background workers.
-    let task_tracker = TaskTracker::new();
-    let cancellation_token = CancellationToken::new();
-
     // Setup database connection pool.
     let mut builder = SslConnector::builder(SslMethod::tls())?;
     builder.set_verify(SslVerifyMode::NONE);
     let connector = MakeTlsConnector::new(builder.build());
     let pool = cfg.db.create_pool(Some(Runtime::Tokio1), connector)?;
     let db = Arc::new(PgDB::new(pool));
+
+    // Setup image store.
+    let image_store = img::setup(&cfg.image_storage, &cfg.image_processing, db.clone())
+        .context("error setting up image store")?;
+
+    // Run the command requested.
+    match args.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(&cfg, db, image_store).await,
+        Command::Maintenance(command) => cli::run(db, image_store, command).await,
+    }
+}
+
+/// Sets up the HTTP server and background workers, and runs them until a shutdown
+/// signal is received.
+async fn serve(cfg: &Config, db: Arc<PgDB>, image_store: DynImageStore) -> Result<()> {
+    // Setup metrics recorder.
+    let metrics_handle = metrics::setup().context("error setting up metrics")?;
+
+    // Setup task tracker and cancellation token for background workers.
+    let task_tracker = TaskTracker::new();
+    let cancellation_token = CancellationToken::new();
+
+    // Setup database transactions cleaner.
     {
         let db = db.clone();
         let cancellation_token = cancellation_token.clone();
@@ -84,8 +110,14 @@ async fn main() -> Result<()> {
         });
     }
 
-    // Setup image store.
-    let image_store = Arc::new(DbImageStore::new(db.clone()));
+    // Setup metrics recorder upkeep.
+    {
+        let metrics_handle = metrics_handle.clone();
+        let cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            metrics::upkeep(metrics_handle, cancellation_token).await;
+        });
+    }
 
     // Setup notifications manager.
     let email_sender: DynEmailSender = Arc::new(LettreEmailSender::new(&cfg.email)?);
@@ -105,7 +137,29 @@ async fn main() -> Result<()> {
     ));
 
     // Run additional background workers.
-    workers::run(db.clone(), &task_tracker, cancellation_token.clone());
+    workers::run(
+        db.clone(),
+        image_store.clone(),
+        &cfg.stats,
+        &task_tracker,
+        &cancellation_token,
+    );
+
+    // Setup and launch the metrics server, when enabled.
+    if let Some(metrics_addr) = &cfg.server.metrics_addr {
+        let metrics_router = router::setup_metrics(db.clone(), metrics_handle);
+        let listener = TcpListener::bind(metrics_addr).await?;
+        info!(%metrics_addr, "metrics server listening");
+        let cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            if let Err(err) = axum::serve(listener, metrics_router)
+                .with_graceful_shutdown(async move { cancellation_token.cancelled().await })
+                .await
+            {
+                error!(?err, "metrics server error");
+            }
+        });
+    }
 
     // Setup and launch the HTTP server.
     let router = router::setup(
@@ -119,9 +173,12 @@ async fn main() -> Result<()> {
     let listener = TcpListener::bind(&cfg.server.addr).await?;
     info!("server started");
     info!(%cfg.server.addr, "listening");
-    if let Err(err) = axum::serve(listener, router)
-        .with_graceful_shutdown(shutdown_signal())
-        .await
+    if let Err(err) = axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await
     {
         error!(?err, "server error");
         return Err(err.into());
@@ -0,0 +1,149 @@
+//! This module defines the Prometheus metrics exposed by the server, as well as some
+//! helpers used to record them from the different components.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use chrono::Utc;
+use deadpool_postgres::Status;
+use metrics::{counter, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use tokio::time::{Instant, sleep};
+use tokio_util::sync::CancellationToken;
+
+use crate::{db::workers::RollupSummary, img::CleanupSummary, notifications::PendingNotificationsStats};
+
+/// Buckets used for the HTTP requests duration histogram (seconds).
+const HTTP_REQUEST_DURATION_BUCKETS: &[f64] =
+    &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Buckets used for the event tracker flush size histogram (entries).
+const EVENT_TRACKER_FLUSH_SIZE_BUCKETS: &[f64] =
+    &[1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 5000.0];
+
+/// How often the metrics recorder upkeep tasks are run.
+const UPKEEP_FREQUENCY: Duration = Duration::from_secs(5);
+
+/// Installs the Prometheus metrics recorder, returning a handle to render them.
+pub(crate) fn setup() -> Result<PrometheusHandle> {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full("http_request_duration_seconds".to_string()),
+            HTTP_REQUEST_DURATION_BUCKETS,
+        )?
+        .set_buckets_for_metric(
+            Matcher::Full("event_tracker_flush_size".to_string()),
+            EVENT_TRACKER_FLUSH_SIZE_BUCKETS,
+        )?
+        .install_recorder()?;
+
+    Ok(handle)
+}
+
+/// Runs the metrics recorder upkeep tasks periodically until asked to stop.
+pub(crate) async fn upkeep(handle: PrometheusHandle, cancellation_token: CancellationToken) {
+    loop {
+        handle.run_upkeep();
+
+        tokio::select! {
+            () = sleep(UPKEEP_FREQUENCY) => {},
+            () = cancellation_token.cancelled() => break,
+        }
+    }
+}
+
+/// Middleware that records the number and duration of the requests per route.
+pub(crate) async fn track_http_requests(request: Request, next: Next) -> Response {
+    // Collect some request details before passing it along
+    let start = Instant::now();
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unknown", MatchedPath::as_str)
+        .to_string();
+
+    // Process request and record metrics
+    let response = next.run(request).await;
+    let labels = [
+        ("method", method),
+        ("route", route),
+        ("status", response.status().as_u16().to_string()),
+    ];
+    counter!("http_requests_total", &labels).increment(1);
+    histogram!("http_request_duration_seconds", &labels).record(start.elapsed().as_secs_f64());
+
+    response
+}
+
+/// Records the result of an archiver run.
+pub(crate) fn record_archiver_run(succeeded: bool) {
+    counter!("archiver_runs_total", "result" => result_label(succeeded)).increment(1);
+}
+
+/// Records the current status of the database connections pool.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn record_db_pool_status(status: &Status) {
+    gauge!("db_pool_max_size").set(status.max_size as f64);
+    gauge!("db_pool_size").set(status.size as f64);
+    gauge!("db_pool_available").set(status.available as f64);
+    gauge!("db_pool_waiting").set(status.waiting as f64);
+}
+
+/// Records the number of entries written to the database by the event tracker.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn record_event_tracker_flush(kind: &'static str, size: usize) {
+    histogram!("event_tracker_flush_size", "kind" => kind).record(size as f64);
+}
+
+/// Records an event discarded by the event tracker, along with the reason.
+pub(crate) fn record_event_tracker_discarded(reason: &'static str) {
+    counter!("event_tracker_events_discarded_total", "reason" => reason).increment(1);
+}
+
+/// Records the result of a notification delivery attempt.
+pub(crate) fn record_notification_processed(delivered: bool) {
+    counter!("notifications_processed_total", "result" => result_label(delivered)).increment(1);
+}
+
+/// Records the result of an images garbage collector run, as well as the number
+/// of images deleted and the storage space reclaimed when it succeeds.
+pub(crate) fn record_images_gc_run(summary: Option<&CleanupSummary>) {
+    counter!("images_gc_runs_total", "result" => result_label(summary.is_some())).increment(1);
+    if let Some(summary) = summary {
+        counter!("images_gc_deleted_total").increment(summary.images);
+        counter!("images_gc_reclaimed_bytes_total").increment(summary.bytes);
+    }
+}
+
+/// Records the result of a stats rollup run, as well as the number of daily
+/// rows compacted when it succeeds.
+pub(crate) fn record_stats_rollup_run(summary: Option<&RollupSummary>) {
+    counter!("stats_rollup_runs_total", "result" => result_label(summary.is_some())).increment(1);
+    if let Some(summary) = summary {
+        counter!("stats_rollup_compacted_rows_total", "kind" => "job_views").increment(summary.job_views);
+        counter!("stats_rollup_compacted_rows_total", "kind" => "search_appearances")
+            .increment(summary.search_appearances);
+    }
+}
+
+/// Records the current state of the notifications queue.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn record_pending_notifications(stats: &PendingNotificationsStats) {
+    let oldest_age = stats
+        .oldest_created_at
+        .map_or(0, |created_at| (Utc::now() - created_at).num_seconds().max(0));
+
+    gauge!("notifications_pending").set(stats.pending as f64);
+    gauge!("notifications_oldest_pending_age_seconds").set(oldest_age as f64);
+}
+
+/// Returns the label used to represent the result of an operation.
+fn result_label(succeeded: bool) -> &'static str {
+    if succeeded { "success" } else { "error" }
+}
@@ -5,6 +5,7 @@ use std::{sync::Arc, time::Duration};
 use anyhow::{Result, anyhow};
 use askama::Template;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use lettre::{
     AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
     message::{Mailbox, MessageBuilder, header::ContentType},
@@ -21,6 +22,7 @@ use uuid::Uuid;
 use crate::{
     config::EmailConfig,
     db::DynDB,
+    metrics,
     templates::notifications::{EmailVerification, TeamInvitation},
 };
 
@@ -156,6 +158,7 @@ impl Worker {
             };
 
             // Update notification with result
+            metrics::record_notification_processed(err.is_none());
             if let Err(err) = self.db.update_notification(client_id, notification, err).await {
                 error!("error updating notification: {err}");
             }
@@ -292,6 +295,16 @@ pub(crate) struct Notification {
     pub template_data: Option<serde_json::Value>,
 }
 
+/// Stats about the notifications waiting to be delivered.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PendingNotificationsStats {
+    /// Number of notifications waiting to be delivered.
+    pub pending: u64,
+
+    /// Creation timestamp of the oldest notification waiting to be delivered.
+    pub oldest_created_at: Option<DateTime<Utc>>,
+}
+
 /// Supported notification types.
 #[derive(Debug, Clone, Serialize, Deserialize, strum::Display, strum::EnumString)]
 #[serde(rename_all = "kebab-case")]
@@ -16,6 +16,7 @@ use axum::{
 use axum_extra::headers::{Authorization, Header, authorization::Basic};
 use axum_login::login_required;
 use axum_messages::MessagesManagerLayer;
+use metrics_exporter_prometheus::PrometheusHandle;
 use reqwest::Client;
 use rust_embed::Embed;
 use serde_qs::axum::{QsQueryConfig, QsQueryRejection};
@@ -34,10 +35,11 @@ use crate::{
     event_tracker::DynEventTracker,
     handlers::{
         auth::{self, LOG_IN_URL},
-        dashboard, img, jobboard,
+        dashboard, health, img, jobboard,
         misc::{not_found, search_locations, search_members, search_projects, user_menu_section},
     },
     img::DynImageStore,
+    metrics,
     notifications::DynNotificationsManager,
 };
 
@@ -68,8 +70,18 @@ pub(crate) struct State {
     pub http_client: Client,
 }
 
+/// Holds shared state for the metrics router.
+#[derive(Clone, FromRef)]
+pub(crate) struct MetricsState {
+    /// Database handle.
+    pub db: DynDB,
+    /// Handle used to render the Prometheus metrics.
+    pub metrics_handle: PrometheusHandle,
+}
+
 /// Sets up the main application router and all sub-routers.
 #[instrument(skip_all, err)]
+#[allow(clippy::too_many_lines)]
 pub(crate) async fn setup(
     cfg: HttpServerConfig,
     db: DynDB,
@@ -93,6 +105,7 @@ pub(crate) async fn setup(
     let auth_layer = crate::auth::setup_layer(&cfg, db).await?;
 
     // Setup sub-routers
+    let admin_dashboard_router = setup_admin_dashboard_router(state.clone());
     let employer_dashboard_router = setup_employer_dashboard_router(state.clone());
     let job_seeker_dashboard_router = setup_job_seeker_dashboard_router();
     let moderator_dashboard_router = setup_moderator_dashboard_router(state.clone());
@@ -110,6 +123,7 @@ pub(crate) async fn setup(
             "/dashboard/account/update/password",
             put(auth::update_user_password),
         )
+        .nest("/dashboard/admin", admin_dashboard_router)
         .nest("/dashboard/employer", employer_dashboard_router)
         .nest("/dashboard/images", dashboard_images_router)
         .nest("/dashboard/job-seeker", job_seeker_dashboard_router)
@@ -126,8 +140,12 @@ pub(crate) async fn setup(
         .route("/about", get(jobboard::about::page))
         .route("/embed", get(jobboard::embed::jobs_page))
         .route("/embed/job/{job_id}/card.svg", get(jobboard::embed::job_card))
-        .route("/health-check", get(health_check))
+        .route("/health-check", get(health::health_check))
         .nest("/jobboard/images", jobboard_images_router)
+        .route(
+            "/jobs/{job_id}/apply-redirect",
+            get(jobboard::jobs::apply_redirect),
+        )
         .route("/jobs/{job_id}/views", post(jobboard::jobs::track_view))
         .route(
             "/jobs/search-appearances",
@@ -158,14 +176,17 @@ pub(crate) async fn setup(
     router = router
         .route("/log-out", get(auth::log_out))
         .route("/projects/search", get(search_projects))
+        .route("/readiness-check", get(health::readiness_check))
         .route("/section/jobs/{job_id}", get(jobboard::jobs::job_section))
         .route("/section/jobs/results", get(jobboard::jobs::results_section))
         .route("/section/user-menu", get(user_menu_section))
         .route("/sign-up", get(auth::sign_up_page))
         .route("/stats", get(jobboard::stats::page))
+        .route("/stats.json", get(jobboard::stats::data))
         .route_layer(MessagesManagerLayer)
         .route_layer(auth_layer)
         .route_layer(ServiceBuilder::new().layer(TraceLayer::new_for_http()))
+        .route_layer(middleware::from_fn(metrics::track_http_requests))
         .route_layer(Extension(QsQueryConfig::new().config(serde_qs_de).error_handler(
             |err| QsQueryRejection::new(err, StatusCode::UNPROCESSABLE_ENTITY),
         )))
@@ -189,6 +210,49 @@ pub(crate) async fn setup(
     Ok(router.with_state(state))
 }
 
+/// Sets up the metrics router, which is served on a separate address so that
+/// metrics are not exposed publicly.
+pub(crate) fn setup_metrics(db: DynDB, metrics_handle: PrometheusHandle) -> Router {
+    let state = MetricsState { db, metrics_handle };
+
+    Router::new()
+        .route("/metrics", get(health::metrics))
+        .with_state(state)
+}
+
+/// Sets up the admin dashboard router and its routes.
+fn setup_admin_dashboard_router(state: State) -> Router<State> {
+    // Setup middleware
+    let user_is_admin = middleware::from_fn_with_state(state, auth::user_is_admin);
+
+    // Setup router
+    Router::new()
+        .route("/", get(dashboard::admin::home::page))
+        .route("/certifications", get(dashboard::admin::certifications::page))
+        .route("/certifications/add", post(dashboard::admin::certifications::add))
+        .route(
+            "/certifications/{certification_id}/delete",
+            delete(dashboard::admin::certifications::delete),
+        )
+        .route(
+            "/certifications/{certification_id}/update",
+            put(dashboard::admin::certifications::update),
+        )
+        .route("/foundations", get(dashboard::admin::foundations::page))
+        .route("/foundations/add", post(dashboard::admin::foundations::add))
+        .route(
+            "/foundations/{foundation_id}/update",
+            put(dashboard::admin::foundations::update),
+        )
+        .route("/moderators", get(dashboard::admin::moderators::page))
+        .route("/moderators/add", post(dashboard::admin::moderators::add))
+        .route(
+            "/moderators/{user_id}/revoke",
+            put(dashboard::admin::moderators::revoke),
+        )
+        .route_layer(user_is_admin)
+}
+
 /// Sets up the employer dashboard router and its routes.
 fn setup_employer_dashboard_router(state: State) -> Router<State> {
     // Setup middleware
@@ -203,6 +267,8 @@ fn setup_employer_dashboard_router(state: State) -> Router<State> {
     // Setup router
     Router::new()
         // Routes that require selected employer context
+        .route("/analytics", get(dashboard::employer::analytics::page))
+        .route("/analytics/export", get(dashboard::employer::analytics::export))
         .route(
             "/applications/list",
             get(dashboard::employer::applications::list_page),
@@ -306,6 +372,10 @@ fn setup_job_seeker_dashboard_router() -> Router<State> {
             "/profile/update",
             get(dashboard::job_seeker::profile::update_page).put(dashboard::job_seeker::profile::update),
         )
+        .route(
+            "/recommended/list",
+            get(dashboard::job_seeker::recommended::list_page),
+        )
 }
 
 /// Sets up the moderator dashboard router and its routes.
@@ -316,6 +386,16 @@ fn setup_moderator_dashboard_router(state: State) -> Router<State> {
     // Setup router
     Router::new()
         .route("/", get(dashboard::moderator::home::page))
+        .route("/activity", get(dashboard::moderator::accounts::activity_page))
+        .route("/employers", get(dashboard::moderator::accounts::employers_page))
+        .route(
+            "/employers/{employer_id}/suspend",
+            put(dashboard::moderator::accounts::suspend_employer),
+        )
+        .route(
+            "/employers/{employer_id}/unsuspend",
+            put(dashboard::moderator::accounts::unsuspend_employer),
+        )
         .route("/jobs/live", get(dashboard::moderator::jobs::live_page))
         .route("/jobs/pending", get(dashboard::moderator::jobs::pending_page))
         .route("/jobs/{job_id}/approve", put(dashboard::moderator::jobs::approve))
@@ -324,6 +404,16 @@ fn setup_moderator_dashboard_router(state: State) -> Router<State> {
             "/jobs/{employer_id}/{job_id}/preview",
             get(dashboard::moderator::jobs::preview_page),
         )
+        .route("/stats", get(dashboard::moderator::stats::page))
+        .route("/users", get(dashboard::moderator::accounts::users_page))
+        .route(
+            "/users/{user_id}/suspend",
+            put(dashboard::moderator::accounts::suspend_user),
+        )
+        .route(
+            "/users/{user_id}/unsuspend",
+            put(dashboard::moderator::accounts::unsuspend_user),
+        )
         .route_layer(user_is_moderator)
 }
 
@@ -333,10 +423,16 @@ fn setup_dashboard_images_router(state: State) -> Router<State> {
     let check_user_has_image_access = middleware::from_fn_with_state(state, auth::user_has_image_access);
 
     // Setup router
-    Router::new().route("/", post(img::upload)).route(
-        "/{image_id}/{version}",
-        get(img::get).layer(check_user_has_image_access),
-    )
+    Router::new()
+        .route("/", post(img::upload))
+        .route(
+            "/{image_id}/crop",
+            post(img::crop).layer(check_user_has_image_access.clone()),
+        )
+        .route(
+            "/{image_id}/{version}",
+            get(img::get).layer(check_user_has_image_access),
+        )
 }
 
 /// Sets up the job board images router for public image access.
@@ -351,12 +447,6 @@ fn setup_jobboard_images_router(state: State) -> Router<State> {
     )
 }
 
-/// Responds to health check requests with HTTP 200 OK.
-#[instrument(skip_all)]
-async fn health_check() -> impl IntoResponse {
-    StatusCode::OK
-}
-
 /// Serves static files embedded in the binary, with cache headers.
 #[instrument]
 async fn static_handler(uri: Uri) -> impl IntoResponse {
@@ -452,15 +542,6 @@ mod tests {
 
     use super::*;
 
-    #[tokio::test]
-    async fn test_health_check_returns_ok() {
-        let response = health_check().await.into_response();
-        let (parts, body) = response.into_parts();
-
-        assert_eq!(parts.status, StatusCode::OK);
-        assert!(to_bytes(body, usize::MAX).await.unwrap().is_empty());
-    }
-
     #[tokio::test]
     async fn test_static_handler_missing_asset_returns_not_found() {
         let uri = Uri::from_static("/static/does/not/exist.txt");
@@ -75,8 +75,11 @@ pub(crate) struct UpdateUserDetailsInput {
 }
 
 /// User information for authentication templates and session state.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub(crate) struct User {
+    /// Whether the user is an administrator.
+    pub admin: bool,
     /// Whether the user has a profile.
     pub has_profile: bool,
     /// Whether the user is logged in.
@@ -96,6 +99,7 @@ impl From<AuthSession> for User {
         let user = session.user.as_ref();
 
         Self {
+            admin: user.is_some_and(|u| u.admin),
             has_profile: user.is_some_and(|u| u.has_profile),
             logged_in: user.is_some(),
             moderator: user.is_some_and(|u| u.moderator),
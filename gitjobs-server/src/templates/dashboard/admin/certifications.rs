@@ -0,0 +1,49 @@
+//! Templates and types for the admin dashboard certifications page.
+
+use askama::Template;
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    templates::{helpers::empty_string_as_none, misc::Certification},
+    validation::{MAX_LEN_DESCRIPTION_SHORT, MAX_LEN_ENTITY_NAME, MAX_LEN_L, MAX_LEN_S, trimmed_non_empty},
+};
+
+// Pages templates.
+
+/// Template for the certifications page in the admin dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/admin/certifications.html")]
+pub(crate) struct CertificationsPage {
+    /// Certifications available in the catalogue.
+    pub certifications: Vec<Certification>,
+}
+
+// Types.
+
+/// Information for adding or updating a certification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub(crate) struct CertificationInput {
+    /// Full name of the certification.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_ENTITY_NAME))]
+    pub name: String,
+    /// Provider of the certification.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
+    pub provider: String,
+    /// Short name or abbreviation.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
+    pub short_name: String,
+
+    /// Description of the certification.
+    #[garde(length(max = MAX_LEN_DESCRIPTION_SHORT))]
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub description: Option<String>,
+    /// Logo URL for the certification.
+    #[garde(url, length(max = MAX_LEN_L))]
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub logo_url: Option<String>,
+    /// URL to certification information.
+    #[garde(url, length(max = MAX_LEN_L))]
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub url: Option<String>,
+}
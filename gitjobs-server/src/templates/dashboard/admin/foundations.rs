@@ -0,0 +1,67 @@
+//! Templates and types for the admin dashboard foundations page.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    templates::{filters, helpers::empty_string_as_none},
+    validation::{MAX_LEN_L, MAX_LEN_S, trimmed_non_empty},
+};
+
+// Pages templates.
+
+/// Template for the foundations page in the admin dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/admin/foundations.html")]
+pub(crate) struct FoundationsPage {
+    /// Foundations available in the site.
+    pub foundations: Vec<Foundation>,
+}
+
+// Types.
+
+/// Foundation information for the admin dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Foundation {
+    /// Unique identifier for the foundation.
+    pub foundation_id: Uuid,
+    /// Number of members synced from the foundation landscape.
+    pub members: u64,
+    /// Name of the foundation.
+    pub name: String,
+    /// Number of projects synced from the foundation landscape.
+    pub projects: u64,
+
+    /// Landscape URL used to sync projects and members.
+    pub landscape_url: Option<String>,
+    /// Time the last successful synchronization finished.
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
+/// Information for adding a new foundation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub(crate) struct NewFoundation {
+    /// Name of the foundation.
+    #[garde(custom(trimmed_non_empty), length(max = MAX_LEN_S))]
+    pub name: String,
+
+    /// Landscape URL used to sync projects and members.
+    #[garde(url, length(max = MAX_LEN_L))]
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub landscape_url: Option<String>,
+}
+
+/// Information for updating an existing foundation.
+///
+/// Foundation names are referenced by projects and members, so only the
+/// landscape URL can be changed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub(crate) struct FoundationUpdate {
+    /// Landscape URL used to sync projects and members.
+    #[garde(url, length(max = MAX_LEN_L))]
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub landscape_url: Option<String>,
+}
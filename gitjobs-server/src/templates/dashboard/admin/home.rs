@@ -0,0 +1,86 @@
+//! Templates and types for the admin dashboard home page.
+
+use askama::Template;
+use axum_messages::{Level, Message};
+use serde::{Deserialize, Serialize};
+
+use crate::templates::{
+    Config, PageId,
+    auth::User,
+    dashboard::admin::{certifications, foundations, moderators},
+    filters,
+};
+
+// Pages templates.
+
+/// Template for the admin dashboard home page.
+#[derive(Debug, Clone, Template)]
+#[template(path = "dashboard/admin/home.html")]
+pub(crate) struct Page {
+    /// Server configuration.
+    pub cfg: Config,
+    /// Content section for the dashboard.
+    pub content: Content,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Flash or status messages to display.
+    pub messages: Vec<Message>,
+    /// Authenticated user information.
+    pub user: User,
+
+    /// Name of the authentication provider, if any.
+    pub auth_provider: Option<String>,
+}
+
+// Types.
+
+/// Content section for the admin dashboard home page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum Content {
+    /// Certifications page content.
+    Certifications(certifications::CertificationsPage),
+    /// Foundations page content.
+    Foundations(foundations::FoundationsPage),
+    /// Moderators page content.
+    Moderators(moderators::ModeratorsPage),
+}
+
+impl Content {
+    /// Check if the content is the certifications page.
+    fn is_certifications(&self) -> bool {
+        matches!(self, Content::Certifications(_))
+    }
+
+    /// Check if the content is the foundations page.
+    fn is_foundations(&self) -> bool {
+        matches!(self, Content::Foundations(_))
+    }
+
+    /// Check if the content is the moderators page.
+    fn is_moderators(&self) -> bool {
+        matches!(self, Content::Moderators(_))
+    }
+}
+
+impl std::fmt::Display for Content {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Content::Certifications(template) => write!(f, "{}", template.render()?),
+            Content::Foundations(template) => write!(f, "{}", template.render()?),
+            Content::Moderators(template) => write!(f, "{}", template.render()?),
+        }
+    }
+}
+
+/// Tab selection for the admin dashboard home page.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum Tab {
+    /// Certifications tab.
+    Certifications,
+    /// Foundations tab.
+    Foundations,
+    /// Moderators tab (default).
+    #[default]
+    Moderators,
+}
@@ -0,0 +1,6 @@
+//! This module defines the templates for the admin dashboard.
+
+pub(crate) mod certifications;
+pub(crate) mod foundations;
+pub(crate) mod home;
+pub(crate) mod moderators;
@@ -0,0 +1,43 @@
+//! Templates and types for the admin dashboard moderators page.
+
+use askama::Template;
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::validation::MAX_LEN_M;
+
+// Pages templates.
+
+/// Template for the moderators page in the admin dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/admin/moderators.html")]
+pub(crate) struct ModeratorsPage {
+    /// Users with moderator rights.
+    pub moderators: Vec<Moderator>,
+}
+
+// Types.
+
+/// User with moderator rights.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Moderator {
+    /// Whether the user is also an administrator.
+    pub admin: bool,
+    /// Email address of the user.
+    pub email: String,
+    /// Display name of the user.
+    pub name: String,
+    /// Unique identifier for the user.
+    pub user_id: Uuid,
+    /// Username of the user.
+    pub username: String,
+}
+
+/// Information for granting moderator rights to a user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub(crate) struct NewModerator {
+    /// Email address of the user.
+    #[garde(email, length(max = MAX_LEN_M))]
+    pub email: String,
+}
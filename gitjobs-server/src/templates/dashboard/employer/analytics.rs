@@ -0,0 +1,265 @@
+//! Templates and types for the employer dashboard analytics page.
+
+use std::fmt::Write as _;
+
+use askama::Template;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::templates::{dashboard::employer::jobs::JobStatus, filters};
+
+// Pages templates.
+
+/// Analytics page template for the employer dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/employer/analytics/page.html")]
+pub(crate) struct AnalyticsPage {
+    /// Analytics of the employer's jobs for the selected period.
+    pub analytics: EmployerAnalytics,
+    /// Filters applied to the analytics.
+    pub filters: Filters,
+}
+
+// Types.
+
+/// Analytics of the employer's jobs for a given period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EmployerAnalytics {
+    /// Funnel of each job per day, only including days with some activity.
+    pub daily: Vec<DailyJobFunnel>,
+    /// Funnel of each job for the whole period, sorted by views.
+    pub jobs: Vec<JobFunnel>,
+    /// Funnel of all the employer's jobs for the whole period.
+    pub totals: Funnel,
+}
+
+impl EmployerAnalytics {
+    /// Returns the daily series in CSV format.
+    pub(crate) fn daily_csv(&self) -> String {
+        let mut csv = String::from("day,job_id,title,search_appearances,views,apply_clicks,applications\n");
+        for entry in &self.daily {
+            let Funnel {
+                applications,
+                apply_clicks,
+                search_appearances,
+                views,
+            } = entry.funnel;
+            _ = writeln!(
+                csv,
+                "{},{},{},{search_appearances},{views},{apply_clicks},{applications}",
+                entry.day,
+                entry.job_id,
+                csv_field(&entry.title),
+            );
+        }
+        csv
+    }
+}
+
+/// Funnel of a job on a given day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DailyJobFunnel {
+    /// Day the counters belong to.
+    pub day: NaiveDate,
+    /// Funnel counters for the day.
+    #[serde(flatten)]
+    pub funnel: Funnel,
+    /// Unique identifier of the job.
+    pub job_id: Uuid,
+    /// Title of the job.
+    pub title: String,
+}
+
+/// Funnel of a job for the whole period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct JobFunnel {
+    /// Funnel counters for the period.
+    #[serde(flatten)]
+    pub funnel: Funnel,
+    /// Unique identifier of the job.
+    pub job_id: Uuid,
+    /// Current status of the job.
+    pub status: JobStatus,
+    /// Title of the job.
+    pub title: String,
+}
+
+/// Counters of each step of the funnel: search appearances, views, apply clicks
+/// and applications.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Funnel {
+    /// Number of applications received.
+    pub applications: u64,
+    /// Number of clicks on the apply button.
+    pub apply_clicks: u64,
+    /// Number of times the job appeared in search results.
+    pub search_appearances: u64,
+    /// Number of times the job was viewed.
+    pub views: u64,
+}
+
+impl Funnel {
+    /// Returns the steps of the funnel, along with the conversion rate from the
+    /// previous step and their width relative to the largest step.
+    pub(crate) fn steps(&self) -> Vec<FunnelStep> {
+        let values = [
+            ("Search appearances", self.search_appearances),
+            ("Views", self.views),
+            ("Apply clicks", self.apply_clicks),
+            ("Applications", self.applications),
+        ];
+        let max = values.iter().map(|(_, value)| *value).max().unwrap_or_default();
+
+        let mut steps = Vec::with_capacity(values.len());
+        let mut previous: Option<u64> = None;
+        for (name, value) in values {
+            steps.push(FunnelStep {
+                name,
+                rate: previous.map(|previous| conversion_rate(value, previous)),
+                value,
+                width: percentage(value, max),
+            });
+            previous = Some(value);
+        }
+        steps
+    }
+
+    /// Returns the views to search appearances conversion rate.
+    pub(crate) fn views_rate(&self) -> String {
+        conversion_rate(self.views, self.search_appearances)
+    }
+
+    /// Returns the apply clicks to views conversion rate.
+    pub(crate) fn apply_clicks_rate(&self) -> String {
+        conversion_rate(self.apply_clicks, self.views)
+    }
+
+    /// Returns the applications to apply clicks conversion rate.
+    pub(crate) fn applications_rate(&self) -> String {
+        conversion_rate(self.applications, self.apply_clicks)
+    }
+}
+
+/// Step of the funnel, prepared to be rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FunnelStep {
+    /// Name of the step.
+    pub name: &'static str,
+    /// Value of the step.
+    pub value: u64,
+    /// Width of the step, as a percentage of the largest step.
+    pub width: u64,
+
+    /// Conversion rate from the previous step, if any.
+    pub rate: Option<String>,
+}
+
+/// Filters used to select the analytics period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Filters {
+    /// Period covered by the analytics.
+    #[serde(default)]
+    pub range: Range,
+}
+
+/// Options used to export the analytics daily series.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ExportOptions {
+    /// Format of the exported data.
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Period covered by the exported data.
+    #[serde(default)]
+    pub range: Range,
+}
+
+/// Format of the exported analytics data.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ExportFormat {
+    /// Comma separated values.
+    #[default]
+    Csv,
+    /// JSON array of daily entries.
+    Json,
+}
+
+/// Period covered by the analytics.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+pub(crate) enum Range {
+    /// Last 7 days.
+    #[serde(rename = "7d")]
+    #[strum(serialize = "7d")]
+    Week,
+    /// Last 30 days (default).
+    #[default]
+    #[serde(rename = "30d")]
+    #[strum(serialize = "30d")]
+    Month,
+    /// Last 90 days.
+    #[serde(rename = "90d")]
+    #[strum(serialize = "90d")]
+    Quarter,
+    /// Last 365 days.
+    #[serde(rename = "365d")]
+    #[strum(serialize = "365d")]
+    Year,
+}
+
+impl Range {
+    /// All the ranges available, in the order they are displayed.
+    pub(crate) const ALL: [Range; 4] = [Range::Week, Range::Month, Range::Quarter, Range::Year];
+
+    /// Returns the number of days covered by the range.
+    pub(crate) fn days(self) -> i32 {
+        match self {
+            Range::Week => 7,
+            Range::Month => 30,
+            Range::Quarter => 90,
+            Range::Year => 365,
+        }
+    }
+
+    /// Returns the label used to display the range.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Range::Week => "Last 7 days",
+            Range::Month => "Last 30 days",
+            Range::Quarter => "Last 90 days",
+            Range::Year => "Last 365 days",
+        }
+    }
+}
+
+/// Returns the conversion rate between two values as a percentage, or a dash
+/// when it cannot be calculated.
+#[allow(clippy::cast_precision_loss)]
+fn conversion_rate(value: u64, from: u64) -> String {
+    if from == 0 {
+        return "-".to_string();
+    }
+    format!("{:.1}%", value as f64 * 100.0 / from as f64)
+}
+
+/// Returns the percentage a value represents of the total provided.
+fn percentage(value: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+    value.saturating_mul(100) / total
+}
+
+/// Escapes a CSV field when needed.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
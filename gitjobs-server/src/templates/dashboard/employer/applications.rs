@@ -9,8 +9,9 @@ use uuid::Uuid;
 
 use crate::templates::{
     dashboard::employer::jobs::{JobSummary, Workplace},
+    filters,
     helpers::{DATE_FORMAT, build_dashboard_image_url},
-    misc::Location,
+    misc::{JobMatch, Location},
     pagination::{NavigationLinks, Pagination},
 };
 
@@ -60,6 +61,9 @@ pub(crate) struct Application {
     /// Workplace type for the job.
     job_workplace: Workplace,
 
+    /// How well the applicant's profile matches the job, if available.
+    #[serde(rename = "match")]
+    job_match: Option<JobMatch>,
     /// Location of the job, if specified.
     job_location: Option<Location>,
     /// Last position held by the applicant, if any.
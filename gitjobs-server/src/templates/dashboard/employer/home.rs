@@ -47,6 +47,8 @@ pub(crate) struct Page {
 pub(crate) enum Content {
     /// User account page.
     Account(auth::UpdateUserPage),
+    /// Employer analytics page.
+    Analytics(employer::analytics::AnalyticsPage),
     /// Applications list page.
     Applications(employer::applications::ApplicationsPage),
     /// Initial setup page for employer profile.
@@ -67,6 +69,11 @@ impl Content {
         matches!(self, Content::Account(_))
     }
 
+    /// Check if the content is the analytics page.
+    fn is_analytics(&self) -> bool {
+        matches!(self, Content::Analytics(_))
+    }
+
     /// Check if the content is the applications page.
     fn is_applications(&self) -> bool {
         matches!(self, Content::Applications(_))
@@ -97,6 +104,7 @@ impl std::fmt::Display for Content {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Content::Account(template) => write!(f, "{}", template.render()?),
+            Content::Analytics(template) => write!(f, "{}", template.render()?),
             Content::Applications(template) => write!(f, "{}", template.render()?),
             Content::EmployerInitialSetup(template) => write!(f, "{}", template.render()?),
             Content::Invitations(template) => write!(f, "{}", template.render()?),
@@ -113,6 +121,8 @@ impl std::fmt::Display for Content {
 pub(crate) enum Tab {
     /// User account tab.
     Account,
+    /// Analytics tab.
+    Analytics,
     /// Applications tab.
     Applications,
     /// Employer initial setup tab.
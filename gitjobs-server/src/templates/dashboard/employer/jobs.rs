@@ -229,14 +229,27 @@ impl Job {
 /// Statistics for a specific job.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct JobStats {
+    /// Daily apply clicks for the last month.
+    /// Each entry is a tuple of (`timestamp_ms`, count).
+    pub apply_clicks_daily: Option<Vec<(u64, u64)>>,
+    /// Total apply clicks in the last month.
+    pub apply_clicks_total_last_month: u64,
     /// Daily search appearances for the last month.
     /// Each entry is a tuple of (`timestamp_ms`, count).
     pub search_appearances_daily: Option<Vec<(u64, u64)>>,
+    /// Total search appearances, including the ones rolled up into monthly
+    /// aggregates.
+    pub search_appearances_total: u64,
     /// Total search appearances in the last month.
     pub search_appearances_total_last_month: u64,
     /// Daily views for the last month.
     /// Each entry is a tuple of (`timestamp_ms`, count).
     pub views_daily: Option<Vec<(u64, u64)>>,
+    /// Views in the last month broken down by traffic source.
+    /// Each entry is a tuple of (source, count), sorted by count.
+    pub views_sources_last_month: Option<Vec<(String, u64)>>,
+    /// Total views, including the ones rolled up into monthly aggregates.
+    pub views_total: u64,
     /// Total views in the last month.
     pub views_total_last_month: u64,
 }
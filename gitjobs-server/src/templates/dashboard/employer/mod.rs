@@ -1,5 +1,6 @@
 //! This module defines the templates for the employer dashboard.
 
+pub(crate) mod analytics;
 pub(crate) mod applications;
 pub(crate) mod employers;
 pub(crate) mod home;
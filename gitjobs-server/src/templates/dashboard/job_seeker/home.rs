@@ -44,6 +44,8 @@ pub(crate) enum Content {
     Applications(job_seeker::applications::ApplicationsPage),
     /// Profile update page content.
     Profile(job_seeker::profile::UpdatePage),
+    /// Recommended jobs page content.
+    Recommended(job_seeker::recommended::RecommendedPage),
 }
 
 impl Content {
@@ -62,6 +64,11 @@ impl Content {
     fn is_profile(&self) -> bool {
         matches!(self, Content::Profile(_))
     }
+
+    /// Check if the content is the recommended jobs page.
+    fn is_recommended(&self) -> bool {
+        matches!(self, Content::Recommended(_))
+    }
 }
 
 impl std::fmt::Display for Content {
@@ -70,6 +77,7 @@ impl std::fmt::Display for Content {
             Content::Account(template) => write!(f, "{}", template.render()?),
             Content::Applications(template) => write!(f, "{}", template.render()?),
             Content::Profile(template) => write!(f, "{}", template.render()?),
+            Content::Recommended(template) => write!(f, "{}", template.render()?),
         }
     }
 }
@@ -85,4 +93,6 @@ pub(crate) enum Tab {
     /// Profile tab (default).
     #[default]
     Profile,
+    /// Recommended jobs tab.
+    Recommended,
 }
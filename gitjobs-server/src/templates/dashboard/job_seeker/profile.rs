@@ -10,7 +10,7 @@ use uuid::Uuid;
 use crate::{
     templates::{
         filters,
-        helpers::{DATE_FORMAT_2, build_dashboard_image_url, normalize},
+        helpers::{DATE_FORMAT_2, build_dashboard_image_srcset, build_dashboard_image_url, normalize},
         misc::Location,
     },
     validation::{
@@ -0,0 +1,21 @@
+//! Templates and types for the job seeker recommended jobs page.
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::templates::{
+    dashboard::employer::jobs::Workplace,
+    filters,
+    helpers::{DATE_FORMAT_3, build_jobboard_image_srcset, build_jobboard_image_url},
+    jobboard::jobs::JobSummary,
+};
+
+// Pages templates.
+
+/// Recommended jobs page template for job seeker dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/job_seeker/recommended/list.html")]
+pub(crate) struct RecommendedPage {
+    /// Jobs recommended to the job seeker based on their profile.
+    pub jobs: Vec<JobSummary>,
+}
@@ -1,5 +1,6 @@
 //! This module defines the templates for the dashboard pages.
 
+pub(crate) mod admin;
 pub(crate) mod employer;
 pub(crate) mod job_seeker;
 pub(crate) mod moderator;
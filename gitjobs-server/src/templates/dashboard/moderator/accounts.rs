@@ -0,0 +1,244 @@
+//! Templates and types for moderator dashboard accounts pages.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::templates::{
+    helpers::{DATE_FORMAT, build_dashboard_image_url, empty_string_as_none},
+    pagination::{NavigationLinks, Pagination, build_url},
+};
+
+// Pages templates.
+
+/// Template for the moderation activity page in the moderator dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/moderator/activity.html")]
+pub(crate) struct ActivityPage {
+    /// Most recent moderation actions.
+    pub actions: Vec<ModerationAction>,
+}
+
+/// Template for the employers page in the moderator dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/moderator/employers.html")]
+pub(crate) struct EmployersPage {
+    /// List of employers matching the filters.
+    pub employers: Vec<Employer>,
+    /// Filters applied to the employers list.
+    pub filters: EmployersFilters,
+    /// Navigation links for pagination.
+    pub navigation_links: NavigationLinks,
+}
+
+/// Template for the users page in the moderator dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/moderator/users.html")]
+pub(crate) struct UsersPage {
+    /// Filters applied to the users list.
+    pub filters: UsersFilters,
+    /// Navigation links for pagination.
+    pub navigation_links: NavigationLinks,
+    /// List of users matching the filters.
+    pub users: Vec<User>,
+}
+
+// Types.
+
+/// Employer information used in the moderator dashboard accounts pages.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Employer {
+    /// Name of the company.
+    pub company: String,
+    /// Timestamp when the employer was created.
+    pub created_at: DateTime<Utc>,
+    /// Unique identifier for the employer.
+    pub employer_id: Uuid,
+    /// Number of jobs currently published by the employer.
+    pub published_jobs: i64,
+    /// Whether the employer is currently suspended.
+    pub suspended: bool,
+
+    /// Optional logo identifier for the employer.
+    pub logo_id: Option<Uuid>,
+    /// Timestamp when the employer was suspended.
+    pub suspended_at: Option<DateTime<Utc>>,
+    /// Timestamp when the suspension expires.
+    pub suspended_until: Option<DateTime<Utc>>,
+    /// Reason provided by the moderator for the suspension.
+    pub suspension_reason: Option<String>,
+    /// Optional website URL for the employer.
+    pub website_url: Option<String>,
+}
+
+/// Filters used to search for employers in the moderator dashboard.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EmployersFilters {
+    /// Limit the number of results.
+    pub limit: Option<usize>,
+    /// Offset for pagination.
+    pub offset: Option<usize>,
+    /// Text to search for in the company name or website.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub ts_query: Option<String>,
+}
+
+impl EmployersFilters {
+    /// URL used to reload the current page of results.
+    pub(crate) fn current_hx_url(&self) -> String {
+        build_url(&self.get_base_hx_url(), self).unwrap_or_else(|_| self.get_base_hx_url())
+    }
+}
+
+impl Pagination for EmployersFilters {
+    fn get_base_hx_url(&self) -> String {
+        "/dashboard/moderator/employers".to_string()
+    }
+
+    fn get_base_url(&self) -> String {
+        "/dashboard/moderator?tab=employers".to_string()
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: Option<usize>) {
+        self.offset = offset;
+    }
+}
+
+/// Action taken by a moderator, as recorded in the audit trail.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ModerationAction {
+    /// Timestamp when the action was taken.
+    pub created_at: DateTime<Utc>,
+    /// Kind of action taken.
+    pub kind: ModerationActionKind,
+    /// Unique identifier for the moderation action.
+    pub moderation_action_id: Uuid,
+
+    /// Company name of the employer affected by the action.
+    pub employer_company: Option<String>,
+    /// Timestamp when the suspension expires, if applicable.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Title of the job affected by the action.
+    pub job_title: Option<String>,
+    /// Name of the moderator who took the action.
+    pub moderator_name: Option<String>,
+    /// Reason or notes provided by the moderator.
+    pub reason: Option<String>,
+    /// Name of the user affected by the action.
+    pub user_name: Option<String>,
+}
+
+/// Kind of action a moderator can take.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ModerationActionKind {
+    /// A job was approved.
+    ApproveJob,
+    /// A job was rejected.
+    RejectJob,
+    /// An employer was suspended.
+    SuspendEmployer,
+    /// A user was suspended.
+    SuspendUser,
+    /// An employer suspension was lifted.
+    UnsuspendEmployer,
+    /// A user suspension was lifted.
+    UnsuspendUser,
+}
+
+impl ModerationActionKind {
+    /// Human readable label for the action kind.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            ModerationActionKind::ApproveJob => "Approved job",
+            ModerationActionKind::RejectJob => "Rejected job",
+            ModerationActionKind::SuspendEmployer => "Suspended employer",
+            ModerationActionKind::SuspendUser => "Suspended user",
+            ModerationActionKind::UnsuspendEmployer => "Unsuspended employer",
+            ModerationActionKind::UnsuspendUser => "Unsuspended user",
+        }
+    }
+}
+
+/// User information used in the moderator dashboard accounts pages.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct User {
+    /// Timestamp when the user signed up.
+    pub created_at: DateTime<Utc>,
+    /// User's email address.
+    pub email: String,
+    /// Whether the user is a moderator.
+    pub moderator: bool,
+    /// User's display name.
+    pub name: String,
+    /// Whether the user is currently suspended.
+    pub suspended: bool,
+    /// Unique identifier for the user.
+    pub user_id: Uuid,
+    /// User's username.
+    pub username: String,
+
+    /// Timestamp when the user was suspended.
+    pub suspended_at: Option<DateTime<Utc>>,
+    /// Timestamp when the suspension expires.
+    pub suspended_until: Option<DateTime<Utc>>,
+    /// Reason provided by the moderator for the suspension.
+    pub suspension_reason: Option<String>,
+}
+
+/// Filters used to search for users in the moderator dashboard.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct UsersFilters {
+    /// Limit the number of results.
+    pub limit: Option<usize>,
+    /// Offset for pagination.
+    pub offset: Option<usize>,
+    /// Text to search for in the user's email, name or username.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub ts_query: Option<String>,
+}
+
+impl UsersFilters {
+    /// URL used to reload the current page of results.
+    pub(crate) fn current_hx_url(&self) -> String {
+        build_url(&self.get_base_hx_url(), self).unwrap_or_else(|_| self.get_base_hx_url())
+    }
+}
+
+impl Pagination for UsersFilters {
+    fn get_base_hx_url(&self) -> String {
+        "/dashboard/moderator/users".to_string()
+    }
+
+    fn get_base_url(&self) -> String {
+        "/dashboard/moderator?tab=users".to_string()
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: Option<usize>) {
+        self.offset = offset;
+    }
+}
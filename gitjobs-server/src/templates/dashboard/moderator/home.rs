@@ -4,7 +4,12 @@ use askama::Template;
 use axum_messages::{Level, Message};
 use serde::{Deserialize, Serialize};
 
-use crate::templates::{Config, PageId, auth::User, dashboard::moderator::jobs, filters};
+use crate::templates::{
+    Config, PageId,
+    auth::User,
+    dashboard::moderator::{accounts, jobs, stats},
+    filters,
+};
 
 // Pages templates.
 
@@ -32,13 +37,31 @@ pub(crate) struct Page {
 /// Content section for the moderator dashboard home page.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum Content {
+    /// Moderation activity page content.
+    Activity(accounts::ActivityPage),
+    /// Employers page content.
+    Employers(accounts::EmployersPage),
     /// Live jobs page content.
     LiveJobs(jobs::LivePage),
     /// Pending jobs page content.
     PendingJobs(jobs::PendingPage),
+    /// Stats page content.
+    Stats(stats::StatsPage),
+    /// Users page content.
+    Users(accounts::UsersPage),
 }
 
 impl Content {
+    /// Check if the content is the moderation activity page.
+    fn is_activity(&self) -> bool {
+        matches!(self, Content::Activity(_))
+    }
+
+    /// Check if the content is the employers page.
+    fn is_employers(&self) -> bool {
+        matches!(self, Content::Employers(_))
+    }
+
     /// Check if the content is the live jobs page.
     fn is_live_jobs(&self) -> bool {
         matches!(self, Content::LiveJobs(_))
@@ -48,13 +71,27 @@ impl Content {
     fn is_pending_jobs(&self) -> bool {
         matches!(self, Content::PendingJobs(_))
     }
+
+    /// Check if the content is the stats page.
+    fn is_stats(&self) -> bool {
+        matches!(self, Content::Stats(_))
+    }
+
+    /// Check if the content is the users page.
+    fn is_users(&self) -> bool {
+        matches!(self, Content::Users(_))
+    }
 }
 
 impl std::fmt::Display for Content {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Content::Activity(template) => write!(f, "{}", template.render()?),
+            Content::Employers(template) => write!(f, "{}", template.render()?),
             Content::LiveJobs(template) => write!(f, "{}", template.render()?),
             Content::PendingJobs(template) => write!(f, "{}", template.render()?),
+            Content::Stats(template) => write!(f, "{}", template.render()?),
+            Content::Users(template) => write!(f, "{}", template.render()?),
         }
     }
 }
@@ -63,9 +100,17 @@ impl std::fmt::Display for Content {
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "kebab-case")]
 pub(crate) enum Tab {
+    /// Moderation activity tab.
+    Activity,
+    /// Employers tab.
+    Employers,
     /// Live jobs tab.
     LiveJobs,
     /// Pending jobs tab (default).
     #[default]
     PendingJobs,
+    /// Stats tab.
+    Stats,
+    /// Users tab.
+    Users,
 }
@@ -1,4 +1,6 @@
 //! This module defines the templates for the moderator dashboard.
 
+pub(crate) mod accounts;
 pub(crate) mod home;
 pub(crate) mod jobs;
+pub(crate) mod stats;
@@ -0,0 +1,133 @@
+//! Templates and types for the moderator dashboard stats page.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::templates::helpers::DATE_FORMAT;
+
+// Pages templates.
+
+/// Template for the stats page in the moderator dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/moderator/stats.html")]
+pub(crate) struct StatsPage {
+    /// Moderation stats information.
+    pub stats: Stats,
+}
+
+// Types.
+
+/// Moderation stats information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Stats {
+    /// Reviews statistics.
+    pub reviews: ReviewsStats,
+    /// Maximum number of hours a job should wait for review.
+    pub review_sla_hours: u64,
+    /// Timestamp representing the current time.
+    pub ts_now: Timestamp,
+    /// Timestamp representing three months ago.
+    pub ts_three_months_ago: Timestamp,
+}
+
+/// Reviews statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ReviewsStats {
+    /// Number of jobs currently waiting for review.
+    pub pending: Total,
+    /// Number of jobs waiting for review longer than the SLA.
+    pub pending_over_sla: Total,
+
+    /// Number of approvals and rejections per moderator.
+    /// Each entry is a tuple of (moderator, approvals, rejections).
+    pub decisions_per_moderator: Option<Vec<(String, Total, Total)>>,
+
+    /// Number of approvals and rejections per week.
+    /// Each entry is a tuple of (timestamp, approvals, rejections).
+    pub decisions_weekly: Option<Vec<(Timestamp, Total, Total)>>,
+
+    /// Median time from review request to decision.
+    pub median_review_time: Option<Seconds>,
+
+    /// Median time from review request to decision per week.
+    /// Each entry is a tuple of (timestamp, seconds).
+    pub median_review_time_weekly: Option<Vec<(Timestamp, Seconds)>>,
+
+    /// Jobs that have been waiting for review the longest.
+    pub oldest_pending: Option<Vec<PendingJob>>,
+
+    /// Number of jobs waiting for review at the end of each day.
+    /// Each entry is a tuple of (timestamp, count).
+    pub pending_daily: Option<Vec<(Timestamp, Total)>>,
+}
+
+impl ReviewsStats {
+    /// Returns the median review time formatted for display.
+    pub(crate) fn median_review_time_label(&self) -> Option<String> {
+        self.median_review_time.map(format_duration)
+    }
+}
+
+/// Job waiting for review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PendingJob {
+    /// Name of the company that posted the job.
+    pub company: String,
+    /// Unique identifier for the job.
+    pub job_id: Uuid,
+    /// Whether the job has been waiting longer than the SLA.
+    pub over_sla: bool,
+    /// Timestamp when the review was requested.
+    pub review_requested_at: DateTime<Utc>,
+    /// Title of the job.
+    pub title: String,
+}
+
+/// Formats a duration in seconds as a short human readable string.
+fn format_duration(seconds: Seconds) -> String {
+    let hours = seconds / 3600;
+    if hours >= 24 {
+        format!("{}d {}h", hours / 24, hours % 24)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, (seconds % 3600) / 60)
+    } else {
+        format!("{}m", seconds / 60)
+    }
+}
+
+/// Type alias for a duration in seconds.
+type Seconds = u64;
+
+/// Type alias for a timestamp.
+type Timestamp = u64;
+
+/// Type alias for a total count.
+type Total = u64;
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::format_duration;
+
+    // Macro for generating tests for the format_duration function.
+    macro_rules! format_duration_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (seconds, expected) = $value;
+                assert_eq!(format_duration(seconds), expected);
+            }
+        )*
+        }
+    }
+
+    format_duration_tests! {
+        test_format_duration_minutes: (150, "2m"),
+        test_format_duration_hours: (5_400, "1h 30m"),
+        test_format_duration_days: (93_600, "1d 2h"),
+    }
+}
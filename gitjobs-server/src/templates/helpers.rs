@@ -4,12 +4,13 @@ use std::{collections::HashMap, sync::LazyLock, time::Duration};
 
 use anyhow::Result;
 use cached::proc_macro::cached;
+use chrono::NaiveDate;
 use regex::Regex;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, de};
 use tracing::{debug, warn};
 use uuid::Uuid;
 
-use crate::templates::dashboard::employer::employers::EmployerSummary;
+use crate::{img::RETINA_SUFFIX, templates::dashboard::employer::employers::EmployerSummary};
 
 /// The date format used in the templates (YYYY-MM-DD).
 pub(crate) const DATE_FORMAT: &str = "%Y-%m-%d";
@@ -25,11 +26,28 @@ pub(crate) fn build_dashboard_image_url(image_id: &Uuid, version: &str) -> Strin
     format!("/dashboard/images/{image_id}/{version}")
 }
 
+/// Build dashboard image srcset for a specific image version, including its
+/// retina (2x) variant.
+pub(crate) fn build_dashboard_image_srcset(image_id: &Uuid, version: &str) -> String {
+    build_image_srcset(&build_dashboard_image_url(image_id, version))
+}
+
 /// Build job board image URL for a specific image version.
 pub(crate) fn build_jobboard_image_url(image_id: &Uuid, version: &str) -> String {
     format!("/jobboard/images/{image_id}/{version}")
 }
 
+/// Build job board image srcset for a specific image version, including its
+/// retina (2x) variant.
+pub(crate) fn build_jobboard_image_srcset(image_id: &Uuid, version: &str) -> String {
+    build_image_srcset(&build_jobboard_image_url(image_id, version))
+}
+
+/// Build an image srcset from the URL of the 1x version of the image.
+fn build_image_srcset(url: &str) -> String {
+    format!("{url} 1x, {url}{RETINA_SUFFIX} 2x")
+}
+
 /// Deserializes an optional string and maps empty values to `None`.
 pub(crate) fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
@@ -40,6 +58,16 @@ where
     Ok(value.and_then(|value| if value.is_empty() { None } else { Some(value) }))
 }
 
+/// Deserializes an optional date (YYYY-MM-DD) and maps empty values to `None`.
+pub(crate) fn empty_string_as_none_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    empty_string_as_none(deserializer)?
+        .map(|value| NaiveDate::parse_from_str(&value, DATE_FORMAT).map_err(de::Error::custom))
+        .transpose()
+}
+
 /// Find an employer by id in a list of employers.
 pub(crate) fn find_employer<'a>(
     employer_id: Option<&'a Uuid>,
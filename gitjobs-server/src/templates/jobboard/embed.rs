@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::templates::{
     dashboard::employer::jobs::{JobKind, Workplace},
     filters,
-    helpers::{DATE_FORMAT_3, build_jobboard_image_url},
+    helpers::{DATE_FORMAT_3, build_jobboard_image_srcset, build_jobboard_image_url},
     jobboard::jobs::{Job, JobSummary},
 };
 
@@ -1,5 +1,7 @@
 //! Templates and types for job board pages, sections, and job-related data.
 
+use std::collections::BTreeMap;
+
 use askama::Template;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -11,8 +13,11 @@ use crate::templates::{
     auth::User,
     dashboard::employer::jobs::{JobKind, SalaryKind, Workplace},
     filters,
-    helpers::{DATE_FORMAT, DATE_FORMAT_3, build_jobboard_image_url, option_is_none_or_default},
-    misc::{Certification, Foundation, Location, Member, Project},
+    helpers::{
+        DATE_FORMAT, DATE_FORMAT_3, build_jobboard_image_srcset, build_jobboard_image_url,
+        option_is_none_or_default,
+    },
+    misc::{Certification, Foundation, JobMatch, Location, Member, Project},
     pagination::{NavigationLinks, Pagination},
 };
 
@@ -43,6 +48,8 @@ pub(crate) struct ExploreSection {
     pub filters: Filters,
     /// Available options for filters.
     pub filters_options: FiltersOptions,
+    /// Whether jobs can be sorted by how well they match the user's profile.
+    pub match_available: bool,
     /// Section displaying the results.
     pub results_section: ResultsSection,
 }
@@ -58,6 +65,8 @@ pub(crate) struct ResultsSection {
     /// Total number of jobs found.
     pub total: usize,
 
+    /// Number of jobs available for each filter option, if requested.
+    pub facets: Option<Facets>,
     /// Offset for pagination.
     pub offset: Option<usize>,
 }
@@ -70,6 +79,8 @@ pub(crate) struct JobSection {
     pub base_url: String,
     /// Full job details.
     pub job: Job,
+    /// Published jobs similar to this one.
+    pub similar_jobs: Vec<JobSummary>,
 }
 
 // Types.
@@ -80,6 +91,9 @@ pub(crate) struct Filters {
     /// List of required benefits.
     #[serde(skip_serializing_if = "option_is_none_or_default")]
     pub benefits: Option<Vec<String>>,
+    /// Landscape category of the job projects.
+    #[serde(skip_serializing_if = "option_is_none_or_default")]
+    pub category: Option<String>,
     /// Date range for job posting.
     #[serde(skip_serializing_if = "option_is_none_or_default")]
     pub date_range: Option<DateRange>,
@@ -125,6 +139,14 @@ pub(crate) struct Filters {
     /// Full-text search query.
     #[serde(skip_serializing_if = "option_is_none_or_default")]
     pub ts_query: Option<String>,
+    /// End of the timezone window of the job seeker (e.g. UTC+2).
+    #[serde(skip_serializing_if = "option_is_none_or_default")]
+    pub tz_end: Option<String>,
+    /// Start of the timezone window of the job seeker (e.g. UTC-3). Remote and
+    /// hybrid jobs whose timezone window overlaps with it are returned, as well
+    /// as remote jobs without a timezone window.
+    #[serde(skip_serializing_if = "option_is_none_or_default")]
+    pub tz_start: Option<String>,
     /// Upstream commitment filter.
     #[serde(skip_serializing_if = "option_is_none_or_default")]
     pub upstream_commitment: Option<usize>,
@@ -213,6 +235,8 @@ pub(crate) enum Sort {
     /// Sort by date (default).
     #[default]
     Date,
+    /// Sort by how well jobs match the user's profile.
+    Match,
     /// Sort by open source commitment.
     OpenSource,
     /// Sort by salary.
@@ -221,11 +245,32 @@ pub(crate) enum Sort {
     UpstreamCommitment,
 }
 
+/// Number of jobs available for each filter option under the current filters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Facets {
+    /// Number of jobs per benefit.
+    pub benefits: BTreeMap<String, usize>,
+    /// Number of jobs per foundation.
+    pub foundation: BTreeMap<String, usize>,
+    /// Number of jobs per kind.
+    pub kind: BTreeMap<String, usize>,
+    /// Number of jobs per minimum yearly salary in USD.
+    pub salary: BTreeMap<String, usize>,
+    /// Number of jobs per seniority level.
+    pub seniority: BTreeMap<String, usize>,
+    /// Number of jobs per skill, limited to the most requested ones.
+    pub skills: BTreeMap<String, usize>,
+    /// Number of jobs per workplace.
+    pub workplace: BTreeMap<String, usize>,
+}
+
 /// Options for filters in the explore section, such as available foundations.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct FiltersOptions {
     /// List of available foundations.
     pub foundations: Vec<Foundation>,
+    /// List of available landscape categories of projects.
+    pub project_categories: Vec<String>,
 }
 
 /// Summary information for a job, used in job listings.
@@ -245,6 +290,9 @@ pub(crate) struct JobSummary {
     /// Workplace type for the job.
     pub workplace: Workplace,
 
+    /// How well the job matches the user's profile, if any.
+    #[serde(rename = "match")]
+    pub job_match: Option<JobMatch>,
     /// Location of the job, if specified.
     pub location: Option<Location>,
     /// Open source status, if specified.
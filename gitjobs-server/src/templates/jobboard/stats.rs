@@ -50,10 +50,33 @@ pub(crate) struct JobsStats {
     /// Each entry is a tuple of (year, month, count).
     pub published_per_month: Option<Vec<(Year, Month, Total)>>,
 
+    /// Number of jobs published per workplace.
+    /// Each entry is a tuple of (workplace, count).
+    pub published_per_workplace: Option<Vec<(String, Total)>>,
+
     /// Running total of published jobs.
     /// Each entry is a tuple of (timestamp, count).
     pub published_running_total: Option<Vec<(Timestamp, Total)>>,
 
+    /// Median normalized salary (USD per year) per seniority, for the jobs
+    /// published in the last two years.
+    /// Each entry is a tuple of (seniority, salary).
+    pub salary_median_per_seniority: Option<Vec<(String, Salary)>>,
+
+    /// Median normalized salary (USD per year) per workplace, for the jobs
+    /// published in the last two years.
+    /// Each entry is a tuple of (workplace, salary).
+    pub salary_median_per_workplace: Option<Vec<(String, Salary)>>,
+
+    /// Number of jobs published per month requiring each of the top skills in
+    /// the last two years.
+    /// Each entry is a tuple of (skill, [(timestamp, count)]).
+    pub skills_monthly: Option<Vec<(String, Series)>>,
+
+    /// Projects referenced by the largest number of published jobs.
+    /// Each entry is a tuple of (foundation, project, count).
+    pub top_projects: Option<Vec<(String, String, Total)>>,
+
     /// Number of job views per day.
     /// Each entry is a tuple of (timestamp, count).
     pub views_daily: Option<Vec<(Timestamp, Total)>>,
@@ -66,6 +89,12 @@ pub(crate) struct JobsStats {
 /// Type alias for a month.
 type Month = String;
 
+/// Type alias for a salary amount.
+type Salary = i64;
+
+/// Type alias for a time series of (timestamp, count) entries.
+type Series = Vec<(Timestamp, Total)>;
+
 /// Type alias for a timestamp.
 type Timestamp = u64;
 
@@ -2,6 +2,7 @@
 
 use askama::Template;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::templates::{Config, PageId, auth::User, filters, helpers::format_location};
@@ -63,6 +64,34 @@ pub(crate) struct Foundation {
     pub name: String,
 }
 
+/// How well a job seeker profile matches a job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct JobMatch {
+    /// How the seeker's location fits the job's workplace.
+    pub location_fit: LocationFit,
+    /// Skills required by the job the seeker has.
+    pub matched_skills: Vec<String>,
+    /// Skills required by the job the seeker does not have.
+    pub missing_skills: Vec<String>,
+    /// Match score, from 0 to 100.
+    pub score: i32,
+}
+
+/// How a job seeker's location fits a job's workplace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum LocationFit {
+    /// The seeker is located near the job.
+    Nearby,
+    /// The seeker is open to relocating for the job.
+    Relocation,
+    /// The job is remote and the seeker is open to remote work.
+    Remote,
+    /// The seeker's location does not fit the job.
+    Mismatch,
+}
+
 /// Information about a location.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub(crate) struct Location {
@@ -104,6 +133,7 @@ pub(crate) struct Member {
 }
 
 /// Information about a project.
+#[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct Project {
     /// Unique identifier for the project.
@@ -116,4 +146,32 @@ pub(crate) struct Project {
     pub maturity: String,
     /// Name of the project.
     pub name: String,
+
+    /// Landscape category of the project, if available.
+    pub category: Option<String>,
+    /// Short description of the project, if available.
+    pub description: Option<String>,
+    /// Homepage URL of the project, if available.
+    pub homepage_url: Option<String>,
+    /// Repository URL of the project, if available.
+    pub repository_url: Option<String>,
+    /// Landscape subcategory of the project, if available.
+    pub subcategory: Option<String>,
+}
+
+impl Project {
+    /// Returns the text displayed in the project's tooltip, built from its
+    /// landscape category and description, if available.
+    pub(crate) fn tooltip(&self) -> Option<String> {
+        let category = match (&self.category, &self.subcategory) {
+            (Some(category), Some(subcategory)) => Some(format!("{category} / {subcategory}")),
+            (Some(category), None) => Some(category.clone()),
+            _ => None,
+        };
+        let lines: Vec<&str> = [category.as_deref(), self.description.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
 }
@@ -32,6 +32,7 @@ impl From<HttpServerConfig> for Config {
 #[serde(rename_all = "snake_case")]
 pub(crate) enum PageId {
     About,
+    AdminDashboard,
     EmployerDashboard,
     JobBoard,
     JobSeekerDashboard,
@@ -2,17 +2,49 @@
 
 use std::time::Duration;
 
+use chrono::{TimeDelta, Utc};
 use tokio::time::sleep;
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
-use tracing::{debug, error};
+use tracing::{debug, error, info};
 
-use crate::db::DynDB;
+use crate::{config::StatsConfig, db::DynDB, img::DynImageStore, metrics};
+
+/// Time unreferenced images are kept after being uploaded, so that images that
+/// haven't been linked to a profile or employer yet are not deleted.
+const IMAGES_GC_GRACE_PERIOD: TimeDelta = TimeDelta::hours(24);
+
+/// How often the unreferenced images are deleted.
+const IMAGES_GC_FREQUENCY: Duration = Duration::from_hours(6);
+
+/// How often the daily stats are rolled up into monthly aggregates.
+const STATS_ROLLUP_FREQUENCY: Duration = Duration::from_hours(24);
 
 /// Launches all background workers.
-pub(crate) fn run(db: DynDB, task_tracker: &TaskTracker, cancellation_token: CancellationToken) {
+pub(crate) fn run(
+    db: DynDB,
+    image_store: DynImageStore,
+    stats_cfg: &StatsConfig,
+    task_tracker: &TaskTracker,
+    cancellation_token: &CancellationToken,
+) {
     // Jobs archiver
+    let archiver_db = db.clone();
+    let archiver_cancellation_token = cancellation_token.clone();
+    task_tracker.spawn(async move {
+        archiver(archiver_db, archiver_cancellation_token).await;
+    });
+
+    // Stats rollup
+    let horizon_days = stats_cfg.effective_rollup_horizon_days();
+    let stats_rollup_cancellation_token = cancellation_token.clone();
     task_tracker.spawn(async move {
-        archiver(db, cancellation_token).await;
+        stats_rollup(db, horizon_days, stats_rollup_cancellation_token).await;
+    });
+
+    // Images garbage collector
+    let images_gc_cancellation_token = cancellation_token.clone();
+    task_tracker.spawn(async move {
+        images_gc(image_store, images_gc_cancellation_token).await;
     });
 }
 
@@ -27,13 +59,77 @@ pub(crate) async fn archiver(db: DynDB, cancellation_token: CancellationToken) {
     loop {
         // Archive expired jobs
         debug!("archiving expired jobs");
-        if let Err(err) = db.archive_expired_jobs().await {
+        let result = db.archive_expired_jobs().await;
+        metrics::record_archiver_run(result.is_ok());
+        if let Err(err) = result {
             error!("error archiving expired jobs: {err}");
         }
 
         // Pause for a while before the next iteration
         tokio::select! {
-            () = sleep(Duration::from_secs(60*60)) => {},
+            () = sleep(Duration::from_hours(1)) => {},
+            () = cancellation_token.cancelled() => break,
+        }
+    }
+}
+
+/// Worker that deletes the images that are no longer referenced periodically.
+pub(crate) async fn images_gc(image_store: DynImageStore, cancellation_token: CancellationToken) {
+    // Random sleep to avoid multiple workers running at the same time
+    tokio::select! {
+        () = sleep(Duration::from_secs(rand::random_range(60..300))) => {},
+        () = cancellation_token.cancelled() => return,
+    }
+
+    loop {
+        // Delete unreferenced images
+        debug!("deleting unreferenced images");
+        let created_before = Utc::now() - IMAGES_GC_GRACE_PERIOD;
+        let result = image_store.delete_unreferenced(created_before).await;
+        metrics::record_images_gc_run(result.as_ref().ok());
+        match result {
+            Ok(summary) => info!(
+                images = summary.images,
+                bytes = summary.bytes,
+                "unreferenced images deleted"
+            ),
+            Err(err) => error!("error deleting unreferenced images: {err}"),
+        }
+
+        // Pause for a while before the next iteration
+        tokio::select! {
+            () = sleep(IMAGES_GC_FREQUENCY) => {},
+            () = cancellation_token.cancelled() => break,
+        }
+    }
+}
+
+/// Worker that rolls up the daily stats older than the horizon provided into
+/// monthly aggregates periodically.
+pub(crate) async fn stats_rollup(db: DynDB, horizon_days: u32, cancellation_token: CancellationToken) {
+    // Random sleep to avoid multiple workers running at the same time
+    tokio::select! {
+        () = sleep(Duration::from_secs(rand::random_range(60..300))) => {},
+        () = cancellation_token.cancelled() => return,
+    }
+
+    loop {
+        // Roll up daily stats
+        debug!("rolling up daily stats");
+        let result = db.rollup_daily_stats(horizon_days).await;
+        metrics::record_stats_rollup_run(result.as_ref().ok());
+        match result {
+            Ok(summary) => info!(
+                job_views = summary.job_views,
+                search_appearances = summary.search_appearances,
+                "daily stats rolled up"
+            ),
+            Err(err) => error!("error rolling up daily stats: {err}"),
+        }
+
+        // Pause for a while before the next iteration
+        tokio::select! {
+            () = sleep(STATS_ROLLUP_FREQUENCY) => {},
             () = cancellation_token.cancelled() => break,
         }
     }
@@ -862,7 +862,51 @@ export const bindToggleCheckbox = ({ toggleId, hiddenInputId }) => {
 };
 
 /**
- * Tracks a view for a specific job by sending a POST request.
+ * Returns the referral information of the current visit (referrer and UTM
+ * parameters). It is captured when the visitor lands on the site and kept for
+ * the rest of the browser session, so that later views are attributed to it.
+ * @returns {URLSearchParams} Referral parameters to send along with views
+ * @private
+ */
+const getViewReferral = () => {
+  const storageKey = "gitjobs_view_referral";
+
+  try {
+    const stored = sessionStorage.getItem(storageKey);
+    if (stored !== null) {
+      return new URLSearchParams(stored);
+    }
+  } catch (error) {
+    // Session storage may be unavailable (e.g. disabled cookies)
+  }
+
+  const referral = new URLSearchParams();
+  const landingParams = new URLSearchParams(window.location.search);
+  if (document.referrer) {
+    referral.set("referrer", document.referrer);
+  }
+  ["utm_medium", "utm_source"].forEach((param) => {
+    const value = landingParams.get(param);
+    if (value) {
+      referral.set(param, value);
+    }
+  });
+
+  try {
+    sessionStorage.setItem(storageKey, referral.toString());
+  } catch (error) {
+    // Session storage may be unavailable (e.g. disabled cookies)
+  }
+
+  return referral;
+};
+
+// Capture the referral as soon as the visitor lands, before the URL changes
+getViewReferral();
+
+/**
+ * Tracks a view for a specific job by sending a POST request, including the
+ * referral information used to attribute the view to a traffic source.
  * Silently handles errors without user notification.
  * @param {string} jobId - The ID of the job to register a view for
  */
@@ -870,7 +914,8 @@ export const trackerJobView = async (jobId) => {
   if (!jobId) return;
 
   try {
-    await fetch(`/jobs/${jobId}/views`, {
+    const referral = getViewReferral().toString();
+    await fetch(`/jobs/${jobId}/views${referral ? `?${referral}` : ""}`, {
       method: "POST",
     });
   } catch (error) {
@@ -330,6 +330,7 @@ export class SearchProjects extends LitWrapper {
                           isSelected ? "bg-stone-100 opacity-50" : "cursor-pointer hover:bg-stone-100"
                         } capitalize block w-full text-left px-3 py-1`}
                         ?disabled="${isSelected}"
+                        title="${option.description || ""}"
                       >
                         <div class="flex items-center space-x-3">
                           <div class="size-8 shrink-0 flex items-center justify-center">
@@ -350,7 +351,7 @@ export class SearchProjects extends LitWrapper {
                               <div
                                 class="truncate text-nowrap uppercase max-w-[100%] text-[0.65rem] font-medium text-stone-500/75"
                               >
-                                ${option.maturity}
+                                ${option.maturity}${option.category ? ` · ${option.category}` : ""}
                               </div>
                             </div>
                           </div>
@@ -21,6 +21,7 @@ export class SearchableFilter extends LitWrapper {
    * @property {string} form - Form ID for input association
    * @property {'top'|'bottom'} alignment - Dropdown alignment
    * @property {number|null} activeIndex - Active suggestion index
+   * @property {Object|null} counts - Number of jobs available per option, if known
    */
   static properties = {
     name: { type: String },
@@ -33,6 +34,7 @@ export class SearchableFilter extends LitWrapper {
     form: { type: String },
     alignment: { type: String },
     activeIndex: { type: Number | null },
+    counts: { type: Object },
   };
 
   constructor() {
@@ -47,6 +49,7 @@ export class SearchableFilter extends LitWrapper {
     this.form = "";
     this.alignment = "bottom";
     this.activeIndex = null;
+    this.counts = null;
   }
 
   connectedCallback() {
@@ -122,6 +125,16 @@ export class SearchableFilter extends LitWrapper {
     this.activeIndex = null;
   }
 
+  /**
+   * Checks if an option would not return any result when selected.
+   * @param {string} option - Option to check
+   * @returns {boolean} True if the option is known to have no results
+   * @private
+   */
+  _isEmpty(option) {
+    return this.counts !== null && !this.counts[option] && !this.selected.includes(option);
+  }
+
   /**
    * Handles click outside to close dropdown.
    * @param {MouseEvent} event - The click event
@@ -153,7 +166,7 @@ export class SearchableFilter extends LitWrapper {
         event.preventDefault();
         if (this.activeIndex !== null && this.visibleOptions.length > 0) {
           const activeItem = this.visibleOptions[this.activeIndex];
-          if (activeItem) {
+          if (activeItem && !this._isEmpty(activeItem)) {
             const activeItem = this.visibleOptions[this.activeIndex];
             this._onSelect(activeItem);
           }
@@ -254,6 +267,8 @@ export class SearchableFilter extends LitWrapper {
             ? html`<ul class="text-sm text-stone-700 overflow-auto max-h-[180px]">
                 ${this.visibleOptions.map((option, index) => {
                   const isSelected = this.selected.includes(option);
+                  const count = this.counts ? this.counts[option] || 0 : null;
+                  const isEmpty = this._isEmpty(option);
                   return html`<li
                     class="group ${this.activeIndex === index ? "active" : ""}"
                     data-index="${index}"
@@ -263,9 +278,11 @@ export class SearchableFilter extends LitWrapper {
                       @click=${() => this._onSelect(option)}
                       @mouseover=${() => (this.activeIndex = index)}
                       class=${`group-[.active]:bg-stone-100 ${
-                        isSelected ? "bg-stone-100 opacity-50" : "cursor-pointer hover:bg-stone-100"
+                        isSelected || isEmpty
+                          ? "bg-stone-100 opacity-50"
+                          : "cursor-pointer hover:bg-stone-100"
                       } capitalize block w-full text-left px-4 py-1`}
-                      ?disabled="${isSelected}"
+                      ?disabled="${isSelected || isEmpty}"
                     >
                       <div class="flex items-center">
                         <div class="size-3 me-2">
@@ -273,7 +290,12 @@ export class SearchableFilter extends LitWrapper {
                             ? html`<div class="svg-icon size-3 icon-check bg-stone-400"></div>`
                             : ""}
                         </div>
-                        <div class="truncate text-[0.8rem]/6">${unnormalize(option)}</div>
+                        <div class="truncate text-[0.8rem]/6">
+                          ${unnormalize(option)}
+                          ${count !== null
+                            ? html`<span class="text-stone-400 text-[0.7rem]">(${count})</span>`
+                            : ""}
+                        </div>
                       </div>
                     </button>
                   </li>`;
@@ -0,0 +1,11 @@
+import { initializeCloseMenuControls, initializeOpenMenuButton } from "/static/js/dashboard/base.js";
+
+/**
+ * Initializes admin dashboard drawer controls.
+ */
+const initializeAdminBase = () => {
+  initializeOpenMenuButton();
+  initializeCloseMenuControls();
+};
+
+initializeAdminBase();
@@ -0,0 +1,77 @@
+import { initializeConfirmHtmxButtons } from "/static/js/common/alerts.js";
+import { initializeFormModal, openFormModal } from "/static/js/dashboard/admin/common.js";
+
+const CERTIFICATION_MODAL_ID = "certification-modal";
+const CERTIFICATION_FORM_ID = "certification-form";
+const ADD_CERTIFICATION_BUTTON_ID = "add-certification-button";
+const ADD_CERTIFICATION_URL = "/dashboard/admin/certifications/add";
+
+/**
+ * Initializes admin certifications page interactions.
+ */
+export const initializeAdminCertifications = () => {
+  const addCertificationButton = document.getElementById(ADD_CERTIFICATION_BUTTON_ID);
+  if (addCertificationButton && addCertificationButton.dataset.addCertificationBound !== "true") {
+    addCertificationButton.addEventListener("click", () => {
+      openFormModal({
+        modalId: CERTIFICATION_MODAL_ID,
+        formId: CERTIFICATION_FORM_ID,
+        title: "Add certification",
+        method: "post",
+        url: ADD_CERTIFICATION_URL,
+      });
+    });
+    addCertificationButton.dataset.addCertificationBound = "true";
+  }
+
+  document.querySelectorAll(".edit-certification-button").forEach((button) => {
+    if (button.dataset.editCertificationBound === "true") {
+      return;
+    }
+
+    button.addEventListener("click", (event) => {
+      const {
+        certificationUrl,
+        certificationName,
+        certificationProvider,
+        certificationShortName,
+        certificationDescription,
+        certificationLogoUrl,
+        certificationInfoUrl,
+      } = event.currentTarget.dataset;
+      if (!certificationUrl) {
+        return;
+      }
+
+      openFormModal({
+        modalId: CERTIFICATION_MODAL_ID,
+        formId: CERTIFICATION_FORM_ID,
+        title: `Edit ${certificationShortName || "certification"}`,
+        method: "put",
+        url: certificationUrl,
+        values: {
+          name: certificationName,
+          provider: certificationProvider,
+          short_name: certificationShortName,
+          description: certificationDescription,
+          logo_url: certificationLogoUrl,
+          url: certificationInfoUrl,
+        },
+      });
+    });
+
+    button.dataset.editCertificationBound = "true";
+  });
+
+  initializeFormModal({
+    modalId: CERTIFICATION_MODAL_ID,
+    formId: CERTIFICATION_FORM_ID,
+    errorMessage: "Something went wrong saving this certification. Please try again later.",
+  });
+
+  initializeConfirmHtmxButtons({
+    selector: "[data-delete-certification-button]",
+    confirmMessage: "Are you sure you would like to delete this certification?",
+    errorMessage: "Something went wrong deleting this certification. Please try again later.",
+  });
+};
@@ -0,0 +1,75 @@
+import { handleHtmxResponse } from "/static/js/common/alerts.js";
+import {
+  bindHtmxAfterRequestOnce,
+  initializeModalCloseHandlers,
+  toggleModalVisibility,
+} from "/static/js/common/common.js";
+
+/**
+ * Opens a modal form configured to add or update an item.
+ * Updates the form request attributes and fills the fields provided.
+ * @param {Object} params - Form modal params
+ * @param {string} params.modalId - Modal identifier
+ * @param {string} params.formId - Form identifier
+ * @param {string} params.title - Title displayed in the modal header
+ * @param {"post"|"put"} params.method - HTTP method used by the form
+ * @param {string} params.url - URL the form is submitted to
+ * @param {Object<string, string>} [params.values] - Field values keyed by name
+ * @param {string[]} [params.readOnlyFields] - Names of fields that cannot be edited
+ */
+export const openFormModal = ({ modalId, formId, title, method, url, values = {}, readOnlyFields = [] }) => {
+  const form = document.getElementById(formId);
+  if (!(form instanceof HTMLFormElement)) {
+    return;
+  }
+
+  const modalTitle = document.getElementById(`${modalId}-title`);
+  if (modalTitle) {
+    modalTitle.textContent = title;
+  }
+
+  form.reset();
+  Array.from(form.elements).forEach((field) => {
+    if (!field.name) {
+      return;
+    }
+    if (field.name in values) {
+      field.value = values[field.name] || "";
+    }
+    field.readOnly = readOnlyFields.includes(field.name);
+  });
+
+  form.removeAttribute("hx-post");
+  form.removeAttribute("hx-put");
+  form.setAttribute(`hx-${method}`, url);
+  const htmxInstance = window.htmx;
+  if (typeof htmxInstance?.process === "function") {
+    htmxInstance.process(form);
+  }
+
+  toggleModalVisibility(modalId, "open");
+};
+
+/**
+ * Binds the submit response handler and close controls of a modal form.
+ * @param {Object} params - Form modal params
+ * @param {string} params.modalId - Modal identifier
+ * @param {string} params.formId - Form identifier
+ * @param {string} params.errorMessage - Message displayed when the request fails
+ */
+export const initializeFormModal = ({ modalId, formId, errorMessage }) => {
+  bindHtmxAfterRequestOnce({
+    selector: `#${formId}`,
+    handler: (event) => {
+      if (handleHtmxResponse({ xhr: event.detail.xhr, errorMessage })) {
+        toggleModalVisibility(modalId, "close");
+      }
+    },
+    boundAttribute: "formModalSubmitBound",
+  });
+
+  initializeModalCloseHandlers({
+    modalId,
+    triggerIds: [`close-${modalId}`, `backdrop-${modalId}`],
+  });
+};
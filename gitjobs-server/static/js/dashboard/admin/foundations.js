@@ -0,0 +1,56 @@
+import { initializeFormModal, openFormModal } from "/static/js/dashboard/admin/common.js";
+
+const FOUNDATION_MODAL_ID = "foundation-modal";
+const FOUNDATION_FORM_ID = "foundation-form";
+const ADD_FOUNDATION_BUTTON_ID = "add-foundation-button";
+const ADD_FOUNDATION_URL = "/dashboard/admin/foundations/add";
+
+/**
+ * Initializes admin foundations page interactions.
+ */
+export const initializeAdminFoundations = () => {
+  const addFoundationButton = document.getElementById(ADD_FOUNDATION_BUTTON_ID);
+  if (addFoundationButton && addFoundationButton.dataset.addFoundationBound !== "true") {
+    addFoundationButton.addEventListener("click", () => {
+      openFormModal({
+        modalId: FOUNDATION_MODAL_ID,
+        formId: FOUNDATION_FORM_ID,
+        title: "Add foundation",
+        method: "post",
+        url: ADD_FOUNDATION_URL,
+      });
+    });
+    addFoundationButton.dataset.addFoundationBound = "true";
+  }
+
+  document.querySelectorAll(".edit-foundation-button").forEach((button) => {
+    if (button.dataset.editFoundationBound === "true") {
+      return;
+    }
+
+    button.addEventListener("click", (event) => {
+      const { foundationUrl, foundationName, foundationLandscapeUrl } = event.currentTarget.dataset;
+      if (!foundationUrl) {
+        return;
+      }
+
+      openFormModal({
+        modalId: FOUNDATION_MODAL_ID,
+        formId: FOUNDATION_FORM_ID,
+        title: `Edit ${foundationName || "foundation"}`,
+        method: "put",
+        url: foundationUrl,
+        values: { name: foundationName, landscape_url: foundationLandscapeUrl },
+        readOnlyFields: ["name"],
+      });
+    });
+
+    button.dataset.editFoundationBound = "true";
+  });
+
+  initializeFormModal({
+    modalId: FOUNDATION_MODAL_ID,
+    formId: FOUNDATION_FORM_ID,
+    errorMessage: "Something went wrong saving this foundation. Please try again later.",
+  });
+};
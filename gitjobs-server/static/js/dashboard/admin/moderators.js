@@ -0,0 +1,51 @@
+import { handleHtmxResponse, initializeConfirmHtmxButtons } from "/static/js/common/alerts.js";
+import {
+  bindHtmxAfterRequestOnce,
+  initializeModalCloseHandlers,
+  toggleModalVisibility,
+} from "/static/js/common/common.js";
+
+const ADD_MODERATOR_BUTTON_ID = "add-moderator-button";
+const ADD_MODERATOR_MODAL_ID = "add-moderator-modal";
+const ADD_MODERATOR_FORM_SELECTOR = "#add-moderator-form";
+const CLOSE_ADD_MODERATOR_MODAL_BUTTON_ID = "close-add-moderator-modal";
+const BACKDROP_ADD_MODERATOR_MODAL_ID = "backdrop-add-moderator-modal";
+
+/**
+ * Initializes admin moderators page interactions.
+ */
+export const initializeAdminModerators = () => {
+  const addModeratorButton = document.getElementById(ADD_MODERATOR_BUTTON_ID);
+  if (addModeratorButton && addModeratorButton.dataset.addModeratorBound !== "true") {
+    addModeratorButton.addEventListener("click", () => {
+      toggleModalVisibility(ADD_MODERATOR_MODAL_ID, "open");
+    });
+    addModeratorButton.dataset.addModeratorBound = "true";
+  }
+
+  bindHtmxAfterRequestOnce({
+    selector: ADD_MODERATOR_FORM_SELECTOR,
+    handler: (event) => {
+      if (
+        handleHtmxResponse({
+          xhr: event.detail.xhr,
+          errorMessage: "Something went wrong adding this moderator. Please try again later.",
+        })
+      ) {
+        toggleModalVisibility(ADD_MODERATOR_MODAL_ID, "close");
+      }
+    },
+    boundAttribute: "addModeratorSubmitBound",
+  });
+
+  initializeModalCloseHandlers({
+    modalId: ADD_MODERATOR_MODAL_ID,
+    triggerIds: [CLOSE_ADD_MODERATOR_MODAL_BUTTON_ID, BACKDROP_ADD_MODERATOR_MODAL_ID],
+  });
+
+  initializeConfirmHtmxButtons({
+    selector: "[data-revoke-moderator-button]",
+    confirmMessage: "Are you sure you would like to revoke the moderator rights of this user?",
+    errorMessage: "Something went wrong revoking the moderator rights. Please try again later.",
+  });
+};
@@ -12,14 +12,24 @@ import { initializeDashboardActionButton } from "/static/js/dashboard/employer/d
 const STATS_MODAL_ID = "stats-modal";
 const JOB_CHART_VIEWS_ID = "job-chart-views";
 const JOB_CHART_SEARCH_APPEARANCES_ID = "job-chart-search-appearances";
+const JOB_CHART_APPLY_CLICKS_ID = "job-chart-apply-clicks";
 const TOTAL_VIEWS_ID = "total-views";
 const TOTAL_SEARCH_APPEARANCES_ID = "total-search-appearances";
+const TOTAL_VIEWS_ALL_TIME_ID = "total-views-all-time";
+const TOTAL_SEARCH_APPEARANCES_ALL_TIME_ID = "total-search-appearances-all-time";
+const TOTAL_APPLY_CLICKS_ID = "total-apply-clicks";
+const JOB_VIEWS_SOURCES_ID = "job-views-sources";
 const CLOSE_STATS_MODAL_BUTTON_ID = "close-stats-modal";
 const BACKDROP_STATS_MODAL_ID = "backdrop-stats-modal";
 const ADD_JOB_BUTTON_ID = "add-job-button";
 const CLEAN_SEARCH_JOBS_BUTTON_ID = "clean-search-jobs";
 const SEARCH_JOBS_INPUT_ID = "search_jobs";
-const JOBS_STATS_CHART_IDS = [JOB_CHART_VIEWS_ID, JOB_CHART_SEARCH_APPEARANCES_ID];
+const JOBS_STATS_CHART_IDS = [JOB_CHART_VIEWS_ID, JOB_CHART_SEARCH_APPEARANCES_ID, JOB_CHART_APPLY_CLICKS_ID];
+const CHART_LABELS = {
+  views: "Views",
+  search_appearances: "Search appearances",
+  apply_clicks: "Apply clicks",
+};
 const STATS_FETCH_ERROR_MESSAGE = "Something went wrong fetching the stats. Please try again later.";
 
 /**
@@ -58,12 +68,17 @@ const showStats = async (id) => {
       if (searchAppearancesChartWrapper) {
         searchAppearancesChartWrapper.classList.remove("hidden");
       }
+      const applyClicksChartWrapper = document.querySelector('[data-chart="apply-clicks"]');
+      if (applyClicksChartWrapper) {
+        applyClicksChartWrapper.classList.remove("hidden");
+      }
 
       const hasViewsData = data.views_daily && data.views_daily.length > 0;
       const hasSearchAppearancesData =
         data.search_appearances_daily && data.search_appearances_daily.length > 0;
+      const hasApplyClicksData = data.apply_clicks_daily && data.apply_clicks_daily.length > 0;
 
-      if (hasViewsData || hasSearchAppearancesData) {
+      if (hasViewsData || hasSearchAppearancesData || hasApplyClicksData) {
         // Open the statistics modal if we have data for at least one chart
         toggleModalVisibility(STATS_MODAL_ID, "open");
 
@@ -76,6 +91,12 @@ const showStats = async (id) => {
               totalViewsElement.textContent = prettifyNumber(data.views_total_last_month);
             }
           }
+          if (data.views_total !== undefined) {
+            const totalViewsAllTimeElement = document.getElementById(TOTAL_VIEWS_ALL_TIME_ID);
+            if (totalViewsAllTimeElement) {
+              totalViewsAllTimeElement.textContent = prettifyNumber(data.views_total);
+            }
+          }
         } else {
           // Hide views chart if no data is available
           if (viewsChartWrapper) {
@@ -92,12 +113,42 @@ const showStats = async (id) => {
               totalSearchElement.textContent = prettifyNumber(data.search_appearances_total_last_month);
             }
           }
+          if (data.search_appearances_total !== undefined) {
+            const totalSearchAllTimeElement = document.getElementById(TOTAL_SEARCH_APPEARANCES_ALL_TIME_ID);
+            if (totalSearchAllTimeElement) {
+              totalSearchAllTimeElement.textContent = prettifyNumber(data.search_appearances_total);
+            }
+          }
         } else {
           // Hide search appearances chart if no data is available
           if (searchAppearancesChartWrapper) {
             searchAppearancesChartWrapper.classList.add("hidden");
           }
         }
+
+        // Render apply clicks chart if data exists
+        if (hasApplyClicksData) {
+          renderChart(data.apply_clicks_daily, JOB_CHART_APPLY_CLICKS_ID, "apply_clicks");
+          if (data.apply_clicks_total_last_month !== undefined) {
+            const totalApplyClicksElement = document.getElementById(TOTAL_APPLY_CLICKS_ID);
+            if (totalApplyClicksElement) {
+              totalApplyClicksElement.textContent = prettifyNumber(data.apply_clicks_total_last_month);
+            }
+          }
+        } else {
+          // Hide apply clicks chart if no data is available
+          if (applyClicksChartWrapper) {
+            applyClicksChartWrapper.classList.add("hidden");
+          }
+        }
+
+        // Render views sources if data exists
+        const viewsSourcesWrapper = document.querySelector('[data-chart="views-sources"]');
+        if (data.views_sources_last_month && data.views_sources_last_month.length > 0) {
+          renderViewsSources(data.views_sources_last_month);
+        } else if (viewsSourcesWrapper) {
+          viewsSourcesWrapper.classList.add("hidden");
+        }
       } else {
         // Show message when no data is available for either chart
         showInfoAlert(
@@ -141,6 +192,18 @@ const closeStats = () => {
   if (totalSearchElement) {
     totalSearchElement.textContent = "";
   }
+  const totalViewsAllTimeElement = document.getElementById(TOTAL_VIEWS_ALL_TIME_ID);
+  if (totalViewsAllTimeElement) {
+    totalViewsAllTimeElement.textContent = "";
+  }
+  const totalSearchAllTimeElement = document.getElementById(TOTAL_SEARCH_APPEARANCES_ALL_TIME_ID);
+  if (totalSearchAllTimeElement) {
+    totalSearchAllTimeElement.textContent = "";
+  }
+  const totalApplyClicksElement = document.getElementById(TOTAL_APPLY_CLICKS_ID);
+  if (totalApplyClicksElement) {
+    totalApplyClicksElement.textContent = "";
+  }
 
   // Display charts wrapper
   const viewsChartWrapper = document.querySelector('[data-chart="views"]');
@@ -151,13 +214,80 @@ const closeStats = () => {
   if (searchAppearancesChartWrapper) {
     searchAppearancesChartWrapper.classList.remove("hidden");
   }
+  const applyClicksChartWrapper = document.querySelector('[data-chart="apply-clicks"]');
+  if (applyClicksChartWrapper) {
+    applyClicksChartWrapper.classList.remove("hidden");
+  }
+
+  // Clear the views sources list
+  const viewsSourcesList = document.getElementById(JOB_VIEWS_SOURCES_ID);
+  if (viewsSourcesList) {
+    viewsSourcesList.replaceChildren();
+  }
+  const viewsSourcesWrapper = document.querySelector('[data-chart="views-sources"]');
+  if (viewsSourcesWrapper) {
+    viewsSourcesWrapper.classList.remove("hidden");
+  }
+};
+
+/**
+ * Returns a human readable label for a traffic source
+ * @param {string} source - The normalized source (e.g. 'direct', 'embed:cncf.io')
+ * @returns {string} The label to display
+ * @private
+ */
+const formatTrafficSource = (source) => {
+  const [kind, detail] = source.split(/:(.*)/s);
+  switch (kind) {
+    case "direct":
+      return "Direct";
+    case "search":
+      return "Search engines";
+    case "other":
+      return "Other";
+    case "embed":
+      return detail ? `Embed (${detail})` : "Embed";
+    case "utm":
+      return `Campaign (${detail})`;
+    case "ref":
+      return detail;
+    default:
+      return source;
+  }
+};
+
+/**
+ * Renders the list of views per traffic source
+ * @param {Array} sources - List of [source, total] entries
+ * @private
+ */
+const renderViewsSources = (sources) => {
+  const list = document.getElementById(JOB_VIEWS_SOURCES_ID);
+  if (!list) {
+    return;
+  }
+
+  const items = sources.map(([source, total]) => {
+    const item = document.createElement("li");
+    item.className = "flex items-center justify-between px-4 py-2";
+
+    const label = document.createElement("span");
+    label.textContent = formatTrafficSource(source);
+    const value = document.createElement("span");
+    value.className = "font-semibold";
+    value.textContent = prettifyNumber(total);
+
+    item.append(label, value);
+    return item;
+  });
+  list.replaceChildren(...items);
 };
 
 /**
  * Function to render a chart
  * @param {Array} data - The chart data
  * @param {string} chartId - The ID of the chart container
- * @param {string} chartType - The type of chart ('views' or 'search_appearances')
+ * @param {string} chartType - The type of chart ('views', 'search_appearances' or 'apply_clicks')
  * @private
  */
 const renderChart = (data, chartId, chartType) => {
@@ -202,7 +332,7 @@ const renderChart = (data, chartId, chartType) => {
       ...getBarStatsOptions().tooltip,
       formatter: (params) => {
         const chartdate = echarts.time.format(params.data[0], "{dd} {MMM}'{yy}");
-        const label = chartType === "views" ? "Views" : "Search appearances";
+        const label = CHART_LABELS[chartType];
         return `${chartdate}<br />${label}: ${prettifyNumber(params.data[1])}`;
       },
     },
@@ -0,0 +1,83 @@
+import { handleHtmxResponse } from "/static/js/common/alerts.js";
+import {
+  bindHtmxAfterRequestOnce,
+  initializeModalCloseHandlers,
+  toggleModalVisibility,
+} from "/static/js/common/common.js";
+
+const SUSPEND_MODAL_ID = "suspend-modal";
+const SUSPEND_FORM_ID = "suspend-form";
+const SUSPEND_FORM_SELECTOR = `#${SUSPEND_FORM_ID}`;
+const SUSPEND_MODAL_TARGET_ID = "suspend-modal-target";
+const CLOSE_SUSPEND_MODAL_BUTTON_ID = "close-suspend-modal";
+const BACKDROP_SUSPEND_MODAL_ID = "backdrop-suspend-modal";
+
+/**
+ * Initializes moderation actions for suspending and unsuspending accounts.
+ */
+export const initializeModeratorAccounts = () => {
+  bindHtmxAfterRequestOnce({
+    selector: "[data-unsuspend-button]",
+    handler: (event) => {
+      handleHtmxResponse({
+        xhr: event.detail.xhr,
+        errorMessage: "Something went wrong lifting this suspension. Please try again later.",
+      });
+    },
+    boundAttribute: "unsuspendBound",
+  });
+
+  const suspendButtons = document.querySelectorAll(".suspend-modal");
+  suspendButtons.forEach((button) => {
+    if (button.dataset.suspendOpenBound === "true") {
+      return;
+    }
+
+    button.addEventListener("click", (event) => {
+      const { suspendUrl, suspendTarget } = event.currentTarget.dataset;
+      const suspendForm = document.getElementById(SUSPEND_FORM_ID);
+      if (!suspendForm || !suspendUrl) {
+        return;
+      }
+
+      const modalTarget = document.getElementById(SUSPEND_MODAL_TARGET_ID);
+      if (modalTarget) {
+        modalTarget.textContent = suspendTarget || "";
+      }
+
+      suspendForm.setAttribute("hx-put", suspendUrl);
+      const htmxInstance = window.htmx;
+      if (typeof htmxInstance?.process === "function") {
+        htmxInstance.process(suspendForm);
+      }
+      toggleModalVisibility(SUSPEND_MODAL_ID, "open");
+    });
+
+    button.dataset.suspendOpenBound = "true";
+  });
+
+  bindHtmxAfterRequestOnce({
+    selector: SUSPEND_FORM_SELECTOR,
+    handler: (event) => {
+      if (
+        handleHtmxResponse({
+          xhr: event.detail.xhr,
+          errorMessage: "Something went wrong suspending this account. Please try again later.",
+        })
+      ) {
+        const suspendForm = event.currentTarget;
+        if (!(suspendForm instanceof HTMLFormElement)) {
+          return;
+        }
+        suspendForm.reset();
+        toggleModalVisibility(SUSPEND_MODAL_ID, "close");
+      }
+    },
+    boundAttribute: "suspendSubmitBound",
+  });
+
+  initializeModalCloseHandlers({
+    modalId: SUSPEND_MODAL_ID,
+    triggerIds: [CLOSE_SUSPEND_MODAL_BUTTON_ID, BACKDROP_SUSPEND_MODAL_ID],
+  });
+};
@@ -0,0 +1,269 @@
+import { getBarStatsOptions, gitjobsChartTheme } from "/static/js/jobboard/stats.js";
+import { prettifyNumber, registerChartResizeHandler } from "/static/js/common/common.js";
+
+const MESSAGE_EMPTY_STATS = "No data available yet";
+const STATS_CONTAINER_ID = "moderator-stats";
+const PENDING_DAILY_CHART_ID = "moderator-pending-daily";
+const DECISIONS_WEEKLY_CHART_ID = "moderator-decisions-weekly";
+const REVIEW_TIME_WEEKLY_CHART_ID = "moderator-review-time-weekly";
+const MODERATOR_STATS_CHART_IDS = [
+  PENDING_DAILY_CHART_ID,
+  DECISIONS_WEEKLY_CHART_ID,
+  REVIEW_TIME_WEEKLY_CHART_ID,
+];
+
+/**
+ * Returns the chart instance for the given element, creating it if needed.
+ * @param {HTMLElement} chartDom - Chart container element
+ * @returns {Object} ECharts instance
+ * @private
+ */
+const getChart = (chartDom) => {
+  const chart =
+    echarts.getInstanceByDom(chartDom) ||
+    echarts.init(chartDom, "gitjobs", {
+      renderer: "svg",
+      useDirtyRect: false,
+    });
+  chart.clear();
+  return chart;
+};
+
+/**
+ * Formats a number of seconds as hours for chart labels.
+ * @param {number} seconds - Duration in seconds
+ * @returns {string} Duration in hours
+ * @private
+ */
+const formatHours = (seconds) => `${prettifyNumber(Math.round(seconds / 360) / 10)}h`;
+
+/**
+ * Renders a line chart showing the pending queue size at the end of each day.
+ * @param {Array} data - Daily data with timestamps and pending jobs counts
+ * @private
+ */
+const renderPendingDailyChart = (data) => {
+  const chartDom = document.getElementById(PENDING_DAILY_CHART_ID);
+  if (!chartDom) return;
+
+  const chart = getChart(chartDom);
+  const option = {
+    dataset: [
+      {
+        dimensions: ["timestamp", "pending"],
+        source: data,
+      },
+    ],
+    tooltip: {
+      trigger: "axis",
+      formatter: (params) => {
+        const [item] = params;
+        const chartdate = echarts.time.format(item.data[0], "{dd} {MMM} {yyyy}");
+        return `<strong>${chartdate}</strong><br />Pending jobs: ${item.data[1]}`;
+      },
+    },
+    xAxis: {
+      type: "time",
+      axisLabel: { formatter: "{dd} {MMM}", hideOverlap: true },
+    },
+    yAxis: {
+      type: "value",
+      minInterval: 1,
+      axisLabel: {
+        formatter: (value) => `${prettifyNumber(value)}`,
+      },
+    },
+    series: {
+      type: "line",
+      name: "Pending jobs",
+      encode: { x: "timestamp", y: "pending" },
+      showSymbol: false,
+      areaStyle: {
+        color: new echarts.graphic.LinearGradient(0, 0, 0, 1, [
+          {
+            offset: 0,
+            color: "rgb(253, 77, 18)",
+          },
+          {
+            offset: 1,
+            color: "rgb(255, 230, 212)",
+          },
+        ]),
+      },
+    },
+  };
+
+  chart.setOption(option);
+};
+
+/**
+ * Renders a stacked bar chart showing approvals and rejections per week.
+ * @param {Array} data - Weekly data with timestamps, approvals and rejections
+ * @param {number} max - Maximum date value for x-axis
+ * @param {number} min - Minimum date value for x-axis
+ * @private
+ */
+const renderDecisionsWeeklyChart = (data, max, min) => {
+  const chartDom = document.getElementById(DECISIONS_WEEKLY_CHART_ID);
+  if (!chartDom) return;
+
+  const chart = getChart(chartDom);
+  const baseOptions = getBarStatsOptions();
+  const series = (name, dimension) => ({
+    ...baseOptions.series,
+    name,
+    stack: "decisions",
+    encode: { x: "timestamp", y: dimension },
+    datasetIndex: 0,
+    label: { show: false },
+  });
+  const option = {
+    ...baseOptions,
+    dataset: [
+      {
+        dimensions: ["timestamp", "approvals", "rejections"],
+        source: data,
+      },
+    ],
+    legend: { bottom: 0 },
+    tooltip: {
+      ...baseOptions.tooltip,
+      trigger: "axis",
+      formatter: (params) => {
+        const [item] = params;
+        const chartdate = echarts.time.format(item.data[0], "{dd} {MMM}'{yy}");
+        return `Week of ${chartdate}<br />Approvals: ${item.data[1]}<br />Rejections: ${item.data[2]}`;
+      },
+    },
+    xAxis: {
+      ...baseOptions.xAxis,
+      axisLabel: { formatter: "{dd} {MMM}", hideOverlap: true },
+      min,
+      max,
+    },
+    series: [series("Approvals", "approvals"), series("Rejections", "rejections")],
+  };
+
+  chart.setOption(option);
+};
+
+/**
+ * Renders a bar chart showing the median review time per week.
+ * @param {Array} data - Weekly data with timestamps and median seconds
+ * @param {number} max - Maximum date value for x-axis
+ * @param {number} min - Minimum date value for x-axis
+ * @private
+ */
+const renderReviewTimeWeeklyChart = (data, max, min) => {
+  const chartDom = document.getElementById(REVIEW_TIME_WEEKLY_CHART_ID);
+  if (!chartDom) return;
+
+  const chart = getChart(chartDom);
+  const baseOptions = getBarStatsOptions();
+  const option = {
+    ...baseOptions,
+    dataset: [
+      {
+        dimensions: ["timestamp", "seconds"],
+        source: data,
+      },
+    ],
+    tooltip: {
+      ...baseOptions.tooltip,
+      formatter: (params) => {
+        const chartdate = echarts.time.format(params.data[0], "{dd} {MMM}'{yy}");
+        return `Week of ${chartdate}<br />Median review time: ${formatHours(params.data[1])}`;
+      },
+    },
+    xAxis: {
+      ...baseOptions.xAxis,
+      axisLabel: { formatter: "{dd} {MMM}", hideOverlap: true },
+      min,
+      max,
+    },
+    yAxis: {
+      ...baseOptions.yAxis,
+      axisLabel: {
+        formatter: (value) => formatHours(value),
+      },
+    },
+    series: {
+      ...baseOptions.series,
+      name: "Median review time",
+      encode: { x: "timestamp", y: "seconds" },
+      datasetIndex: 0,
+      label: {
+        ...baseOptions.series.label,
+        formatter: (params) => formatHours(params.value[1]),
+      },
+    },
+  };
+
+  chart.setOption(option);
+};
+
+/**
+ * Displays the empty stats message in the chart container provided.
+ * @param {string} chartId - Chart container identifier
+ * @private
+ */
+const renderEmptyChart = (chartId) => {
+  const chartDom = document.getElementById(chartId);
+  if (chartDom) {
+    chartDom.innerHTML = `<div>${MESSAGE_EMPTY_STATS}</div>`;
+  }
+};
+
+/**
+ * Initializes and renders the moderator stats charts.
+ * Reads data from DOM element and creates visualizations.
+ */
+export const renderModeratorStats = () => {
+  const container = document.getElementById(STATS_CONTAINER_ID);
+  if (!container) return;
+
+  const data = container.dataset.stats;
+  if (!data) return;
+
+  let stats;
+  try {
+    stats = JSON.parse(data);
+  } catch (_) {
+    return;
+  }
+
+  if (!stats?.reviews) return;
+
+  // Register the GitJobs theme for ECharts
+  if (!window.echarts?.__gitjobsEchartsThemeRegistered) {
+    echarts.registerTheme("gitjobs", gitjobsChartTheme);
+    window.echarts.__gitjobsEchartsThemeRegistered = true;
+  }
+
+  registerChartResizeHandler({
+    chartIds: MODERATOR_STATS_CHART_IDS,
+    guardKey: "__gitjobsModeratorStatsResizeBound",
+  });
+
+  if (!stats.reviews.pending_daily) {
+    renderEmptyChart(PENDING_DAILY_CHART_ID);
+  } else {
+    renderPendingDailyChart(stats.reviews.pending_daily);
+  }
+
+  if (!stats.reviews.decisions_weekly) {
+    renderEmptyChart(DECISIONS_WEEKLY_CHART_ID);
+  } else {
+    renderDecisionsWeeklyChart(stats.reviews.decisions_weekly, stats.ts_now, stats.ts_three_months_ago);
+  }
+
+  if (!stats.reviews.median_review_time_weekly) {
+    renderEmptyChart(REVIEW_TIME_WEEKLY_CHART_ID);
+  } else {
+    renderReviewTimeWeeklyChart(
+      stats.reviews.median_review_time_weekly,
+      stats.ts_now,
+      stats.ts_three_months_ago,
+    );
+  }
+};
@@ -1,10 +1,35 @@
 import { trackSearchAppearances } from "/static/js/common/common.js";
 
+/**
+ * Tags the job links with the host of the page embedding the jobs, so that
+ * views coming from the embed can be attributed to it.
+ * @private
+ */
+const tagJobLinksWithEmbedHost = () => {
+  let embedHost = "";
+  try {
+    embedHost = document.referrer ? new URL(document.referrer).host : "";
+  } catch (error) {
+    // Ignore invalid referrers
+  }
+  if (!embedHost) {
+    return;
+  }
+
+  document.querySelectorAll("[data-embed-job-link]").forEach((link) => {
+    const url = new URL(link.href);
+    url.searchParams.set("utm_source", embedHost);
+    link.href = url.toString();
+  });
+};
+
 /**
  * Initializes the embedded jobs page tracking.
  * @param {string[]} jobIds - IDs for jobs currently visible in embed results
  */
 export const initializeEmbedJobsPage = (jobIds = []) => {
+  tagJobLinksWithEmbedHost();
+
   const validJobIds = jobIds.filter(Boolean);
   if (validJobIds.length === 0) {
     return;
@@ -1,4 +1,9 @@
-import { setDrawerVisibility, triggerActionOnForm as triggerFormAction } from "/static/js/common/common.js";
+import {
+  prettifyNumber,
+  setDrawerVisibility,
+  triggerActionOnForm as triggerFormAction,
+  unnormalize,
+} from "/static/js/common/common.js";
 
 const DRAWER_FILTERS_ID = "drawer-filters";
 const SEARCHBAR_ID = "searchbar";
@@ -90,6 +95,66 @@ export const updateResults = (content) => {
   resultsContainer.innerHTML = content;
 };
 
+/**
+ * Updates the filters with the number of jobs available for each option,
+ * disabling the options that would not return any job.
+ * @param {Object} facets - Number of jobs per filter option
+ */
+export const updateFacets = (facets) => {
+  const getCount = (facet, value) => (facets[facet] && facets[facet][value]) || 0;
+
+  // Checkboxes
+  document.querySelectorAll("input[type=checkbox][data-facet]").forEach((checkbox) => {
+    const count = getCount(checkbox.dataset.facet, checkbox.value);
+    checkbox.disabled = count === 0 && !checkbox.checked;
+
+    const countElement = checkbox.parentElement.querySelector("[data-facet-count]");
+    if (countElement) {
+      countElement.textContent = `(${count})`;
+    }
+  });
+
+  // Selects (the empty value stands for any option)
+  document.querySelectorAll("select[data-facet]").forEach((selectElement) => {
+    Array.from(selectElement.options).forEach((option) => {
+      if (option.value === "") {
+        return;
+      }
+
+      if (!option.dataset.label) {
+        option.dataset.label = option.textContent.trim();
+      }
+      const count = getCount(selectElement.dataset.facet, option.value);
+      option.textContent = `${option.dataset.label} (${count})`;
+      option.disabled = count === 0 && !option.selected;
+    });
+  });
+
+  // Benefits
+  document.querySelectorAll("searchable-filter[name=benefits]").forEach((searchableFilter) => {
+    searchableFilter.counts = facets.benefits || {};
+  });
+
+  // Salary
+  document.querySelectorAll("[data-facet-salary]").forEach((element) => {
+    element.textContent = Object.entries(facets.salary || {})
+      .sort(([a], [b]) => Number(a) - Number(b))
+      .map(([salaryMin, count]) => `$${prettifyNumber(Number(salaryMin), 0)}+ (${count})`)
+      .join(" · ");
+  });
+
+  // Most requested skills
+  const skills = Object.entries(facets.skills || {}).sort(([a, countA], [b, countB]) => {
+    return countB - countA || a.localeCompare(b);
+  });
+  document.querySelectorAll("[data-facet-skills]").forEach((element) => {
+    element.textContent = skills.map(([skill, count]) => `${unnormalize(skill)} (${count})`).join(" · ");
+  });
+  document.querySelectorAll("[data-facet-skills-wrapper]").forEach((element) => {
+    element.classList.toggle("hidden", skills.length === 0);
+  });
+};
+
 /**
  * Resets all form fields to their default values.
  * Handles various input types and custom components.
@@ -66,9 +66,10 @@ const initializeApplyButton = (root = document) => {
     });
   } else {
     if (applyUrl !== "") {
-      // Open external link in a new tab
+      // Open external link in a new tab (through the server so the click is tracked)
+      const jobId = applyButton.dataset.jobId;
       applyButton.addEventListener("click", () => {
-        window.open(applyUrl, "_blank", "noopener,noreferrer");
+        window.open(`/jobs/${jobId}/apply-redirect`, "_blank", "noopener,noreferrer");
       });
     } else {
       if (hasProfile === "false") {
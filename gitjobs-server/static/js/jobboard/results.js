@@ -5,12 +5,13 @@ import {
   trackerJobView,
   trackSearchAppearances,
 } from "/static/js/common/common.js";
-import { resetForm, updateResults } from "/static/js/jobboard/filters.js";
+import { resetForm, updateFacets, updateResults } from "/static/js/jobboard/filters.js";
 
 const RESET_DESKTOP_FILTERS_LINK_ID = "reset-link-desktop-filters";
 const RESET_MOBILE_FILTERS_LINK_ID = "reset-link-mobile-filters";
 const DESKTOP_JOBS_FORM_ID = "desktop-jobs-form";
 const MOBILE_JOBS_FORM_ID = "mobile-jobs-form";
+const RESULTS_FACETS_ID = "results-facets";
 
 /**
  * Initializes no-results reset links and result-card interactions.
@@ -69,6 +70,11 @@ export const initializeJobboardResults = ({
 
   updateResults(currentPageContent);
 
+  const resultsFacets = document.getElementById(RESULTS_FACETS_ID);
+  if (resultsFacets && resultsFacets.dataset.facets) {
+    updateFacets(JSON.parse(resultsFacets.dataset.facets));
+  }
+
   if (hasJobs) {
     const jobButtons = document.querySelectorAll("[data-job-id]");
     const jobIds = Array.from(jobButtons)
@@ -1,4 +1,5 @@
-import { prettifyNumber, registerChartResizeHandler } from "/static/js/common/common.js";
+import { prettifyNumber, registerChartResizeHandler, unnormalize } from "/static/js/common/common.js";
+import { skillsDisplayName } from "/static/js/common/data.js";
 
 /**
  * ECharts theme configuration for GitJobs charts.
@@ -369,7 +370,15 @@ const STATS_CONTAINER_ID = "stats";
 const LINE_CHART_ID = "line-chart";
 const BAR_DAILY_CHART_ID = "bar-daily";
 const BAR_MONTHLY_CHART_ID = "bar-monthly";
-const JOBBOARD_STATS_CHART_IDS = [LINE_CHART_ID, BAR_DAILY_CHART_ID, BAR_MONTHLY_CHART_ID];
+const SKILLS_CHART_ID = "skills-chart";
+const WORKPLACE_CHART_ID = "workplace-chart";
+const JOBBOARD_STATS_CHART_IDS = [
+  LINE_CHART_ID,
+  BAR_DAILY_CHART_ID,
+  BAR_MONTHLY_CHART_ID,
+  SKILLS_CHART_ID,
+  WORKPLACE_CHART_ID,
+];
 
 /**
  * Finds the smallest value in an array of numbers.
@@ -693,6 +702,105 @@ const renderBarMonthlyChart = (data, max, min) => {
   myChart.setOption(option);
 };
 
+/**
+ * Renders a multi-line chart showing the monthly demand of the top skills.
+ * @param {Array} data - Skills with their monthly series of published jobs
+ * @param {number} max - Maximum date value for x-axis
+ * @param {number} min - Minimum date value for x-axis
+ * @private
+ */
+const renderSkillsChart = (data, max, min) => {
+  const chartDom = document.getElementById(SKILLS_CHART_ID);
+  if (!chartDom) return;
+
+  const myChart =
+    echarts.getInstanceByDom(chartDom) ||
+    echarts.init(chartDom, "gitjobs", {
+      renderer: "svg",
+      useDirtyRect: false,
+    });
+  myChart.clear();
+
+  const points = data.flatMap(([, series]) => series);
+  const option = {
+    legend: {
+      type: "scroll",
+      bottom: 0,
+    },
+    grid: {
+      bottom: "60px",
+    },
+    tooltip: {
+      trigger: "axis",
+      valueFormatter: (value) => prettifyNumber(value),
+    },
+    xAxis: {
+      type: "time",
+      min: getMinDateValue(points, min),
+      max: getMaxDateValue(points, max),
+      axisLabel: { formatter: "{MMM}'{yy}", hideOverlap: true },
+      splitLine: {
+        show: false,
+      },
+    },
+    yAxis: {
+      type: "value",
+      minInterval: 1,
+      axisLabel: {
+        formatter: (value) => `${prettifyNumber(value)}`,
+      },
+    },
+    series: data.map(([skill, series]) => ({
+      type: "line",
+      name: skillsDisplayName[skill] || unnormalize(skill),
+      data: [...series].sort((a, b) => a[0] - b[0]),
+      showSymbol: false,
+    })),
+  };
+
+  myChart.setOption(option);
+};
+
+/**
+ * Renders a pie chart showing the split of published jobs per workplace.
+ * @param {Array} data - Workplaces with their number of published jobs
+ * @private
+ */
+const renderWorkplaceChart = (data) => {
+  const chartDom = document.getElementById(WORKPLACE_CHART_ID);
+  if (!chartDom) return;
+
+  const myChart =
+    echarts.getInstanceByDom(chartDom) ||
+    echarts.init(chartDom, "gitjobs", {
+      renderer: "svg",
+      useDirtyRect: false,
+    });
+  myChart.clear();
+
+  const option = {
+    tooltip: {
+      trigger: "item",
+      formatter: (params) => `${params.name}<br />Jobs: ${prettifyNumber(params.value)} (${params.percent}%)`,
+    },
+    legend: {
+      bottom: 0,
+    },
+    series: {
+      type: "pie",
+      name: "Workplace",
+      radius: ["40%", "70%"],
+      center: ["50%", "45%"],
+      label: {
+        formatter: "{b}: {d}%",
+      },
+      data: data.map(([workplace, jobs]) => ({ name: unnormalize(workplace), value: jobs })),
+    },
+  };
+
+  myChart.setOption(option);
+};
+
 /**
  * Initializes and renders all statistics charts.
  * Reads data from DOM element and creates visualizations.
@@ -750,4 +858,22 @@ export const renderStats = () => {
   } else {
     renderBarMonthlyChart(stats.jobs.views_monthly, stats.ts_now, stats.ts_two_years_ago);
   }
+
+  if (!stats.jobs.skills_monthly) {
+    const chartDom = document.getElementById(SKILLS_CHART_ID);
+    if (chartDom) {
+      chartDom.innerHTML = `<div>${MESSAGE_EMPTY_STATS}</div>`;
+    }
+  } else {
+    renderSkillsChart(stats.jobs.skills_monthly, stats.ts_now, stats.ts_two_years_ago);
+  }
+
+  if (!stats.jobs.published_per_workplace) {
+    const chartDom = document.getElementById(WORKPLACE_CHART_ID);
+    if (chartDom) {
+      chartDom.innerHTML = `<div>${MESSAGE_EMPTY_STATS}</div>`;
+    }
+  } else {
+    renderWorkplaceChart(stats.jobs.published_per_workplace);
+  }
 };
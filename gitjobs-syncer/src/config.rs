@@ -11,20 +11,37 @@ use figment::{
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
+/// Default time between synchronizations of each foundation (6 hours).
+const DEFAULT_SYNC_INTERVAL: u64 = 6 * 60 * 60;
+
+/// Default maximum percentage of existing members or projects of a foundation
+/// that can be removed in a single synchronization.
+const DEFAULT_MAX_REMOVAL_PERCENTAGE: f64 = 20.0;
+
 /// Server configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct Config {
+    /// Daemon mode configuration.
+    pub daemon: DaemonConfig,
     /// Database configuration.
     pub db: DbConfig,
     /// Logging configuration.
     pub log: LogConfig,
+    /// Synchronization configuration.
+    pub sync: SyncConfig,
 }
 
 impl Config {
     /// Create a new Config instance.
     #[instrument(err)]
     pub(crate) fn new(config_file: Option<&PathBuf>) -> Result<Self> {
-        let mut figment = Figment::new().merge(Serialized::default("log.format", "json"));
+        let mut figment = Figment::new()
+            .merge(Serialized::default("daemon.interval", DEFAULT_SYNC_INTERVAL))
+            .merge(Serialized::default("log.format", "json"))
+            .merge(Serialized::default(
+                "sync.max_removal_percentage",
+                DEFAULT_MAX_REMOVAL_PERCENTAGE,
+            ));
 
         if let Some(config_file) = config_file {
             figment = figment.merge(Yaml::file(config_file));
@@ -37,6 +54,13 @@ impl Config {
     }
 }
 
+/// Daemon mode configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct DaemonConfig {
+    /// Time between synchronizations of each foundation, in seconds.
+    pub interval: u64,
+}
+
 /// Logs configuration.
 ///
 /// Specifies the format for application logs.
@@ -57,3 +81,13 @@ pub(crate) enum LogFormat {
     /// Human-readable pretty log format.
     Pretty,
 }
+
+/// Synchronization configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct SyncConfig {
+    /// Maximum percentage of the existing members or projects of a foundation
+    /// that can be removed in a single synchronization. Synchronizations that
+    /// would remove more are aborted, as this usually means the landscape data
+    /// is not valid.
+    pub max_removal_percentage: f64,
+}
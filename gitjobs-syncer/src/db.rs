@@ -4,19 +4,27 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use deadpool_postgres::Pool;
-use tracing::{instrument, trace};
+use deadpool_postgres::{Pool, Transaction};
+#[cfg(test)]
+use mockall::automock;
+use tracing::{debug, instrument, trace};
 
-use crate::syncer::{Foundation, Member, Project};
+use uuid::Uuid;
+
+use crate::syncer::{Changes, Foundation, FoundationDiff, Member, Project, SyncStats};
 
 /// Abstraction layer over the database. Trait that defines operations a `DB` must support.
 #[async_trait]
+#[cfg_attr(test, automock, allow(clippy::ref_option_ref))]
 pub(crate) trait DB {
-    /// Adds a new member to a foundation.
-    async fn add_member(&self, member: &Member) -> Result<()>;
+    /// Applies all the changes needed to synchronize a foundation in a single
+    /// transaction, so that the foundation is never left partially synchronized.
+    async fn apply_foundation_diff(&self, diff: &FoundationDiff) -> Result<()>;
 
-    /// Adds a new project to a foundation.
-    async fn add_project(&self, project: &Project) -> Result<()>;
+    /// Registers the end of a synchronization run, along with its stats and the
+    /// error found, if any.
+    async fn finish_sync_run(&self, sync_run_id: &Uuid, stats: &SyncStats, error: Option<&str>)
+    -> Result<()>;
 
     /// Lists all foundations present in the database.
     async fn list_foundations(&self) -> Result<Vec<Foundation>>;
@@ -27,17 +35,8 @@ pub(crate) trait DB {
     /// Lists all projects of a given foundation.
     async fn list_projects(&self, foundation: &str) -> Result<Vec<Project>>;
 
-    /// Removes a member from a foundation.
-    async fn remove_member(&self, foundation: &str, member_name: &str) -> Result<()>;
-
-    /// Removes a project from a foundation.
-    async fn remove_project(&self, foundation: &str, project_name: &str) -> Result<()>;
-
-    /// Updates an existing member's information.
-    async fn update_member(&self, member: &Member) -> Result<()>;
-
-    /// Updates an existing project's information.
-    async fn update_project(&self, project: &Project) -> Result<()>;
+    /// Registers the start of a synchronization run for a foundation.
+    async fn start_sync_run(&self, foundation: &str) -> Result<Uuid>;
 }
 
 /// Type alias for a thread-safe, reference-counted `DB` trait object.
@@ -58,33 +57,37 @@ impl PgDB {
 
 #[async_trait]
 impl DB for PgDB {
-    #[instrument(skip(self), err)]
-    async fn add_member(&self, member: &Member) -> Result<()> {
-        trace!("db: add member");
+    #[instrument(skip_all, fields(foundation = diff.foundation), err)]
+    async fn apply_foundation_diff(&self, diff: &FoundationDiff) -> Result<()> {
+        trace!("db: apply foundation diff");
 
-        let db = self.pool.get().await?;
-        db.execute(
-            "select add_member($1::text, $2::text, $3::text, $4::text)",
-            &[&member.foundation, &member.name, &member.level, &member.logo_url],
-        )
-        .await?;
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Apply members and projects changes
+        apply_members_changes(&tx, &diff.foundation, &diff.members).await?;
+        apply_projects_changes(&tx, &diff.foundation, &diff.projects).await?;
+
+        // Commit transaction
+        tx.commit().await?;
 
         Ok(())
     }
 
     #[instrument(skip(self), err)]
-    async fn add_project(&self, project: &Project) -> Result<()> {
-        trace!("db: add project");
+    async fn finish_sync_run(
+        &self,
+        sync_run_id: &Uuid,
+        stats: &SyncStats,
+        error: Option<&str>,
+    ) -> Result<()> {
+        trace!("db: finish sync run");
 
         let db = self.pool.get().await?;
         db.execute(
-            "select add_project($1::text, $2::text, $3::text, $4::text)",
-            &[
-                &project.foundation,
-                &project.name,
-                &project.maturity,
-                &project.logo_url,
-            ],
+            "select finish_sync_run($1::uuid, $2::jsonb, $3::text);",
+            &[&sync_run_id, &serde_json::to_value(stats)?, &error],
         )
         .await?;
 
@@ -129,63 +132,158 @@ impl DB for PgDB {
     }
 
     #[instrument(skip(self), err)]
-    async fn remove_member(&self, foundation: &str, member_name: &str) -> Result<()> {
-        trace!("db: remove member");
+    async fn start_sync_run(&self, foundation: &str) -> Result<Uuid> {
+        trace!("db: start sync run");
 
         let db = self.pool.get().await?;
-        db.execute(
-            "select remove_member($1::text, $2::text);",
-            &[&foundation, &member_name],
-        )
-        .await?;
+        let row = db
+            .query_one("select start_sync_run($1::text);", &[&foundation])
+            .await?;
 
-        Ok(())
+        Ok(row.get(0))
     }
+}
 
-    #[instrument(skip(self), err)]
-    async fn remove_project(&self, foundation: &str, project_name: &str) -> Result<()> {
-        trace!("db: remove project");
-
-        let db = self.pool.get().await?;
-        db.execute(
-            "select remove_project($1::text, $2::text);",
-            &[&foundation, &project_name],
+// Helpers.
+
+/// Applies the members changes provided using the transaction given.
+async fn apply_members_changes(
+    tx: &Transaction<'_>,
+    foundation: &str,
+    changes: &Changes<Member>,
+) -> Result<()> {
+    for renamed in &changes.renamed {
+        debug!(from = renamed.from, to = renamed.to.name, "renaming member");
+        tx.execute(
+            "select rename_member($1::text, $2::text, $3::text);",
+            &[&foundation, &renamed.from, &renamed.to.name],
         )
         .await?;
-
-        Ok(())
+        update_member(tx, &renamed.to).await?;
     }
-
-    #[instrument(skip(self), err)]
-    async fn update_member(&self, member: &Member) -> Result<()> {
-        trace!("db: update member");
-
-        let db = self.pool.get().await?;
-        db.execute(
-            "select update_member($1::text, $2::text, $3::text, $4::text);",
-            &[&member.foundation, &member.name, &member.level, &member.logo_url],
+    for member in &changes.added {
+        debug!(name = member.name, "adding member");
+        tx.execute(
+            "select add_member($1::text, $2::text, $3::text, $4::text, $5::text, $6::text);",
+            &[
+                &member.foundation,
+                &member.name,
+                &member.level,
+                &member.logo_url,
+                &member.homepage_url,
+                &member.crunchbase_url,
+            ],
         )
         .await?;
-
-        Ok(())
+    }
+    for member_name in &changes.removed {
+        debug!(name = member_name, "removing member");
+        tx.execute(
+            "select remove_member($1::text, $2::text);",
+            &[&foundation, &member_name],
+        )
+        .await?;
+    }
+    for member in &changes.updated {
+        debug!(name = member.name, "updating member");
+        update_member(tx, member).await?;
     }
 
-    #[instrument(skip(self), err)]
-    async fn update_project(&self, project: &Project) -> Result<()> {
-        trace!("db: update project");
+    Ok(())
+}
 
-        let db = self.pool.get().await?;
-        db.execute(
-            "select update_project($1::text, $2::text, $3::text, $4::text);",
+/// Applies the projects changes provided using the transaction given.
+async fn apply_projects_changes(
+    tx: &Transaction<'_>,
+    foundation: &str,
+    changes: &Changes<Project>,
+) -> Result<()> {
+    for renamed in &changes.renamed {
+        debug!(from = renamed.from, to = renamed.to.name, "renaming project");
+        tx.execute(
+            "select rename_project($1::text, $2::text, $3::text);",
+            &[&foundation, &renamed.from, &renamed.to.name],
+        )
+        .await?;
+        update_project(tx, &renamed.to).await?;
+    }
+    for project in &changes.added {
+        debug!(name = project.name, "adding project");
+        tx.execute(
+            "
+                select add_project(
+                    $1::text, $2::text, $3::text, $4::text, $5::text, $6::text, $7::text, $8::text, $9::text
+                );
+                ",
             &[
                 &project.foundation,
                 &project.name,
                 &project.maturity,
                 &project.logo_url,
+                &project.homepage_url,
+                &project.repository_url,
+                &project.category,
+                &project.subcategory,
+                &project.description,
             ],
         )
         .await?;
-
-        Ok(())
     }
+    for project_name in &changes.removed {
+        debug!(name = project_name, "removing project");
+        tx.execute(
+            "select remove_project($1::text, $2::text);",
+            &[&foundation, &project_name],
+        )
+        .await?;
+    }
+    for project in &changes.updated {
+        debug!(name = project.name, "updating project");
+        update_project(tx, project).await?;
+    }
+
+    Ok(())
+}
+
+/// Updates an existing member's information using the transaction given.
+async fn update_member(tx: &Transaction<'_>, member: &Member) -> Result<()> {
+    tx.execute(
+        "select update_member($1::text, $2::text, $3::text, $4::text, $5::text, $6::text);",
+        &[
+            &member.foundation,
+            &member.name,
+            &member.level,
+            &member.logo_url,
+            &member.homepage_url,
+            &member.crunchbase_url,
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Updates an existing project's information using the transaction given.
+async fn update_project(tx: &Transaction<'_>, project: &Project) -> Result<()> {
+    tx.execute(
+        "
+            select update_project(
+                $1::text, $2::text, $3::text, $4::text, $5::text, $6::text, $7::text, $8::text, $9::text
+            );
+            ",
+        &[
+            &project.foundation,
+            &project.name,
+            &project.maturity,
+            &project.logo_url,
+            &project.homepage_url,
+            &project.repository_url,
+            &project.category,
+            &project.subcategory,
+            &project.description,
+        ],
+    )
+    .await?;
+
+    Ok(())
 }
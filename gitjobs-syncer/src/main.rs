@@ -1,7 +1,7 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::struct_field_names)]
 
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use anyhow::{Context, Result};
 use clap::Parser;
@@ -10,7 +10,9 @@ use db::PgDB;
 use deadpool_postgres::Runtime;
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
-use syncer::Syncer;
+use syncer::{DiffFormat, FoundationDiff, Syncer};
+use tokio::signal;
+use tracing::info;
 use tracing_subscriber::EnvFilter;
 
 mod config;
@@ -24,6 +26,18 @@ struct Args {
     /// Optional path to the configuration file.
     #[clap(short, long)]
     config_file: Option<PathBuf>,
+
+    /// Keep running, synchronizing each foundation periodically.
+    #[clap(long, conflicts_with = "dry_run")]
+    daemon: bool,
+
+    /// Print the changes that would be applied without applying them.
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Format used to print the changes in dry run mode.
+    #[clap(long, value_enum, default_value_t = DiffFormat::Text, requires = "dry_run")]
+    diff_format: DiffFormat,
 }
 
 /// Main entry point for the application.
@@ -54,7 +68,57 @@ async fn main() -> Result<()> {
     let db = Arc::new(PgDB::new(pool));
 
     // Run syncer
-    Syncer::new(db).run().await?;
+    let syncer = Syncer::new(cfg.sync, db);
+    if args.dry_run {
+        let diffs = syncer.dry_run().await?;
+        print_diffs(&diffs, args.diff_format)?;
+    } else if args.daemon {
+        let interval = Duration::from_secs(cfg.daemon.interval);
+        tokio::select! {
+            result = syncer.run_daemon(interval) => result?,
+            () = shutdown_signal() => info!("shutdown signal received, stopping"),
+        }
+    } else {
+        syncer.run().await?;
+    }
 
     Ok(())
 }
+
+/// Prints the changes found in a dry run in the format provided.
+fn print_diffs(diffs: &[FoundationDiff], format: DiffFormat) -> Result<()> {
+    match format {
+        DiffFormat::Json => println!("{}", serde_json::to_string_pretty(diffs)?),
+        DiffFormat::Text => diffs.iter().for_each(|diff| print!("{diff}")),
+    }
+
+    Ok(())
+}
+
+/// Returns a future that completes when the program receives a shutdown signal.
+async fn shutdown_signal() {
+    // Setup ctrl+c signal handler.
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("failed to install ctrl+c signal handler");
+    };
+
+    #[cfg(unix)]
+    // Setup terminate signal handler (Unix only).
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install terminate signal handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    // Wait for either ctrl+c or terminate signal.
+    tokio::select! {
+        () = ctrl_c => {},
+        () = terminate => {},
+    }
+}
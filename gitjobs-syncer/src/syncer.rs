@@ -1,16 +1,20 @@
 //! This module defines types and logic to synchronize foundation members and projects
 //! with the `GitJobs` database.
 
-use std::{sync::LazyLock, time::Duration};
+use std::{fmt, sync::LazyLock, time::Duration};
 
-use anyhow::{Context, Error, Result, format_err};
+use anyhow::{Context, Error, Result, bail, format_err};
+use chrono::{DateTime, Utc};
 use futures::stream::{self, StreamExt};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use tokio::time::timeout;
-use tracing::{debug, info, instrument};
+use tokio::time::{sleep, timeout};
+use tracing::{error, info, instrument};
 
-use crate::db::DynDB;
+use crate::{config::SyncConfig, db::DynDB};
+
+/// How often the daemon checks if any foundation is due to be synchronized.
+const DAEMON_CHECK_FREQUENCY: Duration = Duration::from_mins(1);
 
 /// Maximum time, in seconds, allowed for synchronizing a foundation.
 const FOUNDATION_TIMEOUT: u64 = 300;
@@ -18,6 +22,8 @@ const FOUNDATION_TIMEOUT: u64 = 300;
 /// Responsible for synchronizing members and projects of all registered foundations.
 /// Feeds from the landscape API and updates the `GitJobs` database accordingly.
 pub(crate) struct Syncer {
+    /// Synchronization configuration.
+    cfg: SyncConfig,
     /// Database handle for storing and retrieving foundation data.
     db: DynDB,
     /// HTTP client used to fetch data from the landscape API.
@@ -26,8 +32,9 @@ pub(crate) struct Syncer {
 
 impl Syncer {
     /// Create a new `Syncer` instance.
-    pub(crate) fn new(db: DynDB) -> Self {
+    pub(crate) fn new(cfg: SyncConfig, db: DynDB) -> Self {
         Self {
+            cfg,
             db,
             http_client: reqwest::Client::new(),
         }
@@ -39,20 +46,69 @@ impl Syncer {
         info!("started");
 
         let foundations = self.db.list_foundations().await?;
-        #[allow(clippy::manual_try_fold)]
-        let result = stream::iter(foundations)
-            .map(|foundation| async {
-                let foundation_name = foundation.name.clone();
-                match timeout(
-                    Duration::from_secs(FOUNDATION_TIMEOUT),
-                    self.sync_foundation(foundation),
-                )
+        let result = self.sync_foundations(foundations).await;
+
+        info!("finished");
+        result
+    }
+
+    /// Returns the changes that a synchronization would apply to all registered
+    /// foundations, without applying them.
+    #[instrument(skip_all, err)]
+    pub(crate) async fn dry_run(&self) -> Result<Vec<FoundationDiff>> {
+        let foundations = self.db.list_foundations().await?;
+        let mut diffs = Vec::with_capacity(foundations.len());
+        for foundation in foundations {
+            let mut diff = self
+                .diff_foundation(&foundation)
                 .await
-                {
-                    Ok(result) => result,
-                    Err(err) => Err(err.into()),
+                .context(format!("error checking foundation {}", foundation.name))?;
+            diff.warnings = diff.removal_threshold_violations(self.cfg.max_removal_percentage);
+            diffs.push(diff);
+        }
+
+        Ok(diffs)
+    }
+
+    /// Run the syncer in daemon mode, synchronizing each foundation when the
+    /// interval provided has elapsed since its last synchronization started.
+    #[instrument(skip_all, err)]
+    pub(crate) async fn run_daemon(&self, interval: Duration) -> anyhow::Result<()> {
+        info!(interval_secs = interval.as_secs(), "daemon started");
+
+        loop {
+            // Synchronize the foundations due
+            match self.db.list_foundations().await {
+                Ok(foundations) => {
+                    let now = Utc::now();
+                    let foundations_due: Vec<Foundation> = foundations
+                        .into_iter()
+                        .filter(|foundation| foundation.is_sync_due(now, interval))
+                        .collect();
+                    if !foundations_due.is_empty()
+                        && let Err(err) = self.sync_foundations(foundations_due).await
+                    {
+                        error!("error synchronizing foundations: {err:#}");
+                    }
                 }
-                .context(format!("error synchronizing foundation {foundation_name}"))
+                Err(err) => error!("error listing foundations: {err:#}"),
+            }
+
+            // Pause for a while before the next check
+            sleep(DAEMON_CHECK_FREQUENCY).await;
+        }
+    }
+
+    /// Synchronize the provided foundations concurrently, returning an error
+    /// including all the errors found, if any.
+    async fn sync_foundations(&self, foundations: Vec<Foundation>) -> Result<()> {
+        #[allow(clippy::manual_try_fold)]
+        stream::iter(foundations)
+            .map(|foundation| async move {
+                let foundation_name = foundation.name.clone();
+                self.sync_foundation(foundation)
+                    .await
+                    .context(format!("error synchronizing foundation {foundation_name}"))
             })
             .buffer_unordered(3)
             .collect::<Vec<Result<()>>>()
@@ -67,27 +123,77 @@ impl Syncer {
                         Err(final_err) => Err(format_err!("{final_err:#}\n{task_err:#}")),
                     },
                 },
-            );
-
-        info!("finished");
-        result
+            )
     }
 
-    /// Synchronize the members and projects of the provided foundation.
+    /// Synchronize the members and projects of the provided foundation, recording
+    /// the synchronization run in the database.
     #[instrument(fields(foundation = foundation.name), skip_all, err)]
     async fn sync_foundation(&self, foundation: Foundation) -> Result<()> {
         info!("started");
 
-        self.sync_members(foundation.clone()).await?;
-        self.sync_projects(foundation).await?;
+        // Synchronize members and projects, tracking the changes applied
+        let sync_run_id = self.db.start_sync_run(&foundation.name).await?;
+        let mut stats = SyncStats::default();
+        let result = match timeout(
+            Duration::from_secs(FOUNDATION_TIMEOUT),
+            self.sync_foundation_entries(&foundation, &mut stats),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(err) => Err(err.into()),
+        };
+
+        // Record the result of the synchronization run (when the synchronization
+        // failed, an error recording it must not hide the original error)
+        let error = result.as_ref().err().map(|err| format!("{err:#}"));
+        if let Err(err) = self.db.finish_sync_run(&sync_run_id, &stats, error.as_deref()).await {
+            if result.is_ok() {
+                return Err(err.context("error recording synchronization run"));
+            }
+            error!("error recording synchronization run: {err:#}");
+        }
+
+        info!(?stats, "finished");
+        result
+    }
+
+    /// Synchronize the members and projects of the provided foundation.
+    ///
+    /// All changes are applied in a single transaction. No changes are applied
+    /// when the number of members or projects that would be removed exceeds the
+    /// configured threshold.
+    async fn sync_foundation_entries(&self, foundation: &Foundation, stats: &mut SyncStats) -> Result<()> {
+        let diff = self.diff_foundation(foundation).await?;
+        if let Some(violation) = diff
+            .removal_threshold_violations(self.cfg.max_removal_percentage)
+            .first()
+        {
+            bail!("aborting synchronization: {violation}");
+        }
+
+        self.db.apply_foundation_diff(&diff).await?;
+        *stats = SyncStats::from(&diff);
 
-        info!("finished");
         Ok(())
     }
 
-    /// Synchronize the members of the provided foundation.
+    /// Returns the changes needed to synchronize the members and projects of the
+    /// provided foundation.
+    async fn diff_foundation(&self, foundation: &Foundation) -> Result<FoundationDiff> {
+        Ok(FoundationDiff {
+            foundation: foundation.name.clone(),
+            members: self.diff_members(foundation).await?,
+            projects: self.diff_projects(foundation).await?,
+            warnings: vec![],
+        })
+    }
+
+    /// Returns the changes needed to synchronize the members of the provided
+    /// foundation.
     #[instrument(fields(foundation = foundation.name), skip_all, err)]
-    async fn sync_members(&self, foundation: Foundation) -> Result<()> {
+    async fn diff_members(&self, foundation: &Foundation) -> Result<Changes<Member>> {
         // Get members from landscape
         let url = format!(
             "{}/api/members/all.json",
@@ -112,8 +218,8 @@ impl Syncer {
         // Get members from database
         let members_in_db = self.db.list_members(&foundation.name).await?;
 
-        // Add new members (members in landscape but not in db)
-        let members_added: Vec<Member> = members_in_landscape
+        // New members (members in landscape but not in db)
+        let added = members_in_landscape
             .iter()
             .filter(|landscape_member| {
                 !members_in_db
@@ -121,61 +227,51 @@ impl Syncer {
                     .any(|db_member| db_member.name == landscape_member.name)
                     && !landscape_member.name.to_lowercase().contains("non-public")
             })
-            .map(|landscape_member| Member {
-                foundation: foundation.name.clone(),
-                name: landscape_member.name.clone(),
-                level: landscape_member.subcategory.clone(),
-                logo_url: landscape_member.logo_url.clone(),
-            })
+            .map(|landscape_member| landscape_member.to_member(&foundation.name))
             .collect();
-        for member in members_added {
-            debug!(name = member.name, "adding member");
-            self.db.add_member(&member).await?;
-        }
 
-        // Remove non-existing members (members in db but not in landscape)
-        let members_removed: Vec<&String> = members_in_db
+        // Non-existing members (members in db but not in landscape)
+        let removed = members_in_db
             .iter()
             .filter(|db_member| {
                 !members_in_landscape
                     .iter()
                     .any(|landscape_member| landscape_member.name == db_member.name)
             })
-            .map(|db_member| &db_member.name)
+            .map(|db_member| db_member.name.clone())
             .collect();
-        for member_name in members_removed {
-            debug!(name = member_name, "removing member");
-            self.db.remove_member(&foundation.name, member_name).await?;
-        }
 
-        // Update existing members (members in both landscape and db)
-        let members_updated: Vec<Member> = members_in_landscape
+        // Existing members updated (members in both landscape and db)
+        let updated = members_in_landscape
             .iter()
             .filter(|landscape_member| {
                 members_in_db.iter().any(|db_member| {
                     db_member.name == landscape_member.name
                         && (db_member.level != landscape_member.subcategory
-                            || db_member.logo_url != landscape_member.logo_url)
+                            || db_member.logo_url != landscape_member.logo_url
+                            || db_member.homepage_url != landscape_member.homepage_url
+                            || db_member.crunchbase_url != landscape_member.crunchbase_url)
                 })
             })
-            .map(|landscape_member| Member {
-                foundation: foundation.name.clone(),
-                name: landscape_member.name.clone(),
-                level: landscape_member.subcategory.clone(),
-                logo_url: landscape_member.logo_url.clone(),
-            })
+            .map(|landscape_member| landscape_member.to_member(&foundation.name))
             .collect();
-        for member in members_updated {
-            debug!(name = member.name, "updating member");
-            self.db.update_member(&member).await?;
-        }
 
-        Ok(())
+        let mut changes = Changes {
+            existing: members_in_db.len(),
+            added,
+            removed,
+            renamed: vec![],
+            updated,
+        };
+        changes.detect_renames(&members_in_db);
+
+        Ok(changes)
     }
 
-    /// Synchronize the projects of the provided foundation.
+    /// Returns the changes needed to synchronize the projects of the provided
+    /// foundation.
     #[instrument(fields(foundation = foundation.name), skip_all, err)]
-    async fn sync_projects(&self, foundation: Foundation) -> Result<()> {
+    async fn diff_projects(&self, foundation: &Foundation) -> Result<Changes<Project>> {
         // Get projects from landscape
         let url = format!(
             "{}/api/projects/all.json",
@@ -196,8 +292,8 @@ impl Syncer {
         // Get projects from database
         let projects_in_db = self.db.list_projects(&foundation.name).await?;
 
-        // Add new projects (projects in landscape but not in db)
-        let projects_added: Vec<Project> = projects_in_landscape
+        // New projects (projects in landscape but not in db)
+        let added = projects_in_landscape
             .iter()
             .filter(|landscape_project| {
                 !projects_in_db
@@ -205,56 +301,48 @@ impl Syncer {
                     .any(|db_project| db_project.name == landscape_project.name)
                     && landscape_project.maturity != "archived"
             })
-            .map(|landscape_project| Project {
-                foundation: foundation.name.clone(),
-                name: landscape_project.name.clone(),
-                maturity: landscape_project.maturity.clone(),
-                logo_url: landscape_project.logo_url.clone(),
-            })
+            .map(|landscape_project| landscape_project.to_project(&foundation.name))
             .collect();
-        for project in projects_added {
-            debug!(name = project.name, "adding project");
-            self.db.add_project(&project).await?;
-        }
 
-        // Remove non-existing projects (projects in db but not in landscape)
-        let projects_removed: Vec<&String> = projects_in_db
+        // Non-existing projects (projects in db but not in landscape)
+        let removed = projects_in_db
             .iter()
             .filter(|db_project| {
                 !projects_in_landscape
                     .iter()
                     .any(|landscape_project| landscape_project.name == db_project.name)
             })
-            .map(|db_project| &db_project.name)
+            .map(|db_project| db_project.name.clone())
             .collect();
-        for project_name in projects_removed {
-            debug!(name = project_name, "removing project");
-            self.db.remove_project(&foundation.name, project_name).await?;
-        }
 
-        // Update existing projects (projects in both landscape and db)
-        let projects_updated: Vec<Project> = projects_in_landscape
+        // Existing projects updated (projects in both landscape and db)
+        let updated = projects_in_landscape
             .iter()
             .filter(|landscape_project| {
                 projects_in_db.iter().any(|db_project| {
                     db_project.name == landscape_project.name
                         && (db_project.maturity != landscape_project.maturity
-                            || db_project.logo_url != landscape_project.logo_url)
+                            || db_project.logo_url != landscape_project.logo_url
+                            || db_project.homepage_url != landscape_project.homepage_url
+                            || db_project.repository_url != landscape_project.repository_url()
+                            || db_project.category != landscape_project.category
+                            || db_project.subcategory != landscape_project.subcategory
+                            || db_project.description != landscape_project.description)
                 })
             })
-            .map(|landscape_project| Project {
-                foundation: foundation.name.clone(),
-                name: landscape_project.name.clone(),
-                maturity: landscape_project.maturity.clone(),
-                logo_url: landscape_project.logo_url.clone(),
-            })
+            .map(|landscape_project| landscape_project.to_project(&foundation.name))
             .collect();
-        for project in projects_updated {
-            debug!(name = project.name, "updating project");
-            self.db.update_project(&project).await?;
-        }
 
-        Ok(())
+        let mut changes = Changes {
+            existing: projects_in_db.len(),
+            added,
+            removed,
+            renamed: vec![],
+            updated,
+        };
+        changes.detect_renames(&projects_in_db);
+
+        Ok(changes)
     }
 }
 
@@ -264,6 +352,114 @@ static MEMBER_KIND: LazyLock<Regex> =
 
 // Types.
 
+/// Changes needed to synchronize the members or projects of a foundation.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Changes<T> {
+    /// Entries in the landscape that are not in the database yet.
+    pub added: Vec<T>,
+    /// Names of the entries in the database that are no longer in the landscape.
+    pub removed: Vec<String>,
+    /// Entries in the database whose name has changed in the landscape.
+    pub renamed: Vec<Renamed<T>>,
+    /// Entries whose details in the landscape have changed.
+    pub updated: Vec<T>,
+
+    /// Number of entries in the database before applying the changes.
+    #[serde(skip)]
+    pub existing: usize,
+}
+
+impl<T: Entry> Changes<T> {
+    /// Turns the added entries that share a landscape URL with a removed entry
+    /// into renames, so that the existing entry and its associations are kept.
+    /// Ambiguous matches, where an added entry matches several removed entries
+    /// or several added entries match the same removed one, are not renames.
+    fn detect_renames(&mut self, entries_in_db: &[T]) {
+        // Removed entries in the database matching each added entry
+        let matches: Vec<Vec<&T>> = self
+            .added
+            .iter()
+            .map(|entry| {
+                let urls = entry.normalized_urls();
+                entries_in_db
+                    .iter()
+                    .filter(|db_entry| {
+                        self.removed.iter().any(|name| name == db_entry.name())
+                            && db_entry.normalized_urls().iter().any(|url| urls.contains(url))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Turn the unambiguous matches into renames
+        let mut added = Vec::with_capacity(self.added.len());
+        let mut renamed = vec![];
+        for (entry, entry_matches) in std::mem::take(&mut self.added).into_iter().zip(&matches) {
+            let unambiguous_match = match entry_matches.as_slice() {
+                [previous] => {
+                    matches
+                        .iter()
+                        .filter(|other_matches| {
+                            other_matches.iter().any(|other| other.name() == previous.name())
+                        })
+                        .count()
+                        == 1
+                }
+                _ => false,
+            };
+            if unambiguous_match {
+                renamed.push(Renamed {
+                    from: entry_matches[0].name().to_string(),
+                    to: entry,
+                });
+            } else {
+                added.push(entry);
+            }
+        }
+        self.removed
+            .retain(|name| !renamed.iter().any(|renamed| &renamed.from == name));
+        self.added = added;
+        self.renamed.extend(renamed);
+    }
+
+    /// Checks if the percentage of existing entries that would be removed is
+    /// above the maximum provided.
+    #[allow(clippy::cast_precision_loss)]
+    fn exceeds_removal_threshold(&self, max_removal_percentage: f64) -> bool {
+        if self.existing == 0 {
+            return false;
+        }
+        self.removed.len() as f64 * 100.0 / self.existing as f64 > max_removal_percentage
+    }
+}
+
+/// Member or project that can be matched against the landscape.
+pub(crate) trait Entry {
+    /// Name of the entry.
+    fn name(&self) -> &str;
+
+    /// Landscape URLs that identify the entry regardless of its name.
+    fn urls(&self) -> Vec<&str>;
+
+    /// Landscape URLs of the entry, normalized to be compared.
+    fn normalized_urls(&self) -> Vec<String> {
+        self.urls()
+            .into_iter()
+            .map(|url| url.trim().trim_end_matches('/').to_lowercase())
+            .filter(|url| !url.is_empty())
+            .collect()
+    }
+}
+
+/// Format used to print the changes found in a dry run.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub(crate) enum DiffFormat {
+    /// JSON document, for processing it with other tools.
+    Json,
+    /// Human-readable text.
+    Text,
+}
+
 /// Foundation details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Foundation {
@@ -271,6 +467,119 @@ pub(crate) struct Foundation {
     pub name: String,
     /// Base URL of the foundation's landscape API.
     pub landscape_url: String,
+
+    /// Time the last synchronization of the foundation started.
+    #[serde(default)]
+    pub last_sync_started_at: Option<DateTime<Utc>>,
+}
+
+impl Foundation {
+    /// Checks if the foundation is due to be synchronized at the given time.
+    fn is_sync_due(&self, now: DateTime<Utc>, interval: Duration) -> bool {
+        let Some(last_sync_started_at) = self.last_sync_started_at else {
+            return true;
+        };
+        (now - last_sync_started_at).to_std().unwrap_or_default() >= interval
+    }
+}
+
+/// Changes needed to synchronize a foundation.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FoundationDiff {
+    /// Name of the foundation.
+    pub foundation: String,
+    /// Changes to the foundation's members.
+    pub members: Changes<Member>,
+    /// Changes to the foundation's projects.
+    pub projects: Changes<Project>,
+    /// Issues that would prevent the changes from being applied.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl FoundationDiff {
+    /// Returns the reasons why applying these changes would exceed the maximum
+    /// percentage of existing members or projects that can be removed.
+    pub(crate) fn removal_threshold_violations(&self, max_removal_percentage: f64) -> Vec<String> {
+        let mut violations = vec![];
+        if self.members.exceeds_removal_threshold(max_removal_percentage) {
+            violations.push(format!(
+                "{} of {} existing members would be removed (maximum allowed: {max_removal_percentage}%)",
+                self.members.removed.len(),
+                self.members.existing
+            ));
+        }
+        if self.projects.exceeds_removal_threshold(max_removal_percentage) {
+            violations.push(format!(
+                "{} of {} existing projects would be removed (maximum allowed: {max_removal_percentage}%)",
+                self.projects.removed.len(),
+                self.projects.existing
+            ));
+        }
+        violations
+    }
+}
+
+impl fmt::Display for FoundationDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.foundation)?;
+
+        writeln!(
+            f,
+            "  members: {} added, {} removed, {} renamed, {} updated",
+            self.members.added.len(),
+            self.members.removed.len(),
+            self.members.renamed.len(),
+            self.members.updated.len()
+        )?;
+        for member in &self.members.added {
+            writeln!(f, "    + {} ({})", member.name, member.level)?;
+        }
+        for member_name in &self.members.removed {
+            writeln!(f, "    - {member_name}")?;
+        }
+        for renamed in &self.members.renamed {
+            writeln!(
+                f,
+                "    > {} -> {} ({})",
+                renamed.from, renamed.to.name, renamed.to.level
+            )?;
+        }
+        for member in &self.members.updated {
+            writeln!(f, "    ~ {} ({})", member.name, member.level)?;
+        }
+
+        writeln!(
+            f,
+            "  projects: {} added, {} removed, {} renamed, {} updated",
+            self.projects.added.len(),
+            self.projects.removed.len(),
+            self.projects.renamed.len(),
+            self.projects.updated.len()
+        )?;
+        for project in &self.projects.added {
+            writeln!(f, "    + {} ({})", project.name, project.maturity)?;
+        }
+        for project_name in &self.projects.removed {
+            writeln!(f, "    - {project_name}")?;
+        }
+        for renamed in &self.projects.renamed {
+            writeln!(
+                f,
+                "    > {} -> {} ({})",
+                renamed.from, renamed.to.name, renamed.to.maturity
+            )?;
+        }
+        for project in &self.projects.updated {
+            writeln!(f, "    ~ {} ({})", project.name, project.maturity)?;
+        }
+
+        for warning in &self.warnings {
+            writeln!(f, "  warning: synchronization would be aborted, {warning}")?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Details of a member as returned by the landscape API.
@@ -282,6 +591,27 @@ struct LandscapeMember {
     subcategory: String,
     /// URL to the member's logo image.
     logo_url: String,
+
+    /// URL to the member's Crunchbase profile.
+    #[serde(default)]
+    crunchbase_url: Option<String>,
+    /// URL to the member's homepage.
+    #[serde(default)]
+    homepage_url: Option<String>,
+}
+
+impl LandscapeMember {
+    /// Converts the landscape member into a member of the foundation provided.
+    fn to_member(&self, foundation: &str) -> Member {
+        Member {
+            foundation: foundation.to_string(),
+            name: self.name.clone(),
+            level: self.subcategory.clone(),
+            logo_url: self.logo_url.clone(),
+            crunchbase_url: self.crunchbase_url.clone(),
+            homepage_url: self.homepage_url.clone(),
+        }
+    }
 }
 
 /// Details of a project as returned by the landscape API.
@@ -293,6 +623,60 @@ struct LandscapeProject {
     logo_url: String,
     /// Project maturity level (e.g. "sandbox", "incubating", "graduated", "archived").
     maturity: String,
+
+    /// Landscape category of the project (e.g. "Observability and Analysis").
+    #[serde(default)]
+    category: Option<String>,
+    /// Short description of the project.
+    #[serde(default)]
+    description: Option<String>,
+    /// URL to the project's homepage.
+    #[serde(default)]
+    homepage_url: Option<String>,
+    /// Landscape subcategory of the project (e.g. "Monitoring").
+    #[serde(default)]
+    subcategory: Option<String>,
+    /// Repositories of the project.
+    #[serde(default)]
+    repositories: Vec<LandscapeRepository>,
+}
+
+impl LandscapeProject {
+    /// Returns the URL of the project's primary repository, falling back to the
+    /// first repository listed when none is flagged as primary.
+    fn repository_url(&self) -> Option<String> {
+        self.repositories
+            .iter()
+            .find(|repository| repository.primary.unwrap_or(false))
+            .or_else(|| self.repositories.first())
+            .map(|repository| repository.url.clone())
+    }
+
+    /// Converts the landscape project into a project of the foundation provided.
+    fn to_project(&self, foundation: &str) -> Project {
+        Project {
+            foundation: foundation.to_string(),
+            name: self.name.clone(),
+            maturity: self.maturity.clone(),
+            logo_url: self.logo_url.clone(),
+            category: self.category.clone(),
+            description: self.description.clone(),
+            homepage_url: self.homepage_url.clone(),
+            repository_url: self.repository_url(),
+            subcategory: self.subcategory.clone(),
+        }
+    }
+}
+
+/// Details of a project's repository as returned by the landscape API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LandscapeRepository {
+    /// URL of the repository.
+    url: String,
+
+    /// Whether this is the project's primary repository.
+    #[serde(default)]
+    primary: Option<bool>,
 }
 
 /// Member details as stored in the database.
@@ -306,6 +690,55 @@ pub(crate) struct Member {
     pub level: String,
     /// URL to the member's logo image.
     pub logo_url: String,
+
+    /// URL to the member's Crunchbase profile.
+    #[serde(default)]
+    pub crunchbase_url: Option<String>,
+    /// URL to the member's homepage.
+    #[serde(default)]
+    pub homepage_url: Option<String>,
+}
+
+impl Entry for Member {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn urls(&self) -> Vec<&str> {
+        self.homepage_url.iter().map(String::as_str).collect()
+    }
+}
+
+/// Number of changes applied during a foundation synchronization.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct SyncStats {
+    /// Number of members added.
+    pub members_added: u32,
+    /// Number of members removed.
+    pub members_removed: u32,
+    /// Number of members updated.
+    pub members_updated: u32,
+    /// Number of projects added.
+    pub projects_added: u32,
+    /// Number of projects removed.
+    pub projects_removed: u32,
+    /// Number of projects updated.
+    pub projects_updated: u32,
+}
+
+impl From<&FoundationDiff> for SyncStats {
+    /// Renamed entries are counted as updated.
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(diff: &FoundationDiff) -> Self {
+        Self {
+            members_added: diff.members.added.len() as u32,
+            members_removed: diff.members.removed.len() as u32,
+            members_updated: (diff.members.renamed.len() + diff.members.updated.len()) as u32,
+            projects_added: diff.projects.added.len() as u32,
+            projects_removed: diff.projects.removed.len() as u32,
+            projects_updated: (diff.projects.renamed.len() + diff.projects.updated.len()) as u32,
+        }
+    }
 }
 
 /// Project details as stored in the database.
@@ -319,4 +752,282 @@ pub(crate) struct Project {
     pub maturity: String,
     /// URL to the project's logo image.
     pub logo_url: String,
+
+    /// Landscape category of the project.
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Short description of the project.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// URL to the project's homepage.
+    #[serde(default)]
+    pub homepage_url: Option<String>,
+    /// URL to the project's primary repository.
+    #[serde(default)]
+    pub repository_url: Option<String>,
+    /// Landscape subcategory of the project.
+    #[serde(default)]
+    pub subcategory: Option<String>,
+}
+
+impl Entry for Project {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn urls(&self) -> Vec<&str> {
+        self.repository_url
+            .iter()
+            .chain(self.homepage_url.iter())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Entry in the database that has been renamed in the landscape.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Renamed<T> {
+    /// Name of the entry in the database.
+    pub from: String,
+    /// Entry details in the landscape, including its new name.
+    pub to: T,
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::TimeDelta;
+    use uuid::Uuid;
+
+    use crate::db::MockDB;
+
+    use super::*;
+
+    #[test]
+    fn exceeds_removal_threshold_boundaries() {
+        assert!(!removal_changes(10, 2).exceeds_removal_threshold(20.0));
+        assert!(removal_changes(10, 3).exceeds_removal_threshold(20.0));
+        assert!(removal_changes(3, 1).exceeds_removal_threshold(0.0));
+        assert!(!removal_changes(3, 0).exceeds_removal_threshold(0.0));
+        assert!(!removal_changes(3, 3).exceeds_removal_threshold(100.0));
+    }
+
+    #[test]
+    fn exceeds_removal_threshold_empty_db() {
+        assert!(!removal_changes(0, 0).exceeds_removal_threshold(0.0));
+    }
+
+    #[test]
+    fn is_sync_due_first_run() {
+        let foundation = foundation(None);
+
+        assert!(foundation.is_sync_due(Utc::now(), Duration::from_hours(1)));
+    }
+
+    #[test]
+    fn is_sync_due_interval_elapsed() {
+        let now = Utc::now();
+
+        assert!(foundation(Some(now - TimeDelta::hours(1))).is_sync_due(now, Duration::from_hours(1)));
+        assert!(foundation(Some(now - TimeDelta::hours(2))).is_sync_due(now, Duration::from_hours(1)));
+    }
+
+    #[test]
+    fn is_sync_due_interval_not_elapsed() {
+        let now = Utc::now();
+
+        assert!(!foundation(Some(now - TimeDelta::minutes(59))).is_sync_due(now, Duration::from_hours(1)));
+        assert!(!foundation(Some(now + TimeDelta::minutes(5))).is_sync_due(now, Duration::from_hours(1)));
+    }
+
+    #[tokio::test]
+    async fn sync_foundation_returns_sync_error_when_recording_run_fails() {
+        // Setup database mock
+        let sync_run_id = Uuid::new_v4();
+        let mut db = MockDB::new();
+        db.expect_start_sync_run()
+            .times(1)
+            .returning(move |_| Box::pin(async move { Ok(sync_run_id) }));
+        db.expect_finish_sync_run()
+            .times(1)
+            .withf(move |id, _, error| {
+                *id == sync_run_id && error.is_some_and(|error| error.contains("error fetching landscape"))
+            })
+            .returning(|_, _, _| Box::pin(async { Err(format_err!("database unavailable")) }));
+
+        // Synchronize foundation (the landscape is not reachable)
+        let cfg = SyncConfig {
+            max_removal_percentage: 20.0,
+        };
+        let syncer = Syncer::new(cfg, Arc::new(db));
+        let err = syncer.sync_foundation(foundation(None)).await.unwrap_err();
+
+        assert!(format!("{err:#}").contains("error fetching landscape"));
+    }
+
+    #[test]
+    fn detect_renames_by_member_homepage() {
+        let members_in_db = vec![member("Old Name", Some("https://example.com/"))];
+        let mut changes = changes(
+            &members_in_db,
+            vec![member("New Name", Some("https://EXAMPLE.com"))],
+        );
+
+        changes.detect_renames(&members_in_db);
+
+        assert!(changes.added.is_empty());
+        assert!(changes.removed.is_empty());
+        assert_eq!(changes.renamed.len(), 1);
+        assert_eq!(changes.renamed[0].from, "Old Name");
+        assert_eq!(changes.renamed[0].to.name, "New Name");
+    }
+
+    #[test]
+    fn detect_renames_by_project_repository() {
+        let projects_in_db = vec![project("old-name", Some("https://github.com/org/repo"), None)];
+        let mut changes = changes(
+            &projects_in_db,
+            vec![project(
+                "new-name",
+                Some("https://github.com/org/repo/"),
+                Some("https://new-name.io"),
+            )],
+        );
+
+        changes.detect_renames(&projects_in_db);
+
+        assert!(changes.added.is_empty());
+        assert!(changes.removed.is_empty());
+        assert_eq!(changes.renamed.len(), 1);
+        assert_eq!(changes.renamed[0].from, "old-name");
+        assert_eq!(changes.renamed[0].to.name, "new-name");
+    }
+
+    #[test]
+    fn detect_renames_ignores_added_entry_matching_several_removed_entries() {
+        let members_in_db = vec![
+            member("Old Name 1", Some("https://example.com")),
+            member("Old Name 2", Some("https://example.com")),
+        ];
+        let mut changes = changes(
+            &members_in_db,
+            vec![member("New Name", Some("https://example.com"))],
+        );
+
+        changes.detect_renames(&members_in_db);
+
+        assert_eq!(names(&changes.added), vec!["New Name"]);
+        assert_eq!(changes.removed, vec!["Old Name 1", "Old Name 2"]);
+        assert!(changes.renamed.is_empty());
+    }
+
+    #[test]
+    fn detect_renames_ignores_removed_entry_matched_by_several_added_entries() {
+        let members_in_db = vec![member("Old Name", Some("https://example.com"))];
+        let mut changes = changes(
+            &members_in_db,
+            vec![
+                member("New Name 1", Some("https://example.com")),
+                member("New Name 2", Some("https://example.com")),
+            ],
+        );
+
+        changes.detect_renames(&members_in_db);
+
+        assert_eq!(names(&changes.added), vec!["New Name 1", "New Name 2"]);
+        assert_eq!(changes.removed, vec!["Old Name"]);
+        assert!(changes.renamed.is_empty());
+    }
+
+    #[test]
+    fn detect_renames_keeps_entries_without_matches() {
+        let members_in_db = vec![
+            member("Removed", Some("https://removed.com")),
+            member("Without Homepage", None),
+        ];
+        let mut changes = changes(
+            &members_in_db,
+            vec![
+                member("Added", Some("https://added.com")),
+                member("No Homepage", None),
+            ],
+        );
+
+        changes.detect_renames(&members_in_db);
+
+        assert_eq!(names(&changes.added), vec!["Added", "No Homepage"]);
+        assert_eq!(changes.removed, vec!["Removed", "Without Homepage"]);
+        assert!(changes.renamed.is_empty());
+    }
+
+    // Helpers.
+
+    /// Returns changes adding the entries provided and removing all the ones in
+    /// the database.
+    fn changes<T: Entry>(entries_in_db: &[T], added: Vec<T>) -> Changes<T> {
+        Changes {
+            added,
+            removed: entries_in_db.iter().map(|entry| entry.name().to_string()).collect(),
+            renamed: vec![],
+            updated: vec![],
+            existing: entries_in_db.len(),
+        }
+    }
+
+    /// Returns a foundation whose last synchronization started at the time
+    /// provided.
+    fn foundation(last_sync_started_at: Option<DateTime<Utc>>) -> Foundation {
+        Foundation {
+            name: "cncf".to_string(),
+            landscape_url: "http://127.0.0.1:1".to_string(),
+            last_sync_started_at,
+        }
+    }
+
+    /// Returns a member with the name and homepage provided.
+    fn member(name: &str, homepage_url: Option<&str>) -> Member {
+        Member {
+            foundation: "cncf".to_string(),
+            name: name.to_string(),
+            level: "Gold".to_string(),
+            logo_url: "https://example.com/logo.svg".to_string(),
+            crunchbase_url: None,
+            homepage_url: homepage_url.map(ToString::to_string),
+        }
+    }
+
+    /// Returns the names of the entries provided.
+    fn names<T: Entry>(entries: &[T]) -> Vec<&str> {
+        entries.iter().map(Entry::name).collect()
+    }
+
+    /// Returns a project with the name, repository and homepage provided.
+    fn project(name: &str, repository_url: Option<&str>, homepage_url: Option<&str>) -> Project {
+        Project {
+            foundation: "cncf".to_string(),
+            name: name.to_string(),
+            maturity: "sandbox".to_string(),
+            logo_url: "https://example.com/logo.svg".to_string(),
+            category: None,
+            description: None,
+            homepage_url: homepage_url.map(ToString::to_string),
+            repository_url: repository_url.map(ToString::to_string),
+            subcategory: None,
+        }
+    }
+
+    /// Returns changes removing the number of entries provided out of the
+    /// existing ones.
+    fn removal_changes(existing: usize, removed: usize) -> Changes<Member> {
+        Changes {
+            added: vec![],
+            removed: (0..removed).map(|i| format!("member-{i}")).collect(),
+            renamed: vec![],
+            updated: vec![],
+            existing,
+        }
+    }
 }